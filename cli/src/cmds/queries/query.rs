@@ -28,7 +28,9 @@ use comfy_table::Color;
 use comfy_table::Table;
 use common_base::ProgressValues;
 use common_datavalues::DataSchemaRef;
+use http::header::AUTHORIZATION;
 use http::HeaderMap;
+use http::HeaderValue;
 use http::StatusCode;
 use http::Uri;
 use lexical_util::num::AsPrimitive;
@@ -209,7 +211,11 @@ fn build_endpoint(status: &Status, path: &str) -> Result<(reqwest::Client, Strin
     let query_configs = status.get_local_query_configs();
 
     let (_, query) = query_configs.get(0).expect("cannot find query configs");
+    // the local cluster is accessed as root, which has no password
+    let mut headers = HeaderMap::new();
+    headers.insert(AUTHORIZATION, HeaderValue::from_static("Basic cm9vdDo="));
     let client = reqwest::Client::builder()
+        .default_headers(headers)
         .build()
         .expect("Cannot build query client");
 
@@ -15,9 +15,12 @@
 
 use std::str::FromStr;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::Stream;
+use futures::TryStreamExt;
 use rusoto_core::credential::DefaultCredentialsProvider;
 use rusoto_core::credential::StaticProvider;
 use rusoto_core::ByteStream;
@@ -25,12 +28,15 @@ use rusoto_core::Client;
 use rusoto_core::HttpClient;
 use rusoto_core::Region;
 use rusoto_s3::DeleteObjectRequest;
+use rusoto_s3::ListObjectsV2Request;
 use rusoto_s3::PutObjectRequest;
 use rusoto_s3::S3Client;
 use rusoto_s3::S3 as RusotoS3;
 
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
+use crate::ObjectStream;
 use crate::S3InputStream;
 
 pub struct S3 {
@@ -189,4 +195,62 @@ impl DataAccessor for S3 {
 
         Ok(())
     }
+
+    async fn list(&self, prefix: &str) -> Result<ObjectStream> {
+        let client = self.client.clone();
+        let bucket = self.bucket.clone();
+        let prefix = prefix.to_owned();
+
+        // the state is the continuation token of the next page, None after the last page
+        let pages =
+            futures::stream::try_unfold(Some(None), move |state: Option<Option<String>>| {
+                let client = client.clone();
+                let req = state.map(|continuation_token| ListObjectsV2Request {
+                    bucket: bucket.clone(),
+                    prefix: Some(prefix.clone()),
+                    continuation_token,
+                    ..Default::default()
+                });
+                async move {
+                    let req = match req {
+                        Some(req) => req,
+                        None => return Ok(None),
+                    };
+                    let output = client
+                        .list_objects_v2(req)
+                        .await
+                        .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?;
+
+                    let objects = output
+                        .contents
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|object| {
+                            let last_modified = object
+                                .last_modified
+                                .and_then(|t| DateTime::parse_from_rfc3339(&t).ok())
+                                .map(|t| t.with_timezone(&Utc));
+                            Some(ObjectMeta {
+                                path: object.key?,
+                                size: object.size.unwrap_or_default() as u64,
+                                last_modified,
+                                etag: object.e_tag,
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    let next = match (output.is_truncated, output.next_continuation_token) {
+                        (Some(true), Some(token)) => Some(Some(token)),
+                        _ => None,
+                    };
+                    Ok::<_, ErrorCode>(Some((objects, next)))
+                }
+            });
+
+        Ok(Box::pin(
+            pages
+                .map_ok(|objects| futures::stream::iter(objects.into_iter().map(Ok)))
+                .try_flatten(),
+        ))
+    }
 }
@@ -15,6 +15,7 @@
 
 use std::sync::Arc;
 
+use azure_core::prelude::NextMarker;
 use azure_core::HttpClient;
 use azure_storage::clients::StorageAccountClient;
 use azure_storage::prelude::*;
@@ -23,11 +24,14 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::Stream;
 use futures::StreamExt;
+use futures::TryStreamExt;
 
 use crate::AzureBlobInputStream;
 use crate::Bytes;
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
+use crate::ObjectStream;
 
 pub struct AzureBlobAccessor {
     client: Arc<StorageClient>,
@@ -167,4 +171,51 @@ impl DataAccessor for AzureBlobAccessor {
             "remove not implemented for azure blob storage yet",
         ))
     }
+
+    async fn list(&self, prefix: &str) -> Result<ObjectStream> {
+        let container = self.client.as_container_client(&self.container);
+        let prefix = prefix.to_owned();
+
+        // the state is the marker of the next page, None after the last page
+        let pages =
+            futures::stream::try_unfold(Some(None), move |state: Option<Option<NextMarker>>| {
+                let container = container.clone();
+                let prefix = prefix.clone();
+                async move {
+                    let marker = match state {
+                        Some(marker) => marker,
+                        None => return Ok(None),
+                    };
+                    let mut req = container.list_blobs().prefix(prefix.as_str());
+                    if let Some(marker) = marker {
+                        req = req.next_marker(marker);
+                    }
+                    let response = req.execute().await.map_err(|e| {
+                        ErrorCode::DalTransportError(format!(
+                            "Failed on azure blob list operation, {}",
+                            e
+                        ))
+                    })?;
+
+                    let objects = response
+                        .blobs
+                        .blobs
+                        .into_iter()
+                        .map(|blob| ObjectMeta {
+                            path: blob.name,
+                            size: blob.properties.content_length,
+                            last_modified: Some(blob.properties.last_modified),
+                            etag: Some(blob.properties.etag.to_string()),
+                        })
+                        .collect::<Vec<_>>();
+                    Ok::<_, ErrorCode>(Some((objects, response.next_marker.map(Some))))
+                }
+            });
+
+        Ok(Box::pin(
+            pages
+                .map_ok(|objects| futures::stream::iter(objects.into_iter().map(Ok)))
+                .try_flatten(),
+        ))
+    }
 }
@@ -20,6 +20,8 @@ use std::path::Path;
 use std::path::PathBuf;
 
 use async_compat::CompatExt;
+use chrono::DateTime;
+use chrono::Utc;
 use common_base::tokio;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -29,6 +31,8 @@ use tokio::io::AsyncWriteExt;
 
 use crate::DataAccessor;
 use crate::InputStream;
+use crate::ObjectMeta;
+use crate::ObjectStream;
 
 pub struct Local {
     root: PathBuf,
@@ -110,8 +114,92 @@ impl DataAccessor for Local {
 
     async fn remove(&self, location: &str) -> Result<()> {
         let path = self.prefix_with_root(location)?;
-        std::fs::remove_file(path)?; // use std fs
-        Ok(())
+        // use std fs
+        std::fs::remove_file(path).map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                ErrorCode::DalPathNotFound(e.to_string())
+            } else {
+                e.into()
+            }
+        })
+    }
+
+    async fn list(&self, prefix: &str) -> Result<ObjectStream> {
+        // starts from the deepest directory of the prefix
+        let dir = match prefix.rfind('/') {
+            Some(pos) => self.prefix_with_root(&prefix[..pos])?,
+            None => self.root.clone(),
+        };
+
+        let lister = LocalLister {
+            root: self.root.clone(),
+            prefix: prefix.to_owned(),
+            dirs: if dir.is_dir() { vec![dir] } else { vec![] },
+            entries: None,
+        };
+        Ok(Box::pin(futures::stream::try_unfold(
+            lister,
+            LocalLister::next,
+        )))
+    }
+}
+
+/// Walks the directories which may contain objects of the prefix, one entry at a time.
+struct LocalLister {
+    root: PathBuf,
+    prefix: String,
+    dirs: Vec<PathBuf>,
+    entries: Option<tokio::fs::ReadDir>,
+}
+
+impl LocalLister {
+    async fn next(mut self) -> Result<Option<(ObjectMeta, Self)>> {
+        loop {
+            let entries = match &mut self.entries {
+                Some(entries) => entries,
+                None => match self.dirs.pop() {
+                    Some(dir) => {
+                        self.entries = Some(tokio::fs::read_dir(dir).await?);
+                        continue;
+                    }
+                    None => return Ok(None),
+                },
+            };
+            let entry = match entries.next_entry().await? {
+                Some(entry) => entry,
+                None => {
+                    self.entries = None;
+                    continue;
+                }
+            };
+            let path = entry.path();
+            let metadata = entry.metadata().await?;
+
+            // paths of the objects are separated by '/', whatever the platform is
+            let relative_path = path
+                .strip_prefix(&self.root)
+                .map_err(|e| ErrorCode::DalTransportError(e.to_string()))?
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            if metadata.is_dir() {
+                // only descends into the directories which may contain objects of the prefix
+                let dir_path = format!("{}/", relative_path);
+                if dir_path.starts_with(&self.prefix) || self.prefix.starts_with(&dir_path) {
+                    self.dirs.push(path);
+                }
+            } else if relative_path.starts_with(&self.prefix) {
+                let object = ObjectMeta {
+                    path: relative_path,
+                    size: metadata.len(),
+                    last_modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+                    etag: None,
+                };
+                return Ok(Some((object, self)));
+            }
+        }
     }
 }
 
@@ -15,7 +15,10 @@
 use std::io::ErrorKind;
 use std::io::Read;
 use std::io::Seek;
+use std::pin::Pin;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use futures::stream::Stream;
@@ -35,6 +38,20 @@ pub trait SeekableReader: Read + Seek {}
 
 impl<T> SeekableReader for T where T: Read + Seek {}
 
+/// Metadata of an object listed from the storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectMeta {
+    /// The path of the object, relative to the root of the accessor.
+    pub path: String,
+    pub size: u64,
+    pub last_modified: Option<DateTime<Utc>>,
+    /// The entity tag of the content, if the storage provides one.
+    pub etag: Option<String>,
+}
+
+/// The objects listed from the storage, the pages of the listing are requested lazily.
+pub type ObjectStream = Pin<Box<dyn Stream<Item = Result<ObjectMeta>> + Send>>;
+
 #[async_trait::async_trait]
 pub trait DataAccessor: Send + Sync {
     fn get_input_stream(&self, path: &str, stream_len: Option<u64>) -> Result<InputStream>;
@@ -68,4 +85,12 @@ pub trait DataAccessor: Send + Sync {
     }
 
     async fn remove(&self, _path: &str) -> Result<()>;
+
+    /// Lists all the objects whose paths start with `prefix`, recursively.
+    async fn list(&self, prefix: &str) -> Result<ObjectStream> {
+        Err(ErrorCode::UnImplement(format!(
+            "listing objects of prefix {} is not supported by this data accessor",
+            prefix
+        )))
+    }
 }
@@ -22,6 +22,7 @@ use crate::DalContext;
 use crate::DataAccessor;
 use crate::InputStream;
 use crate::InputStreamInterceptor;
+use crate::ObjectStream;
 
 /// A interceptor for data accessor.
 pub struct DataAccessorInterceptor {
@@ -75,4 +76,8 @@ impl DataAccessor for DataAccessorInterceptor {
     async fn remove(&self, path: &str) -> common_exception::Result<()> {
         self.inner.remove(path).await
     }
+
+    async fn list(&self, prefix: &str) -> common_exception::Result<ObjectStream> {
+        self.inner.list(prefix).await
+    }
 }
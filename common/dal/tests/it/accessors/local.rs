@@ -16,6 +16,8 @@
 use common_base::tokio;
 use common_dal::DataAccessor;
 use common_dal::Local;
+use common_exception::ErrorCode;
+use futures::TryStreamExt;
 use tempfile::TempDir;
 
 async fn local_read(loops: u32) -> common_exception::Result<()> {
@@ -42,3 +44,48 @@ async fn test_da_local_normal() -> common_exception::Result<()> {
     let read_fut = local_read(1000);
     read_fut.await
 }
+
+#[tokio::test]
+async fn test_da_local_list() -> common_exception::Result<()> {
+    let tmp_root_dir = TempDir::new().unwrap();
+    let root_path = tmp_root_dir.path().to_str().unwrap();
+    let local_da = Local::new(root_path);
+
+    for file in ["a/b/1", "a/b/2", "a/c/3", "ab/4", "x"] {
+        local_da.put(file, vec![1, 2, 3]).await?;
+    }
+
+    let list = |prefix: &'static str| {
+        let local_da = &local_da;
+        async move {
+            let mut paths = local_da
+                .list(prefix)
+                .await?
+                .map_ok(|o| o.path)
+                .try_collect::<Vec<_>>()
+                .await?;
+            paths.sort();
+            common_exception::Result::Ok(paths)
+        }
+    };
+
+    assert_eq!(list("a/").await?, vec!["a/b/1", "a/b/2", "a/c/3"]);
+    assert_eq!(list("a/b").await?, vec!["a/b/1", "a/b/2"]);
+    assert_eq!(list("a").await?, vec!["a/b/1", "a/b/2", "a/c/3", "ab/4"]);
+    assert_eq!(list("").await?.len(), 5);
+    assert!(list("not_exist/").await?.is_empty());
+
+    let objects = local_da.list("x").await?.try_collect::<Vec<_>>().await?;
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].size, 3);
+    assert!(objects[0].last_modified.is_some());
+
+    // removing a missing object
+    local_da.remove("x").await?;
+    let res = local_da.remove("x").await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::DalPathNotFound("").code()
+    );
+    Ok(())
+}
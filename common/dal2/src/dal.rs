@@ -17,7 +17,9 @@ use std::sync::Arc;
 
 use crate::error::Result;
 use crate::ops::Delete;
+use crate::ops::List;
 use crate::ops::Object;
+use crate::ops::ObjectStream;
 use crate::ops::Read;
 use crate::ops::ReadBuilder;
 use crate::ops::Stat;
@@ -69,3 +71,11 @@ where S: Delete<S>
         self.s.delete(path).await
     }
 }
+
+impl<'d, S> DataAccessor<'d, S>
+where S: List<S>
+{
+    pub async fn list(&self, path: &'d str) -> Result<ObjectStream> {
+        self.s.list(path).await
+    }
+}
@@ -26,4 +26,7 @@ mod tests {
     mod ops {
         mod io;
     }
+    mod services {
+        mod fs;
+    }
 }
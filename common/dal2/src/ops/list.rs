@@ -0,0 +1,35 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use futures::Stream;
+
+use crate::error::Result;
+use crate::ops::Object;
+
+/// `ObjectStream` yields the objects listed, the pages of the listing are requested lazily.
+pub type ObjectStream = Pin<Box<dyn Stream<Item = Result<Object>> + Send>>;
+
+/// `List` will list all the objects whose paths start with `path`, recursively.
+///
+/// The paths of the objects listed are relative to the root of the backend.
+#[async_trait]
+pub trait List<S: Send + Sync>: Send + Sync {
+    async fn list(&self, path: &str) -> Result<ObjectStream> {
+        let _ = path;
+        unimplemented!()
+    }
+}
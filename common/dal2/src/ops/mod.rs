@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod delete;
+mod list;
 mod object;
 mod read;
 mod stat;
@@ -23,6 +24,8 @@ pub mod io;
 pub use delete::Delete;
 pub use io::Reader;
 pub use io::ReaderStream;
+pub use list::List;
+pub use list::ObjectStream;
 pub use object::Object;
 pub use read::Read;
 pub use read::ReadBuilder;
@@ -25,7 +25,9 @@ use tokio::io::AsyncSeekExt;
 use crate::error::Error;
 use crate::error::Result;
 use crate::ops::Delete;
+use crate::ops::List;
 use crate::ops::Object;
+use crate::ops::ObjectStream;
 use crate::ops::Read;
 use crate::ops::ReadBuilder;
 use crate::ops::Reader;
@@ -155,6 +157,92 @@ impl<S: Send + Sync> Delete<S> for Backend {
     }
 }
 
+#[async_trait]
+impl<S: Send + Sync> List<S> for Backend {
+    async fn list(&self, path: &str) -> Result<ObjectStream> {
+        let root = PathBuf::from(&self.root);
+        // starts from the deepest directory of the path
+        let dir = match path.rfind('/') {
+            Some(pos) => root.join(&path[..pos]),
+            None => root.clone(),
+        };
+
+        let lister = Lister {
+            root,
+            prefix: path.to_string(),
+            dirs: vec![dir],
+            entries: None,
+        };
+        Ok(Box::pin(futures::stream::try_unfold(lister, Lister::next)))
+    }
+}
+
+/// Walks the directories which may contain objects of the prefix, one entry at a time.
+struct Lister {
+    root: PathBuf,
+    prefix: String,
+    dirs: Vec<PathBuf>,
+    entries: Option<fs::ReadDir>,
+}
+
+impl Lister {
+    async fn next(mut self) -> Result<Option<(Object, Self)>> {
+        loop {
+            let entries = match &mut self.entries {
+                Some(entries) => entries,
+                None => match self.dirs.pop() {
+                    Some(dir) => {
+                        match fs::read_dir(&dir).await {
+                            Ok(entries) => self.entries = Some(entries),
+                            // The prefix matches nothing.
+                            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                            Err(e) => return Err(parse_io_error(&e, &dir)),
+                        }
+                        continue;
+                    }
+                    None => return Ok(None),
+                },
+            };
+            let entry = match entries.next_entry().await {
+                Ok(Some(entry)) => entry,
+                Ok(None) => {
+                    self.entries = None;
+                    continue;
+                }
+                Err(e) => return Err(parse_io_error(&e, &self.root)),
+            };
+            let path = entry.path();
+            let meta = entry
+                .metadata()
+                .await
+                .map_err(|e| parse_io_error(&e, &path))?;
+
+            // Paths of the objects are separated by `/`, whatever the platform is.
+            let relative_path = path
+                .strip_prefix(&self.root)
+                .map_err(|e| Error::Unexpected(e.to_string()))?
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            if meta.is_dir() {
+                // Only descends into the directories which may contain objects of the prefix.
+                let dir_path = format!("{}/", relative_path);
+                if dir_path.starts_with(&self.prefix) || self.prefix.starts_with(&dir_path) {
+                    self.dirs.push(path);
+                }
+            } else if relative_path.starts_with(&self.prefix) {
+                let o = Object {
+                    path: relative_path,
+                    size: meta.len(),
+                };
+                return Ok(Some((o, self)));
+            }
+        }
+    }
+}
+
 /// Parse all path related errors.
 ///
 /// ## Notes
@@ -28,7 +28,9 @@ use crate::credential::Credential;
 use crate::error::Error;
 use crate::error::Result;
 use crate::ops::Delete;
+use crate::ops::List;
 use crate::ops::Object;
+use crate::ops::ObjectStream;
 use crate::ops::Read;
 use crate::ops::ReadBuilder;
 use crate::ops::Reader;
@@ -261,6 +263,65 @@ impl<S: Send + Sync> Delete<S> for Backend {
     }
 }
 
+#[async_trait]
+impl<S: Send + Sync> List<S> for Backend {
+    async fn list(&self, path: &str) -> Result<ObjectStream> {
+        let client = self.client.clone();
+        let bucket = self.bucket.clone();
+        let prefix = self.get_abs_path(path);
+        let root = format!("{}/", self.root);
+
+        // The state is the continuation token of the next page, `None` after the last page.
+        let pages =
+            futures::stream::try_unfold(Some(None), move |state: Option<Option<String>>| {
+                let client = client.clone();
+                let bucket = bucket.clone();
+                let prefix = prefix.clone();
+                let root = root.clone();
+                async move {
+                    let token = match state {
+                        Some(token) => token,
+                        None => return Ok(None),
+                    };
+                    let output = client
+                        .list_objects_v2()
+                        .bucket(bucket)
+                        .prefix(prefix)
+                        .set_continuation_token(token)
+                        .send()
+                        .await
+                        .map_err(|e| Error::Unexpected(e.to_string()))?;
+
+                    // Keys are returned relative to the root, as the paths accepted by other ops.
+                    let objects = output
+                        .contents
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|o| {
+                            let key = o.key?;
+                            Some(Object {
+                                path: key.strip_prefix(&root).unwrap_or(&key).to_string(),
+                                size: o.size as u64,
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    let next = match output.next_continuation_token {
+                        Some(token) if output.is_truncated => Some(Some(token)),
+                        _ => None,
+                    };
+                    Ok::<_, Error>(Some((objects, next)))
+                }
+            });
+
+        Ok(Box::pin(
+            pages
+                .map_ok(|objects| futures::stream::iter(objects.into_iter().map(Ok)))
+                .try_flatten(),
+        ))
+    }
+}
+
 struct S3Stream(aws_smithy_http::byte_stream::ByteStream);
 
 impl futures::Stream for S3Stream {
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::TryStreamExt;
+
+use crate::services::fs::Backend;
+use crate::DataAccessor;
+
+#[tokio::test]
+async fn list() {
+    let root = std::env::temp_dir().join(format!("dal2_fs_list_{}", std::process::id()));
+    for path in ["a/b/1", "a/b/2", "a/c/3", "ab/4", "x"] {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, "abc").unwrap();
+    }
+
+    let mut builder = Backend::build();
+    builder.root(&root.to_string_lossy());
+    let da = DataAccessor::new(builder.finish());
+
+    let list = |path: &'static str| {
+        let da = &da;
+        async move {
+            let mut paths = da
+                .list(path)
+                .await
+                .unwrap()
+                .map_ok(|o| o.path)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+            paths.sort();
+            paths
+        }
+    };
+
+    assert_eq!(list("a/").await, vec!["a/b/1", "a/b/2", "a/c/3"]);
+    assert_eq!(list("a/b").await, vec!["a/b/1", "a/b/2"]);
+    assert_eq!(list("a").await, vec!["a/b/1", "a/b/2", "a/c/3", "ab/4"]);
+    assert_eq!(list("").await.len(), 5);
+    assert!(list("not_exist/").await.is_empty());
+
+    let objects = da
+        .list("x")
+        .await
+        .unwrap()
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].size, 3);
+
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -190,6 +190,11 @@ build_exceptions! {
     SecretKeyNotSet(3002),
     DalTransportError(3003),
     DalPathNotFound(3004),
+    // the table is changed concurrently, and the conflicts can not be resolved
+    TableCommitConflict(3005),
+    TableHistoricalDataNotFound(3006),
+    // the meta files are written in a format version unknown to this query node
+    UnsupportedFormatVersion(3007),
 }
 
 // Cache errors [4001, 5000].
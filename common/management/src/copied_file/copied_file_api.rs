@@ -0,0 +1,33 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::MetaId;
+
+#[async_trait::async_trait]
+pub trait CopiedFileMgrApi: Sync + Send {
+    // Record the files loaded into a table by a COPY, in one entry /tenant/table-id/batch-id,
+    // so that either all or none of them are recorded.
+    async fn add_copied_files(
+        &self,
+        table_id: MetaId,
+        files: Vec<(String, CopiedFileInfo)>,
+    ) -> Result<()>;
+
+    // Get all the files loaded into a table, by the file path.
+    async fn get_copied_files(&self, table_id: MetaId) -> Result<HashMap<String, CopiedFileInfo>>;
+}
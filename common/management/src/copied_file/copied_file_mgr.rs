@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaId;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVAction;
+
+use crate::copied_file::CopiedFileMgrApi;
+
+static COPIED_FILE_API_KEY_PREFIX: &str = "__fd_copied_files";
+
+pub struct CopiedFileMgr {
+    kv_api: Arc<dyn KVApi>,
+    copied_file_prefix: String,
+}
+
+impl CopiedFileMgr {
+    pub fn new(kv_api: Arc<dyn KVApi>, tenant: &str) -> Self {
+        CopiedFileMgr {
+            kv_api,
+            copied_file_prefix: format!("{}/{}", COPIED_FILE_API_KEY_PREFIX, tenant),
+        }
+    }
+
+    fn table_prefix(&self, table_id: MetaId) -> String {
+        // ends with '/', table 1 must not list the files of table 10
+        format!("{}/{}/", self.copied_file_prefix, table_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl CopiedFileMgrApi for CopiedFileMgr {
+    async fn add_copied_files(
+        &self,
+        table_id: MetaId,
+        files: Vec<(String, CopiedFileInfo)>,
+    ) -> Result<()> {
+        let table_prefix = self.table_prefix(table_id);
+        let val = serde_json::to_vec(&files)?;
+
+        // the files are recorded in one entry, keyed by the time they are recorded
+        let mut batch_id = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        loop {
+            let key = format!("{}{:020}", table_prefix, batch_id);
+            let upsert = UpsertKVAction::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(val.clone()),
+                None,
+            );
+            if self.kv_api.upsert_kv(upsert).await?.changed() {
+                return Ok(());
+            }
+            // the key is taken by the files recorded by another COPY at the same time
+            batch_id += 1;
+        }
+    }
+
+    async fn get_copied_files(&self, table_id: MetaId) -> Result<HashMap<String, CopiedFileInfo>> {
+        let table_prefix = self.table_prefix(table_id);
+        let values = self.kv_api.prefix_list_kv(&table_prefix).await?;
+
+        // the entries are listed in the order they are recorded, the info of a file loaded
+        // again overwrites the earlier one.
+        let mut files = HashMap::new();
+        for (_, value) in values {
+            let batch = serde_json::from_slice::<Vec<(String, CopiedFileInfo)>>(&value.data)?;
+            files.extend(batch);
+        }
+        Ok(files)
+    }
+}
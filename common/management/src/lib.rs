@@ -13,12 +13,15 @@
 // limitations under the License.
 
 mod cluster;
+mod copied_file;
 mod stage;
 mod udf;
 mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use copied_file::CopiedFileMgr;
+pub use copied_file::CopiedFileMgrApi;
 pub use stage::StageMgr;
 pub use stage::StageMgrApi;
 pub use udf::UdfMgr;
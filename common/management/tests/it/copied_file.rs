@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::*;
+use common_meta_api::KVApi;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::PrefixListReply;
+
+use crate::user::MockKV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_copied_files() -> Result<()> {
+    let (kv_api, copied_file_api) = new_copied_file_api().await?;
+
+    // the files of a COPY are recorded in one entry
+    let files = vec![
+        (
+            "logs/a.csv.gz".to_string(),
+            create_test_copied_file_info("\"a\""),
+        ),
+        (
+            "logs/b.csv.gz".to_string(),
+            create_test_copied_file_info("\"b\""),
+        ),
+    ];
+    copied_file_api.add_copied_files(1, files.clone()).await?;
+
+    let values = kv_api
+        .prefix_list_kv("__fd_copied_files/databend_query/1/")
+        .await?;
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].1.data, serde_json::to_vec(&files)?);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_copied_files_failure() -> Result<()> {
+    // none of the files are recorded if the only write of them fails
+    let mut kv_api = MockKV::new();
+    kv_api
+        .expect_upsert_kv()
+        .times(1)
+        .returning(|_| Err(ErrorCode::MetaServiceError("mock error")));
+    kv_api
+        .expect_prefix_list_kv()
+        .returning(|_| Ok(PrefixListReply::default()));
+    let copied_file_api = CopiedFileMgr::new(Arc::new(kv_api), "databend_query");
+
+    let info = create_test_copied_file_info("\"abc\"");
+    let res = copied_file_api
+        .add_copied_files(1, vec![
+            ("logs/a.csv".to_string(), info.clone()),
+            ("logs/b.csv".to_string(), info.clone()),
+        ])
+        .await;
+    assert!(res.is_err());
+    assert!(copied_file_api.get_copied_files(1).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_get_copied_files() -> Result<()> {
+    let (_, copied_file_api) = new_copied_file_api().await?;
+
+    assert!(copied_file_api.get_copied_files(1).await?.is_empty());
+
+    let info_a = create_test_copied_file_info("\"a\"");
+    let info_b = create_test_copied_file_info("\"b\"");
+    copied_file_api
+        .add_copied_files(1, vec![
+            ("logs/a.csv".to_string(), info_a.clone()),
+            ("logs/b.csv".to_string(), info_a.clone()),
+        ])
+        .await?;
+    // files of other tables
+    copied_file_api
+        .add_copied_files(10, vec![("logs/c.csv".to_string(), info_a.clone())])
+        .await?;
+    // loading a file again overwrites its info
+    copied_file_api
+        .add_copied_files(1, vec![("logs/b.csv".to_string(), info_b.clone())])
+        .await?;
+
+    let files = copied_file_api.get_copied_files(1).await?;
+    assert_eq!(files.len(), 2);
+    assert_eq!(files.get("logs/a.csv"), Some(&info_a));
+    assert_eq!(files.get("logs/b.csv"), Some(&info_b));
+    Ok(())
+}
+
+fn create_test_copied_file_info(etag: &str) -> CopiedFileInfo {
+    CopiedFileInfo {
+        etag: Some(etag.to_string()),
+        content_length: 100,
+        last_modified: Some(1640995200),
+    }
+}
+
+async fn new_copied_file_api() -> Result<(Arc<MetaEmbedded>, CopiedFileMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = CopiedFileMgr::new(test_api.clone(), "databend_query");
+    Ok((test_api, mgr))
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod cluster;
+mod copied_file;
 mod stage;
 mod udf;
 mod user;
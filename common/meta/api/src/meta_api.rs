@@ -28,11 +28,20 @@ use common_meta_types::DropTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTableHistoryReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -54,10 +63,23 @@ pub trait MetaApi: Send + Sync {
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
+
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply>;
+
+    /// Removes a dropped table for good, it is no longer restorable by `undrop_table`.
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply>;
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>>;
 
     async fn list_tables(&self, req: ListTableReq) -> Result<Vec<Arc<TableInfo>>>;
 
+    /// Lists the tables along with the dropped ones, a dropped table has `meta.drop_on` set.
+    async fn list_tables_history(&self, req: ListTableHistoryReq) -> Result<Vec<Arc<TableInfo>>>;
+
     async fn get_table_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)>;
 
     async fn upsert_table_option(
@@ -65,5 +87,7 @@ pub trait MetaApi: Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply>;
+
     fn name(&self) -> String;
 }
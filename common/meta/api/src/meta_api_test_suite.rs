@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_datavalues::chrono::Duration;
 use common_datavalues::chrono::Utc;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
@@ -28,10 +29,15 @@ use common_meta_types::DropTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTableHistoryReq;
 use common_meta_types::ListTableReq;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
 
@@ -485,6 +491,54 @@ impl MetaApiTestSuite {
                 }
             }
 
+            tracing::info!("--- update table meta");
+            {
+                tracing::info!("--- update table meta with a new schema");
+                {
+                    let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                    let new_schema = Arc::new(DataSchema::new(vec![
+                        DataField::new("number", DataType::UInt64, false),
+                        DataField::new("name", DataType::String, true),
+                    ]));
+                    let new_table_meta = TableMeta {
+                        schema: new_schema.clone(),
+                        ..table.meta.clone()
+                    };
+
+                    mt.update_table_meta(UpdateTableMetaReq::new(&table.ident, new_table_meta))
+                        .await?;
+
+                    let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                    assert_eq!(table.schema(), new_schema);
+                    assert_eq!(table.options().get("key1"), Some(&"val1".into()));
+                }
+
+                tracing::info!("--- update table meta with a stale version");
+                {
+                    let table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+
+                    let got = mt
+                        .update_table_meta(UpdateTableMetaReq::new(
+                            &TableIdent {
+                                table_id: table.ident.table_id,
+                                version: table.ident.version - 1,
+                            },
+                            TableMeta {
+                                schema: schema.clone(),
+                                ..table.meta.clone()
+                            },
+                        ))
+                        .await;
+
+                    let got = got.unwrap_err();
+                    assert_eq!(ErrorCode::TableVersionMissMatch("").code(), got.code());
+
+                    // table is not affected.
+                    let new_table = mt.get_table((tenant, "db1", "tb2").into()).await.unwrap();
+                    assert_eq!(table.schema(), new_table.schema());
+                }
+            }
+
             tracing::info!("--- drop table with if_exists = false");
             {
                 let plan = DropTableReq {
@@ -594,6 +648,200 @@ impl MetaApiTestSuite {
 
         Ok(())
     }
+
+    pub async fn table_rename_undrop<MT: MetaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+
+        tracing::info!("--- prepare db1 and db2");
+        {
+            self.create_database(mt, tenant, "db1").await?;
+            self.create_database(mt, tenant, "db2").await?;
+        }
+
+        let schema = Arc::new(DataSchema::new(vec![DataField::new(
+            "number",
+            DataType::UInt64,
+            false,
+        )]));
+
+        let create_req = |db: &str, table: &str| CreateTableReq {
+            if_not_exists: false,
+            tenant: tenant.to_string(),
+            db: db.to_string(),
+            table: table.to_string(),
+            table_meta: TableMeta {
+                schema: schema.clone(),
+                engine: "JSON".to_string(),
+                ..Default::default()
+            },
+        };
+        let rename_req = |db: &str, table: &str, new_db: &str, new_table: &str| RenameTableReq {
+            if_exists: false,
+            tenant: tenant.to_string(),
+            db: db.to_string(),
+            table: table.to_string(),
+            new_db: new_db.to_string(),
+            new_table: new_table.to_string(),
+        };
+        let drop_req = |db: &str, table: &str| DropTableReq {
+            if_exists: false,
+            tenant: tenant.to_string(),
+            db: db.to_string(),
+            table: table.to_string(),
+        };
+        let undrop_req = |db: &str, table: &str| UndropTableReq {
+            tenant: tenant.to_string(),
+            db: db.to_string(),
+            table: table.to_string(),
+            drop_after: None,
+        };
+        let purge_req = |db: &str, table: &str, table_id: u64| PurgeDroppedTableReq {
+            tenant: tenant.to_string(),
+            db: db.to_string(),
+            table: table.to_string(),
+            table_id,
+        };
+
+        tracing::info!("--- rename db1.tb1 to db2.tb2");
+        {
+            let res = mt.create_table(create_req("db1", "tb1")).await?;
+            assert_eq!(1, res.table_id, "table id is 1");
+
+            mt.rename_table(rename_req("db1", "tb1", "db2", "tb2"))
+                .await?;
+
+            let res = mt.get_table((tenant, "db1", "tb1").into()).await;
+            assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+
+            let got = mt.get_table((tenant, "db2", "tb2").into()).await?;
+            assert_eq!(1, got.ident.table_id, "the table is moved to db2.tb2");
+        }
+
+        tracing::info!("--- rename an absent table");
+        {
+            let res = mt
+                .rename_table(rename_req("db1", "tb1", "db2", "tb3"))
+                .await;
+            assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+
+            let mut req = rename_req("db1", "tb1", "db2", "tb3");
+            req.if_exists = true;
+            mt.rename_table(req).await?;
+        }
+
+        tracing::info!("--- rename to an existing table");
+        {
+            let res = mt.create_table(create_req("db1", "tb1")).await?;
+            assert_eq!(2, res.table_id, "table id is 2");
+
+            let res = mt
+                .rename_table(rename_req("db1", "tb1", "db2", "tb2"))
+                .await;
+            assert_eq!(
+                ErrorCode::TableAlreadyExists("").code(),
+                res.unwrap_err().code()
+            );
+
+            let got = mt.get_table((tenant, "db1", "tb1").into()).await?;
+            assert_eq!(2, got.ident.table_id, "source table is not affected");
+        }
+
+        tracing::info!("--- drop db2.tb2 keeps it in the history");
+        {
+            mt.drop_table(drop_req("db2", "tb2")).await?;
+
+            let res = mt.list_tables(ListTableReq::new(tenant, "db2")).await?;
+            assert!(res.is_empty());
+
+            let res = mt
+                .list_tables_history(ListTableHistoryReq::new(tenant, "db2"))
+                .await?;
+            assert_eq!(1, res.len());
+            assert_eq!(1, res[0].ident.table_id);
+            assert_eq!("tb2", res[0].name);
+            assert!(res[0].meta.drop_on.is_some());
+        }
+
+        tracing::info!("--- undrop db2.tb2");
+        {
+            let res = mt.undrop_table(undrop_req("db2", "tb2")).await?;
+            assert_eq!(1, res.table_id);
+
+            let got = mt.get_table((tenant, "db2", "tb2").into()).await?;
+            assert_eq!(1, got.ident.table_id);
+            assert!(got.meta.drop_on.is_none());
+
+            let res = mt.undrop_table(undrop_req("db2", "tb2")).await;
+            assert_eq!(
+                ErrorCode::TableAlreadyExists("").code(),
+                res.unwrap_err().code(),
+                "the name is taken by the restored table"
+            );
+
+            let res = mt.undrop_table(undrop_req("db1", "tb3")).await;
+            assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+        }
+
+        tracing::info!("--- undrop restores the most recently dropped table");
+        {
+            mt.drop_table(drop_req("db2", "tb2")).await?;
+            mt.rename_table(rename_req("db1", "tb1", "db2", "tb2"))
+                .await?;
+            mt.drop_table(drop_req("db2", "tb2")).await?;
+
+            let res = mt
+                .list_tables_history(ListTableHistoryReq::new(tenant, "db2"))
+                .await?;
+            assert_eq!(2, res.len());
+
+            let res = mt.undrop_table(undrop_req("db2", "tb2")).await?;
+            assert_eq!(2, res.table_id);
+        }
+
+        tracing::info!("--- undrop rejects a table dropped before drop_after");
+        {
+            mt.drop_table(drop_req("db2", "tb2")).await?;
+
+            let mut req = undrop_req("db2", "tb2");
+            req.drop_after = Some(Utc::now() + Duration::hours(1));
+            let res = mt.undrop_table(req).await;
+            assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+
+            let res = mt
+                .list_tables_history(ListTableHistoryReq::new(tenant, "db2"))
+                .await?;
+            assert_eq!(2, res.len(), "the dropped table is kept");
+        }
+
+        tracing::info!("--- purge a dropped table");
+        {
+            mt.purge_dropped_table(purge_req("db2", "tb2", 2)).await?;
+
+            let res = mt
+                .list_tables_history(ListTableHistoryReq::new(tenant, "db2"))
+                .await?;
+            assert_eq!(1, res.len());
+            assert_eq!(1, res[0].ident.table_id);
+
+            let res = mt.get_table_by_id(2).await;
+            assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+
+            let res = mt.purge_dropped_table(purge_req("db2", "tb2", 2)).await;
+            assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+
+            let res = mt.undrop_table(undrop_req("db2", "tb2")).await?;
+            assert_eq!(1, res.table_id, "the purged table is not restorable");
+
+            let res = mt.purge_dropped_table(purge_req("db2", "tb2", 1)).await;
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                res.unwrap_err().code(),
+                "a present table is not purged"
+            );
+        }
+
+        Ok(())
+    }
 }
 
 impl MetaApiTestSuite {
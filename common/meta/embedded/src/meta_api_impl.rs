@@ -29,11 +29,20 @@ use common_meta_types::DropTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTableHistoryReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -71,6 +80,24 @@ impl MetaApi for MetaEmbedded {
         sm.drop_table(req).await
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        let sm = self.inner.lock().await;
+        sm.rename_table(req).await
+    }
+
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        let sm = self.inner.lock().await;
+        sm.undrop_table(req).await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        let sm = self.inner.lock().await;
+        sm.purge_dropped_table(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>> {
         let sm = self.inner.lock().await;
         sm.get_table(req).await
@@ -81,6 +108,11 @@ impl MetaApi for MetaEmbedded {
         sm.list_tables(req).await
     }
 
+    async fn list_tables_history(&self, req: ListTableHistoryReq) -> Result<Vec<Arc<TableInfo>>> {
+        let sm = self.inner.lock().await;
+        sm.list_tables_history(req).await
+    }
+
     async fn get_table_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
         let sm = self.inner.lock().await;
         sm.get_table_by_id(table_id).await
@@ -94,6 +126,11 @@ impl MetaApi for MetaEmbedded {
         sm.upsert_table_option(req).await
     }
 
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        let sm = self.inner.lock().await;
+        sm.update_table_meta(req).await
+    }
+
     fn name(&self) -> String {
         "meta-embedded".to_string()
     }
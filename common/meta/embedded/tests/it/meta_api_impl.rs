@@ -47,3 +47,9 @@ async fn test_meta_embedded_table_list() -> anyhow::Result<()> {
     let mt = MetaEmbedded::new_temp().await?;
     MetaApiTestSuite {}.table_list(&mt).await
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_meta_embedded_table_rename_undrop() -> anyhow::Result<()> {
+    let mt = MetaEmbedded::new_temp().await?;
+    MetaApiTestSuite {}.table_rename_undrop(&mt).await
+}
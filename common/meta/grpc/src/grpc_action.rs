@@ -32,11 +32,20 @@ use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetKVActionReply;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTableHistoryReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MGetKVActionReply;
 use common_meta_types::MetaId;
 use common_meta_types::PrefixListReply;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableInfo;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertKVAction;
 use common_meta_types::UpsertKVActionReply;
 use common_meta_types::UpsertTableOptionReply;
@@ -55,7 +64,11 @@ pub enum MetaGrpcWriteReq {
 
     CreateTable(CreateTableReq),
     DropTable(DropTableReq),
+    RenameTable(RenameTableReq),
+    UndropTable(UndropTableReq),
+    PurgeDroppedTable(PurgeDroppedTableReq),
     CommitTable(UpsertTableOptionReq),
+    UpdateTableMeta(UpdateTableMetaReq),
     UpsertKV(UpsertKVAction),
 }
 
@@ -66,6 +79,7 @@ pub enum MetaGrpcReadReq {
     GetTable(GetTableReq),
     GetTableExt(GetTableExtReq),
     ListTables(ListTableReq),
+    ListTablesHistory(ListTableHistoryReq),
     GetKV(GetKVAction),
     MGetKV(MGetKVAction),
     PrefixListKV(PrefixListReq),
@@ -183,6 +197,18 @@ impl RequestFor for DropTableReq {
     type Reply = DropTableReply;
 }
 
+impl RequestFor for RenameTableReq {
+    type Reply = RenameTableReply;
+}
+
+impl RequestFor for UndropTableReq {
+    type Reply = UndropTableReply;
+}
+
+impl RequestFor for PurgeDroppedTableReq {
+    type Reply = PurgeDroppedTableReply;
+}
+
 impl RequestFor for GetTableReq {
     type Reply = Arc<TableInfo>;
 }
@@ -199,10 +225,18 @@ impl RequestFor for UpsertTableOptionReq {
     type Reply = UpsertTableOptionReply;
 }
 
+impl RequestFor for UpdateTableMetaReq {
+    type Reply = UpdateTableMetaReply;
+}
+
 impl RequestFor for ListTableReq {
     type Reply = Vec<Arc<TableInfo>>;
 }
 
+impl RequestFor for ListTableHistoryReq {
+    type Reply = Vec<Arc<TableInfo>>;
+}
+
 impl RequestFor for ListDatabaseReq {
     type Reply = Vec<Arc<DatabaseInfo>>;
 }
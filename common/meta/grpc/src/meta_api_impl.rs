@@ -27,11 +27,20 @@ use common_meta_types::DropTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTableHistoryReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -79,6 +88,27 @@ impl MetaApi for MetaGrpcClient {
         self.do_write(req).await
     }
 
+    async fn rename_table(
+        &self,
+        req: RenameTableReq,
+    ) -> common_exception::Result<RenameTableReply> {
+        self.do_write(req).await
+    }
+
+    async fn undrop_table(
+        &self,
+        req: UndropTableReq,
+    ) -> common_exception::Result<UndropTableReply> {
+        self.do_write(req).await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> common_exception::Result<PurgeDroppedTableReply> {
+        self.do_write(req).await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> common_exception::Result<Arc<TableInfo>> {
         self.do_get(req).await
     }
@@ -90,6 +120,13 @@ impl MetaApi for MetaGrpcClient {
         self.do_get(req).await
     }
 
+    async fn list_tables_history(
+        &self,
+        req: ListTableHistoryReq,
+    ) -> common_exception::Result<Vec<Arc<TableInfo>>> {
+        self.do_get(req).await
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -105,6 +142,13 @@ impl MetaApi for MetaGrpcClient {
         self.do_write(req).await
     }
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> common_exception::Result<UpdateTableMetaReply> {
+        self.do_write(req).await
+    }
+
     fn name(&self) -> String {
         "MetaGrpcClient".to_string()
     }
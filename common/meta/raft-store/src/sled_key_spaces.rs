@@ -30,6 +30,7 @@ use crate::state_machine::ClientLastRespValue;
 use crate::state_machine::DatabaseLookupKey;
 use crate::state_machine::StateMachineMetaKey;
 use crate::state_machine::StateMachineMetaValue;
+use crate::state_machine::TableIdList;
 use crate::state_machine::TableLookupKey;
 
 /// Types for raft log in SledTree
@@ -134,3 +135,13 @@ impl SledKeySpace for TableLookup {
     type K = TableLookupKey;
     type V = SeqV<TableLookupValue>;
 }
+
+/// The dropped tables of a name in a database, which can be restored by `UNDROP TABLE`.
+pub struct TableHistory {}
+
+impl SledKeySpace for TableHistory {
+    const PREFIX: u8 = 13;
+    const NAME: &'static str = "table-history";
+    type K = TableLookupKey;
+    type V = SeqV<TableIdList>;
+}
@@ -21,6 +21,7 @@ pub use sm::StateMachine;
 pub use snapshot::Snapshot;
 pub use state_machine_meta::StateMachineMetaKey;
 pub use state_machine_meta::StateMachineMetaValue;
+pub use table_lookup::TableIdList;
 pub use table_lookup::TableLookupKey;
 pub use table_lookup::TableLookupValue;
 
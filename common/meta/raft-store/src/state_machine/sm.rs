@@ -20,6 +20,8 @@ use std::time::UNIX_EPOCH;
 use async_raft::raft::Entry;
 use async_raft::raft::EntryPayload;
 use async_raft::raft::MembershipConfig;
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::ToErrorCode;
 use common_meta_sled_store::get_sled_db;
@@ -57,6 +59,7 @@ use crate::sled_key_spaces::GenericKV;
 use crate::sled_key_spaces::Nodes;
 use crate::sled_key_spaces::Sequences;
 use crate::sled_key_spaces::StateMachineMeta;
+use crate::sled_key_spaces::TableHistory;
 use crate::sled_key_spaces::TableLookup;
 use crate::sled_key_spaces::Tables;
 use crate::state_machine::ClientLastRespValue;
@@ -495,12 +498,17 @@ impl StateMachine {
         )))
     }
 
+    /// Drops a table by removing its name, the table itself is kept with the time it is dropped at,
+    /// and is appended to the history of the name, so that it can be restored by `UndropTable`.
+    ///
+    /// Without `drop_on`, e.g., an entry written by an older client, the table is removed at once.
     #[tracing::instrument(level = "debug", skip(self, txn_tree))]
     fn apply_drop_table_cmd(
         &self,
         tenant: &str,
         db_name: &str,
         table_name: &str,
+        drop_on: &Option<DateTime<Utc>>,
 
         txn_tree: &TransactionSledTree,
     ) -> common_exception::Result<AppliedState> {
@@ -528,13 +536,265 @@ impl StateMachine {
             None,
         )?;
 
+        let drop_on = match drop_on {
+            Some(drop_on) => drop_on,
+            None => {
+                let tables = txn_tree.key_space::<Tables>();
+                let (prev, result) = self.sub_txn_tree_upsert(
+                    &tables,
+                    &table_id,
+                    &MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                )?;
+                if prev.is_some() && result.is_none() {
+                    self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+                }
+                tracing::debug!("applied drop Table: {} {:?}", table_name, result);
+                return Ok(Change::new_with_id(table_id, prev, result).into());
+            }
+        };
+
+        let prev = self.txn_get_table_meta_by_id(&table_id, txn_tree)?;
+        if let Some(seq_table_meta) = &prev {
+            let mut table_meta = seq_table_meta.data.clone();
+            table_meta.drop_on = Some(*drop_on);
+
+            let tables = txn_tree.key_space::<Tables>();
+            self.sub_txn_tree_upsert(
+                &tables,
+                &table_id,
+                &MatchSeq::Any,
+                Operation::Update(table_meta),
+                None,
+            )?;
+
+            let table_history = txn_tree.key_space::<TableHistory>();
+            let mut dropped = table_history
+                .get(&lookup_key)?
+                .map(|x| x.data)
+                .unwrap_or_default();
+            dropped.0.push(table_id);
+            self.sub_txn_tree_upsert(
+                &table_history,
+                &lookup_key,
+                &MatchSeq::Any,
+                Operation::Update(dropped),
+                None,
+            )?;
+
+            self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+        }
+        tracing::debug!("applied drop Table: {} {:?}", table_name, prev);
+        Ok(Change::new_with_id(table_id, prev, None).into())
+    }
+
+    /// Renames a table by moving the table id to the new name, the table itself is unchanged.
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_rename_table_cmd(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+        new_db_name: &str,
+        new_table_name: &str,
+
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<AppliedState> {
+        let db_id = self.txn_get_database_id(tenant, db_name, txn_tree)?;
+        let new_db_id = self.txn_get_database_id(tenant, new_db_name, txn_tree)?;
+
+        let lookup_key = TableLookupKey {
+            database_id: db_id,
+            table_name: table_name.to_string(),
+        };
+        let new_lookup_key = TableLookupKey {
+            database_id: new_db_id,
+            table_name: new_table_name.to_string(),
+        };
+
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        let seq_table_id = table_lookup_tree.get(&lookup_key)?;
+
+        if seq_table_id.is_none() {
+            return Ok(Change::<TableMeta>::new(None, None).into());
+        }
+
+        if let Some(u) = table_lookup_tree.get(&new_lookup_key)? {
+            let table_id = u.data.0;
+
+            let prev = self.txn_get_table_meta_by_id(&table_id, txn_tree)?;
+
+            return Ok(AppliedState::TableMeta(Change::nochange_with_id(
+                table_id, prev,
+            )));
+        }
+
+        let table_id = seq_table_id.unwrap().data.0;
+
+        self.sub_txn_tree_upsert(
+            &table_lookup_tree,
+            &lookup_key,
+            &MatchSeq::Any,
+            Operation::Delete,
+            None,
+        )?;
+        self.sub_txn_tree_upsert(
+            &table_lookup_tree,
+            &new_lookup_key,
+            &MatchSeq::Exact(0),
+            Operation::Update(TableLookupValue(table_id)),
+            None,
+        )?;
+        self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+
+        let result = self.txn_get_table_meta_by_id(&table_id, txn_tree)?;
+
+        tracing::debug!(
+            "applied rename Table: {} => {} {:?}",
+            table_name,
+            new_table_name,
+            result
+        );
+        Ok(Change::new_with_id(table_id, None, result).into())
+    }
+
+    /// Restores the most recently dropped table of a name, if the name is not taken,
+    /// and the table is not dropped before `drop_after`.
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_undrop_table_cmd(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+        drop_after: &Option<DateTime<Utc>>,
+
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<AppliedState> {
+        let db_id = self.txn_get_database_id(tenant, db_name, txn_tree)?;
+
+        let lookup_key = TableLookupKey {
+            database_id: db_id,
+            table_name: table_name.to_string(),
+        };
+
+        let table_lookup_tree = txn_tree.key_space::<TableLookup>();
+        if let Some(u) = table_lookup_tree.get(&lookup_key)? {
+            let table_id = u.data.0;
+
+            let prev = self.txn_get_table_meta_by_id(&table_id, txn_tree)?;
+
+            return Ok(AppliedState::TableMeta(Change::nochange_with_id(
+                table_id, prev,
+            )));
+        }
+
+        let table_history = txn_tree.key_space::<TableHistory>();
+        let mut dropped = table_history
+            .get(&lookup_key)?
+            .map(|x| x.data)
+            .unwrap_or_default();
+
+        let table_id = match dropped.0.last() {
+            None => return Ok(Change::<TableMeta>::new(None, None).into()),
+            Some(table_id) => *table_id,
+        };
+
+        let seq_table_meta = self
+            .txn_get_table_meta_by_id(&table_id, txn_tree)?
+            .ok_or_else(|| {
+                ErrorCode::IllegalMetaState(format!(" table of id {}, not found", table_id))
+            })?;
+        let mut table_meta = seq_table_meta.data;
+        if let (Some(drop_on), Some(drop_after)) = (&table_meta.drop_on, drop_after) {
+            if drop_on < drop_after {
+                return Ok(Change::<TableMeta>::new(None, None).into());
+            }
+        }
+        table_meta.drop_on = None;
+
+        dropped.0.pop();
+        let history_op = match dropped.0.is_empty() {
+            true => Operation::Delete,
+            false => Operation::Update(dropped),
+        };
+        self.sub_txn_tree_upsert(
+            &table_history,
+            &lookup_key,
+            &MatchSeq::Any,
+            history_op,
+            None,
+        )?;
+
+        let tables = txn_tree.key_space::<Tables>();
+        let (_prev, result) = self.sub_txn_tree_upsert(
+            &tables,
+            &table_id,
+            &MatchSeq::Any,
+            Operation::Update(table_meta),
+            None,
+        )?;
+
+        self.sub_txn_tree_upsert(
+            &table_lookup_tree,
+            &lookup_key,
+            &MatchSeq::Exact(0),
+            Operation::Update(TableLookupValue(table_id)),
+            None,
+        )?;
+        self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+
+        tracing::debug!("applied undrop Table: {} {:?}", table_name, result);
+        Ok(Change::new_with_id(table_id, None, result).into())
+    }
+
+    /// Removes a dropped table from the history of its name, and removes the table itself.
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_purge_dropped_table_cmd(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+        table_id: u64,
+
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<AppliedState> {
+        let db_id = self.txn_get_database_id(tenant, db_name, txn_tree)?;
+
+        let lookup_key = TableLookupKey {
+            database_id: db_id,
+            table_name: table_name.to_string(),
+        };
+
+        let table_history = txn_tree.key_space::<TableHistory>();
+        let mut dropped = table_history
+            .get(&lookup_key)?
+            .map(|x| x.data)
+            .unwrap_or_default();
+
+        match dropped.0.iter().position(|id| *id == table_id) {
+            None => return Ok(Change::<TableMeta>::new(None, None).into()),
+            Some(pos) => dropped.0.remove(pos),
+        };
+
+        let history_op = match dropped.0.is_empty() {
+            true => Operation::Delete,
+            false => Operation::Update(dropped),
+        };
+        self.sub_txn_tree_upsert(
+            &table_history,
+            &lookup_key,
+            &MatchSeq::Any,
+            history_op,
+            None,
+        )?;
+
         let tables = txn_tree.key_space::<Tables>();
         let (prev, result) =
             self.sub_txn_tree_upsert(&tables, &table_id, &MatchSeq::Any, Operation::Delete, None)?;
-        if prev.is_some() && result.is_none() {
-            self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
-        }
-        tracing::debug!("applied drop Table: {} {:?}", table_name, result);
+        self.txn_incr_seq(SEQ_DATABASE_META_ID, txn_tree)?;
+
+        tracing::debug!("applied purge dropped Table: {} {:?}", table_name, prev);
         Ok(Change::new_with_id(table_id, prev, result).into())
     }
 
@@ -611,6 +871,41 @@ impl StateMachine {
         )))
     }
 
+    #[tracing::instrument(level = "debug", skip(self, txn_tree))]
+    fn apply_update_table_meta_cmd(
+        &self,
+        req: &common_meta_types::UpdateTableMetaReq,
+
+        txn_tree: &TransactionSledTree,
+    ) -> common_exception::Result<AppliedState> {
+        let table_tree = txn_tree.key_space::<Tables>();
+        let prev = table_tree.get(&req.table_id)?;
+
+        // Same as upsert-options, prev to be None is not allowed.
+        let prev =
+            prev.ok_or_else(|| ErrorCode::UnknownTableId(format!("table_id:{}", req.table_id)))?;
+
+        if req.seq.match_seq(&prev).is_err() {
+            let res = AppliedState::TableMeta(Change::new(Some(prev.clone()), Some(prev)));
+            return Ok(res);
+        }
+
+        let new_seq = self.txn_incr_seq(Tables::NAME, txn_tree)?;
+        let sv = SeqV {
+            seq: new_seq,
+            meta: prev.meta.clone(),
+            data: req.new_table_meta.clone(),
+        };
+
+        table_tree.insert(&req.table_id, &sv)?;
+
+        Ok(AppliedState::TableMeta(Change::new_with_id(
+            req.table_id,
+            Some(prev),
+            Some(sv),
+        )))
+    }
+
     /// Apply a `Cmd` to state machine.
     ///
     /// Already applied log should be filtered out before passing into this function.
@@ -652,7 +947,39 @@ impl StateMachine {
                 tenant,
                 ref db_name,
                 ref table_name,
-            } => self.apply_drop_table_cmd(tenant, db_name, table_name, txn_tree),
+                ref drop_on,
+            } => self.apply_drop_table_cmd(tenant, db_name, table_name, drop_on, txn_tree),
+
+            Cmd::RenameTable {
+                ref tenant,
+                ref db_name,
+                ref table_name,
+                ref new_db_name,
+                ref new_table_name,
+            } => self.apply_rename_table_cmd(
+                tenant,
+                db_name,
+                table_name,
+                new_db_name,
+                new_table_name,
+                txn_tree,
+            ),
+
+            Cmd::UndropTable {
+                ref tenant,
+                ref db_name,
+                ref table_name,
+                ref drop_after,
+            } => self.apply_undrop_table_cmd(tenant, db_name, table_name, drop_after, txn_tree),
+
+            Cmd::PurgeDroppedTable {
+                ref tenant,
+                ref db_name,
+                ref table_name,
+                table_id,
+            } => {
+                self.apply_purge_dropped_table_cmd(tenant, db_name, table_name, *table_id, txn_tree)
+            }
 
             Cmd::UpsertKV {
                 key,
@@ -662,6 +989,8 @@ impl StateMachine {
             } => self.apply_update_kv_cmd(key, seq, value_op, value_meta, txn_tree),
 
             Cmd::UpsertTableOptions(ref req) => self.apply_upsert_table_options_cmd(req, txn_tree),
+
+            Cmd::UpdateTableMeta(ref req) => self.apply_update_table_meta_cmd(req, txn_tree),
         }
     }
 
@@ -1068,4 +1397,8 @@ impl StateMachine {
     pub fn table_lookup(&self) -> AsKeySpace<TableLookup> {
         self.sm_tree.key_space()
     }
+
+    pub fn table_history(&self) -> AsKeySpace<TableHistory> {
+        self.sm_tree.key_space()
+    }
 }
@@ -15,8 +15,10 @@
 use std::convert::TryInto;
 use std::sync::Arc;
 
+use common_datavalues::chrono::Utc;
 use common_exception::ErrorCode;
 use common_meta_api::MetaApi;
+use common_meta_types::AddResult;
 use common_meta_types::Change;
 use common_meta_types::Cmd;
 use common_meta_types::CreateDatabaseReply;
@@ -32,11 +34,21 @@ use common_meta_types::DropTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTableHistoryReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::OkOrExist;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -182,6 +194,7 @@ impl MetaApi for StateMachine {
             tenant,
             db_name: db_name.clone(),
             table_name: table_name.clone(),
+            drop_on: Some(Utc::now()),
         };
 
         let res = self.sm_tree.txn(true, |t| {
@@ -201,6 +214,93 @@ impl MetaApi for StateMachine {
         Ok(DropTableReply {})
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply, ErrorCode> {
+        let cr = Cmd::RenameTable {
+            tenant: req.tenant.clone(),
+            db_name: req.db.clone(),
+            table_name: req.table.clone(),
+            new_db_name: req.new_db.clone(),
+            new_table_name: req.new_table.clone(),
+        };
+
+        // An unknown database is returned as an error instead of a panic.
+        let res = self.sm_tree.txn(true, |t| self.apply_cmd(&cr, &t))?;
+
+        if res.result().is_none() {
+            return if req.if_exists {
+                Ok(RenameTableReply {})
+            } else {
+                Err(ErrorCode::UnknownTable(format!(
+                    "Unknown table: '{:}'",
+                    req.table
+                )))
+            };
+        }
+
+        let add_res: AddResult<TableMeta, u64> = res.try_into()?;
+        if let OkOrExist::Exists(_) = add_res.res {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.new_table
+            )));
+        }
+
+        Ok(RenameTableReply {})
+    }
+
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply, ErrorCode> {
+        let cr = Cmd::UndropTable {
+            tenant: req.tenant.clone(),
+            db_name: req.db.clone(),
+            table_name: req.table.clone(),
+            drop_after: req.drop_after,
+        };
+
+        let res = self.sm_tree.txn(true, |t| self.apply_cmd(&cr, &t))?;
+
+        if res.result().is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "No restorable dropped table: '{:}'",
+                req.table
+            )));
+        }
+
+        let add_res: AddResult<TableMeta, u64> = res.try_into()?;
+        if let OkOrExist::Exists(_) = add_res.res {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.table
+            )));
+        }
+
+        Ok(UndropTableReply {
+            table_id: add_res.id.unwrap(),
+        })
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply, ErrorCode> {
+        let cr = Cmd::PurgeDroppedTable {
+            tenant: req.tenant.clone(),
+            db_name: req.db.clone(),
+            table_name: req.table.clone(),
+            table_id: req.table_id,
+        };
+
+        let res = self.sm_tree.txn(true, |t| self.apply_cmd(&cr, &t))?;
+
+        if res.prev().is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "No dropped table of id {}: '{:}'",
+                req.table_id, req.table
+            )));
+        }
+
+        Ok(PurgeDroppedTableReply {})
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>, ErrorCode> {
         let tenant = &req.tenant;
         let db = &req.db_name;
@@ -272,6 +372,44 @@ impl MetaApi for StateMachine {
         Ok(tbls)
     }
 
+    async fn list_tables_history(
+        &self,
+        req: ListTableHistoryReq,
+    ) -> Result<Vec<Arc<TableInfo>>, ErrorCode> {
+        let tenant = &req.tenant;
+        let db_name = &req.db_name;
+        let db_id = self.get_database_id(tenant, db_name)?;
+
+        let mut tbls = self.list_tables(ListTableReq::new(tenant, db_name)).await?;
+
+        let tables = self.tables();
+        let history_iter = self.table_history().range(..)?;
+        for r in history_iter {
+            let (k, seq_table_ids) = r?;
+
+            if k.database_id != db_id {
+                continue;
+            }
+
+            for table_id in seq_table_ids.data.0 {
+                let seq_table_meta = tables.get(&table_id)?.ok_or_else(|| {
+                    ErrorCode::IllegalMetaState(format!(" table of id {}, not found", table_id))
+                })?;
+
+                let table_info = TableInfo::new(
+                    db_name,
+                    &k.table_name,
+                    TableIdent::new(table_id, seq_table_meta.seq),
+                    seq_table_meta.data,
+                );
+
+                tbls.push(Arc::new(table_info));
+            }
+        }
+
+        Ok(tbls)
+    }
+
     async fn get_table_by_id(
         &self,
         table_id: MetaId,
@@ -311,6 +449,29 @@ impl MetaApi for StateMachine {
         Ok(UpsertTableOptionReply {})
     }
 
+    async fn update_table_meta(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply, ErrorCode> {
+        let cmd = Cmd::UpdateTableMeta(req.clone());
+
+        let res = self.sm_tree.txn(true, |t| {
+            let r = self.apply_cmd(&cmd, &t).unwrap();
+            Ok(r)
+        })?;
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let (prev, _result) = ch.unwrap();
+
+            return Err(ErrorCode::TableVersionMissMatch(format!(
+                "targeting version {:?}, current version {}",
+                req.seq, prev.seq,
+            )));
+        }
+
+        Ok(UpdateTableMetaReply {})
+    }
+
     fn name(&self) -> String {
         "StateMachine".to_string()
     }
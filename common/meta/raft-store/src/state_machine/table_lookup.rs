@@ -65,3 +65,13 @@ impl fmt::Display for TableLookupValue {
         write!(f, "{}", self.0)
     }
 }
+
+/// The ids of the dropped tables of a name, in the order they are dropped.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct TableIdList(pub Vec<u64>);
+
+impl fmt::Display for TableIdList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
@@ -69,3 +69,13 @@ async fn test_meta_embedded_table_list() -> anyhow::Result<()> {
 
     MetaApiTestSuite {}.table_list(&sm).await
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_meta_embedded_table_rename_undrop() -> anyhow::Result<()> {
+    let (_log_guards, ut_span) = init_raft_store_ut!();
+    let _ent = ut_span.enter();
+    let tc = new_raft_test_context();
+    let sm = StateMachine::open(&tc.raft_config, 1).await?;
+
+    MetaApiTestSuite {}.table_rename_undrop(&sm).await
+}
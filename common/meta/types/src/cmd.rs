@@ -15,6 +15,8 @@
 use std::fmt;
 
 use async_raft::NodeId;
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -24,6 +26,7 @@ use crate::MatchSeq;
 use crate::Node;
 use crate::Operation;
 use crate::TableMeta;
+use crate::UpdateTableMetaReq;
 use crate::UpsertTableOptionReq;
 
 /// A Cmd describes what a user want to do to raft state machine
@@ -56,10 +59,52 @@ pub enum Cmd {
     },
 
     /// Drop a table if absent
+    ///
+    /// The table is moved into the history of its database with the time it is dropped at,
+    /// instead of being removed, thus it can be restored by `UndropTable`.
+    /// A table is removed at once if `drop_on` is absent, as the entries of older clients are.
     DropTable {
         tenant: String,
         db_name: String,
         table_name: String,
+        #[serde(default)]
+        drop_on: Option<DateTime<Utc>>,
+    },
+
+    /// Rename a table, the target database can be a different one from the source database.
+    ///
+    /// It returns the state of the target name: (None, Some) if the table is renamed,
+    /// (Some, Some) if the target name is already taken, or (None, None) if the source table is absent.
+    RenameTable {
+        tenant: String,
+        db_name: String,
+        table_name: String,
+        new_db_name: String,
+        new_table_name: String,
+    },
+
+    /// Restore the most recently dropped table of a name.
+    ///
+    /// It returns the state of the name: (None, Some) if the table is restored,
+    /// (Some, Some) if the name is already taken, or (None, None) if there is no dropped table of the name,
+    /// or the table is dropped before `drop_after`.
+    UndropTable {
+        tenant: String,
+        db_name: String,
+        table_name: String,
+        #[serde(default)]
+        drop_after: Option<DateTime<Utc>>,
+    },
+
+    /// Remove a dropped table from the history of its name, along with its meta.
+    ///
+    /// It returns (Some, None) if the table is purged,
+    /// or (None, None) if the table is not a dropped one of the name, e.g., it is restored.
+    PurgeDroppedTable {
+        tenant: String,
+        db_name: String,
+        table_name: String,
+        table_id: u64,
     },
 
     /// Update, remove or insert table options.
@@ -71,6 +116,15 @@ pub enum Cmd {
     /// Otherwise it returns the TableMeta before and after update.
     UpsertTableOptions(UpsertTableOptionReq),
 
+    /// Replace the whole meta of a table, e.g., the schema after an `ALTER TABLE`.
+    ///
+    /// This Cmd requires a present table to operate on.
+    /// Otherwise an `UnknownTableId` is returned.
+    ///
+    /// With mismatched seq, it returns a unchanged state: (prev:TableMeta, prev:TableMeta)
+    /// Otherwise it returns the TableMeta before and after update.
+    UpdateTableMeta(UpdateTableMetaReq),
+
     /// Update or insert a general purpose kv store
     UpsertKV {
         key: String,
@@ -120,8 +174,50 @@ impl fmt::Display for Cmd {
                 tenant,
                 db_name,
                 table_name,
+                drop_on,
             } => {
-                write!(f, "delete_table:{}/{}-{}", tenant, db_name, table_name)
+                write!(f, "delete_table:{}/{}-{}", tenant, db_name, table_name)?;
+                match drop_on {
+                    Some(drop_on) => write!(f, " at {}", drop_on),
+                    None => Ok(()),
+                }
+            }
+            Cmd::RenameTable {
+                tenant,
+                db_name,
+                table_name,
+                new_db_name,
+                new_table_name,
+            } => {
+                write!(
+                    f,
+                    "rename_table:{}/{}-{}=>{}-{}",
+                    tenant, db_name, table_name, new_db_name, new_table_name
+                )
+            }
+            Cmd::UndropTable {
+                tenant,
+                db_name,
+                table_name,
+                drop_after,
+            } => {
+                write!(f, "undrop_table:{}/{}-{}", tenant, db_name, table_name)?;
+                match drop_after {
+                    Some(drop_after) => write!(f, " after {}", drop_after),
+                    None => Ok(()),
+                }
+            }
+            Cmd::PurgeDroppedTable {
+                tenant,
+                db_name,
+                table_name,
+                table_id,
+            } => {
+                write!(
+                    f,
+                    "purge_dropped_table:{}/{}-{}={}",
+                    tenant, db_name, table_name, table_id
+                )
             }
             Cmd::UpsertKV {
                 key,
@@ -142,6 +238,13 @@ impl fmt::Display for Cmd {
                     req.table_id, req.seq, req.options
                 )
             }
+            Cmd::UpdateTableMeta(req) => {
+                write!(
+                    f,
+                    "update-table-meta: table-id:{}({:?}) = {}",
+                    req.table_id, req.seq, req.new_table_meta
+                )
+            }
         }
     }
 }
@@ -32,6 +32,7 @@ use crate::GetKVReq;
 use crate::GetTableReq;
 use crate::ListDatabaseReq;
 use crate::ListKVReq;
+use crate::ListTableHistoryReq;
 use crate::ListTableReq;
 use crate::LogEntry;
 use crate::MGetKVActionReply;
@@ -64,6 +65,7 @@ pub enum ForwardRequestBody {
     ListDatabase(ListDatabaseReq),
     GetDatabase(GetDatabaseReq),
     ListTable(ListTableReq),
+    ListTableHistory(ListTableHistoryReq),
     GetTable(GetTableReq),
 
     GetKV(GetKVReq),
@@ -107,7 +107,18 @@ pub struct TableMeta {
     pub engine: String,
     pub engine_options: HashMap<String, String>,
     pub options: HashMap<String, String>,
+    /// The serialized expressions of the cluster key, `None` if the table is not clustered.
+    #[serde(default)]
+    pub cluster_keys: Option<String>,
+    /// The serialized expression of the time to live of the rows, `None` if the rows never expire.
+    #[serde(default)]
+    pub ttl: Option<String>,
     pub created_on: DateTime<Utc>,
+    /// The time the table is dropped at, `None` if the table is not dropped.
+    ///
+    /// A dropped table is kept in the history of its database, until it is restored by `UNDROP TABLE`.
+    #[serde(default)]
+    pub drop_on: Option<DateTime<Utc>>,
 }
 
 impl TableInfo {
@@ -163,7 +174,10 @@ impl Default for TableMeta {
             engine: "".to_string(),
             engine_options: HashMap::new(),
             options: HashMap::new(),
+            cluster_keys: None,
+            ttl: None,
             created_on: Utc::now(),
+            drop_on: None,
         }
     }
 }
@@ -213,6 +227,48 @@ pub struct DropTableReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct DropTableReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableReq {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub db: String,
+    pub table: String,
+    pub new_db: String,
+    pub new_table: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct RenameTableReply {}
+
+/// Restores the most recently dropped table of the name.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UndropTableReq {
+    pub tenant: String,
+    pub db: String,
+    pub table: String,
+
+    /// Only a table dropped after it can be restored, i.e., a table out of the retention is not restorable.
+    #[serde(default)]
+    pub drop_after: Option<DateTime<Utc>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UndropTableReply {
+    pub table_id: u64,
+}
+
+/// Removes a dropped table from the history of its name, it can not be restored any more.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PurgeDroppedTableReq {
+    pub tenant: String,
+    pub db: String,
+    pub table: String,
+    pub table_id: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct PurgeDroppedTableReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReq {
     pub table_id: u64,
@@ -242,6 +298,26 @@ impl UpsertTableOptionReq {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct UpsertTableOptionReply {}
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableMetaReq {
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub new_table_meta: TableMeta,
+}
+
+impl UpdateTableMetaReq {
+    pub fn new(table_ident: &TableIdent, new_table_meta: TableMeta) -> UpdateTableMetaReq {
+        UpdateTableMetaReq {
+            table_id: table_ident.table_id,
+            seq: MatchSeq::Exact(table_ident.version),
+            new_table_meta,
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct UpdateTableMetaReply {}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct GetTableReq {
     pub inner: TableNameIndent,
@@ -296,3 +372,28 @@ impl ListTableReq {
         }
     }
 }
+
+/// Lists the tables of a database, including the dropped ones.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ListTableHistoryReq {
+    pub inner: DatabaseNameIdent,
+}
+
+impl Deref for ListTableHistoryReq {
+    type Target = DatabaseNameIdent;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl ListTableHistoryReq {
+    pub fn new(tenant: impl Into<String>, db_name: impl Into<String>) -> ListTableHistoryReq {
+        ListTableHistoryReq {
+            inner: DatabaseNameIdent {
+                tenant: tenant.into(),
+                db_name: db_name.into(),
+            },
+        }
+    }
+}
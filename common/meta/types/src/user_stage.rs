@@ -73,6 +73,7 @@ pub enum Format {
     Csv,
     Parquet,
     Json,
+    Tsv,
 }
 
 impl Default for Format {
@@ -89,7 +90,8 @@ impl FromStr for Format {
         match s.as_str() {
             "csv" => Ok(Format::Csv),
             "parquet" => Ok(Format::Parquet),
-            "json" => Ok(Format::Json),
+            "json" | "ndjson" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
 
             other => Err(ErrorCode::StrParseError(format!(
                 "no match for format: {}",
@@ -133,6 +135,8 @@ impl FromStr for Compression {
             "zstd" => Ok(Compression::Zstd),
             "deflate" => Ok(Compression::Deflate),
             "raw_deflate" => Ok(Compression::RawDeflate),
+            "lzo" => Ok(Compression::Lzo),
+            "snappy" => Ok(Compression::Snappy),
             "none" => Ok(Compression::None),
             other => Err(ErrorCode::StrParseError(format!(
                 "no match for compression: {}",
@@ -190,3 +194,13 @@ impl TryFrom<Vec<u8>> for UserStageInfo {
         }
     }
 }
+
+/// A file loaded into a table by COPY, a file with the same info is not loaded again.
+#[derive(serde::Serialize, serde::Deserialize, Default, Clone, Debug, Eq, PartialEq)]
+#[serde(default)]
+pub struct CopiedFileInfo {
+    pub etag: Option<String>,
+    pub content_length: u64,
+    /// Seconds since the unix epoch.
+    pub last_modified: Option<i64>,
+}
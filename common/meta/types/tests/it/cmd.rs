@@ -0,0 +1,34 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::Cmd;
+
+#[test]
+fn test_drop_table_cmd_compatible() -> Result<()> {
+    // An entry written before `drop_on` is introduced.
+    let ser = r#"{"DropTable":{"tenant":"t","db_name":"db","table_name":"tb"}}"#;
+    let de: Cmd = serde_json::from_str(ser)?;
+    assert_eq!(
+        Cmd::DropTable {
+            tenant: "t".to_string(),
+            db_name: "db".to_string(),
+            table_name: "tb".to_string(),
+            drop_on: None,
+        },
+        de
+    );
+
+    Ok(())
+}
@@ -17,8 +17,10 @@ mod plan_aggregator_partial;
 mod plan_broadcast;
 mod plan_builder;
 mod plan_copy;
+mod plan_copy_into_stage;
 mod plan_database_create;
 mod plan_database_drop;
+mod plan_delete;
 mod plan_describe_stage;
 mod plan_describe_table;
 mod plan_display;
@@ -42,9 +44,11 @@ mod plan_filter;
 mod plan_grant_privilege;
 mod plan_having;
 mod plan_insert_into;
+mod plan_join;
 mod plan_kill;
 mod plan_limit;
 mod plan_limit_by;
+mod plan_list_stage;
 mod plan_node;
 mod plan_partition;
 mod plan_projection;
@@ -62,10 +66,14 @@ mod plan_sort;
 mod plan_stage;
 mod plan_statistics;
 mod plan_subqueries_set;
+mod plan_table_alter;
 mod plan_table_create;
 mod plan_table_drop;
 mod plan_table_optimize;
+mod plan_table_rename;
+mod plan_table_undrop;
 mod plan_truncate_table;
+mod plan_update;
 mod plan_use_database;
 mod plan_use_tenant;
 mod plan_user_alter;
@@ -83,10 +91,13 @@ pub use plan_aggregator_final::AggregatorFinalPlan;
 pub use plan_aggregator_partial::AggregatorPartialPlan;
 pub use plan_broadcast::BroadcastPlan;
 pub use plan_builder::PlanBuilder;
+pub use plan_copy::CopyOnError;
 pub use plan_copy::CopyPlan;
+pub use plan_copy_into_stage::CopyIntoStagePlan;
 pub use plan_database_create::CreateDatabasePlan;
 pub use plan_database_create::DatabaseOptions;
 pub use plan_database_drop::DropDatabasePlan;
+pub use plan_delete::DeletePlan;
 pub use plan_describe_stage::DescribeStagePlan;
 pub use plan_describe_table::DescribeTablePlan;
 pub use plan_empty::EmptyPlan;
@@ -131,9 +142,12 @@ pub use plan_grant_privilege::GrantPrivilegePlan;
 pub use plan_having::HavingPlan;
 pub use plan_insert_into::InsertInputSource;
 pub use plan_insert_into::InsertPlan;
+pub use plan_join::JoinPlan;
+pub use plan_join::JoinType;
 pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
 pub use plan_limit_by::LimitByPlan;
+pub use plan_list_stage::ListStagePlan;
 pub use plan_node::PlanNode;
 pub use plan_partition::Part;
 pub use plan_partition::Partitions;
@@ -156,12 +170,17 @@ pub use plan_stage::StageKind;
 pub use plan_stage::StagePlan;
 pub use plan_statistics::Statistics;
 pub use plan_subqueries_set::SubQueriesSetPlan;
+pub use plan_table_alter::AlterTableOperation;
+pub use plan_table_alter::AlterTablePlan;
 pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_drop::DropTablePlan;
 pub use plan_table_optimize::Optimization;
 pub use plan_table_optimize::OptimizeTablePlan;
+pub use plan_table_rename::RenameTablePlan;
+pub use plan_table_undrop::UndropTablePlan;
 pub use plan_truncate_table::TruncateTablePlan;
+pub use plan_update::UpdatePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_use_tenant::UseTenantPlan;
 pub use plan_user_alter::AlterUserPlan;
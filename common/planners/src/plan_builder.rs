@@ -20,6 +20,7 @@ use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::col;
@@ -34,6 +35,8 @@ use crate::Expression;
 use crate::ExpressionPlan;
 use crate::FilterPlan;
 use crate::HavingPlan;
+use crate::JoinPlan;
+use crate::JoinType;
 use crate::LimitByPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
@@ -208,6 +211,38 @@ impl PlanBuilder {
         })))
     }
 
+    /// Join with the right plan, the current plan is the left side
+    pub fn join(
+        &self,
+        join_type: JoinType,
+        left_keys: &[Expression],
+        right_keys: &[Expression],
+        right: &PlanNode,
+    ) -> Result<Self> {
+        if left_keys.len() != right_keys.len() {
+            return Err(ErrorCode::LogicalError(format!(
+                "Join keys mismatch, left: {}, right: {}",
+                left_keys.len(),
+                right_keys.len()
+            )));
+        }
+
+        for expr in left_keys.iter().chain(right_keys.iter()) {
+            validate_expression(expr)?;
+        }
+
+        let left_schema = self.plan.schema();
+        let right_schema = right.schema();
+        Ok(Self::from(&PlanNode::Join(JoinPlan {
+            join_type,
+            left_keys: left_keys.to_vec(),
+            right_keys: right_keys.to_vec(),
+            left: Arc::new(self.plan.clone()),
+            right: Arc::new(right.clone()),
+            schema: JoinPlan::join_schema(&left_schema, &right_schema, join_type),
+        })))
+    }
+
     /// Apply a having
     pub fn having(&self, expr: Expression) -> Result<Self> {
         validate_expression(&expr)?;
@@ -13,10 +13,46 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::str::FromStr;
 
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_meta_types::MetaId;
 
+/// What COPY does with a file it fails to read.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum CopyOnError {
+    /// Loads the rows read before the error, and goes on with the next files.
+    Continue,
+    /// Loads nothing of the file, and goes on with the next files.
+    SkipFile,
+    /// Fails the whole COPY.
+    Abort,
+}
+
+impl Default for CopyOnError {
+    fn default() -> Self {
+        CopyOnError::Abort
+    }
+}
+
+impl FromStr for CopyOnError {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<CopyOnError> {
+        match s.to_uppercase().as_str() {
+            "CONTINUE" => Ok(CopyOnError::Continue),
+            "SKIP_FILE" => Ok(CopyOnError::SkipFile),
+            "ABORT" => Ok(CopyOnError::Abort),
+            other => Err(ErrorCode::BadOption(format!(
+                "Unknown ON_ERROR: {}, expect one of CONTINUE, SKIP_FILE, ABORT",
+                other
+            ))),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone)]
 pub struct CopyPlan {
     pub db_name: String,
@@ -24,6 +60,9 @@ pub struct CopyPlan {
     pub tbl_id: MetaId,
     pub schema: DataSchemaRef,
     pub location: String,
+    /// Loads the files under the location whose paths fully match the regex.
+    pub pattern: Option<String>,
+    pub on_error: CopyOnError,
     pub format: String,
     pub options: HashMap<String, String>,
 }
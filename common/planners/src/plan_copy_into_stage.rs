@@ -0,0 +1,42 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_meta_types::FileFormat;
+
+use crate::PlanNode;
+
+/// Unloads the result of the query to the files under the stage location.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Clone)]
+pub struct CopyIntoStagePlan {
+    pub location: String,
+    pub query: Box<PlanNode>,
+    pub file_format: FileFormat,
+    /// A new file is started once the file written reaches this size in bytes.
+    pub max_file_size: u64,
+}
+
+impl CopyIntoStagePlan {
+    /// The files written, with the number of their rows and their sizes in bytes.
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("file", DataType::String, false),
+            DataField::new("rows", DataType::UInt64, false),
+            DataField::new("bytes", DataType::UInt64, false),
+        ])
+    }
+}
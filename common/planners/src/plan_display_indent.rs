@@ -24,6 +24,7 @@ use crate::DropDatabasePlan;
 use crate::DropTablePlan;
 use crate::Expression;
 use crate::ExpressionPlan;
+use crate::JoinPlan;
 use crate::LimitPlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
@@ -63,6 +64,7 @@ impl<'a> fmt::Display for PlanNodeIndentFormatDisplay<'a> {
             PlanNode::AggregatorFinal(plan) => Self::format_aggregator_final(f, plan),
             PlanNode::Filter(plan) => write!(f, "Filter: {:?}", plan.predicate),
             PlanNode::Having(plan) => write!(f, "Having: {:?}", plan.predicate),
+            PlanNode::Join(plan) => Self::format_join(f, plan),
             PlanNode::Sort(plan) => Self::format_sort(f, plan),
             PlanNode::Limit(plan) => Self::format_limit(f, plan),
             PlanNode::SubQueryExpression(plan) => Self::format_subquery_expr(f, plan),
@@ -191,6 +193,14 @@ impl<'a> PlanNodeIndentFormatDisplay<'a> {
         }
     }
 
+    fn format_join(f: &mut Formatter, plan: &JoinPlan) -> fmt::Result {
+        write!(
+            f,
+            "HashJoin: {}, left keys=[{:?}], right keys=[{:?}]",
+            plan.join_type, plan.left_keys, plan.right_keys
+        )
+    }
+
     fn format_subquery_expr(f: &mut Formatter, plan: &SubQueriesSetPlan) -> fmt::Result {
         let mut names = Vec::with_capacity(plan.expressions.len());
         for expression in &plan.expressions {
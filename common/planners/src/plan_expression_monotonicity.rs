@@ -22,6 +22,7 @@ use common_datavalues::DataType;
 use common_datavalues::DataTypeAndNullable;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_functions::scalars::CastFunction;
 use common_functions::scalars::Function;
 use common_functions::scalars::FunctionFactory;
 use common_functions::scalars::Monotonicity;
@@ -166,6 +167,35 @@ impl ExpressionMonotonicityVisitor {
         Ok(self)
     }
 
+    fn visit_cast(mut self, data_type: &DataType) -> Result<Self> {
+        let (arg_type, arg_monotonic) = self.stack.pop().ok_or_else(|| {
+            ErrorCode::LogicalError("Expected 1 arguments, actual 0.".to_string())
+        })?;
+
+        let func = CastFunction::create("cast".to_string(), data_type.clone())?;
+        let return_type = func.return_type(&[arg_type.clone()])?;
+
+        let mut monotonic = if self.single_point || arg_monotonic.is_constant {
+            Monotonicity::create_constant()
+        } else if is_order_preserving_cast(arg_type.data_type(), data_type) {
+            Monotonicity::clone_without_range(&arg_monotonic)
+        } else {
+            return Err(ErrorCode::UnknownException(format!(
+                "Cast from {:?} to {:?} is not monotonic",
+                arg_type.data_type(),
+                data_type
+            )));
+        };
+
+        monotonic.left =
+            Self::try_calculate_boundary(func.as_ref(), data_type, vec![arg_monotonic.left])?;
+        monotonic.right =
+            Self::try_calculate_boundary(func.as_ref(), data_type, vec![arg_monotonic.right])?;
+
+        self.stack.push((return_type, monotonic));
+        Ok(self)
+    }
+
     /// Check whether the expression is monotonic or not. The left should be <= right.
     /// Return the monotonicity information, together with column name if any.
     pub fn check_expression(
@@ -271,8 +301,31 @@ impl ExpressionVisitor for ExpressionMonotonicityVisitor {
             Expression::BinaryExpression { op, .. } => self.visit_function(op, 2),
             Expression::UnaryExpression { op, .. } => self.visit_function(op, 1),
             Expression::ScalarFunction { op, args } => self.visit_function(op, args.len()),
-            // Todo: Expression::Cast
+            Expression::Cast { data_type, .. } => self.visit_cast(data_type),
             _ => Err(ErrorCode::UnknownException("Unable to get monotonicity")),
         }
     }
 }
+
+// Whether the order of the values is kept after casting them from one type to the other,
+// e.g. widening the integers, or truncating the datetimes to the dates.
+fn is_order_preserving_cast(from: &DataType, to: &DataType) -> bool {
+    if from == to {
+        return true;
+    }
+
+    let byte_size = |t: &DataType| t.numeric_byte_size().unwrap_or(0);
+    match (from, to) {
+        (f, t) if f.is_unsigned_integer() && t.is_unsigned_integer() => {
+            byte_size(f) <= byte_size(t)
+        }
+        (f, t) if f.is_unsigned_integer() && t.is_signed_integer() => byte_size(f) < byte_size(t),
+        (f, t) if f.is_signed_integer() && t.is_signed_integer() => byte_size(f) <= byte_size(t),
+        (f, t) if f.is_integer() && t.is_floating() => true,
+        (DataType::Float32, DataType::Float64) => true,
+        (DataType::Date16, DataType::Date32 | DataType::DateTime32(_)) => true,
+        (DataType::Date32, DataType::DateTime32(_)) => true,
+        (DataType::DateTime32(_), DataType::Date16 | DataType::Date32) => true,
+        _ => false,
+    }
+}
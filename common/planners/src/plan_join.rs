@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+
+use crate::Expression;
+use crate::PlanNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+    Cross,
+}
+
+impl JoinType {
+    /// Rows of the left(probe) side must be kept even if they have no match.
+    pub fn preserve_left(&self) -> bool {
+        matches!(self, JoinType::Left | JoinType::Full)
+    }
+
+    /// Rows of the right(build) side must be kept even if they have no match.
+    pub fn preserve_right(&self) -> bool {
+        matches!(self, JoinType::Right | JoinType::Full)
+    }
+}
+
+impl fmt::Display for JoinType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JoinType::Inner => write!(f, "INNER"),
+            JoinType::Left => write!(f, "LEFT"),
+            JoinType::Right => write!(f, "RIGHT"),
+            JoinType::Full => write!(f, "FULL"),
+            JoinType::Cross => write!(f, "CROSS"),
+        }
+    }
+}
+
+/// Equi-join of two inputs, executed as a hash join:
+/// the right input is the build side, the left input is the probe side.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct JoinPlan {
+    pub join_type: JoinType,
+    /// Key expressions evaluated on the left input
+    pub left_keys: Vec<Expression>,
+    /// Key expressions evaluated on the right input, pairwise equal to `left_keys`
+    pub right_keys: Vec<Expression>,
+    pub left: Arc<PlanNode>,
+    pub right: Arc<PlanNode>,
+    /// The output schema: all the left fields followed by all the right fields
+    pub schema: DataSchemaRef,
+}
+
+impl JoinPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        self.schema.clone()
+    }
+
+    pub fn set_inputs(&mut self, left: &PlanNode, right: &PlanNode) {
+        self.left = Arc::new(left.clone());
+        self.right = Arc::new(right.clone());
+    }
+
+    /// Build the output schema of a join, the fields of the side which may be
+    /// padded with NULLs become nullable.
+    pub fn join_schema(
+        left: &DataSchemaRef,
+        right: &DataSchemaRef,
+        join_type: JoinType,
+    ) -> DataSchemaRef {
+        let nullable = |field: &DataField, padded: bool| {
+            DataField::new(
+                field.name(),
+                field.data_type().clone(),
+                field.is_nullable() || padded,
+            )
+        };
+
+        let mut fields = Vec::with_capacity(left.fields().len() + right.fields().len());
+        for field in left.fields() {
+            fields.push(nullable(field, join_type.preserve_right()));
+        }
+        for field in right.fields() {
+            fields.push(nullable(field, join_type.preserve_left()));
+        }
+
+        Arc::new(DataSchema::new(fields))
+    }
+}
@@ -25,11 +25,14 @@ use crate::plan_user_udf_drop::DropUDFPlan;
 use crate::plan_user_udf_show::ShowUDFPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUserPlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUserPlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
@@ -43,13 +46,16 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::ListStagePlan;
 use crate::OptimizeTablePlan;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -60,6 +66,8 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 use crate::UseTenantPlan;
 
@@ -75,6 +83,7 @@ pub enum PlanNode {
     AggregatorPartial(AggregatorPartialPlan),
     AggregatorFinal(AggregatorFinalPlan),
     Filter(FilterPlan),
+    Join(JoinPlan),
     Having(HavingPlan),
     Sort(SortPlan),
     Limit(LimitPlan),
@@ -89,13 +98,19 @@ pub enum PlanNode {
     CreateTable(CreateTablePlan),
     DescribeTable(DescribeTablePlan),
     DropTable(DropTablePlan),
+    RenameTable(RenameTablePlan),
+    UndropTable(UndropTablePlan),
     OptimizeTable(OptimizeTablePlan),
+    AlterTable(AlterTablePlan),
     TruncateTable(TruncateTablePlan),
     UseDatabase(UseDatabasePlan),
     UseTenant(UseTenantPlan),
     SetVariable(SettingPlan),
     Insert(InsertPlan),
+    Delete(DeletePlan),
+    Update(UpdatePlan),
     Copy(CopyPlan),
+    CopyIntoStage(CopyIntoStagePlan),
     ShowCreateTable(ShowCreateTablePlan),
     SubQueryExpression(SubQueriesSetPlan),
     Kill(KillPlan),
@@ -107,6 +122,7 @@ pub enum PlanNode {
     CreateUserStage(CreateUserStagePlan),
     DropUserStage(DropUserStagePlan),
     DescribeStage(DescribeStagePlan),
+    ListStage(ListStagePlan),
     ShowGrants(ShowGrantsPlan),
     CreateUDF(CreateUDFPlan),
     DropUDF(DropUDFPlan),
@@ -127,6 +143,7 @@ impl PlanNode {
             PlanNode::AggregatorPartial(v) => v.schema(),
             PlanNode::AggregatorFinal(v) => v.schema(),
             PlanNode::Filter(v) => v.schema(),
+            PlanNode::Join(v) => v.schema(),
             PlanNode::Having(v) => v.schema(),
             PlanNode::Limit(v) => v.schema(),
             PlanNode::LimitBy(v) => v.schema(),
@@ -137,15 +154,21 @@ impl PlanNode {
             PlanNode::DropDatabase(v) => v.schema(),
             PlanNode::CreateTable(v) => v.schema(),
             PlanNode::DropTable(v) => v.schema(),
+            PlanNode::RenameTable(v) => v.schema(),
+            PlanNode::UndropTable(v) => v.schema(),
             PlanNode::DescribeTable(v) => v.schema(),
             PlanNode::OptimizeTable(v) => v.schema(),
+            PlanNode::AlterTable(v) => v.schema(),
             PlanNode::DescribeStage(v) => v.schema(),
+            PlanNode::ListStage(v) => v.schema(),
             PlanNode::TruncateTable(v) => v.schema(),
             PlanNode::SetVariable(v) => v.schema(),
             PlanNode::Sort(v) => v.schema(),
             PlanNode::UseDatabase(v) => v.schema(),
             PlanNode::UseTenant(v) => v.schema(),
             PlanNode::Insert(v) => v.schema(),
+            PlanNode::Delete(v) => v.schema(),
+            PlanNode::Update(v) => v.schema(),
             PlanNode::ShowCreateTable(v) => v.schema(),
             PlanNode::SubQueryExpression(v) => v.schema(),
             PlanNode::Kill(v) => v.schema(),
@@ -156,6 +179,7 @@ impl PlanNode {
             PlanNode::RevokePrivilege(v) => v.schema(),
             PlanNode::Sink(v) => v.schema(),
             PlanNode::Copy(v) => v.schema(),
+            PlanNode::CopyIntoStage(v) => v.schema(),
             PlanNode::CreateUserStage(v) => v.schema(),
             PlanNode::DropUserStage(v) => v.schema(),
             PlanNode::ShowGrants(v) => v.schema(),
@@ -178,6 +202,7 @@ impl PlanNode {
             PlanNode::AggregatorPartial(_) => "AggregatorPartialPlan",
             PlanNode::AggregatorFinal(_) => "AggregatorFinalPlan",
             PlanNode::Filter(_) => "FilterPlan",
+            PlanNode::Join(_) => "JoinPlan",
             PlanNode::Having(_) => "HavingPlan",
             PlanNode::Limit(_) => "LimitPlan",
             PlanNode::LimitBy(_) => "LimitByPlan",
@@ -189,14 +214,20 @@ impl PlanNode {
             PlanNode::CreateTable(_) => "CreateTablePlan",
             PlanNode::DescribeTable(_) => "DescribeTablePlan",
             PlanNode::OptimizeTable(_) => "OptimizeTablePlan",
+            PlanNode::AlterTable(_) => "AlterTablePlan",
             PlanNode::DescribeStage(_) => "DescribeStagePlan",
+            PlanNode::ListStage(_) => "ListStagePlan",
             PlanNode::DropTable(_) => "DropTablePlan",
+            PlanNode::RenameTable(_) => "RenameTablePlan",
+            PlanNode::UndropTable(_) => "UndropTablePlan",
             PlanNode::TruncateTable(_) => "TruncateTablePlan",
             PlanNode::SetVariable(_) => "SetVariablePlan",
             PlanNode::Sort(_) => "SortPlan",
             PlanNode::UseDatabase(_) => "UseDatabasePlan",
             PlanNode::UseTenant(_) => "UseTenant",
             PlanNode::Insert(_) => "InsertPlan",
+            PlanNode::Delete(_) => "DeletePlan",
+            PlanNode::Update(_) => "UpdatePlan",
             PlanNode::ShowCreateTable(_) => "ShowCreateTablePlan",
             PlanNode::SubQueryExpression(_) => "CreateSubQueriesSets",
             PlanNode::Kill(_) => "KillQuery",
@@ -207,6 +238,7 @@ impl PlanNode {
             PlanNode::RevokePrivilege(_) => "RevokePrivilegePlan",
             PlanNode::Sink(_) => "SinkPlan",
             PlanNode::Copy(_) => "CopyPlan",
+            PlanNode::CopyIntoStage(_) => "CopyIntoStagePlan",
             PlanNode::CreateUserStage(_) => "CreateUserStagePlan",
             PlanNode::DropUserStage(_) => "DropUserStagePlan",
             PlanNode::ShowGrants(_) => "ShowGrantsPlan",
@@ -227,6 +259,7 @@ impl PlanNode {
             PlanNode::AggregatorPartial(v) => vec![v.input.clone()],
             PlanNode::AggregatorFinal(v) => vec![v.input.clone()],
             PlanNode::Filter(v) => vec![v.input.clone()],
+            PlanNode::Join(v) => vec![v.left.clone(), v.right.clone()],
             PlanNode::Having(v) => vec![v.input.clone()],
             PlanNode::Limit(v) => vec![v.input.clone()],
             PlanNode::Explain(v) => vec![v.input.clone()],
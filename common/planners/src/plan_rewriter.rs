@@ -25,14 +25,17 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUDFPlan;
 use crate::AlterUserPlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUDFPlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
@@ -50,15 +53,18 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::ListStagePlan;
 use crate::OptimizeTablePlan;
 use crate::PlanBuilder;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -70,6 +76,8 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 use crate::UseTenantPlan;
 
@@ -100,6 +108,7 @@ pub trait PlanRewriter: Sized {
             PlanNode::Empty(plan) => self.rewrite_empty(plan),
             PlanNode::Projection(plan) => self.rewrite_projection(plan),
             PlanNode::Filter(plan) => self.rewrite_filter(plan),
+            PlanNode::Join(plan) => self.rewrite_join(plan),
             PlanNode::Sort(plan) => self.rewrite_sort(plan),
             PlanNode::Limit(plan) => self.rewrite_limit(plan),
             PlanNode::LimitBy(plan) => self.rewrite_limit_by(plan),
@@ -108,6 +117,7 @@ pub trait PlanRewriter: Sized {
             PlanNode::Explain(plan) => self.rewrite_explain(plan),
             PlanNode::CreateTable(plan) => self.rewrite_create_table(plan),
             PlanNode::OptimizeTable(plan) => self.rewrite_optimize_table(plan),
+            PlanNode::AlterTable(plan) => self.rewrite_alter_table(plan),
             PlanNode::CreateDatabase(plan) => self.rewrite_create_database(plan),
             PlanNode::UseDatabase(plan) => self.rewrite_use_database(plan),
             PlanNode::UseTenant(plan) => self.rewrite_use_tenant(plan),
@@ -119,10 +129,16 @@ pub trait PlanRewriter: Sized {
             PlanNode::Expression(plan) => self.rewrite_expression(plan),
             PlanNode::DescribeTable(plan) => self.rewrite_describe_table(plan),
             PlanNode::DescribeStage(plan) => self.rewrite_describe_stage(plan),
+            PlanNode::ListStage(plan) => self.rewrite_list_stage(plan),
             PlanNode::DropTable(plan) => self.rewrite_drop_table(plan),
+            PlanNode::RenameTable(plan) => self.rewrite_rename_table(plan),
+            PlanNode::UndropTable(plan) => self.rewrite_undrop_table(plan),
             PlanNode::DropDatabase(plan) => self.rewrite_drop_database(plan),
             PlanNode::Insert(plan) => self.rewrite_insert_into(plan),
+            PlanNode::Delete(plan) => self.rewrite_delete(plan),
+            PlanNode::Update(plan) => self.rewrite_update(plan),
             PlanNode::Copy(plan) => self.rewrite_copy(plan),
+            PlanNode::CopyIntoStage(plan) => self.rewrite_copy_into_stage(plan),
             PlanNode::ShowCreateTable(plan) => self.rewrite_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.rewrite_sub_queries_sets(plan),
             PlanNode::TruncateTable(plan) => self.rewrite_truncate_table(plan),
@@ -252,6 +268,19 @@ pub trait PlanRewriter: Sized {
         PlanBuilder::from(&new_input).filter(new_predicate)?.build()
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        let new_left = self.rewrite_plan_node(plan.left.as_ref())?;
+        let new_right = self.rewrite_plan_node(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left)
+            .join(
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                &new_right,
+            )?
+            .build()
+    }
+
     fn rewrite_having(&mut self, plan: &HavingPlan) -> Result<PlanNode> {
         let new_input = self.rewrite_plan_node(plan.input.as_ref())?;
         let new_predicate = self.rewrite_expr(&new_input.schema(), &plan.predicate)?;
@@ -303,6 +332,10 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::OptimizeTable(plan.clone()))
     }
 
+    fn rewrite_alter_table(&mut self, plan: &AlterTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::AlterTable(plan.clone()))
+    }
+
     fn rewrite_create_database(&mut self, plan: &CreateDatabasePlan) -> Result<PlanNode> {
         Ok(PlanNode::CreateDatabase(plan.clone()))
     }
@@ -327,10 +360,22 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::DescribeStage(plan.clone()))
     }
 
+    fn rewrite_list_stage(&mut self, plan: &ListStagePlan) -> Result<PlanNode> {
+        Ok(PlanNode::ListStage(plan.clone()))
+    }
+
     fn rewrite_drop_table(&mut self, plan: &DropTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::DropTable(plan.clone()))
     }
 
+    fn rewrite_rename_table(&mut self, plan: &RenameTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::RenameTable(plan.clone()))
+    }
+
+    fn rewrite_undrop_table(&mut self, plan: &UndropTablePlan) -> Result<PlanNode> {
+        Ok(PlanNode::UndropTable(plan.clone()))
+    }
+
     fn rewrite_drop_database(&mut self, plan: &DropDatabasePlan) -> Result<PlanNode> {
         Ok(PlanNode::DropDatabase(plan.clone()))
     }
@@ -339,10 +384,22 @@ pub trait PlanRewriter: Sized {
         Ok(PlanNode::Insert(plan.clone()))
     }
 
+    fn rewrite_delete(&mut self, plan: &DeletePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Delete(plan.clone()))
+    }
+
+    fn rewrite_update(&mut self, plan: &UpdatePlan) -> Result<PlanNode> {
+        Ok(PlanNode::Update(plan.clone()))
+    }
+
     fn rewrite_copy(&mut self, plan: &CopyPlan) -> Result<PlanNode> {
         Ok(PlanNode::Copy(plan.clone()))
     }
 
+    fn rewrite_copy_into_stage(&mut self, plan: &CopyIntoStagePlan) -> Result<PlanNode> {
+        Ok(PlanNode::CopyIntoStage(plan.clone()))
+    }
+
     fn rewrite_show_create_table(&mut self, plan: &ShowCreateTablePlan) -> Result<PlanNode> {
         Ok(PlanNode::ShowCreateTable(plan.clone()))
     }
@@ -18,14 +18,17 @@ use crate::plan_broadcast::BroadcastPlan;
 use crate::plan_subqueries_set::SubQueriesSetPlan;
 use crate::AggregatorFinalPlan;
 use crate::AggregatorPartialPlan;
+use crate::AlterTablePlan;
 use crate::AlterUDFPlan;
 use crate::AlterUserPlan;
+use crate::CopyIntoStagePlan;
 use crate::CopyPlan;
 use crate::CreateDatabasePlan;
 use crate::CreateTablePlan;
 use crate::CreateUDFPlan;
 use crate::CreateUserPlan;
 use crate::CreateUserStagePlan;
+use crate::DeletePlan;
 use crate::DescribeStagePlan;
 use crate::DescribeTablePlan;
 use crate::DropDatabasePlan;
@@ -41,14 +44,17 @@ use crate::FilterPlan;
 use crate::GrantPrivilegePlan;
 use crate::HavingPlan;
 use crate::InsertPlan;
+use crate::JoinPlan;
 use crate::KillPlan;
 use crate::LimitByPlan;
 use crate::LimitPlan;
+use crate::ListStagePlan;
 use crate::OptimizeTablePlan;
 use crate::PlanNode;
 use crate::ProjectionPlan;
 use crate::ReadDataSourcePlan;
 use crate::RemotePlan;
+use crate::RenameTablePlan;
 use crate::RevokePrivilegePlan;
 use crate::SelectPlan;
 use crate::SettingPlan;
@@ -60,6 +66,8 @@ use crate::SinkPlan;
 use crate::SortPlan;
 use crate::StagePlan;
 use crate::TruncateTablePlan;
+use crate::UndropTablePlan;
+use crate::UpdatePlan;
 use crate::UseDatabasePlan;
 use crate::UseTenantPlan;
 
@@ -112,6 +120,7 @@ pub trait PlanVisitor {
             PlanNode::Empty(plan) => self.visit_empty(plan),
             PlanNode::Projection(plan) => self.visit_projection(plan),
             PlanNode::Filter(plan) => self.visit_filter(plan),
+            PlanNode::Join(plan) => self.visit_join(plan),
             PlanNode::Sort(plan) => self.visit_sort(plan),
             PlanNode::Limit(plan) => self.visit_limit(plan),
             PlanNode::LimitBy(plan) => self.visit_limit_by(plan),
@@ -122,9 +131,13 @@ pub trait PlanVisitor {
             PlanNode::DropDatabase(plan) => self.visit_drop_database(plan),
             PlanNode::CreateTable(plan) => self.visit_create_table(plan),
             PlanNode::DropTable(plan) => self.visit_drop_table(plan),
+            PlanNode::RenameTable(plan) => self.visit_rename_table(plan),
+            PlanNode::UndropTable(plan) => self.visit_undrop_table(plan),
             PlanNode::DescribeTable(plan) => self.visit_describe_table(plan),
             PlanNode::OptimizeTable(plan) => self.visit_optimize_table(plan),
+            PlanNode::AlterTable(plan) => self.visit_alter_table(plan),
             PlanNode::DescribeStage(plan) => self.visit_describe_stage(plan),
+            PlanNode::ListStage(plan) => self.visit_list_stage(plan),
             PlanNode::TruncateTable(plan) => self.visit_truncate_table(plan),
             PlanNode::UseDatabase(plan) => self.visit_use_database(plan),
             PlanNode::UseTenant(plan) => self.visit_use_tenant(plan),
@@ -135,7 +148,10 @@ pub trait PlanVisitor {
             PlanNode::Having(plan) => self.visit_having(plan),
             PlanNode::Expression(plan) => self.visit_expression(plan),
             PlanNode::Insert(plan) => self.visit_insert_into(plan),
+            PlanNode::Delete(plan) => self.visit_delete(plan),
+            PlanNode::Update(plan) => self.visit_update(plan),
             PlanNode::Copy(plan) => self.visit_copy(plan),
+            PlanNode::CopyIntoStage(plan) => self.visit_copy_into_stage(plan),
             PlanNode::ShowCreateTable(plan) => self.visit_show_create_table(plan),
             PlanNode::SubQueryExpression(plan) => self.visit_sub_queries_sets(plan),
             PlanNode::Kill(plan) => self.visit_kill_query(plan),
@@ -230,6 +246,13 @@ pub trait PlanVisitor {
         self.visit_expr(&plan.predicate)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<()> {
+        self.visit_plan_node(plan.left.as_ref())?;
+        self.visit_plan_node(plan.right.as_ref())?;
+        self.visit_exprs(&plan.left_keys)?;
+        self.visit_exprs(&plan.right_keys)
+    }
+
     fn visit_having(&mut self, plan: &HavingPlan) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref())?;
         self.visit_expr(&plan.predicate)
@@ -300,14 +323,30 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_alter_table(&mut self, _: &AlterTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_describe_stage(&mut self, _: &DescribeStagePlan) -> Result<()> {
         Ok(())
     }
 
+    fn visit_list_stage(&mut self, _: &ListStagePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_drop_table(&mut self, _: &DropTablePlan) -> Result<()> {
         Ok(())
     }
 
+    fn visit_rename_table(&mut self, _: &RenameTablePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_undrop_table(&mut self, _: &UndropTablePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_use_database(&mut self, _: &UseDatabasePlan) -> Result<()> {
         Ok(())
     }
@@ -324,10 +363,22 @@ pub trait PlanVisitor {
         Ok(())
     }
 
+    fn visit_delete(&mut self, _: &DeletePlan) -> Result<()> {
+        Ok(())
+    }
+
+    fn visit_update(&mut self, _: &UpdatePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_copy(&mut self, _: &CopyPlan) -> Result<()> {
         Ok(())
     }
 
+    fn visit_copy_into_stage(&mut self, _: &CopyIntoStagePlan) -> Result<()> {
+        Ok(())
+    }
+
     fn visit_show_create_table(&mut self, _: &ShowCreateTablePlan) -> Result<()> {
         Ok(())
     }
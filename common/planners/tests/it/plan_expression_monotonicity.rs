@@ -52,6 +52,12 @@ fn create_datetime(d: u32) -> Option<DataColumnWithField> {
     Some(DataColumnWithField::new(data_column, data_field))
 }
 
+fn create_date16(d: u16) -> Option<DataColumnWithField> {
+    let data_field = DataField::new("x", DataType::Date16, false);
+    let data_column = DataColumn::Constant(DataValue::UInt16(Some(d)), 1);
+    Some(DataColumnWithField::new(data_column, data_field))
+}
+
 fn verify_test(t: Test) -> Result<()> {
     let schema = DataSchemaRefExt::create(vec![
         DataField::new("x", DataType::Float64, false),
@@ -604,3 +610,62 @@ fn test_single_point() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_cast() -> Result<()> {
+    let test_suite = vec![
+        Test {
+            name: "f(x) = cast(x as Float64)",
+            expr: Expression::Cast {
+                expr: Box::new(col("x")),
+                data_type: DataType::Float64,
+            },
+            column: "x",
+            left: create_f64(1.0),
+            right: create_f64(5.0),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_f64(1.0),
+                right: create_f64(5.0),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(z) = cast(z as Date16)",
+            expr: Expression::Cast {
+                expr: Box::new(col("z")),
+                data_type: DataType::Date16,
+            },
+            column: "z",
+            left: create_datetime(2 * 24 * 3600),
+            right: create_datetime(3 * 24 * 3600 + 5),
+            expect_mono: Monotonicity {
+                is_monotonic: true,
+                is_positive: true,
+                is_constant: false,
+                left: create_date16(2),
+                right: create_date16(3),
+            },
+            error: "",
+        },
+        Test {
+            name: "f(x) = cast(x as Int8)",
+            expr: Expression::Cast {
+                expr: Box::new(col("x")),
+                data_type: DataType::Int8,
+            },
+            column: "x",
+            left: create_f64(1.0),
+            right: create_f64(500.0),
+            expect_mono: Monotonicity::default(),
+            error: "Code: 1067, displayText = Cast from Float64 to Int8 is not monotonic.",
+        },
+    ];
+
+    for t in test_suite.into_iter() {
+        verify_test(t)?;
+    }
+    Ok(())
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod sinks;
 mod sources;
 mod stream;
 mod stream_abort;
@@ -26,6 +27,7 @@ mod stream_source;
 mod stream_sub_queries;
 mod stream_take;
 
+pub use sinks::*;
 pub use sources::*;
 pub use stream::*;
 pub use stream_abort::AbortStream;
@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_exception::Result;
+
+/// Serializes blocks into the content of a file, the counterpart of `Source`.
+pub trait Sink: Send {
+    /// Appends the rows of the block to the file.
+    fn write(&mut self, block: &DataBlock) -> Result<()>;
+
+    /// The size of the file so far, estimated by the size of the blocks if the file is
+    /// encoded when it is finished.
+    fn size(&self) -> usize;
+
+    /// Finishes the file and takes its content, the sink starts a new file then.
+    fn finish(&mut self) -> Result<Vec<u8>>;
+}
@@ -0,0 +1,115 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_exception::Result;
+
+use crate::Sink;
+
+/// Writes comma separated values, which `CsvSource` reads.
+/// The values are quoted when needed, NULL is an empty field and an empty string is `""`.
+pub struct CsvSink {
+    schema: DataSchemaRef,
+    header: bool,
+    field_delimiter: u8,
+    record_delimiter: u8,
+    buffer: Vec<u8>,
+}
+
+impl CsvSink {
+    pub fn create(
+        schema: DataSchemaRef,
+        header: bool,
+        field_delimiter: u8,
+        record_delimiter: u8,
+    ) -> Self {
+        CsvSink {
+            schema,
+            header,
+            field_delimiter,
+            record_delimiter,
+            buffer: vec![],
+        }
+    }
+
+    fn write_field(&mut self, value: &str, data_type: &DataType) {
+        let need_quote = (value.is_empty() && data_type == &DataType::String)
+            || value.bytes().any(|c| {
+                c == self.field_delimiter
+                    || c == self.record_delimiter
+                    || matches!(c, b'"' | b'\n' | b'\r')
+            });
+
+        if need_quote {
+            self.buffer.push(b'"');
+            for c in value.bytes() {
+                if c == b'"' {
+                    self.buffer.push(b'"');
+                }
+                self.buffer.push(c);
+            }
+            self.buffer.push(b'"');
+        } else {
+            self.buffer.extend_from_slice(value.as_bytes());
+        }
+    }
+}
+
+impl Sink for CsvSink {
+    fn write(&mut self, block: &DataBlock) -> Result<()> {
+        if self.header && self.buffer.is_empty() {
+            let schema = self.schema.clone();
+            for (i, field) in schema.fields().iter().enumerate() {
+                if i > 0 {
+                    self.buffer.push(self.field_delimiter);
+                }
+                self.write_field(field.name(), &DataType::Null);
+            }
+            self.buffer.push(self.record_delimiter);
+        }
+
+        let mut columns = Vec::with_capacity(block.num_columns());
+        for (column, field) in block.columns().iter().zip(block.schema().fields()) {
+            let series = column.to_array()?;
+            let values = field
+                .data_type()
+                .create_serializer()
+                .serialize_column(column)?;
+            columns.push((series, values, field.data_type()));
+        }
+
+        for row in 0..block.num_rows() {
+            for (i, (series, values, data_type)) in columns.iter().enumerate() {
+                if i > 0 {
+                    self.buffer.push(self.field_delimiter);
+                }
+                if !series.is_null(row) {
+                    self.write_field(&values[row], data_type);
+                }
+            }
+            self.buffer.push(self.record_delimiter);
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        Ok(std::mem::take(&mut self.buffer))
+    }
+}
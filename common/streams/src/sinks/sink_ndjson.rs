@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataType;
+use common_exception::Result;
+
+use crate::Sink;
+
+/// Writes newline delimited JSON, one object per row, which `NDJsonSource` reads.
+/// Numbers and booleans are JSON numbers and booleans, the other values are strings.
+#[derive(Default)]
+pub struct NDJsonSink {
+    buffer: Vec<u8>,
+}
+
+impl NDJsonSink {
+    pub fn create() -> Self {
+        NDJsonSink::default()
+    }
+}
+
+impl Sink for NDJsonSink {
+    fn write(&mut self, block: &DataBlock) -> Result<()> {
+        let mut columns = Vec::with_capacity(block.num_columns());
+        for (column, field) in block.columns().iter().zip(block.schema().fields()) {
+            let series = column.to_array()?;
+            let values = field
+                .data_type()
+                .create_serializer()
+                .serialize_column(column)?;
+            let name = serde_json::to_string(field.name())?;
+            columns.push((name, series, values, field.data_type()));
+        }
+
+        for row in 0..block.num_rows() {
+            self.buffer.push(b'{');
+            for (i, (name, series, values, data_type)) in columns.iter().enumerate() {
+                if i > 0 {
+                    self.buffer.push(b',');
+                }
+                self.buffer.extend_from_slice(name.as_bytes());
+                self.buffer.push(b':');
+
+                let value = &values[row];
+                match data_type {
+                    _ if series.is_null(row) => self.buffer.extend_from_slice(b"null"),
+                    DataType::Boolean => match value.as_str() {
+                        "1" => self.buffer.extend_from_slice(b"true"),
+                        _ => self.buffer.extend_from_slice(b"false"),
+                    },
+                    // NaN and infinity are not JSON numbers
+                    t if t.is_integer() || (t.is_floating() && is_finite(value)) => {
+                        self.buffer.extend_from_slice(value.as_bytes())
+                    }
+                    _ => serde_json::to_writer(&mut self.buffer, value)?,
+                }
+            }
+            self.buffer.extend_from_slice(b"}\n");
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        Ok(std::mem::take(&mut self.buffer))
+    }
+}
+
+fn is_finite(value: &str) -> bool {
+    value.parse::<f64>().map(|v| v.is_finite()).unwrap_or(false)
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::io::parquet::write::Compression;
+use common_arrow::arrow::io::parquet::write::RowGroupIterator;
+use common_arrow::arrow::io::parquet::write::Version;
+use common_arrow::arrow::io::parquet::write::WriteOptions;
+use common_arrow::arrow::record_batch::RecordBatch;
+use common_arrow::parquet::encoding::Encoding;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::Sink;
+
+/// Writes a parquet file, each block is a row group.
+/// The blocks are kept in memory, and encoded when the file is finished.
+pub struct ParquetSink {
+    schema: DataSchemaRef,
+    blocks: Vec<DataBlock>,
+    size: usize,
+}
+
+impl ParquetSink {
+    pub fn create(schema: DataSchemaRef) -> Self {
+        ParquetSink {
+            schema,
+            blocks: vec![],
+            size: 0,
+        }
+    }
+}
+
+impl Sink for ParquetSink {
+    fn write(&mut self, block: &DataBlock) -> Result<()> {
+        if block.num_rows() > 0 {
+            self.size += block.memory_size();
+            self.blocks.push(block.clone());
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: Compression::Snappy,
+            version: Version::V2,
+        };
+        let arrow_schema = self.schema.to_arrow();
+        let encodings = vec![Encoding::Plain; arrow_schema.fields().len()];
+
+        let batches = std::mem::take(&mut self.blocks)
+            .into_iter()
+            .map(RecordBatch::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        self.size = 0;
+
+        let row_groups = RowGroupIterator::try_new(
+            batches.into_iter().map(Ok),
+            &arrow_schema,
+            options,
+            encodings,
+        )
+        .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+        let parquet_schema = row_groups.parquet_schema().clone();
+
+        let mut buffer = vec![];
+        common_arrow::parquet::write::write_file(
+            &mut buffer,
+            row_groups,
+            parquet_schema,
+            options,
+            None,
+            None,
+        )
+        .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
+        Ok(buffer)
+    }
+}
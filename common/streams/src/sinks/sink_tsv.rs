@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+
+use crate::Sink;
+
+/// Writes tab separated values, which `TsvSource` reads.
+/// Tabs, newlines and backslashes in the values are escaped, NULL is `\N`.
+pub struct TsvSink {
+    schema: DataSchemaRef,
+    header: bool,
+    buffer: Vec<u8>,
+}
+
+impl TsvSink {
+    pub fn create(schema: DataSchemaRef, header: bool) -> Self {
+        TsvSink {
+            schema,
+            header,
+            buffer: vec![],
+        }
+    }
+
+    fn write_field(&mut self, value: &str) {
+        for c in value.bytes() {
+            match c {
+                b'\t' => self.buffer.extend_from_slice(b"\\t"),
+                b'\n' => self.buffer.extend_from_slice(b"\\n"),
+                b'\r' => self.buffer.extend_from_slice(b"\\r"),
+                b'\\' => self.buffer.extend_from_slice(b"\\\\"),
+                c => self.buffer.push(c),
+            }
+        }
+    }
+}
+
+impl Sink for TsvSink {
+    fn write(&mut self, block: &DataBlock) -> Result<()> {
+        if self.header && self.buffer.is_empty() {
+            let schema = self.schema.clone();
+            for (i, field) in schema.fields().iter().enumerate() {
+                if i > 0 {
+                    self.buffer.push(b'\t');
+                }
+                self.write_field(field.name());
+            }
+            self.buffer.push(b'\n');
+        }
+
+        let mut columns = Vec::with_capacity(block.num_columns());
+        for (column, field) in block.columns().iter().zip(block.schema().fields()) {
+            let series = column.to_array()?;
+            let values = field
+                .data_type()
+                .create_serializer()
+                .serialize_column(column)?;
+            columns.push((series, values));
+        }
+
+        for row in 0..block.num_rows() {
+            for (i, (series, values)) in columns.iter().enumerate() {
+                if i > 0 {
+                    self.buffer.push(b'\t');
+                }
+                match series.is_null(row) {
+                    true => self.buffer.extend_from_slice(b"\\N"),
+                    false => self.write_field(&values[row]),
+                }
+            }
+            self.buffer.push(b'\n');
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        Ok(std::mem::take(&mut self.buffer))
+    }
+}
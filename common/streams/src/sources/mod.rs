@@ -13,15 +13,21 @@
 // limitations under the License.
 
 mod source;
+mod source_compression;
 mod source_csv;
 mod source_factory;
+mod source_ndjson;
 mod source_parquet;
+mod source_tsv;
 mod source_values;
 
 pub use source::FormatSettings;
 pub use source::Source;
+pub use source_compression::CompressionAlgorithm;
 pub use source_csv::CsvSource;
 pub use source_factory::SourceFactory;
 pub use source_factory::SourceParams;
+pub use source_ndjson::NDJsonSource;
 pub use source_parquet::ParquetSource;
+pub use source_tsv::TsvSource;
 pub use source_values::ValueSource;
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use async_compression::futures::bufread::BrotliDecoder;
+use async_compression::futures::bufread::BzDecoder;
+use async_compression::futures::bufread::DeflateDecoder;
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::futures::bufread::ZlibDecoder;
+use async_compression::futures::bufread::ZstdDecoder;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::io::BufReader;
+use futures::AsyncRead;
+
+/// The compression of a whole input file, decompressed while it is streamed to the source.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CompressionAlgorithm {
+    Gzip,
+    Bz2,
+    Brotli,
+    Zstd,
+    /// Deflate with zlib header, RFC 1950.
+    Deflate,
+    /// Deflate without header, RFC 1951.
+    RawDeflate,
+}
+
+impl CompressionAlgorithm {
+    /// Parses the `compression` option, `auto` detects the compression by the extension of `path`.
+    pub fn from_option(option: &str, path: &str) -> Result<Option<CompressionAlgorithm>> {
+        match option.to_lowercase().as_str() {
+            "auto" => Ok(Self::from_path(path)),
+            "none" => Ok(None),
+            "gzip" => Ok(Some(CompressionAlgorithm::Gzip)),
+            "bz2" => Ok(Some(CompressionAlgorithm::Bz2)),
+            "brotli" => Ok(Some(CompressionAlgorithm::Brotli)),
+            "zstd" => Ok(Some(CompressionAlgorithm::Zstd)),
+            "deflate" => Ok(Some(CompressionAlgorithm::Deflate)),
+            "raw_deflate" => Ok(Some(CompressionAlgorithm::RawDeflate)),
+            "lzo" | "snappy" => Err(ErrorCode::UnImplement(format!(
+                "Compression {} is not supported yet",
+                option
+            ))),
+            other => Err(ErrorCode::BadOption(format!(
+                "Unknown compression: {}, expect one of auto, none, gzip, bz2, brotli, zstd, deflate, raw_deflate",
+                other
+            ))),
+        }
+    }
+
+    pub fn from_path(path: &str) -> Option<CompressionAlgorithm> {
+        let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "gz" | "gzip" => Some(CompressionAlgorithm::Gzip),
+            "bz2" => Some(CompressionAlgorithm::Bz2),
+            "br" => Some(CompressionAlgorithm::Brotli),
+            "zst" | "zstd" => Some(CompressionAlgorithm::Zstd),
+            "deflate" => Some(CompressionAlgorithm::Deflate),
+            "raw_deflate" => Some(CompressionAlgorithm::RawDeflate),
+            _ => None,
+        }
+    }
+
+    pub fn decode<R>(self, reader: R) -> Box<dyn AsyncRead + Unpin + Send>
+    where R: AsyncRead + Unpin + Send + 'static {
+        let reader = BufReader::new(reader);
+        match self {
+            CompressionAlgorithm::Gzip => {
+                let mut decoder = GzipDecoder::new(reader);
+                // concatenated gzip files are still a valid gzip file
+                decoder.multiple_members(true);
+                Box::new(decoder)
+            }
+            CompressionAlgorithm::Bz2 => Box::new(BzDecoder::new(reader)),
+            CompressionAlgorithm::Brotli => Box::new(BrotliDecoder::new(reader)),
+            CompressionAlgorithm::Zstd => Box::new(ZstdDecoder::new(reader)),
+            CompressionAlgorithm::Deflate => Box::new(ZlibDecoder::new(reader)),
+            CompressionAlgorithm::RawDeflate => Box::new(DeflateDecoder::new(reader)),
+        }
+    }
+}
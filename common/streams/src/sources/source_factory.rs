@@ -20,9 +20,12 @@ use common_exception::Result;
 use futures::AsyncRead;
 use futures::AsyncSeek;
 
+use crate::CompressionAlgorithm;
 use crate::CsvSource;
+use crate::NDJsonSource;
 use crate::ParquetSource;
 use crate::Source;
+use crate::TsvSource;
 
 pub struct SourceFactory {}
 
@@ -42,6 +45,32 @@ impl SourceFactory {
     pub fn try_get<R>(params: SourceParams<R>) -> Result<Box<dyn Source>>
     where R: AsyncRead + AsyncSeek + Unpin + Send + 'static {
         let format = params.format.to_lowercase();
+        let compression = params
+            .options
+            .get("compression")
+            .map(|v| v.as_str())
+            .unwrap_or("auto");
+        let compression = CompressionAlgorithm::from_option(compression, params.path)?;
+
+        if format == "parquet" {
+            // parquet compresses the pages, the file itself can't be decompressed as a stream
+            if let Some(compression) = compression {
+                return Err(ErrorCode::BadOption(format!(
+                    "Parquet file {} can not be compressed by {:?}",
+                    params.path, compression
+                )));
+            }
+            return Ok(Box::new(ParquetSource::new(
+                params.reader,
+                params.schema,
+                params.projection,
+            )));
+        }
+
+        let reader: Box<dyn AsyncRead + Unpin + Send> = match compression {
+            Some(compression) => compression.decode(params.reader),
+            None => Box::new(params.reader),
+        };
         match format.as_str() {
             "csv" => {
                 let has_header = params
@@ -69,7 +98,7 @@ impl SourceFactory {
                     .unwrap_or(b'\n');
 
                 Ok(Box::new(CsvSource::try_create(
-                    params.reader,
+                    reader,
                     params.schema,
                     has_header.eq_ignore_ascii_case("1"),
                     field_delimitor,
@@ -77,11 +106,25 @@ impl SourceFactory {
                     params.max_block_size,
                 )?))
             }
-            "parquet" => Ok(Box::new(ParquetSource::new(
-                params.reader,
+            "tsv" => {
+                let has_header = params
+                    .options
+                    .get("csv_header")
+                    .cloned()
+                    .unwrap_or_else(|| "0".to_string());
+
+                Ok(Box::new(TsvSource::try_create(
+                    reader,
+                    params.schema,
+                    has_header.eq_ignore_ascii_case("1"),
+                    params.max_block_size,
+                )?))
+            }
+            "json" | "ndjson" => Ok(Box::new(NDJsonSource::try_create(
+                reader,
                 params.schema,
-                params.projection,
-            ))),
+                params.max_block_size,
+            )?)),
             _ => Err(ErrorCode::InvalidSourceFormat(format)),
         }
     }
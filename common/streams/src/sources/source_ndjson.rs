@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use futures::io::BufReader;
+use futures::AsyncBufReadExt;
+use futures::AsyncRead;
+use serde_json::Value;
+
+use crate::Source;
+
+/// Reads newline delimited JSON, one object per line.
+/// The values are mapped to the columns by name, missing keys and `null` are read as NULL.
+pub struct NDJsonSource<R> {
+    reader: BufReader<R>,
+    schema: DataSchemaRef,
+    block_size: usize,
+    rows: usize,
+    buffer: String,
+}
+
+impl<R> NDJsonSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    pub fn try_create(reader: R, schema: DataSchemaRef, block_size: usize) -> Result<Self> {
+        Ok(Self {
+            reader: BufReader::new(reader),
+            schema,
+            block_size,
+            rows: 0,
+            buffer: String::new(),
+        })
+    }
+}
+
+#[async_trait]
+impl<R> Source for NDJsonSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        let mut desers = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_deserializer(self.block_size))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rows = 0;
+        loop {
+            self.buffer.clear();
+            let size = self
+                .reader
+                .read_line(&mut self.buffer)
+                .await
+                .map_err_to_code(ErrorCode::BadBytes, || {
+                    format!("Read ndjson error at line {}", self.rows)
+                })?;
+            if size == 0 {
+                break;
+            }
+
+            let line = self.buffer.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let value: Value = serde_json::from_str(line)
+                .map_err_to_code(ErrorCode::BadBytes, || {
+                    format!("Parse ndjson error at line {}", self.rows)
+                })?;
+            let object = value.as_object().ok_or_else(|| {
+                ErrorCode::BadBytes(format!(
+                    "Parse ndjson error at line {}, expect a JSON object",
+                    self.rows
+                ))
+            })?;
+
+            for (field, deser) in self.schema.fields().iter().zip(desers.iter_mut()) {
+                match object.get(field.name()) {
+                    None | Some(Value::Null) => deser.de_null(),
+                    Some(Value::String(v)) => deser.de_text(v.as_bytes())?,
+                    Some(v) => deser.de_text(v.to_string().as_bytes())?,
+                }
+            }
+            rows += 1;
+            self.rows += 1;
+
+            if rows >= self.block_size {
+                break;
+            }
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+
+        let series = desers
+            .iter_mut()
+            .map(|deser| deser.finish_to_series())
+            .collect::<Vec<_>>();
+
+        Ok(Some(DataBlock::create_by_array(
+            self.schema.clone(),
+            series,
+        )))
+    }
+}
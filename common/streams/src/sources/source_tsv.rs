@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use futures::io::BufReader;
+use futures::AsyncBufReadExt;
+use futures::AsyncRead;
+
+use crate::Source;
+
+/// Reads tab separated values, one row per line.
+/// Tabs, newlines and backslashes in the values are escaped as `\t`, `\n` and `\\`,
+/// a single `\N` is NULL.
+pub struct TsvSource<R> {
+    reader: BufReader<R>,
+    schema: DataSchemaRef,
+    header: bool,
+    block_size: usize,
+    rows: usize,
+    line: Vec<u8>,
+    field: Vec<u8>,
+}
+
+impl<R> TsvSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    pub fn try_create(
+        reader: R,
+        schema: DataSchemaRef,
+        header: bool,
+        block_size: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            reader: BufReader::new(reader),
+            schema,
+            header,
+            block_size,
+            rows: 0,
+            line: vec![],
+            field: vec![],
+        })
+    }
+
+    async fn read_line(&mut self) -> Result<bool> {
+        self.line.clear();
+        let size = self
+            .reader
+            .read_until(b'\n', &mut self.line)
+            .await
+            .map_err_to_code(ErrorCode::BadBytes, || {
+                format!("Read tsv error at line {}", self.rows)
+            })?;
+
+        while let Some(b'\n' | b'\r') = self.line.last() {
+            self.line.pop();
+        }
+        Ok(size > 0)
+    }
+}
+
+fn unescape(value: &[u8], field: &mut Vec<u8>) {
+    field.clear();
+    let mut bytes = value.iter();
+    while let Some(c) = bytes.next() {
+        match c {
+            b'\\' => match bytes.next() {
+                Some(b't') => field.push(b'\t'),
+                Some(b'n') => field.push(b'\n'),
+                Some(b'r') => field.push(b'\r'),
+                Some(b'0') => field.push(b'\0'),
+                Some(b'b') => field.push(b'\x08'),
+                Some(b'f') => field.push(b'\x0c'),
+                Some(other) => field.push(*other),
+                None => field.push(b'\\'),
+            },
+            other => field.push(*other),
+        }
+    }
+}
+
+#[async_trait]
+impl<R> Source for TsvSource<R>
+where R: AsyncRead + Unpin + Send
+{
+    async fn read(&mut self) -> Result<Option<DataBlock>> {
+        if self.header {
+            self.header = false;
+            self.read_line().await?;
+        }
+
+        let mut desers = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| f.data_type().create_deserializer(self.block_size))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut rows = 0;
+        while self.read_line().await? {
+            if self.line.is_empty() {
+                continue;
+            }
+
+            let mut values = self.line.split(|c| *c == b'\t');
+            for deser in desers.iter_mut() {
+                match values.next() {
+                    Some(b"\\N") | None => deser.de_null(),
+                    Some(value) => {
+                        unescape(value, &mut self.field);
+                        deser.de_text(&self.field)?
+                    }
+                }
+            }
+            rows += 1;
+            self.rows += 1;
+
+            if rows >= self.block_size {
+                break;
+            }
+        }
+
+        if rows == 0 {
+            return Ok(None);
+        }
+
+        let series = desers
+            .iter_mut()
+            .map(|deser| deser.finish_to_series())
+            .collect::<Vec<_>>();
+
+        Ok(Some(DataBlock::create_by_array(
+            self.schema.clone(),
+            series,
+        )))
+    }
+}
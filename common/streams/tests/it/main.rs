@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod sink;
 mod source;
 mod stream_cast;
 mod stream_datablock;
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::tokio;
+use common_datablocks::assert_blocks_eq;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::DataColumn;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_streams::CsvSink;
+use common_streams::CsvSource;
+use common_streams::NDJsonSink;
+use common_streams::NDJsonSource;
+use common_streams::ParquetSink;
+use common_streams::ParquetSource;
+use common_streams::Sink;
+use common_streams::Source;
+use common_streams::TsvSink;
+use common_streams::TsvSource;
+use futures::io::Cursor;
+
+fn test_block() -> (DataSchemaRef, DataBlock) {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, true),
+        DataField::new("b", DataType::String, true),
+        DataField::new("c", DataType::Float64, true),
+    ]);
+    let block = DataBlock::create(schema.clone(), vec![
+        DataColumn::Array(Series::new(vec![Some(1i8), None, Some(3)])),
+        DataColumn::Array(Series::new(vec![Some("x,\"y\""), Some(""), Some("a\\b")])),
+        DataColumn::Array(Series::new(vec![Some(1.5f64), Some(2.0), None])),
+    ]);
+    (schema, block)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sink_csv() -> Result<()> {
+    let (schema, block) = test_block();
+    let mut sink = CsvSink::create(schema.clone(), true, b',', b'\n');
+    sink.write(&block)?;
+    let content = sink.finish()?;
+    assert_eq!(
+        String::from_utf8_lossy(&content),
+        "a,b,c\n1,\"x,\"\"y\"\"\",1.5\n,\"\",2\n3,a\\b,\n"
+    );
+    assert_eq!(sink.size(), 0);
+
+    let mut source = CsvSource::try_create(content.as_slice(), schema, true, b',', b'\n', 10)?;
+    assert_blocks_eq(
+        vec![
+            "+------+-------+------+",
+            "| a    | b     | c    |",
+            "+------+-------+------+",
+            "| 1    | x,\"y\" | 1.5  |",
+            "| NULL |       | 2    |",
+            "| 3    | a\\b   | NULL |",
+            "+------+-------+------+",
+        ],
+        &[source.read().await?.unwrap()],
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sink_tsv() -> Result<()> {
+    let (schema, block) = test_block();
+    let mut sink = TsvSink::create(schema, false);
+    sink.write(&block)?;
+    assert_eq!(
+        String::from_utf8_lossy(&sink.finish()?),
+        "1\tx,\"y\"\t1.5\n\\N\t\t2\n3\ta\\\\b\t\\N\n"
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sink_ndjson() -> Result<()> {
+    let (schema, block) = test_block();
+    let mut sink = NDJsonSink::create();
+    sink.write(&block)?;
+    let content = sink.finish()?;
+    assert_eq!(
+        String::from_utf8_lossy(&content),
+        concat!(
+            "{\"a\":1,\"b\":\"x,\\\"y\\\"\",\"c\":1.5}\n",
+            "{\"a\":null,\"b\":\"\",\"c\":2}\n",
+            "{\"a\":3,\"b\":\"a\\\\b\",\"c\":null}\n",
+        )
+    );
+
+    let mut source = NDJsonSource::try_create(content.as_slice(), schema, 10)?;
+    assert_eq!(source.read().await?.unwrap().num_rows(), 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_sink_parquet() -> Result<()> {
+    let (schema, block) = test_block();
+    let mut sink = ParquetSink::create(schema.clone());
+    sink.write(&block)?;
+    sink.write(&block)?;
+    assert_eq!(sink.size(), block.memory_size() * 2);
+    let content = sink.finish()?;
+    assert_eq!(sink.size(), 0);
+
+    let mut source = ParquetSource::new(Cursor::new(content), schema, vec![0, 1, 2]);
+    let expected = vec![
+        "+------+-------+------+",
+        "| a    | b     | c    |",
+        "+------+-------+------+",
+        "| 1    | x,\"y\" | 1.5  |",
+        "| NULL |       | 2    |",
+        "| 3    | a\\b   | NULL |",
+        "+------+-------+------+",
+    ];
+    assert_blocks_eq(expected.clone(), &[source.read().await?.unwrap()]);
+    assert_blocks_eq(expected, &[source.read().await?.unwrap()]);
+    assert!(source.read().await?.is_none());
+    Ok(())
+}
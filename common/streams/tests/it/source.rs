@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
 
+use async_compression::futures::bufread::GzipEncoder;
 use common_base::tokio;
 use common_dal::DataAccessor;
 use common_dal::Local;
@@ -27,10 +29,17 @@ use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_streams::CompressionAlgorithm;
 use common_streams::CsvSource;
+use common_streams::NDJsonSource;
 use common_streams::ParquetSource;
 use common_streams::Source;
+use common_streams::SourceFactory;
+use common_streams::SourceParams;
+use common_streams::TsvSource;
 use common_streams::ValueSource;
+use futures::io::Cursor;
+use futures::AsyncReadExt;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_parse_values() {
@@ -120,6 +129,137 @@ async fn test_parse_csvs() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_ndjson() -> Result<()> {
+    let buffer = r#"{"b": "str", "a": 1, "c": 1.1}
+
+{"a": -1, "b": " s\"tr ", "c": null, "d": "ignored"}
+{"a": 2, "b": 22}
+"#;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::String, true),
+        DataField::new("c", DataType::Float64, true),
+    ]);
+    let mut ndjson_source = NDJsonSource::try_create(buffer.as_bytes(), schema.clone(), 10)?;
+    let block = ndjson_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+----+--------+------+",
+            "| a  | b      | c    |",
+            "+----+--------+------+",
+            "| 1  | str    | 1.1  |",
+            "| -1 |  s\"tr  | NULL |",
+            "| 2  | 22     | NULL |",
+            "+----+--------+------+",
+        ],
+        &[block],
+    );
+    assert!(ndjson_source.read().await?.is_none());
+
+    let mut ndjson_source = NDJsonSource::try_create("[1, 2]".as_bytes(), schema, 10)?;
+    let result = ndjson_source.read().await;
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().code(), ErrorCode::BadBytes("").code());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_parse_tsv() -> Result<()> {
+    let buffer = "a\tb\tc\n1\ts\\'tr\t1.1\r\n-1\t\\N\t2\n\n2\ta\\\\b\n";
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::String, true),
+        DataField::new("c", DataType::Float64, true),
+    ]);
+    let mut tsv_source = TsvSource::try_create(buffer.as_bytes(), schema, true, 2)?;
+    let block = tsv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+----+------+-----+",
+            "| a  | b    | c   |",
+            "+----+------+-----+",
+            "| 1  | s'tr | 1.1 |",
+            "| -1 | NULL | 2   |",
+            "+----+------+-----+",
+        ],
+        &[block],
+    );
+    let block = tsv_source.read().await?.unwrap();
+    assert_blocks_eq(
+        vec![
+            "+---+-----+------+",
+            "| a | b   | c    |",
+            "+---+-----+------+",
+            "| 2 | a\\b | NULL |",
+            "+---+-----+------+",
+        ],
+        &[block],
+    );
+    assert!(tsv_source.read().await?.is_none());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_source_compression() -> Result<()> {
+    let mut compressed = vec![];
+    GzipEncoder::new("1,a\n2,b\n".as_bytes())
+        .read_to_end(&mut compressed)
+        .await?;
+
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int8, false),
+        DataField::new("b", DataType::String, false),
+    ]);
+    for (path, compression) in [("data.csv.gz", "auto"), ("data.csv", "gzip")] {
+        let mut options = HashMap::new();
+        options.insert("compression".to_string(), compression.to_string());
+        let mut source = SourceFactory::try_get(SourceParams {
+            reader: Cursor::new(compressed.clone()),
+            path,
+            format: "csv",
+            schema: schema.clone(),
+            max_block_size: 10,
+            projection: vec![0, 1],
+            options: &options,
+        })?;
+        let block = source.read().await?.unwrap();
+        assert_blocks_eq(
+            vec![
+                "+---+---+",
+                "| a | b |",
+                "+---+---+",
+                "| 1 | a |",
+                "| 2 | b |",
+                "+---+---+",
+            ],
+            &[block],
+        );
+        assert!(source.read().await?.is_none());
+    }
+
+    assert_eq!(
+        CompressionAlgorithm::from_path("data.tsv.zst"),
+        Some(CompressionAlgorithm::Zstd)
+    );
+    assert_eq!(CompressionAlgorithm::from_path("data.csv"), None);
+    assert_eq!(
+        CompressionAlgorithm::from_option("lzo", "")
+            .unwrap_err()
+            .code(),
+        ErrorCode::UnImplement("").code()
+    );
+    assert_eq!(
+        CompressionAlgorithm::from_option("xz", "")
+            .unwrap_err()
+            .code(),
+        ErrorCode::BadOption("").code()
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_source_parquet() -> Result<()> {
     use common_datavalues::DataType;
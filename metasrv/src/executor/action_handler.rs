@@ -79,10 +79,26 @@ impl ActionHandler {
                 let r = self.handle(a).await.map_err(SerializedError::from);
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::RenameTable(a) => {
+                let r = self.handle(a).await.map_err(SerializedError::from);
+                RaftReply::from(r)
+            }
+            MetaGrpcWriteReq::UndropTable(a) => {
+                let r = self.handle(a).await.map_err(SerializedError::from);
+                RaftReply::from(r)
+            }
+            MetaGrpcWriteReq::PurgeDroppedTable(a) => {
+                let r = self.handle(a).await.map_err(SerializedError::from);
+                RaftReply::from(r)
+            }
             MetaGrpcWriteReq::CommitTable(a) => {
                 let r = self.handle(a).await.map_err(SerializedError::from);
                 RaftReply::from(r)
             }
+            MetaGrpcWriteReq::UpdateTableMeta(a) => {
+                let r = self.handle(a).await.map_err(SerializedError::from);
+                RaftReply::from(r)
+            }
         }
     }
 
@@ -122,6 +138,10 @@ impl ActionHandler {
                 let r = self.handle(a).await?;
                 serde_json::to_string(&r)?
             }
+            MetaGrpcReadReq::ListTablesHistory(a) => {
+                let r = self.handle(a).await?;
+                serde_json::to_string(&r)?
+            }
             MetaGrpcReadReq::GetTableExt(a) => {
                 let r = self.handle(a).await?;
                 serde_json::to_string(&r)?
@@ -15,6 +15,7 @@
 use std::convert::TryInto;
 use std::sync::Arc;
 
+use common_datavalues::chrono::Utc;
 use common_exception::ErrorCode;
 use common_meta_grpc::GetTableExtReq;
 use common_meta_types::AddResult;
@@ -23,6 +24,10 @@ use common_meta_types::Cmd::CreateDatabase;
 use common_meta_types::Cmd::CreateTable;
 use common_meta_types::Cmd::DropDatabase;
 use common_meta_types::Cmd::DropTable;
+use common_meta_types::Cmd::PurgeDroppedTable;
+use common_meta_types::Cmd::RenameTable;
+use common_meta_types::Cmd::UndropTable;
+use common_meta_types::Cmd::UpdateTableMeta;
 use common_meta_types::Cmd::UpsertTableOptions;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
@@ -37,12 +42,21 @@ use common_meta_types::DropTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTableHistoryReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::LogEntry;
 use common_meta_types::OkOrExist;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use common_tracing::tracing;
@@ -195,6 +209,7 @@ impl RequestHandler<DropTableReq> for ActionHandler {
                 tenant,
                 db_name: db_name.clone(),
                 table_name: table_name.clone(),
+                drop_on: Some(Utc::now()),
             },
         };
 
@@ -218,6 +233,122 @@ impl RequestHandler<DropTableReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<RenameTableReq> for ActionHandler {
+    async fn handle(&self, req: RenameTableReq) -> common_exception::Result<RenameTableReply> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: RenameTable {
+                tenant: req.tenant.clone(),
+                db_name: req.db.clone(),
+                table_name: req.table.clone(),
+                new_db_name: req.new_db.clone(),
+                new_table_name: req.new_table.clone(),
+            },
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if res.result().is_none() {
+            return if req.if_exists {
+                Ok(RenameTableReply {})
+            } else {
+                Err(ErrorCode::UnknownTable(format!(
+                    "Unknown table: '{:}'",
+                    req.table
+                )))
+            };
+        }
+
+        let add_res: AddResult<TableMeta, u64> = res.try_into()?;
+        if let OkOrExist::Exists(_) = add_res.res {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.new_table
+            )));
+        }
+
+        Ok(RenameTableReply {})
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<UndropTableReq> for ActionHandler {
+    async fn handle(&self, req: UndropTableReq) -> common_exception::Result<UndropTableReply> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: UndropTable {
+                tenant: req.tenant.clone(),
+                db_name: req.db.clone(),
+                table_name: req.table.clone(),
+                drop_after: req.drop_after,
+            },
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if res.result().is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "No restorable dropped table: '{:}'",
+                req.table
+            )));
+        }
+
+        let add_res: AddResult<TableMeta, u64> = res.try_into()?;
+        if let OkOrExist::Exists(_) = add_res.res {
+            return Err(ErrorCode::TableAlreadyExists(format!(
+                "table exists: {}",
+                req.table
+            )));
+        }
+
+        Ok(UndropTableReply {
+            table_id: add_res.id.unwrap(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RequestHandler<PurgeDroppedTableReq> for ActionHandler {
+    async fn handle(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> common_exception::Result<PurgeDroppedTableReply> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: PurgeDroppedTable {
+                tenant: req.tenant.clone(),
+                db_name: req.db.clone(),
+                table_name: req.table.clone(),
+                table_id: req.table_id,
+            },
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if res.prev().is_none() {
+            return Err(ErrorCode::UnknownTable(format!(
+                "No dropped table of id {}: '{:}'",
+                req.table_id, req.table
+            )));
+        }
+
+        Ok(PurgeDroppedTableReply {})
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<GetTableReq> for ActionHandler {
     async fn handle(&self, req: GetTableReq) -> common_exception::Result<Arc<TableInfo>> {
@@ -266,6 +397,17 @@ impl RequestHandler<ListTableReq> for ActionHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl RequestHandler<ListTableHistoryReq> for ActionHandler {
+    async fn handle(
+        &self,
+        req: ListTableHistoryReq,
+    ) -> common_exception::Result<Vec<Arc<TableInfo>>> {
+        let res = self.meta_node.consistent_read(req).await?;
+        Ok(res)
+    }
+}
+
 #[async_trait::async_trait]
 impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
     async fn handle(
@@ -296,3 +438,34 @@ impl RequestHandler<UpsertTableOptionReq> for ActionHandler {
         Ok(UpsertTableOptionReply {})
     }
 }
+
+#[async_trait::async_trait]
+impl RequestHandler<UpdateTableMetaReq> for ActionHandler {
+    async fn handle(
+        &self,
+        req: UpdateTableMetaReq,
+    ) -> common_exception::Result<UpdateTableMetaReply> {
+        let cr = LogEntry {
+            txid: None,
+            cmd: UpdateTableMeta(req.clone()),
+        };
+
+        let res = self
+            .meta_node
+            .write(cr)
+            .await
+            .map_err(|e| ErrorCode::MetaNodeInternalError(e.to_string()))?;
+
+        if !res.changed() {
+            let ch: Change<TableMeta> = res.try_into().unwrap();
+            let (prev, _result) = ch.unwrap();
+
+            return Err(ErrorCode::TableVersionMissMatch(format!(
+                "targeting version {:?}, current version {}",
+                req.seq, prev.seq,
+            )));
+        }
+
+        Ok(UpdateTableMetaReply {})
+    }
+}
@@ -80,6 +80,11 @@ impl<'a> MetaLeader<'a> {
                 let res = sm.list_tables(req).await?;
                 Ok(ForwardResponse::ListTable(res))
             }
+            ForwardRequestBody::ListTableHistory(req) => {
+                let sm = self.meta_node.get_state_machine().await;
+                let res = sm.list_tables_history(req).await?;
+                Ok(ForwardResponse::ListTable(res))
+            }
             ForwardRequestBody::GetTable(req) => {
                 let sm = self.meta_node.get_state_machine().await;
                 let res = sm.get_table(req).await?;
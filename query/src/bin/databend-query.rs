@@ -164,6 +164,17 @@ async fn main(_global_tracker: Arc<RuntimeTracker>) -> common_exception::Result<
         );
     }
 
+    // Table vacuum.
+    {
+        session_manager
+            .get_table_vacuum()
+            .start(session_manager.clone());
+        tracing::info!(
+            "Table vacuum started, interval: {} secs",
+            conf.query.table_vacuum_interval_secs
+        );
+    }
+
     tracing::info!("Ready for connections.");
     shutdown_handle.wait_for_termination_request().await;
     tracing::info!("Shutdown server.");
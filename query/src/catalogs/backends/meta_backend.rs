@@ -30,11 +30,20 @@ use common_meta_types::DropTableReq;
 use common_meta_types::GetDatabaseReq;
 use common_meta_types::GetTableReq;
 use common_meta_types::ListDatabaseReq;
+use common_meta_types::ListTableHistoryReq;
 use common_meta_types::ListTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -108,6 +117,24 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        self.query_backend(move |cli| async move { cli.rename_table(req).await })
+            .await
+    }
+
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        self.query_backend(move |cli| async move { cli.undrop_table(req).await })
+            .await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        self.query_backend(move |cli| async move { cli.purge_dropped_table(req).await })
+            .await
+    }
+
     async fn get_table(&self, req: GetTableReq) -> Result<Arc<TableInfo>> {
         self.query_backend(move |cli| async move { cli.get_table(req).await })
             .await
@@ -118,6 +145,11 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn list_tables_history(&self, req: ListTableHistoryReq) -> Result<Vec<Arc<TableInfo>>> {
+        self.query_backend(move |cli| async move { cli.list_tables_history(req).await })
+            .await
+    }
+
     async fn get_table_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
         self.query_backend(move |cli| async move { cli.get_table_by_id(table_id).await })
             .await
@@ -131,6 +163,11 @@ impl MetaApi for MetaBackend {
             .await
     }
 
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        self.query_backend(move |cli| async move { cli.update_table_meta(req).await })
+            .await
+    }
+
     fn name(&self) -> String {
         "meta-remote".to_owned()
     }
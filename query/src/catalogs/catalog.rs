@@ -18,14 +18,23 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
+use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 use dyn_clone::DynClone;
@@ -85,10 +94,25 @@ pub trait Catalog: DynClone + Send + Sync {
 
     async fn list_tables(&self, tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>>;
 
-    async fn create_table(&self, req: CreateTableReq) -> Result<()>;
+    // Get all the tables along with the dropped ones, a dropped table has `drop_on` in its meta.
+    async fn list_tables_history(&self, tenant: &str, db_name: &str)
+        -> Result<Vec<Arc<dyn Table>>>;
+
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply>;
 
     async fn drop_table(&self, req: DropTableReq) -> Result<DropTableReply>;
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply>;
+
+    // Restore the most recently dropped table of the name.
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply>;
+
+    // Remove a dropped table for good, it can not be restored any more.
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply>;
+
     // Check a db.table is exists or not.
     async fn exists_table(&self, tenant: &str, db_name: &str, table_name: &str) -> Result<bool> {
         match self.get_table(tenant, db_name, table_name).await {
@@ -108,6 +132,8 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpsertTableOptionReq,
     ) -> Result<UpsertTableOptionReply>;
 
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply>;
+
     ///
     /// Table function
     ///
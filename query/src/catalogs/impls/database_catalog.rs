@@ -19,14 +19,23 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
+use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -192,7 +201,30 @@ impl Catalog for DatabaseCatalog {
         }
     }
 
-    async fn create_table(&self, req: CreateTableReq) -> Result<()> {
+    async fn list_tables_history(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        let r = self
+            .immutable_catalog
+            .list_tables_history(tenant, db_name)
+            .await;
+        match r {
+            Ok(x) => Ok(x),
+            Err(e) => {
+                if e.code() == ErrorCode::UnknownDatabaseCode() {
+                    self.mutable_catalog
+                        .list_tables_history(tenant, db_name)
+                        .await
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
         if self
             .immutable_catalog
             .exists_database(&req.tenant, &req.db)
@@ -214,6 +246,46 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.drop_table(req).await
     }
 
+    async fn rename_table(&self, req: RenameTableReq) -> Result<RenameTableReply> {
+        if self
+            .immutable_catalog
+            .exists_database(&req.tenant, &req.db)
+            .await?
+            || self
+                .immutable_catalog
+                .exists_database(&req.tenant, &req.new_db)
+                .await?
+        {
+            return self.immutable_catalog.rename_table(req).await;
+        }
+        self.mutable_catalog.rename_table(req).await
+    }
+
+    async fn undrop_table(&self, req: UndropTableReq) -> Result<UndropTableReply> {
+        if self
+            .immutable_catalog
+            .exists_database(&req.tenant, &req.db)
+            .await?
+        {
+            return self.immutable_catalog.undrop_table(req).await;
+        }
+        self.mutable_catalog.undrop_table(req).await
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        if self
+            .immutable_catalog
+            .exists_database(&req.tenant, &req.db)
+            .await?
+        {
+            return self.immutable_catalog.purge_dropped_table(req).await;
+        }
+        self.mutable_catalog.purge_dropped_table(req).await
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -222,6 +294,11 @@ impl Catalog for DatabaseCatalog {
         self.mutable_catalog.upsert_table_option(req).await
     }
 
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        // update table meta in BOTTOM layer only
+        self.mutable_catalog.update_table_meta(req).await
+    }
+
     fn get_table_function(
         &self,
         func_name: &str,
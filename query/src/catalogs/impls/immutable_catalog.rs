@@ -18,14 +18,23 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::CreateDatabaseReply;
 use common_meta_types::CreateDatabaseReq;
+use common_meta_types::CreateTableReply;
 use common_meta_types::CreateTableReq;
 use common_meta_types::DropDatabaseReq;
 use common_meta_types::DropTableReply;
 use common_meta_types::DropTableReq;
 use common_meta_types::MetaId;
+use common_meta_types::PurgeDroppedTableReply;
+use common_meta_types::PurgeDroppedTableReq;
+use common_meta_types::RenameTableReply;
+use common_meta_types::RenameTableReq;
 use common_meta_types::TableIdent;
 use common_meta_types::TableInfo;
 use common_meta_types::TableMeta;
+use common_meta_types::UndropTableReply;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UpdateTableMetaReply;
+use common_meta_types::UpdateTableMetaReq;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
 
@@ -126,7 +135,15 @@ impl Catalog for ImmutableCatalog {
         self.sys_db_meta.get_all_tables()
     }
 
-    async fn create_table(&self, _req: CreateTableReq) -> Result<()> {
+    async fn list_tables_history(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        self.list_tables(tenant, db_name).await
+    }
+
+    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
         Err(ErrorCode::UnImplement(
             "Cannot create table in system database",
         ))
@@ -138,6 +155,27 @@ impl Catalog for ImmutableCatalog {
         ))
     }
 
+    async fn rename_table(&self, _req: RenameTableReq) -> Result<RenameTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot rename table in system database",
+        ))
+    }
+
+    async fn undrop_table(&self, _req: UndropTableReq) -> Result<UndropTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot undrop table in system database",
+        ))
+    }
+
+    async fn purge_dropped_table(
+        &self,
+        _req: PurgeDroppedTableReq,
+    ) -> Result<PurgeDroppedTableReply> {
+        Err(ErrorCode::UnImplement(
+            "Cannot purge table in system database",
+        ))
+    }
+
     async fn upsert_table_option(
         &self,
         req: UpsertTableOptionReq,
@@ -147,4 +185,11 @@ impl Catalog for ImmutableCatalog {
             req
         )))
     }
+
+    async fn update_table_meta(&self, req: UpdateTableMetaReq) -> Result<UpdateTableMetaReply> {
+        Err(ErrorCode::UnImplement(format!(
+            "Alter table not allowed for system database {:?}",
+            req
+        )))
+    }
 }
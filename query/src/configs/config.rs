@@ -110,6 +110,7 @@ impl Config {
         if cfg.query.num_cpus == 0 {
             cfg.query.num_cpus = num_cpus::get() as u64;
         }
+        cfg.query.check()?;
         Ok(cfg)
     }
 
@@ -133,6 +134,7 @@ impl Config {
 
         // Query.
         QueryConfig::load_from_env(&mut mut_config);
+        mut_config.query.check()?;
 
         Ok(mut_config)
     }
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use clap::Args;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -41,11 +43,19 @@ pub const QUERY_TABLE_CACHE_BLOCK_META_COUNT: &str = "QUERY_TABLE_CACHE_BLOCK_ME
 pub const QUERY_TABLE_MEMORY_CACHE_MB_SIZE: &str = "QUERY_TABLE_MEMORY_CACHE_MB_SIZE";
 pub const QUERY_TABLE_DISK_CACHE_ROOT: &str = "QUERY_TABLE_DISK_CACHE_ROOT";
 pub const QUERY_TABLE_DISK_CACHE_MB_SIZE: &str = "QUERY_TABLE_DISK_CACHE_MB_SIZE";
+pub const QUERY_TABLE_VACUUM_INTERVAL_SECS: &str = "QUERY_TABLE_VACUUM_INTERVAL_SECS";
+pub const QUERY_TABLE_ORPHAN_RETENTION_SECS: &str = "QUERY_TABLE_ORPHAN_RETENTION_SECS";
+pub const QUERY_TABLE_DROPPED_RETENTION_SECS: &str = "QUERY_TABLE_DROPPED_RETENTION_SECS";
+
+// An object written by an insertion in progress is not reachable from the table until the
+// insertion is committed, it must not be removed as an orphan before that.
+pub const MIN_TABLE_ORPHAN_RETENTION_SECS: u64 = 3600;
 
 const QUERY_HTTP_HANDLER_TLS_SERVER_CERT: &str = "QUERY_HTTP_HANDLER_TLS_SERVER_CERT";
 const QUERY_HTTP_HANDLER_TLS_SERVER_KEY: &str = "QUERY_HTTP_HANDLER_TLS_SERVER_KEY";
 const QUERY_HTTP_HANDLER_TLS_SERVER_ROOT_CA_CERT: &str =
     "QUERY_HTTP_HANDLER_TLS_SERVER_ROOT_CA_CERT";
+const QUERY_HTTP_HANDLER_JWT_SECRET_FILE: &str = "QUERY_HTTP_HANDLER_JWT_SECRET_FILE";
 
 const QUERY_API_TLS_SERVER_CERT: &str = "QUERY_API_TLS_SERVER_CERT";
 const QUERY_API_TLS_SERVER_KEY: &str = "QUERY_API_TLS_SERVER_KEY";
@@ -117,6 +127,10 @@ pub struct QueryConfig {
     #[clap(long, env = QUERY_HTTP_HANDLER_TLS_SERVER_ROOT_CA_CERT, default_value = "")]
     pub http_handler_tls_server_root_ca_cert: String,
 
+    /// Secret file to verify the http handler bearer tokens (HS256 JWT), disabled if empty
+    #[clap(long, env = QUERY_HTTP_HANDLER_JWT_SECRET_FILE, default_value = "")]
+    pub http_handler_jwt_secret_file: String,
+
     #[clap(long, env = QUERY_API_TLS_SERVER_CERT, default_value = "")]
     pub api_tls_server_cert: String,
 
@@ -205,6 +219,18 @@ pub struct QueryConfig {
     #[clap(long, env = QUERY_TABLE_DISK_CACHE_MB_SIZE, default_value = "1024")]
     pub table_disk_cache_mb_size: u64,
 
+    /// Interval of enforcing the snapshot retention policy of tables (secs), 0 disables it
+    #[clap(long, env = QUERY_TABLE_VACUUM_INTERVAL_SECS, default_value = "3600")]
+    pub table_vacuum_interval_secs: u64,
+
+    /// Age of the objects of a table, which are not reachable from it, before they are removed as orphans (secs), at least 3600
+    #[clap(long, env = QUERY_TABLE_ORPHAN_RETENTION_SECS, default_value = "86400")]
+    pub table_orphan_retention_secs: u64,
+
+    /// Time a dropped table can be restored by UNDROP TABLE within, after that it is removed by the vacuum (secs)
+    #[clap(long, env = QUERY_TABLE_DROPPED_RETENTION_SECS, default_value = "86400")]
+    pub table_dropped_retention_secs: u64,
+
     /// If in proxy mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     #[clap(long, env = QUERY_PROXY_MODE)]
     pub proxy_mode: bool,
@@ -232,6 +258,7 @@ impl Default for QueryConfig {
             http_handler_tls_server_cert: "".to_string(),
             http_handler_tls_server_key: "".to_string(),
             http_handler_tls_server_root_ca_cert: "".to_string(),
+            http_handler_jwt_secret_file: "".to_string(),
             rpc_tls_server_cert: "".to_string(),
             rpc_tls_server_key: "".to_string(),
             rpc_tls_query_server_root_ca_cert: "".to_string(),
@@ -249,6 +276,9 @@ impl Default for QueryConfig {
             table_memory_cache_mb_size: 256,
             table_disk_cache_root: "_cache".to_string(),
             table_disk_cache_mb_size: 1024,
+            table_vacuum_interval_secs: 3600,
+            table_orphan_retention_secs: 86400,
+            table_dropped_retention_secs: 86400,
             proxy_mode: false,
         }
     }
@@ -358,6 +388,14 @@ impl QueryConfig {
             QUERY_HTTP_HANDLER_TLS_SERVER_ROOT_CA_CERT
         );
 
+        env_helper!(
+            mut_config,
+            query,
+            http_handler_jwt_secret_file,
+            String,
+            QUERY_HTTP_HANDLER_JWT_SECRET_FILE
+        );
+
         // for query rpc server
         env_helper!(
             mut_config,
@@ -481,6 +519,38 @@ impl QueryConfig {
             u64,
             QUERY_TABLE_DISK_CACHE_MB_SIZE
         );
+        env_helper!(
+            mut_config,
+            query,
+            table_vacuum_interval_secs,
+            u64,
+            QUERY_TABLE_VACUUM_INTERVAL_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_orphan_retention_secs,
+            u64,
+            QUERY_TABLE_ORPHAN_RETENTION_SECS
+        );
+        env_helper!(
+            mut_config,
+            query,
+            table_dropped_retention_secs,
+            u64,
+            QUERY_TABLE_DROPPED_RETENTION_SECS
+        );
         env_helper!(mut_config, query, proxy_mode, bool, QUERY_PROXY_MODE);
     }
+
+    /// Checks the values which are not allowed, whatever they are loaded from.
+    pub fn check(&self) -> Result<()> {
+        if self.table_orphan_retention_secs < MIN_TABLE_ORPHAN_RETENTION_SECS {
+            return Err(ErrorCode::BadArguments(format!(
+                "table_orphan_retention_secs must be at least {}, but got {}",
+                MIN_TABLE_ORPHAN_RETENTION_SECS, self.table_orphan_retention_secs
+            )));
+        }
+        Ok(())
+    }
 }
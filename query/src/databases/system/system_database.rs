@@ -37,6 +37,9 @@ impl SystemDatabase {
             Arc::new(system::CreditsTable::create(sys_db_meta.next_id())),
             Arc::new(system::SettingsTable::create(sys_db_meta.next_id())),
             Arc::new(system::TablesTable::create(sys_db_meta.next_id())),
+            Arc::new(system::TablesTable::create_with_history(
+                sys_db_meta.next_id(),
+            )),
             Arc::new(system::ClustersTable::create(sys_db_meta.next_id())),
             Arc::new(system::DatabasesTable::create(sys_db_meta.next_id())),
             Arc::new(system::TracingTable::create(sys_db_meta.next_id())),
@@ -46,6 +49,7 @@ impl SystemDatabase {
             Arc::new(system::ColumnsTable::create(sys_db_meta.next_id())),
             Arc::new(system::UsersTable::create(sys_db_meta.next_id())),
             Arc::new(system::QueryLogTable::create(sys_db_meta.next_id())),
+            Arc::new(system::VacuumTable::create(sys_db_meta.next_id())),
         ];
 
         for tbl in table_list.into_iter() {
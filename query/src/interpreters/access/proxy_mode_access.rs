@@ -42,6 +42,8 @@ impl ProxyModeAccess {
                 | PlanNode::DescribeTable(_)
                 | PlanNode::DescribeStage(_)
                 | PlanNode::DropTable(_)
+                | PlanNode::RenameTable(_)
+                | PlanNode::UndropTable(_)
                 | PlanNode::ShowCreateTable(_)
                 | PlanNode::CreateUser(_)
                 | PlanNode::AlterUser(_)
@@ -15,9 +15,13 @@
 use std::sync::Arc;
 
 use common_dal::DataAccessor;
+use common_dal::ObjectMeta;
 use common_dal::S3;
+use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+use common_planners::CopyOnError;
 use common_planners::CopyPlan;
 use common_streams::DataBlockStream;
 use common_streams::ProgressStream;
@@ -25,15 +29,19 @@ use common_streams::SendableDataBlockStream;
 use common_streams::SourceFactory;
 use common_streams::SourceParams;
 use common_streams::SourceStream;
+use common_tracing::tracing;
 use futures::io::BufReader;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use nom::bytes::complete::tag;
 use nom::bytes::complete::take_until;
 use nom::IResult;
+use regex::Regex;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::Table;
 
 pub struct CopyInterpreter {
     ctx: Arc<QueryContext>,
@@ -71,13 +79,116 @@ impl Interpreter for CopyInterpreter {
         let (stage, path) = c.unwrap();
 
         let acc = get_dal_by_stage(self.ctx.clone(), stage)?;
+        let files = self.list_files(acc.as_ref(), path).await?;
+
+        // the files loaded before are skipped, unless they are changed
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = self.ctx.get_user_manager();
+        let copied_files = user_mgr.get_copied_files(&tenant, self.plan.tbl_id).await?;
+        let files = files
+            .into_iter()
+            .filter(|file| copied_files.get(&file.path) != Some(&copied_file_info(file)))
+            .collect::<Vec<_>>();
+
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+        let results = futures::stream::iter(files)
+            .map(|file| {
+                let table = table.clone();
+                let acc = acc.clone();
+                async move {
+                    let res = self.copy_file(table, acc, &file).await;
+                    (file, res)
+                }
+            })
+            .buffer_unordered(max_threads.max(1))
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut operations = vec![];
+        let mut copied_files = vec![];
+        for (file, res) in results {
+            match res {
+                Ok(mut file_operations) => {
+                    operations.append(&mut file_operations);
+                    copied_files.push((file.path.clone(), copied_file_info(&file)));
+                }
+                Err(cause) if self.plan.on_error != CopyOnError::Abort => {
+                    tracing::warn!("COPY skips file {}, cause: {}", file.path, cause);
+                }
+                Err(cause) => {
+                    return Err(cause.add_message_back(format!("(while copy file {}).", file.path)))
+                }
+            }
+        }
+
+        if !operations.is_empty() {
+            table
+                .commit_insertion(self.ctx.clone(), operations, false)
+                .await?;
+        }
+
+        // The files are recorded at once after the data is committed. If the recording fails, the
+        // data is kept but none of the files are recorded, they are loaded again by the next COPY.
+        if !copied_files.is_empty() {
+            user_mgr
+                .add_copied_files(&tenant, self.plan.tbl_id, copied_files)
+                .await?;
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
+
+impl CopyInterpreter {
+    /// A location ends with '/', or with a PATTERN, is a directory of files,
+    /// otherwise it is a single file.
+    async fn list_files(&self, acc: &dyn DataAccessor, path: &str) -> Result<Vec<ObjectMeta>> {
+        let prefix = path.trim_start_matches('/');
+        let objects = acc.list(prefix).await?;
+
+        match &self.plan.pattern {
+            None if !prefix.ends_with('/') => {
+                // the listing stops at the file
+                let file = objects
+                    .try_filter(|o| futures::future::ready(o.path == prefix))
+                    .try_next()
+                    .await?;
+                let file = file.ok_or_else(|| {
+                    ErrorCode::DalPathNotFound(format!("File not found in stage: {}", path))
+                })?;
+                Ok(vec![file])
+            }
+            None => objects.try_collect().await,
+            Some(pattern) => {
+                let regex = Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| {
+                    ErrorCode::BadOption(format!("Invalid PATTERN {}: {}", pattern, e))
+                })?;
+                objects
+                    .try_filter(|o| futures::future::ready(regex.is_match(&o.path)))
+                    .try_collect()
+                    .await
+            }
+        }
+    }
+
+    /// Appends the rows of the file to the table, returns the operations to commit.
+    async fn copy_file(
+        &self,
+        table: Arc<dyn Table>,
+        acc: Arc<dyn DataAccessor>,
+        file: &ObjectMeta,
+    ) -> Result<Vec<DataBlock>> {
         let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
-        let input_stream = acc.get_input_stream(path, None)?;
+        let input_stream = acc.get_input_stream(&file.path, Some(file.size))?;
         let read_buffer_size = self.ctx.get_settings().get_storage_read_buffer_size()?;
         let reader = BufReader::with_capacity(read_buffer_size as usize, input_stream);
         let source_params = SourceParams {
             reader,
-            path,
+            path: &file.path,
             format: self.plan.format.as_str(),
             schema: self.plan.schema.clone(),
             max_block_size,
@@ -85,29 +196,40 @@ impl Interpreter for CopyInterpreter {
             options: &self.plan.options,
         };
         let source_stream = SourceStream::new(SourceFactory::try_get(source_params)?);
-        let input_stream = source_stream.execute().await?;
+        let mut input_stream = source_stream.execute().await?;
+        if self.plan.on_error == CopyOnError::Continue {
+            // keeps the rows before the first error of the file
+            let path = file.path.clone();
+            input_stream = Box::pin(input_stream.take_while(move |block| {
+                if let Err(cause) = block {
+                    tracing::warn!("COPY stops reading file {}, cause: {}", path, cause);
+                }
+                futures::future::ready(block.is_ok())
+            }));
+        }
         let progress_stream = Box::pin(ProgressStream::try_create(
             input_stream,
             self.ctx.get_scan_progress(),
         )?);
 
-        let r = table
+        table
             .append_data(self.ctx.clone(), progress_stream)
             .await?
             .try_collect()
-            .await?;
-        table.commit_insertion(self.ctx.clone(), r, false).await?;
+            .await
+    }
+}
 
-        Ok(Box::pin(DataBlockStream::create(
-            self.plan.schema(),
-            None,
-            vec![],
-        )))
+fn copied_file_info(file: &ObjectMeta) -> CopiedFileInfo {
+    CopiedFileInfo {
+        etag: file.etag.clone(),
+        content_length: file.size,
+        last_modified: file.last_modified.map(|t| t.timestamp()),
     }
 }
 
 /// @my_ext_stage/tutorials/sample.csv -> stage: my_ext_stage,  location: /tutorials/sample.csv
-fn extract_stage_location(path: &str) -> IResult<&str, &str> {
+pub(crate) fn extract_stage_location(path: &str) -> IResult<&str, &str> {
     let (path, _) = tag("@")(path)?;
     let (path, stage) = take_until("/")(path)?;
     Ok((stage, path))
@@ -115,7 +237,10 @@ fn extract_stage_location(path: &str) -> IResult<&str, &str> {
 
 //  this is mock implementation from env
 //  todo: support get the stage config from metadata
-fn get_dal_by_stage(ctx: Arc<QueryContext>, _stage_name: &str) -> Result<Arc<dyn DataAccessor>> {
+pub(crate) fn get_dal_by_stage(
+    ctx: Arc<QueryContext>,
+    _stage_name: &str,
+) -> Result<Arc<dyn DataAccessor>> {
     let conf = ctx.get_config().storage.s3;
 
     Ok(Arc::new(S3::try_create(
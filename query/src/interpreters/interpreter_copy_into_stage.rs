@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::Format;
+use common_planners::CopyIntoStagePlan;
+use common_planners::PlanNode;
+use common_streams::CsvSink;
+use common_streams::DataBlockStream;
+use common_streams::NDJsonSink;
+use common_streams::ParquetSink;
+use common_streams::SendableDataBlockStream;
+use common_streams::Sink;
+use common_streams::TsvSink;
+use common_tracing::tracing;
+use futures::StreamExt;
+
+use crate::interpreters::interpreter_copy::extract_stage_location;
+use crate::interpreters::interpreter_copy::get_dal_by_stage;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::sessions::QueryContext;
+
+pub struct CopyIntoStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CopyIntoStagePlan,
+}
+
+impl CopyIntoStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CopyIntoStagePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(CopyIntoStageInterpreter { ctx, plan }))
+    }
+
+    fn create_sink(&self, schema: DataSchemaRef) -> (Box<dyn Sink>, &'static str) {
+        let format = &self.plan.file_format;
+        match format.format {
+            Format::Csv => (
+                Box::new(CsvSink::create(
+                    schema,
+                    format.csv_header,
+                    first_byte(&format.field_delimiter, b','),
+                    first_byte(&format.record_delimiter, b'\n'),
+                )),
+                "csv",
+            ),
+            Format::Tsv => (Box::new(TsvSink::create(schema, format.csv_header)), "tsv"),
+            Format::Json => (Box::new(NDJsonSink::create()), "ndjson"),
+            Format::Parquet => (Box::new(ParquetSink::create(schema)), "parquet"),
+        }
+    }
+
+    // @stage/unload/ -> unload/<query_id>_0.csv, @stage/unload/data -> unload/data_<query_id>_0.csv
+    fn file_name(&self, path: &str, n: usize, ext: &str) -> String {
+        let path = path.trim_start_matches('/');
+        let query_id = self.ctx.get_id();
+        if path.is_empty() || path.ends_with('/') {
+            format!("{}{}_{}.{}", path, query_id, n, ext)
+        } else {
+            format!("{}_{}_{}.{}", path, query_id, n, ext)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CopyIntoStageInterpreter {
+    fn name(&self) -> &str {
+        "CopyIntoStageInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "copy_into_stage_interpreter_execute", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let location = self.plan.location.clone();
+        let (stage, path) = extract_stage_location(location.as_str())
+            .map_err(|_| ErrorCode::BadOption("Cannot convert value to stage and path"))?;
+        let acc = get_dal_by_stage(self.ctx.clone(), stage)?;
+
+        let select = match &*self.plan.query {
+            PlanNode::Select(select) => select.clone(),
+            other => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "COPY INTO stage expects a SELECT query, but got {}",
+                    other.name()
+                )))
+            }
+        };
+        let interpreter = SelectInterpreter::try_create(self.ctx.clone(), select)?;
+        let (mut sink, ext) = self.create_sink(interpreter.schema());
+        let mut stream = interpreter.execute(None).await?;
+
+        let max_file_size = self.plan.max_file_size as usize;
+        let mut files = vec![];
+        let mut rows = 0;
+        while let Some(block) = stream.next().await {
+            let block = block?;
+            sink.write(&block)?;
+            rows += block.num_rows();
+
+            if sink.size() >= max_file_size {
+                let name = self.file_name(path, files.len(), ext);
+                let content = sink.finish()?;
+                files.push(put_file(acc.as_ref(), name, content, rows).await?);
+                rows = 0;
+            }
+        }
+        if rows > 0 || files.is_empty() {
+            let name = self.file_name(path, files.len(), ext);
+            let content = sink.finish()?;
+            files.push(put_file(acc.as_ref(), name, content, rows).await?);
+        }
+
+        let names = files.iter().map(|f| f.0.as_str()).collect::<Vec<_>>();
+        let rows = files.iter().map(|f| f.1).collect::<Vec<_>>();
+        let bytes = files.iter().map(|f| f.2).collect::<Vec<_>>();
+        let block = DataBlock::create_by_array(self.plan.schema(), vec![
+            Series::new(names),
+            Series::new(rows),
+            Series::new(bytes),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![block],
+        )))
+    }
+}
+
+async fn put_file(
+    acc: &dyn DataAccessor,
+    name: String,
+    content: Vec<u8>,
+    rows: usize,
+) -> Result<(String, u64, u64)> {
+    let len = content.len() as u64;
+    let stream = futures::stream::once(async move { Ok(bytes::Bytes::from(content)) });
+    acc.put_stream(&name, Box::new(Box::pin(stream)), len as usize)
+        .await
+        .map_err(|e| e.add_message_back(format!("(while unload file {}).", name)))?;
+    Ok((name, rows as u64, len))
+}
+
+fn first_byte(delimiter: &str, default: u8) -> u8 {
+    delimiter.as_bytes().first().copied().unwrap_or(default)
+}
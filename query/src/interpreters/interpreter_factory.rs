@@ -21,14 +21,17 @@ use common_planners::PlanNode;
 use super::DescribeStageInterpreter;
 use crate::interpreters::interpreter_stage_drop::DropStageInterpreter;
 use crate::interpreters::interpreter_table_optimize::OptimizeTableInterpreter;
+use crate::interpreters::AlterTableInterpreter;
 use crate::interpreters::AlterUDFInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CopyInterpreter;
+use crate::interpreters::CopyIntoStageInterpreter;
 use crate::interpreters::CreatStageInterpreter;
 use crate::interpreters::CreatUDFInterpreter;
 use crate::interpreters::CreateDatabaseInterpreter;
 use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::CreateUserInterpreter;
+use crate::interpreters::DeleteInterpreter;
 use crate::interpreters::DescribeTableInterpreter;
 use crate::interpreters::DropDatabaseInterpreter;
 use crate::interpreters::DropTableInterpreter;
@@ -40,6 +43,8 @@ use crate::interpreters::InsertInterpreter;
 use crate::interpreters::InterceptorInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::KillInterpreter;
+use crate::interpreters::ListStageInterpreter;
+use crate::interpreters::RenameTableInterpreter;
 use crate::interpreters::RevokePrivilegeInterpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::interpreters::SettingInterpreter;
@@ -48,6 +53,8 @@ use crate::interpreters::ShowCreateTableInterpreter;
 use crate::interpreters::ShowGrantsInterpreter;
 use crate::interpreters::ShowUDFInterpreter;
 use crate::interpreters::TruncateTableInterpreter;
+use crate::interpreters::UndropTableInterpreter;
+use crate::interpreters::UpdateInterpreter;
 use crate::interpreters::UseDatabaseInterpreter;
 use crate::interpreters::UseTenantInterpreter;
 use crate::sessions::QueryContext;
@@ -64,13 +71,18 @@ impl InterpreterFactory {
             PlanNode::DropDatabase(v) => DropDatabaseInterpreter::try_create(ctx_clone, v),
             PlanNode::CreateTable(v) => CreateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DropTable(v) => DropTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::RenameTable(v) => RenameTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::UndropTable(v) => UndropTableInterpreter::try_create(ctx_clone, v),
             PlanNode::DescribeTable(v) => DescribeTableInterpreter::try_create(ctx_clone, v),
             PlanNode::TruncateTable(v) => TruncateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::OptimizeTable(v) => OptimizeTableInterpreter::try_create(ctx_clone, v),
+            PlanNode::AlterTable(v) => AlterTableInterpreter::try_create(ctx_clone, v),
             PlanNode::UseDatabase(v) => UseDatabaseInterpreter::try_create(ctx_clone, v),
             PlanNode::UseTenant(v) => UseTenantInterpreter::try_create(ctx_clone, v),
             PlanNode::SetVariable(v) => SettingInterpreter::try_create(ctx_clone, v),
             PlanNode::Insert(v) => InsertInterpreter::try_create(ctx_clone, v),
+            PlanNode::Delete(v) => DeleteInterpreter::try_create(ctx_clone, v),
+            PlanNode::Update(v) => UpdateInterpreter::try_create(ctx_clone, v),
             PlanNode::ShowCreateTable(v) => ShowCreateTableInterpreter::try_create(ctx_clone, v),
             PlanNode::Kill(v) => KillInterpreter::try_create(ctx_clone, v),
             PlanNode::CreateUser(v) => CreateUserInterpreter::try_create(ctx_clone, v),
@@ -79,10 +91,12 @@ impl InterpreterFactory {
             PlanNode::GrantPrivilege(v) => GrantPrivilegeInterpreter::try_create(ctx_clone, v),
             PlanNode::RevokePrivilege(v) => RevokePrivilegeInterpreter::try_create(ctx_clone, v),
             PlanNode::Copy(v) => CopyInterpreter::try_create(ctx_clone, v),
+            PlanNode::CopyIntoStage(v) => CopyIntoStageInterpreter::try_create(ctx_clone, v),
             PlanNode::CreateUserStage(v) => CreatStageInterpreter::try_create(ctx_clone, v),
             PlanNode::DropUserStage(v) => DropStageInterpreter::try_create(ctx_clone, v),
             PlanNode::ShowGrants(v) => ShowGrantsInterpreter::try_create(ctx_clone, v),
             PlanNode::DescribeStage(v) => DescribeStageInterpreter::try_create(ctx_clone, v),
+            PlanNode::ListStage(v) => ListStageInterpreter::try_create(ctx_clone, v),
             PlanNode::ShowCreateDatabase(v) => {
                 ShowCreateDatabaseInterpreter::try_create(ctx_clone, v)
             }
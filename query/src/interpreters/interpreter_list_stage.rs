@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::ListStagePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+use regex::Regex;
+
+use crate::interpreters::interpreter_copy::extract_stage_location;
+use crate::interpreters::interpreter_copy::get_dal_by_stage;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct ListStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ListStagePlan,
+}
+
+impl ListStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ListStagePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(ListStageInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ListStageInterpreter {
+    fn name(&self) -> &str {
+        "ListStageInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "list_stage_interpreter_execute", skip(self, _input_stream), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let (stage, path) = extract_stage_location(self.plan.location.as_str())
+            .map_err(|_| ErrorCode::BadOption("Cannot convert value to stage and path"))?;
+        let acc = get_dal_by_stage(self.ctx.clone(), stage)?;
+
+        // the pattern is matched with the whole path of the file, as COPY does
+        let regex = match &self.plan.pattern {
+            None => None,
+            Some(pattern) => Some(Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| {
+                ErrorCode::BadOption(format!("Invalid PATTERN {}: {}", pattern, e))
+            })?),
+        };
+        let files = acc
+            .list(path.trim_start_matches('/'))
+            .await?
+            .try_filter(|o| {
+                let matched = regex.as_ref().map_or(true, |r| r.is_match(&o.path));
+                futures::future::ready(matched)
+            })
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let names: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+        let sizes: Vec<u64> = files.iter().map(|f| f.size).collect();
+        let etags: Vec<Option<&[u8]>> = files
+            .iter()
+            .map(|f| f.etag.as_ref().map(|e| e.as_bytes()))
+            .collect();
+        let last_modifieds: Vec<Option<Vec<u8>>> = files
+            .iter()
+            .map(|f| {
+                f.last_modified.map(|t| {
+                    t.format("%Y-%m-%d %H:%M:%S.%3f %z")
+                        .to_string()
+                        .into_bytes()
+                })
+            })
+            .collect();
+
+        let schema = self.plan.schema();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(names),
+            Series::new(sizes),
+            Series::new(etags),
+            Series::new(last_modifieds),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
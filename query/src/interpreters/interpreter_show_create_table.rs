@@ -16,7 +16,9 @@ use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::ShowCreateTablePlan;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
@@ -26,6 +28,8 @@ use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::view::ViewTable;
+use crate::storages::view::VIEW_ENGINE;
 
 pub struct ShowCreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -36,6 +40,24 @@ impl ShowCreateTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: ShowCreateTablePlan) -> Result<InterpreterPtr> {
         Ok(Arc::new(ShowCreateTableInterpreter { ctx, plan }))
     }
+
+    fn show_create(name: &str, create_sql: String) -> Result<SendableDataBlockStream> {
+        let show_fields = vec![
+            DataField::new("Table", DataType::String, false),
+            DataField::new("Create Table", DataType::String, false),
+        ];
+        let show_schema = DataSchemaRefExt::create(show_fields);
+
+        let block = DataBlock::create_by_array(show_schema.clone(), vec![
+            Series::new(vec![name.as_bytes()]),
+            Series::new(vec![create_sql.into_bytes()]),
+        ]);
+        tracing::debug!("Show create table executor result: {:?}", block);
+
+        Ok(Box::pin(DataBlockStream::create(show_schema, None, vec![
+            block,
+        ])))
+    }
 }
 
 #[async_trait::async_trait]
@@ -59,6 +81,15 @@ impl Interpreter for ShowCreateTableInterpreter {
         let engine = table.engine();
         let schema = table.schema();
 
+        if engine == VIEW_ENGINE {
+            let view = table
+                .as_any()
+                .downcast_ref::<ViewTable>()
+                .ok_or_else(|| ErrorCode::LogicalError("Logical error: the table is not a view"))?;
+            let view_info = format!("CREATE VIEW `{}` AS {}", name, view.query()?);
+            return Self::show_create(name, view_info);
+        }
+
         let mut table_info = format!("CREATE TABLE `{}` (\n", name);
         for field in schema.fields().iter() {
             let column = format!("  `{}` {},\n", field.name(), field.data_type());
@@ -66,6 +97,18 @@ impl Interpreter for ShowCreateTableInterpreter {
         }
         let table_engine = format!(") ENGINE={}", engine);
         table_info.push_str(table_engine.as_str());
+        if let Some(cluster_keys) = &table.get_table_info().meta.cluster_keys {
+            let cluster_keys: Vec<Expression> = serde_json::from_str(cluster_keys)?;
+            let cluster_keys = cluster_keys
+                .iter()
+                .map(|expr| expr.column_name())
+                .collect::<Vec<_>>();
+            table_info.push_str(format!(" CLUSTER BY ({})", cluster_keys.join(", ")).as_str());
+        }
+        if let Some(ttl) = &table.get_table_info().meta.ttl {
+            let ttl: Expression = serde_json::from_str(ttl)?;
+            table_info.push_str(format!(" TTL {}", ttl.column_name()).as_str());
+        }
         table_info.push_str(
             table
                 .options()
@@ -76,20 +119,6 @@ impl Interpreter for ShowCreateTableInterpreter {
                 .as_str(),
         );
 
-        let show_fields = vec![
-            DataField::new("Table", DataType::String, false),
-            DataField::new("Create Table", DataType::String, false),
-        ];
-        let show_schema = DataSchemaRefExt::create(show_fields);
-
-        let block = DataBlock::create_by_array(show_schema.clone(), vec![
-            Series::new(vec![name.as_bytes()]),
-            Series::new(vec![table_info.into_bytes()]),
-        ]);
-        tracing::debug!("Show create table executor result: {:?}", block);
-
-        Ok(Box::pin(DataBlockStream::create(show_schema, None, vec![
-            block,
-        ])))
+        Self::show_create(name, table_info)
     }
 }
@@ -16,8 +16,12 @@ use std::sync::Arc;
 
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::CreateTableReq;
+use common_meta_types::DropTableReq;
 use common_meta_types::GrantObject;
+use common_meta_types::PurgeDroppedTableReq;
 use common_meta_types::UserPrivilegeType;
 use common_planners::CreateTablePlan;
 use common_planners::InsertInputSource;
@@ -25,12 +29,15 @@ use common_planners::InsertPlan;
 use common_planners::PlanNode;
 use common_streams::DataBlockStream;
 use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
 
 use super::InsertInterpreter;
 use crate::catalogs::Catalog;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::sessions::QueryContext;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
 
 pub struct CreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -77,11 +84,47 @@ impl CreateTableInterpreter {
         let tenant = self.ctx.get_tenant();
         let catalog = self.ctx.get_catalog();
 
+        // Like MySQL, nothing is inserted if the table already exists.
+        if self.plan.if_not_exists
+            && catalog
+                .exists_table(tenant.as_str(), &self.plan.db, &self.plan.table)
+                .await?
+        {
+            return Ok(Box::pin(DataBlockStream::create(
+                self.plan.schema(),
+                None,
+                vec![],
+            )));
+        }
+
         // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        catalog.create_table(self.plan.clone().into()).await?;
+        // The table is always created without IF NOT EXISTS, so that the table created by
+        // a concurrent statement is neither inserted into nor dropped by this one.
+        let mut req: CreateTableReq = self.plan.clone().into();
+        req.if_not_exists = false;
+        let table_id = match catalog.create_table(req).await {
+            Ok(reply) => reply.table_id,
+            Err(e)
+                if self.plan.if_not_exists
+                    && e.code() == ErrorCode::table_already_exists_code() =>
+            {
+                return Ok(Box::pin(DataBlockStream::create(
+                    self.plan.schema(),
+                    None,
+                    vec![],
+                )));
+            }
+            Err(e) => return Err(e),
+        };
         let table = catalog
             .get_table(tenant.as_str(), &self.plan.db, &self.plan.table)
             .await?;
+        if table.get_id() != table_id {
+            return Err(ErrorCode::TableCommitConflict(format!(
+                "table {}.{} is changed concurrently",
+                self.plan.db, self.plan.table
+            )));
+        }
 
         // If the table creation query contains column definitions, like 'CREATE TABLE t1(a int) AS SELECT * from t2',
         // we use the definitions to create the table schema. It may happen that the "AS SELECT" query's schema doesn't
@@ -110,7 +153,18 @@ impl CreateTableInterpreter {
             source: InsertInputSource::SelectPlan(select_plan_node),
         };
         let insert_interpreter = InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?;
-        insert_interpreter.execute(input_stream).await?;
+        if let Err(cause) = insert_interpreter.execute(input_stream).await {
+            // The table is purged if the insertion fails, as if the statement has never happened.
+            if let Err(purge_cause) = self.purge_created_table(table.as_ref()).await {
+                tracing::warn!(
+                    "Failed to purge table {}.{} after CREATE TABLE AS SELECT failed: {}",
+                    self.plan.db,
+                    self.plan.table,
+                    purge_cause
+                );
+            }
+            return Err(cause);
+        }
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -119,6 +173,41 @@ impl CreateTableInterpreter {
         )))
     }
 
+    // Drops the table only if the name still refers to the table created by this statement, then
+    // purges it by its id, so that it can not be undropped, and removes the data written into it.
+    async fn purge_created_table(&self, table: &dyn Table) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog();
+        let table_id = table.get_id();
+        let current = catalog
+            .get_table(tenant.as_str(), &self.plan.db, &self.plan.table)
+            .await?;
+        if current.get_id() != table_id {
+            return Ok(());
+        }
+
+        let drop_req = DropTableReq {
+            if_exists: true,
+            tenant: tenant.clone(),
+            db: self.plan.db.clone(),
+            table: self.plan.table.clone(),
+        };
+        catalog.drop_table(drop_req).await?;
+
+        let purge_req = PurgeDroppedTableReq {
+            tenant,
+            db: self.plan.db.clone(),
+            table: self.plan.table.clone(),
+            table_id,
+        };
+        catalog.purge_dropped_table(purge_req).await?;
+
+        if let Some(fuse_table) = table.as_any().downcast_ref::<FuseTable>() {
+            fuse_table.do_purge(self.ctx.as_ref()).await?;
+        }
+        Ok(())
+    }
+
     async fn create_table(&self) -> Result<SendableDataBlockStream> {
         let catalog = self.ctx.get_catalog();
         catalog.create_table(self.plan.clone().into()).await?;
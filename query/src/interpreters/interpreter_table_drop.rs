@@ -48,8 +48,6 @@ impl Interpreter for DropTableInterpreter {
         _input_stream: Option<SendableDataBlockStream>,
     ) -> Result<SendableDataBlockStream> {
         let db_name = self.plan.db.as_str();
-        let tbl_name = self.plan.table.as_str();
-        let tbl = self.ctx.get_table(db_name, tbl_name).await.ok();
 
         self.ctx.get_current_session().validate_privilege(
             &GrantObject::Database(db_name.into()),
@@ -57,15 +55,10 @@ impl Interpreter for DropTableInterpreter {
         )?;
 
         let catalog = self.ctx.get_catalog();
+        // The data of the dropped table is kept for `UNDROP TABLE`, it is removed by the
+        // vacuum after `table_dropped_retention_secs` of the server config.
         catalog.drop_table(self.plan.clone().into()).await?;
 
-        // `drop_table` throws several types of exceptions
-        // thus `optimize` operation is executed after it.
-        if let Some(tbl) = tbl {
-            let keep_last_snapshot = false;
-            tbl.optimize(self.ctx.clone(), keep_last_snapshot).await?;
-        }
-
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
             None,
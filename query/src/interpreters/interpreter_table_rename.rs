@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_planners::RenameTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct RenameTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RenameTablePlan,
+}
+
+impl RenameTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RenameTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(RenameTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RenameTableInterpreter {
+    fn name(&self) -> &str {
+        "RenameTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let session = self.ctx.get_current_session();
+        session.validate_privilege(
+            &GrantObject::Database(self.plan.db.clone()),
+            UserPrivilegeType::Alter,
+        )?;
+        session.validate_privilege(
+            &GrantObject::Database(self.plan.new_db.clone()),
+            UserPrivilegeType::Create,
+        )?;
+
+        let catalog = self.ctx.get_catalog();
+        catalog.rename_table(self.plan.clone().into()).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UndropTableReq;
+use common_meta_types::UserPrivilegeType;
+use common_planners::UndropTablePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::sessions::QueryContext;
+
+pub struct UndropTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: UndropTablePlan,
+}
+
+impl UndropTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: UndropTablePlan) -> Result<InterpreterPtr> {
+        Ok(Arc::new(UndropTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for UndropTableInterpreter {
+    fn name(&self) -> &str {
+        "UndropTableInterpreter"
+    }
+
+    async fn execute(
+        &self,
+        _input_stream: Option<SendableDataBlockStream>,
+    ) -> Result<SendableDataBlockStream> {
+        let db_name = self.plan.db.as_str();
+
+        self.ctx.get_current_session().validate_privilege(
+            &GrantObject::Database(db_name.into()),
+            UserPrivilegeType::Create,
+        )?;
+
+        // the table dropped out of the retention may have been removed by the vacuum
+        let retention_secs = self.ctx.get_config().query.table_dropped_retention_secs;
+        let mut req: UndropTableReq = self.plan.clone().into();
+        req.drop_after = Some(Utc::now() - Duration::seconds(retention_secs as i64));
+
+        let catalog = self.ctx.get_catalog();
+        catalog.undrop_table(req).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -16,8 +16,10 @@ mod access;
 mod interpreter;
 mod interpreter_common;
 mod interpreter_copy;
+mod interpreter_copy_into_stage;
 mod interpreter_database_create;
 mod interpreter_database_drop;
+mod interpreter_delete;
 mod interpreter_describe_stage;
 mod interpreter_describe_table;
 mod interpreter_explain;
@@ -27,6 +29,7 @@ mod interpreter_insert;
 mod interpreter_insert_with_stream;
 mod interpreter_interceptor;
 mod interpreter_kill;
+mod interpreter_list_stage;
 mod interpreter_query_log;
 mod interpreter_revoke_privilege;
 mod interpreter_select;
@@ -36,14 +39,18 @@ mod interpreter_show_create_table;
 mod interpreter_show_grants;
 mod interpreter_stage_create;
 mod interpreter_stage_drop;
+mod interpreter_table_alter;
 mod interpreter_table_create;
 mod interpreter_table_drop;
 mod interpreter_table_optimize;
+mod interpreter_table_rename;
 mod interpreter_table_truncate;
+mod interpreter_table_undrop;
 mod interpreter_udf_alter;
 mod interpreter_udf_create;
 mod interpreter_udf_drop;
 mod interpreter_udf_show;
+mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_use_tenant;
 mod interpreter_user_alter;
@@ -54,8 +61,10 @@ mod plan_schedulers;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
 pub use interpreter_copy::CopyInterpreter;
+pub use interpreter_copy_into_stage::CopyIntoStageInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
+pub use interpreter_delete::DeleteInterpreter;
 pub use interpreter_describe_stage::DescribeStageInterpreter;
 pub use interpreter_describe_table::DescribeTableInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
@@ -64,6 +73,7 @@ pub use interpreter_grant_privilege::GrantPrivilegeInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_interceptor::InterceptorInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_list_stage::ListStageInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
 pub use interpreter_query_log::LogEvent;
 pub use interpreter_query_log::LogType;
@@ -75,13 +85,17 @@ pub use interpreter_show_create_table::ShowCreateTableInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_stage_create::CreatStageInterpreter;
 pub use interpreter_stage_drop::DropStageInterpreter;
+pub use interpreter_table_alter::AlterTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
+pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_udf_alter::AlterUDFInterpreter;
 pub use interpreter_udf_create::CreatUDFInterpreter;
 pub use interpreter_udf_drop::DropUDFInterpreter;
 pub use interpreter_udf_show::ShowUDFInterpreter;
+pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_use_tenant::UseTenantInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
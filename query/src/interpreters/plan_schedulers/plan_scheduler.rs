@@ -29,6 +29,7 @@ use common_planners::ExpressionPlan;
 use common_planners::Expressions;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::Partitions;
@@ -308,6 +309,7 @@ impl PlanScheduler {
             PlanNode::Stage(plan) => self.visit_stage(plan, tasks),
             PlanNode::Broadcast(plan) => self.visit_broadcast(plan, tasks),
             PlanNode::Having(plan) => self.visit_having(plan, tasks),
+            PlanNode::Join(plan) => self.visit_join(plan, tasks),
             PlanNode::Expression(plan) => self.visit_expression(plan, tasks),
             PlanNode::SubQueryExpression(plan) => self.visit_subqueries_set(plan, tasks),
             _ => Err(ErrorCode::UnImplement("")),
@@ -645,6 +647,34 @@ impl PlanScheduler {
         Ok(subquery_scheduler.nodes_plan)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan, tasks: &mut Tasks) -> Result<()> {
+        // The join is always executed on the local node, both inputs have been converged.
+        let left = self.visit_join_input(plan.left.as_ref(), tasks)?;
+        let right = self.visit_join_input(plan.right.as_ref(), tasks)?;
+
+        let mut join_plan = plan.clone();
+        join_plan.set_inputs(&left, &right);
+
+        self.running_mode = RunningMode::Standalone;
+        self.nodes_plan[self.local_pos] = PlanNode::Join(join_plan);
+        Ok(())
+    }
+
+    fn visit_join_input(&mut self, plan: &PlanNode, tasks: &mut Tasks) -> Result<PlanNode> {
+        let input_context = QueryContext::create_from(self.query_context.clone());
+        let mut input_scheduler = PlanScheduler::try_create(input_context)?;
+        input_scheduler.visit_plan_node(plan, tasks)?;
+
+        match input_scheduler.running_mode {
+            RunningMode::Standalone => {
+                Ok(input_scheduler.nodes_plan[input_scheduler.local_pos].clone())
+            }
+            RunningMode::Cluster => Err(ErrorCode::LogicalError(
+                "Logical error: join input must be converged, it's a bug.",
+            )),
+        }
+    }
+
     fn visit_filter(&mut self, plan: &FilterPlan, tasks: &mut Tasks) -> Result<()> {
         self.visit_plan_node(plan.input.as_ref(), tasks)?;
         match self.running_mode {
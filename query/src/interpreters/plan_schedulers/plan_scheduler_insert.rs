@@ -17,7 +17,6 @@ use std::sync::Arc;
 
 use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
-use common_meta_types::TableInfo;
 use common_planners::PlanNode;
 use common_planners::SelectPlan;
 use common_planners::SinkPlan;
@@ -53,7 +52,7 @@ impl<'a> InsertWithPlan<'a> {
         table: &dyn Table,
     ) -> common_exception::Result<SendableDataBlockStream> {
         if let PlanNode::Select(sel) = self.plan_node {
-            let optimized_plan = self.rewrite_plan(sel, table.get_table_info())?;
+            let optimized_plan = self.rewrite_plan(sel, table)?;
             plan_schedulers::schedule_query(self.ctx, &optimized_plan).await
         } else {
             Err(ErrorCode::UnknownTypeOfQuery(format!(
@@ -66,8 +65,9 @@ impl<'a> InsertWithPlan<'a> {
     fn rewrite_plan(
         &self,
         select_plan: &SelectPlan,
-        table_info: &TableInfo,
+        table: &dyn Table,
     ) -> common_exception::Result<PlanNode> {
+        let table_info = table.get_table_info();
         let cast_schema = if self.check_schema_cast(select_plan)? {
             Some(self.schema.clone())
         } else {
@@ -85,7 +85,12 @@ impl<'a> InsertWithPlan<'a> {
             // if it is a StagePlan Node, we insert the a SinkPlan in between the Stage and Stage.input
             // i.e.
             //    StagePlan <~ PlanNodeA  => StagePlan <~ Sink <~ PlanNodeA
-            PlanNode::Stage(r) => {
+            //
+            // then each node of the cluster appends its partitions to the table, and the append
+            // logs are converged to the current node, which commits them once. It is only for
+            // the tables of which the data is shared by the cluster, the data appended to a local
+            // table(e.g. MEMORY) on the other nodes is invisible to the current node.
+            PlanNode::Stage(r) if !table.is_local() => {
                 let prev_input = r.input.clone();
                 let sink = PlanNode::Sink(SinkPlan {
                     table_info: table_info.clone(),
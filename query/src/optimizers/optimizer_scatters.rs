@@ -22,6 +22,7 @@ use common_planners::AggregatorFinalPlan;
 use common_planners::AggregatorPartialPlan;
 use common_planners::BroadcastPlan;
 use common_planners::Expression;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanBuilder;
@@ -228,6 +229,33 @@ impl PlanRewriter for ScattersOptimizerImpl {
         }
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        // The hash join runs on the local node, so we converge both inputs before it.
+        let join_input = |input: &PlanNode| -> Result<PlanNode> {
+            let input_ctx = QueryContext::create_from(self.ctx.clone());
+            let mut input_optimizer = ScattersOptimizerImpl::create(input_ctx);
+            let rewritten_input = input_optimizer.rewrite_plan_node(input)?;
+
+            match input_optimizer.running_mode {
+                RunningMode::Standalone => Ok(rewritten_input),
+                RunningMode::Cluster => Self::convergent_shuffle_stage(rewritten_input),
+            }
+        };
+
+        let new_left = join_input(plan.left.as_ref())?;
+        let new_right = join_input(plan.right.as_ref())?;
+
+        self.running_mode = RunningMode::Standalone;
+        PlanBuilder::from(&new_left)
+            .join(
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                &new_right,
+            )?
+            .build()
+    }
+
     fn rewrite_aggregate_partial(&mut self, plan: &AggregatorPartialPlan) -> Result<PlanNode> {
         let new_input = Arc::new(self.rewrite_plan_node(&plan.input)?);
 
@@ -131,6 +131,21 @@ impl PlanRewriter for TopNPushDownImpl {
         optimizer.optimize(subquery_plan)
     }
 
+    fn rewrite_join(&mut self, plan: &JoinPlan) -> Result<PlanNode> {
+        // The top n of the join result can't be pushed down to the inputs.
+        let mut optimizer = TopNPushDownOptimizer {};
+        let new_left = optimizer.optimize(plan.left.as_ref())?;
+        let new_right = optimizer.optimize(plan.right.as_ref())?;
+        PlanBuilder::from(&new_left)
+            .join(
+                plan.join_type,
+                &plan.left_keys,
+                &plan.right_keys,
+                &new_right,
+            )?
+            .build()
+    }
+
     fn rewrite_sort(&mut self, plan: &SortPlan) -> Result<PlanNode> {
         if self.limit.is_some() {
             self.order_by = plan.order_by.clone();
@@ -21,6 +21,7 @@ use common_planners::BroadcastPlan;
 use common_planners::ExpressionPlan;
 use common_planners::FilterPlan;
 use common_planners::HavingPlan;
+use common_planners::JoinPlan;
 use common_planners::LimitByPlan;
 use common_planners::LimitPlan;
 use common_planners::PlanNode;
@@ -42,6 +43,8 @@ use crate::pipelines::transforms::CreateSetsTransform;
 use crate::pipelines::transforms::ExpressionTransform;
 use crate::pipelines::transforms::GroupByFinalTransform;
 use crate::pipelines::transforms::GroupByPartialTransform;
+use crate::pipelines::transforms::HashJoinBuildSide;
+use crate::pipelines::transforms::HashJoinTransform;
 use crate::pipelines::transforms::HavingTransform;
 use crate::pipelines::transforms::LimitByTransform;
 use crate::pipelines::transforms::LimitTransform;
@@ -91,6 +94,7 @@ impl PipelineBuilder {
             PlanNode::AggregatorFinal(node) => self.visit_aggregator_final(node),
             PlanNode::Filter(node) => self.visit_filter(node),
             PlanNode::Having(node) => self.visit_having(node),
+            PlanNode::Join(node) => self.visit_join(node),
             PlanNode::Sort(node) => self.visit_sort(node),
             PlanNode::Limit(node) => self.visit_limit(node),
             PlanNode::LimitBy(node) => self.visit_limit_by(node),
@@ -236,6 +240,24 @@ impl PipelineBuilder {
         Ok(pipeline)
     }
 
+    fn visit_join(&mut self, plan: &JoinPlan) -> Result<Pipeline> {
+        let mut pipeline = self.visit(&*plan.left)?;
+
+        // The unmatched rows of the build side can only be output by a single processor.
+        if plan.join_type.preserve_right() {
+            pipeline.merge_processor()?;
+        }
+
+        let build_side = HashJoinBuildSide::try_create(self.ctx.clone(), plan)?;
+        pipeline.add_simple_transform(|| {
+            Ok(Box::new(HashJoinTransform::try_create(
+                plan,
+                build_side.clone(),
+            )?))
+        })?;
+        Ok(pipeline)
+    }
+
     fn visit_sort(&mut self, plan: &SortPlan) -> Result<Pipeline> {
         let mut pipeline = self.visit(&*plan.input)?;
 
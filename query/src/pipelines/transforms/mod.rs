@@ -20,6 +20,7 @@ mod transform_expression_executor;
 mod transform_filter;
 mod transform_group_by_final;
 mod transform_group_by_partial;
+mod transform_hash_join;
 mod transform_limit;
 mod transform_limit_by;
 mod transform_projection;
@@ -43,6 +44,8 @@ pub use transform_filter::HavingTransform;
 pub use transform_filter::WhereTransform;
 pub use transform_group_by_final::GroupByFinalTransform;
 pub use transform_group_by_partial::GroupByPartialTransform;
+pub use transform_hash_join::HashJoinBuildSide;
+pub use transform_hash_join::HashJoinTransform;
 pub use transform_limit::LimitTransform;
 pub use transform_limit_by::LimitByTransform;
 pub use transform_projection::ProjectionTransform;
@@ -0,0 +1,488 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use common_base::TrySpawn;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datablocks::HashMethodSerializer;
+use common_datavalues::compare_coercion;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_planners::Expression;
+use common_planners::JoinPlan;
+use common_planners::JoinType;
+use common_planners::PlanNode;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use common_tracing::tracing;
+use futures::future::BoxFuture;
+use futures::future::Shared;
+use futures::FutureExt;
+use tokio_stream::StreamExt;
+
+use crate::common::HashMap;
+use crate::common::HashTableEntity;
+use crate::pipelines::processors::EmptyProcessor;
+use crate::pipelines::processors::PipelineBuilder;
+use crate::pipelines::processors::Processor;
+use crate::pipelines::transforms::group_by::KeysRef;
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+
+/// Hash join, the right input is the build side and the left input is the probe side.
+///
+/// The build side is executed once(in its own context) and shared by all the probe processors.
+/// For RIGHT and FULL join the probe side must be merged into one processor, so that it can
+/// track which build rows have been matched and output the unmatched ones at the end.
+pub struct HashJoinTransform {
+    join_type: JoinType,
+    schema: DataSchemaRef,
+    probe_keys: JoinKeysEvaluator,
+    build_side: Arc<Mutex<HashJoinBuildSide>>,
+    input: Arc<dyn Processor>,
+}
+
+impl HashJoinTransform {
+    pub fn try_create(
+        plan: &JoinPlan,
+        build_side: Arc<Mutex<HashJoinBuildSide>>,
+    ) -> Result<HashJoinTransform> {
+        let key_types = JoinKeysEvaluator::key_types(plan)?;
+
+        Ok(HashJoinTransform {
+            join_type: plan.join_type,
+            schema: plan.schema(),
+            probe_keys: JoinKeysEvaluator::try_create(
+                plan.left.schema(),
+                &plan.left_keys,
+                key_types,
+            )?,
+            build_side,
+            input: Arc::new(EmptyProcessor::create()),
+        })
+    }
+
+    async fn build_state(&self) -> Result<Arc<HashJoinState>> {
+        let (ctx, future) = {
+            let mut build_side = self.build_side.lock();
+            (build_side.ctx.clone(), build_side.take_build_state()?)
+        };
+
+        match ctx.try_spawn(future)?.await {
+            Ok(res) => res,
+            Err(error) => Err(ErrorCode::TokioError(format!(
+                "Cannot build hash join table. cause: {}",
+                error
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for HashJoinTransform {
+    fn name(&self) -> &str {
+        "HashJoinTransform"
+    }
+
+    fn connect_to(&mut self, input: Arc<dyn Processor>) -> Result<()> {
+        self.input = input;
+        Ok(())
+    }
+
+    fn inputs(&self) -> Vec<Arc<dyn Processor>> {
+        vec![self.input.clone()]
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[tracing::instrument(level = "debug", name = "hash_join_execute", skip(self))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let state = self.build_state().await?;
+        let mut input_stream = self.input.execute().await?;
+
+        let prober = HashJoinProber {
+            join_type: self.join_type,
+            schema: self.schema.clone(),
+            probe_keys: self.probe_keys.clone(),
+            state,
+        };
+
+        if !self.join_type.preserve_right() {
+            let stream = input_stream.filter_map(move |block| {
+                block
+                    .and_then(|block| prober.probe(&block, None))
+                    .transpose()
+            });
+            return Ok(Box::pin(stream));
+        }
+
+        // The unmatched build rows are known only after the whole probe side is consumed.
+        let mut matched = vec![false; prober.state.num_rows()];
+        let mut blocks = vec![];
+        while let Some(block) = input_stream.next().await {
+            if let Some(joined) = prober.probe(&block?, Some(&mut matched))? {
+                blocks.push(joined);
+            }
+        }
+
+        if let Some(unmatched) = prober.unmatched_build_rows(&matched)? {
+            blocks.push(unmatched);
+        }
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.schema.clone(),
+            None,
+            blocks,
+        )))
+    }
+}
+
+type BuildStateData = Result<Arc<HashJoinState>>;
+type SharedFuture<'a> = Shared<BoxFuture<'a, BuildStateData>>;
+
+/// Lazily executes the build side plan of the join, the result is shared by the probe processors.
+pub struct HashJoinBuildSide {
+    ctx: Arc<QueryContext>,
+    plan: PlanNode,
+    build_keys: JoinKeysEvaluator,
+    build_state: Option<SharedFuture<'static>>,
+}
+
+impl HashJoinBuildSide {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: &JoinPlan) -> Result<Arc<Mutex<Self>>> {
+        let key_types = JoinKeysEvaluator::key_types(plan)?;
+        let build_keys =
+            JoinKeysEvaluator::try_create(plan.right.schema(), &plan.right_keys, key_types)?;
+
+        Ok(Arc::new(Mutex::new(HashJoinBuildSide {
+            ctx,
+            plan: plan.right.as_ref().clone(),
+            build_keys,
+            build_state: None,
+        })))
+    }
+
+    fn take_build_state(&mut self) -> Result<SharedFuture<'static>> {
+        if self.build_state.is_none() {
+            // The build side binds its own partitions, so it needs a new context.
+            let build_ctx = QueryContext::create_from(self.ctx.clone());
+            let mut pipeline = PipelineBuilder::create(build_ctx).build(&self.plan)?;
+            let build_keys = self.build_keys.clone();
+
+            let build_future = async move {
+                let mut stream = pipeline.execute().await?;
+                let mut blocks = vec![];
+                while let Some(block) = stream.next().await {
+                    let block = block?;
+                    if block.num_rows() > 0 {
+                        blocks.push(block);
+                    }
+                }
+
+                Ok(Arc::new(HashJoinState::try_create(&blocks, &build_keys)?))
+            };
+
+            self.build_state = Some(build_future.boxed().shared());
+        }
+
+        match &self.build_state {
+            Some(build_state) => Ok(build_state.clone()),
+            None => Err(ErrorCode::LogicalError(
+                "Logical error: hash join build state must be initialized.",
+            )),
+        }
+    }
+}
+
+/// Evaluate the join keys of a block and serialize them into the hash keys.
+#[derive(Clone)]
+struct JoinKeysEvaluator {
+    executor: Option<ExpressionExecutor>,
+    key_types: Vec<DataType>,
+}
+
+impl JoinKeysEvaluator {
+    /// Both sides must be serialized with the same types, otherwise equal values may not match.
+    fn key_types(plan: &JoinPlan) -> Result<Vec<DataType>> {
+        let left_schema = plan.left.schema();
+        let right_schema = plan.right.schema();
+
+        let mut key_types = Vec::with_capacity(plan.left_keys.len());
+        for (left_key, right_key) in plan.left_keys.iter().zip(plan.right_keys.iter()) {
+            let left_type = left_key.to_data_type(&left_schema)?;
+            let right_type = right_key.to_data_type(&right_schema)?;
+            key_types.push(compare_coercion(&left_type, &right_type)?);
+        }
+
+        Ok(key_types)
+    }
+
+    fn try_create(
+        input_schema: DataSchemaRef,
+        keys: &[Expression],
+        key_types: Vec<DataType>,
+    ) -> Result<JoinKeysEvaluator> {
+        if keys.is_empty() {
+            return Ok(JoinKeysEvaluator {
+                executor: None,
+                key_types,
+            });
+        }
+
+        let mut fields = Vec::with_capacity(keys.len());
+        for key in keys {
+            fields.push(key.to_data_field(&input_schema)?);
+        }
+
+        let executor = ExpressionExecutor::try_create(
+            "join keys executor",
+            input_schema,
+            DataSchemaRefExt::create(fields),
+            keys.to_vec(),
+            false,
+        )?;
+
+        Ok(JoinKeysEvaluator {
+            executor: Some(executor),
+            key_types,
+        })
+    }
+
+    /// Returns the serialized keys of each row, and the mask of the rows which contain null keys.
+    fn evaluate(&self, block: &DataBlock) -> Result<(Vec<Vec<u8>>, Option<Vec<bool>>)> {
+        let rows = block.num_rows();
+        let executor = match &self.executor {
+            None => return Ok((vec![vec![]; rows], None)),
+            Some(executor) => executor,
+        };
+
+        let keys_block = executor.execute(block)?;
+        let mut key_columns = Vec::with_capacity(self.key_types.len());
+        let mut null_mask: Option<Vec<bool>> = None;
+
+        for (column, key_type) in keys_block.columns().iter().zip(self.key_types.iter()) {
+            let column = match column.data_type() == *key_type {
+                true => column.clone(),
+                false => column.cast_with_type(key_type)?,
+            };
+
+            let series = column.to_array()?;
+            if series.null_count() > 0 {
+                let mask = null_mask.get_or_insert_with(|| vec![false; rows]);
+                for (row, is_null) in mask.iter_mut().enumerate() {
+                    *is_null = *is_null || series.is_null(row);
+                }
+            }
+
+            key_columns.push(column);
+        }
+
+        let key_columns = key_columns.iter().collect::<Vec<_>>();
+        let keys = HashMethodSerializer::default().build_keys(&key_columns, rows)?;
+        Ok((keys, null_mask))
+    }
+}
+
+/// The hash table of the build side.
+pub struct HashJoinState {
+    block: Option<DataBlock>,
+    keys_area: Bump,
+    // The value is the position of the build rows in `rows_of_keys`.
+    hash_table: HashMap<KeysRef, usize>,
+    rows_of_keys: Vec<Vec<u32>>,
+}
+
+// SAFETY: The keys of `hash_table` are raw pointers into `keys_area`, which is owned by the
+// state and never reallocated. The state is built once by `HashJoinBuildSide` and only shared
+// behind an `Arc` after `try_create` returns, from then on the probe side only reads it.
+#[allow(clippy::non_send_fields_in_send_ty)]
+unsafe impl Send for HashJoinState {}
+
+// SAFETY: See the `Send` impl, the shared state is only read after the build finishes.
+unsafe impl Sync for HashJoinState {}
+
+impl HashJoinState {
+    fn try_create(blocks: &[DataBlock], build_keys: &JoinKeysEvaluator) -> Result<HashJoinState> {
+        let mut state = HashJoinState {
+            block: None,
+            keys_area: Bump::new(),
+            hash_table: HashMap::create(),
+            rows_of_keys: vec![],
+        };
+
+        if blocks.is_empty() {
+            return Ok(state);
+        }
+
+        let block = DataBlock::concat_blocks(blocks)?;
+        let (keys, null_mask) = build_keys.evaluate(&block)?;
+
+        for (row, key) in keys.iter().enumerate() {
+            // NULL never equals to anything.
+            if matches!(&null_mask, Some(mask) if mask[row]) {
+                continue;
+            }
+
+            let mut inserted = false;
+            let mut keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+            let entity = state.hash_table.insert_key(&keys_ref, &mut inserted);
+
+            if inserted {
+                unsafe {
+                    // Keys will be destroyed after call we need copy the keys to the memory pool.
+                    let global_keys = state.keys_area.alloc_slice_copy(key);
+                    let inserted_hash = entity.get_hash();
+                    keys_ref.address = global_keys.as_ptr() as usize;
+                    entity.set_key_and_hash(&keys_ref, inserted_hash);
+                }
+
+                entity.set_value(state.rows_of_keys.len());
+                state.rows_of_keys.push(vec![]);
+            }
+
+            state.rows_of_keys[*entity.get_value()].push(row as u32);
+        }
+
+        state.block = Some(block);
+        Ok(state)
+    }
+
+    fn num_rows(&self) -> usize {
+        match &self.block {
+            None => 0,
+            Some(block) => block.num_rows(),
+        }
+    }
+
+    fn find(&self, key: &[u8]) -> Option<&[u32]> {
+        let keys_ref = KeysRef::create(key.as_ptr() as usize, key.len());
+        self.hash_table
+            .find_key(&keys_ref)
+            .map(|entity| self.rows_of_keys[*entity.get_value()].as_slice())
+    }
+}
+
+struct HashJoinProber {
+    join_type: JoinType,
+    schema: DataSchemaRef,
+    probe_keys: JoinKeysEvaluator,
+    state: Arc<HashJoinState>,
+}
+
+impl HashJoinProber {
+    fn probe(
+        &self,
+        block: &DataBlock,
+        matched: Option<&mut Vec<bool>>,
+    ) -> Result<Option<DataBlock>> {
+        if block.num_rows() == 0 {
+            return Ok(None);
+        }
+
+        let (keys, null_mask) = self.probe_keys.evaluate(block)?;
+        let mut probe_indices = vec![];
+        let mut build_indices = vec![];
+        let mut unmatched_indices = vec![];
+
+        for (row, key) in keys.iter().enumerate() {
+            let build_rows = match &null_mask {
+                Some(mask) if mask[row] => None,
+                _ => self.state.find(key),
+            };
+
+            match build_rows {
+                Some(build_rows) => {
+                    for build_row in build_rows {
+                        probe_indices.push(row as u32);
+                        build_indices.push(*build_row);
+                    }
+                }
+                None if self.join_type.preserve_left() => unmatched_indices.push(row as u32),
+                None => {}
+            }
+        }
+
+        if let Some(matched) = matched {
+            for build_row in &build_indices {
+                matched[*build_row as usize] = true;
+            }
+        }
+
+        let mut blocks = Vec::with_capacity(2);
+        if !probe_indices.is_empty() {
+            let mut columns = Self::take_columns(block, &probe_indices)?;
+            if let Some(build_block) = &self.state.block {
+                columns.extend(Self::take_columns(build_block, &build_indices)?);
+            }
+            blocks.push(DataBlock::create(self.schema.clone(), columns));
+        }
+
+        if !unmatched_indices.is_empty() {
+            let mut columns = Self::take_columns(block, &unmatched_indices)?;
+            let right_fields = &self.schema.fields()[block.num_columns()..];
+            columns.extend(Self::null_columns(right_fields, unmatched_indices.len()));
+            blocks.push(DataBlock::create(self.schema.clone(), columns));
+        }
+
+        match blocks.len() {
+            0 => Ok(None),
+            1 => Ok(blocks.pop()),
+            _ => Ok(Some(DataBlock::concat_blocks(&blocks)?)),
+        }
+    }
+
+    fn unmatched_build_rows(&self, matched: &[bool]) -> Result<Option<DataBlock>> {
+        let build_block = match &self.state.block {
+            None => return Ok(None),
+            Some(build_block) => build_block,
+        };
+
+        let unmatched_indices = matched
+            .iter()
+            .enumerate()
+            .filter(|(_, matched)| !**matched)
+            .map(|(row, _)| row as u32)
+            .collect::<Vec<_>>();
+
+        if unmatched_indices.is_empty() {
+            return Ok(None);
+        }
+
+        let left_len = self.schema.fields().len() - build_block.num_columns();
+        let left_fields = &self.schema.fields()[..left_len];
+        let mut columns = Self::null_columns(left_fields, unmatched_indices.len());
+        columns.extend(Self::take_columns(build_block, &unmatched_indices)?);
+        Ok(Some(DataBlock::create(self.schema.clone(), columns)))
+    }
+
+    fn take_columns(block: &DataBlock, indices: &[u32]) -> Result<Vec<DataColumn>> {
+        let taken = DataBlock::block_take_by_indices(block, &[], indices)?;
+        Ok(taken.columns().to_vec())
+    }
+
+    fn null_columns(fields: &[DataField], rows: usize) -> Vec<DataColumn> {
+        fields
+            .iter()
+            .map(|field| DataColumn::Constant(DataValue::from(field.data_type()), rows))
+            .collect()
+    }
+}
@@ -99,9 +99,13 @@ impl Processor for SinkTransform {
             )?);
         };
 
-        let input_schema = self.input_schema.clone();
+        // The schema of the input stream is the cast schema if the input is casted.
+        let input_schema = match &self.cast_schema {
+            Some(cast_schema) => cast_schema.clone(),
+            None => self.input_schema.clone(),
+        };
         let output_schema = self.table_info.schema();
-        if self.input_schema != output_schema {
+        if input_schema != output_schema {
             input_stream = Box::pin(AddOnStream::try_create(
                 input_stream,
                 input_schema,
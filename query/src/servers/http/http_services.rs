@@ -20,6 +20,7 @@ use common_exception::Result;
 use common_tracing::tracing;
 use poem::get;
 use poem::listener::RustlsConfig;
+use poem::middleware::Compression;
 use poem::put;
 use poem::Endpoint;
 use poem::EndpointExt;
@@ -30,6 +31,7 @@ use crate::configs::Config;
 use crate::servers::http::v1::query_route;
 use crate::servers::http::v1::statement_router;
 use crate::servers::http::v1::streaming_load;
+use crate::servers::http::v1::HTTPSessionMiddleware;
 use crate::servers::Server;
 use crate::sessions::SessionManager;
 
@@ -50,23 +52,34 @@ impl HttpHandler {
     pub fn usage(sock: SocketAddr) -> String {
         format!(
             r#" examples:
-curl --request POST '{:?}/v1/statement/' --header 'Content-Type: text/plain' --data-raw 'SELECT avg(number) FROM numbers(100000000)'
-curl --request POST '{:?}/v1/query/' --header 'Content-Type: application/json' --data-raw '{{"sql": "SELECT avg(number) FROM numbers(100000000)"}}'"#,
+curl --user 'root:' --request POST '{:?}/v1/statement/' --header 'Content-Type: text/plain' --data-raw 'SELECT avg(number) FROM numbers(100000000)'
+curl --user 'root:' --request POST '{:?}/v1/query/' --header 'Content-Type: application/json' --data-raw '{{"sql": "SELECT avg(number) FROM numbers(100000000)"}}'"#,
             sock, sock
         )
     }
 
-    fn build_router(&self, sock: SocketAddr) -> impl Endpoint {
-        Route::new()
+    fn build_router(&self, sock: SocketAddr) -> Result<impl Endpoint> {
+        let session_middleware =
+            HTTPSessionMiddleware::try_create(self.session_manager.get_conf())?;
+        Ok(Route::new()
             .at(
                 "/",
                 get(poem::endpoint::make_sync(move |_| Self::usage(sock))),
             )
-            .nest("/v1/statement", statement_router())
-            .nest("/v1/query", query_route())
-            .at("/v1/streaming_load", put(streaming_load))
+            .nest(
+                "/v1/statement",
+                statement_router().with(session_middleware.clone()),
+            )
+            .nest("/v1/query", query_route().with(session_middleware.clone()))
+            .at(
+                "/v1/streaming_load",
+                // decompresses the request body by Content-Encoding
+                put(streaming_load)
+                    .with(session_middleware)
+                    .with(Compression::default()),
+            )
             .data(self.session_manager.clone())
-            .boxed()
+            .boxed())
     }
 
     fn build_tls(config: &Config) -> Result<RustlsConfig> {
@@ -90,13 +103,13 @@ curl --request POST '{:?}/v1/query/' --header 'Content-Type: application/json' -
 
         let tls_config = Self::build_tls(self.session_manager.get_conf())?;
         self.shutdown_handler
-            .start_service(listening, Some(tls_config), self.build_router(listening))
+            .start_service(listening, Some(tls_config), self.build_router(listening)?)
             .await
     }
 
     async fn start_without_tls(&mut self, listening: SocketAddr) -> Result<SocketAddr> {
         self.shutdown_handler
-            .start_service(listening, None, self.build_router(listening))
+            .start_service(listening, None, self.build_router(listening)?)
             .await
     }
 }
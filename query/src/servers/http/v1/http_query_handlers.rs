@@ -30,18 +30,20 @@ use poem::web::Data;
 use poem::web::Json;
 use poem::web::Path;
 use poem::web::Query;
-use poem::IntoResponse;
 use poem::Route;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::servers::http::v1::query::ExecuteStateName;
 use crate::servers::http::v1::query::HttpQuery;
+use crate::servers::http::v1::query::HttpQueryManager;
+use crate::servers::http::v1::query::HttpQueryRef;
 use crate::servers::http::v1::query::HttpQueryRequest;
 use crate::servers::http::v1::query::HttpQueryResponseInternal;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::JsonBlockRef;
 use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -144,37 +146,31 @@ pub(crate) struct CancelParams {
 #[poem::handler]
 async fn query_cancel_handler(
     sessions_extension: Data<&Arc<SessionManager>>,
+    session: Data<&SessionRef>,
     Query(params): Query<CancelParams>,
     Path(query_id): Path<String>,
-) -> impl IntoResponse {
+) -> PoemResult<StatusCode> {
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
-    match http_query_manager.get_query_by_id(&query_id).await {
-        Some(query) => {
-            query.kill().await;
-            if params.delete.unwrap_or(false) {
-                http_query_manager.remove_query_by_id(&query_id).await;
-            }
-            StatusCode::OK
-        }
-        None => StatusCode::NOT_FOUND,
+    let query = get_query_of_session(&http_query_manager, session.0, &query_id).await?;
+    query.kill().await;
+    if params.delete.unwrap_or(false) {
+        http_query_manager.remove_query_by_id(&query_id).await;
     }
+    Ok(StatusCode::OK)
 }
 
 #[poem::handler]
 async fn query_state_handler(
     sessions_extension: Data<&Arc<SessionManager>>,
+    session: Data<&SessionRef>,
     Path(query_id): Path<String>,
 ) -> PoemResult<Json<QueryResponse>> {
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
-    match http_query_manager.get_query_by_id(&query_id).await {
-        Some(query) => {
-            let response = query.get_response_state_only().await;
-            Ok(Json(QueryResponse::from_internal(query_id, response)))
-        }
-        None => Err(query_id_not_found(query_id)),
-    }
+    let query = get_query_of_session(&http_query_manager, session.0, &query_id).await?;
+    let response = query.get_response_state_only().await;
+    Ok(Json(QueryResponse::from_internal(query_id, response)))
 }
 
 #[derive(Deserialize, Debug)]
@@ -197,27 +193,25 @@ impl PageParams {
 #[poem::handler]
 async fn query_page_handler(
     sessions_extension: Data<&Arc<SessionManager>>,
+    session: Data<&SessionRef>,
     Query(params): Query<PageParams>,
     Path((query_id, page_no)): Path<(String, usize)>,
 ) -> PoemResult<Json<QueryResponse>> {
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
-    match http_query_manager.get_query_by_id(&query_id).await {
-        Some(query) => {
-            let wait_type = params.get_wait_type();
-            let resp = query
-                .get_response_page(page_no, &wait_type, false)
-                .await
-                .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
-            Ok(Json(QueryResponse::from_internal(query_id, resp)))
-        }
-        None => Err(query_id_not_found(query_id)),
-    }
+    let query = get_query_of_session(&http_query_manager, session.0, &query_id).await?;
+    let wait_type = params.get_wait_type();
+    let resp = query
+        .get_response_page(page_no, &wait_type, false)
+        .await
+        .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
+    Ok(Json(QueryResponse::from_internal(query_id, resp)))
 }
 
 #[poem::handler]
 pub(crate) async fn query_handler(
     sessions_extension: Data<&Arc<SessionManager>>,
+    session: Data<&SessionRef>,
     Query(params): Query<PageParams>,
     Json(req): Json<HttpQueryRequest>,
 ) -> PoemResult<Json<QueryResponse>> {
@@ -225,15 +219,18 @@ pub(crate) async fn query_handler(
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
     let query_id = http_query_manager.next_query_id();
-    let query = HttpQuery::try_create(query_id.clone(), req, session_manager).await;
+    let owner = session
+        .0
+        .get_current_user()
+        .map_err(|e| PoemError::from_string(e.message(), StatusCode::UNAUTHORIZED))?
+        .identity();
+    let query = HttpQuery::try_create(query_id.clone(), req, session.0.clone()).await;
 
     match query {
         Ok(query) => {
             http_query_manager
-                .queries
-                .write()
-                .await
-                .insert(query_id.clone(), query.clone());
+                .add_query(&query_id, owner, query.clone())
+                .await;
 
             let wait_type = params.get_wait_type();
             let resp = query
@@ -258,7 +255,26 @@ pub fn query_route() -> Route {
         .at("/:id/kill", get(query_cancel_handler))
 }
 
-fn query_id_not_found(query_id: String) -> PoemError {
+// Only the user who started the query can access it.
+async fn get_query_of_session(
+    http_query_manager: &Arc<HttpQueryManager>,
+    session: &SessionRef,
+    query_id: &str,
+) -> PoemResult<HttpQueryRef> {
+    let user = session
+        .get_current_user()
+        .map_err(|e| PoemError::from_string(e.message(), StatusCode::UNAUTHORIZED))?;
+    match http_query_manager.get_query_by_id(query_id).await {
+        Some((owner, query)) if owner == user.identity() => Ok(query),
+        Some(_) => Err(PoemError::from_string(
+            format!("query {} is not started by {}", query_id, user.identity()),
+            StatusCode::FORBIDDEN,
+        )),
+        None => Err(query_id_not_found(query_id)),
+    }
+}
+
+fn query_id_not_found(query_id: &str) -> PoemError {
     PoemError::from_string(
         format!("query id not found {}", query_id),
         StatusCode::NOT_FOUND,
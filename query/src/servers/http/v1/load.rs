@@ -12,16 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::sync::Arc;
-
 use async_compat::CompatExt;
 use async_stream::stream;
 use common_base::ProgressValues;
+use common_exception::ErrorCode;
+use common_exception::ToErrorCode;
 use common_planners::InsertInputSource;
 use common_planners::PlanNode;
+use common_streams::CompressionAlgorithm;
 use common_streams::CsvSource;
+use common_streams::NDJsonSource;
+use common_streams::ParquetSource;
 use common_streams::Source;
+use common_streams::TsvSource;
 use common_tracing::tracing;
+use futures::io::Cursor;
+use futures::AsyncRead;
 use futures::StreamExt;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
@@ -34,7 +40,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::interpreters::InterpreterFactory;
-use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -49,28 +55,8 @@ pub struct LoadResponse {
 pub async fn streaming_load(
     req: &Request,
     mut multipart: Multipart,
-    sessions_extension: Data<&Arc<SessionManager>>,
+    session: Data<&SessionRef>,
 ) -> PoemResult<Json<LoadResponse>> {
-    let session_manager = sessions_extension.0;
-    let session = session_manager
-        .create_session("Streaming load")
-        .map_err(InternalServerError)?;
-    // Auth.
-    let user_name = "root";
-    let user_manager = session.get_user_manager();
-
-    // TODO: list user's grant list and check client address
-
-    let ctx = session
-        .create_context()
-        .await
-        .map_err(InternalServerError)?;
-    let user_info = user_manager
-        .get_user(&ctx.get_tenant(), user_name, "127.0.0.1")
-        .await
-        .map_err(InternalServerError)?;
-    session.set_current_user(user_info);
-
     let context = session
         .create_context()
         .await
@@ -108,25 +94,36 @@ pub async fn streaming_load(
         })
         .unwrap_or(b'\n');
 
+    // the compression of each uploaded file, detected by the file name by default
+    let compression = req
+        .headers()
+        .get("compression")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("auto")
+        .to_string();
+    // validate the option
+    CompressionAlgorithm::from_option(&compression, "")
+        .map_err(|e| poem::Error::from_string(e.message(), StatusCode::BAD_REQUEST))?;
+
     let plan = PlanParser::parse(insert_sql, context.clone())
         .await
         .map_err(InternalServerError)?;
     context.attach_query_str(insert_sql);
 
     // validate plan
-    match &plan {
+    let format = match &plan {
         PlanNode::Insert(insert) => match &insert.source {
             InsertInputSource::StreamingWithFormat(format) => {
-                if format.to_lowercase().as_str() == "csv" {
-                    Ok(())
-                } else {
-                    Err(poem::Error::from_string(
+                let format = format.to_lowercase();
+                match format.as_str() {
+                    "csv" | "tsv" | "json" | "ndjson" | "parquet" => Ok(format),
+                    _ => Err(poem::Error::from_string(
                         format!(
-                            "Streaming load only supports csv format, but got {}",
+                            "Streaming load only supports csv, tsv, ndjson and parquet format, but got {}",
                             format
                         ),
                         StatusCode::BAD_REQUEST,
-                    ))
+                    )),
                 }
             }
             _non_supported_source => Err(poem::Error::from_string(
@@ -157,15 +154,56 @@ pub async fn streaming_load(
 
     let stream = stream! {
         while let Ok(Some(field)) = multipart.next_field().await {
-            let reader = field.into_async_read();
-            let mut source = CsvSource::try_create(reader.compat(), plan.schema(), csv_header, field_delimitor, record_delimitor, max_block_size)?;
+            let file_name = field.file_name().unwrap_or("").to_string();
+            let compression = CompressionAlgorithm::from_option(&compression, &file_name);
+            let source = match (format.as_str(), compression) {
+                (_, Err(e)) => Err(e),
+                // parquet needs to seek the footer, buffer the whole file
+                ("parquet", Ok(None)) => field
+                    .bytes()
+                    .await
+                    .map_err_to_code(ErrorCode::BadBytes, || "Read parquet file error in streaming load")
+                    .map(|bytes| {
+                        let projection = (0..plan.schema().fields().len()).collect();
+                        Box::new(ParquetSource::new(Cursor::new(bytes), plan.schema(), projection)) as Box<dyn Source>
+                    }),
+                ("parquet", Ok(Some(compression))) => Err(ErrorCode::BadOption(format!(
+                    "Parquet file {} can not be compressed by {:?}",
+                    file_name, compression
+                ))),
+                (format, Ok(compression)) => {
+                    let reader = field.into_async_read().compat();
+                    let reader: Box<dyn AsyncRead + Unpin + Send> = match compression {
+                        Some(compression) => compression.decode(reader),
+                        None => Box::new(reader),
+                    };
+                    match format {
+                        "csv" => CsvSource::try_create(reader, plan.schema(), csv_header, field_delimitor, record_delimitor, max_block_size)
+                            .map(|source| Box::new(source) as Box<dyn Source>),
+                        "tsv" => TsvSource::try_create(reader, plan.schema(), csv_header, max_block_size)
+                            .map(|source| Box::new(source) as Box<dyn Source>),
+                        _ => NDJsonSource::try_create(reader, plan.schema(), max_block_size)
+                            .map(|source| Box::new(source) as Box<dyn Source>),
+                    }
+                }
+            };
+            let mut source = match source {
+                Ok(source) => source,
+                Err(e) => {
+                    yield(Err(e));
+                    break;
+                }
+            };
 
             loop {
                 let block = source.read().await;
                 match block {
                     Ok(None) => break,
                     Ok(Some(b)) =>  yield(Ok(b)),
-                    Err(e) => yield(Err(e)),
+                    Err(e) => {
+                        yield(Err(e));
+                        break;
+                    }
                 }
             }
         }
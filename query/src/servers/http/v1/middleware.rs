@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UserInfo;
+use common_tracing::tracing;
+use headers::authorization::Basic;
+use headers::authorization::Bearer;
+use headers::Authorization;
+use headers::HeaderMapExt;
+use jwt_simple::algorithms::HS256Key;
+use jwt_simple::algorithms::MACLike;
+use jwt_simple::claims::NoCustomClaims;
+use poem::http::StatusCode;
+use poem::Endpoint;
+use poem::Middleware;
+use poem::Request;
+
+use crate::configs::Config;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
+use crate::users::CertifiedInfo;
+use crate::users::UserApiProvider;
+
+/// Authenticates the requests of the http handler and attaches a session of the
+/// authenticated user to them, so the handlers can take it with `Data<&SessionRef>`.
+///
+/// Two kinds of `Authorization` header are accepted:
+/// - `Basic`, checked against the password of the user,
+/// - `Bearer`, a HS256 JWT whose `sub` claim is the user name, only if
+///   `http_handler_jwt_secret_file` is configured.
+///
+/// The user is matched with the address of the client, like the other handlers; a request
+/// without the address, e.g. one called in process, only matches the users of host `%`.
+#[derive(Clone)]
+pub struct HTTPSessionMiddleware {
+    jwt_key: Option<HS256Key>,
+}
+
+impl HTTPSessionMiddleware {
+    pub fn try_create(conf: &Config) -> Result<HTTPSessionMiddleware> {
+        let secret_file = &conf.query.http_handler_jwt_secret_file;
+        let jwt_key = match secret_file.is_empty() {
+            true => None,
+            false => {
+                let secret = std::fs::read_to_string(secret_file)?;
+                Some(HS256Key::from_bytes(secret.trim().as_bytes()))
+            }
+        };
+        Ok(HTTPSessionMiddleware { jwt_key })
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for HTTPSessionMiddleware {
+    type Output = HTTPSessionEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        HTTPSessionEndpoint {
+            ep,
+            jwt_key: self.jwt_key.clone(),
+        }
+    }
+}
+
+pub struct HTTPSessionEndpoint<E> {
+    ep: E,
+    jwt_key: Option<HS256Key>,
+}
+
+enum Credential {
+    Password { name: String, password: Vec<u8> },
+    Token(String),
+}
+
+impl<E> HTTPSessionEndpoint<E> {
+    fn get_credential(req: &Request) -> Result<Credential> {
+        let headers = req.headers();
+        if let Some(Authorization(basic)) = headers.typed_get::<Authorization<Basic>>() {
+            Ok(Credential::Password {
+                name: basic.username().to_string(),
+                password: basic.password().as_bytes().to_vec(),
+            })
+        } else if let Some(Authorization(bearer)) = headers.typed_get::<Authorization<Bearer>>() {
+            Ok(Credential::Token(bearer.token().to_string()))
+        } else {
+            Err(ErrorCode::AuthenticateFailure(
+                "No Authorization header, use HTTP basic auth or a bearer token",
+            ))
+        }
+    }
+
+    // Without the address of the client, only the users allowed from any host are matched.
+    async fn get_user(
+        user_manager: &UserApiProvider,
+        tenant: &str,
+        name: &str,
+        client_ip: Option<&str>,
+    ) -> Result<UserInfo> {
+        match client_ip {
+            Some(client_ip) => {
+                user_manager
+                    .get_user_with_client_ip(tenant, name, client_ip)
+                    .await
+            }
+            None => user_manager.get_user(tenant, name, "%").await,
+        }
+    }
+
+    async fn auth(
+        &self,
+        session_manager: Arc<SessionManager>,
+        credential: Credential,
+        client_ip: Option<&str>,
+    ) -> Result<SessionRef> {
+        let session = session_manager.create_session("HTTPSession")?;
+        let ctx = session.create_context().await?;
+        let tenant = ctx.get_tenant();
+        let user_manager = session.get_user_manager();
+
+        let user_info = match credential {
+            Credential::Password { name, password } => {
+                let user_info = Self::get_user(&user_manager, &tenant, &name, client_ip).await?;
+                let info = CertifiedInfo::create(&name, password, client_ip.unwrap_or_default());
+                if !user_manager.auth_user(user_info.clone(), info).await? {
+                    return Err(ErrorCode::AuthenticateFailure(format!(
+                        "Wrong password for user {}",
+                        name
+                    )));
+                }
+                user_info
+            }
+            Credential::Token(token) => {
+                let jwt_key = self.jwt_key.as_ref().ok_or_else(|| {
+                    ErrorCode::AuthenticateFailure(
+                        "Bearer token is not enabled, http_handler_jwt_secret_file is not set",
+                    )
+                })?;
+                let claims = jwt_key
+                    .verify_token::<NoCustomClaims>(&token, None)
+                    .map_err(|e| {
+                        ErrorCode::AuthenticateFailure(format!("Invalid bearer token: {}", e))
+                    })?;
+                let name = claims.subject.ok_or_else(|| {
+                    ErrorCode::AuthenticateFailure("Bearer token without the sub claim")
+                })?;
+                Self::get_user(&user_manager, &tenant, &name, client_ip).await?
+            }
+        };
+
+        session.set_current_user(user_info);
+        Ok(session)
+    }
+}
+
+#[async_trait::async_trait]
+impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
+    type Output = E::Output;
+
+    async fn call(&self, mut req: Request) -> poem::Result<Self::Output> {
+        let session_manager = req
+            .extensions()
+            .get::<Arc<SessionManager>>()
+            .cloned()
+            .ok_or_else(|| {
+                poem::Error::from_string(
+                    "No session manager in the http request",
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                )
+            })?;
+        let client_ip = req
+            .remote_addr()
+            .as_socket_addr()
+            .map(|addr| addr.ip().to_string());
+
+        let credential = Self::get_credential(&req);
+        let session = match credential {
+            Ok(credential) => {
+                self.auth(session_manager, credential, client_ip.as_deref())
+                    .await
+            }
+            Err(cause) => Err(cause),
+        }
+        .map_err(|e| {
+            tracing::error!(
+                "HTTP handler authenticate failed, client_address: {:?}, cause: {:?}",
+                client_ip,
+                e
+            );
+            poem::Error::from_string(e.message(), StatusCode::UNAUTHORIZED)
+        })?;
+        req.extensions_mut().insert(session);
+        self.ep.call(req).await
+    }
+}
@@ -15,6 +15,7 @@
 pub mod block_to_json;
 mod http_query_handlers;
 mod load;
+mod middleware;
 mod query;
 mod statement;
 
@@ -29,6 +30,8 @@ pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
 pub use load::streaming_load;
 pub use load::LoadResponse;
+pub use middleware::HTTPSessionEndpoint;
+pub use middleware::HTTPSessionMiddleware;
 pub use query::ExecuteStateName;
 pub use query::HttpQueryHandle;
 pub use query::HttpQueryManager;
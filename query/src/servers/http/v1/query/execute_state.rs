@@ -34,7 +34,6 @@ use ExecuteState::*;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::sessions::QueryContext;
-use crate::sessions::SessionManager;
 use crate::sessions::SessionRef;
 use crate::sql::PlanParser;
 
@@ -48,7 +47,6 @@ pub struct HttpQueryRequest {
 #[derive(Deserialize, Debug, Default)]
 pub struct HttpSessionConf {
     pub database: Option<String>,
-    pub user: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq)]
@@ -148,27 +146,15 @@ pub(crate) struct ExecuteRunning {
 impl ExecuteState {
     pub(crate) async fn try_create(
         request: &HttpQueryRequest,
-        session_manager: &Arc<SessionManager>,
+        session: SessionRef,
         block_tx: mpsc::Sender<DataBlock>,
     ) -> Result<(ExecutorRef, DataSchemaRef)> {
         let sql = &request.sql;
-        let session = session_manager.create_session("http-statement")?;
         let context = session.create_context().await?;
         if let Some(db) = &request.session.database {
             context.set_current_database(db.clone()).await?;
         };
         context.attach_query_str(sql);
-        let default_user = "root".to_string();
-        let user_name = request.session.user.as_ref().unwrap_or(&default_user);
-        let user_manager = session.get_user_manager();
-
-        // take root@127.0.0.1 with all privileges yet
-        // TODO: verify the user identity by jwt
-        let ctx = session.create_context().await?;
-        let user_info = user_manager
-            .get_user(&ctx.get_tenant(), user_name, "127.0.0.1")
-            .await?;
-        session.set_current_user(user_info);
 
         let plan = PlanParser::parse(sql, context.clone()).await?;
         let schema = plan.schema();
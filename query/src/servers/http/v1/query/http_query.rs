@@ -29,7 +29,7 @@ use crate::servers::http::v1::query::HttpQueryRequest;
 use crate::servers::http::v1::query::ResponseData;
 use crate::servers::http::v1::query::ResultDataManager;
 use crate::servers::http::v1::query::Wait;
-use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
 
 pub struct ResponseInitialState {
     pub schema: Option<DataSchemaRef>,
@@ -62,12 +62,12 @@ impl HttpQuery {
     pub(crate) async fn try_create(
         id: String,
         request: HttpQueryRequest,
-        session_manager: &Arc<SessionManager>,
+        session: SessionRef,
     ) -> Result<HttpQueryRef> {
         //TODO(youngsofun): support config/set channel size
         let (block_tx, block_rx) = mpsc::channel(10);
 
-        let (state, schema) = ExecuteState::try_create(&request, session_manager, block_tx).await?;
+        let (state, schema) = ExecuteState::try_create(&request, session, block_tx).await?;
         let data = Arc::new(TokioMutex::new(ResultDataManager::new(schema, block_rx)));
         let query = HttpQuery {
             id,
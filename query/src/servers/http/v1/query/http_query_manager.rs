@@ -17,12 +17,14 @@ use std::sync::Arc;
 
 use common_base::tokio::sync::RwLock;
 use common_exception::Result;
+use common_meta_types::UserIdentity;
 
 use crate::configs::Config;
 use crate::servers::http::v1::query::http_query::HttpQueryRef;
 
 pub struct HttpQueryManager {
-    pub(crate) queries: Arc<RwLock<HashMap<String, HttpQueryRef>>>,
+    // the queries along with the users who started them, only the owner can access a query.
+    pub(crate) queries: Arc<RwLock<HashMap<String, (UserIdentity, HttpQueryRef)>>>,
 }
 
 impl HttpQueryManager {
@@ -36,7 +38,20 @@ impl HttpQueryManager {
         uuid::Uuid::new_v4().to_string()
     }
 
-    pub(crate) async fn get_query_by_id(self: &Arc<Self>, query_id: &str) -> Option<HttpQueryRef> {
+    pub(crate) async fn add_query(
+        self: &Arc<Self>,
+        query_id: &str,
+        owner: UserIdentity,
+        query: HttpQueryRef,
+    ) {
+        let mut queries = self.queries.write().await;
+        queries.insert(query_id.to_string(), (owner, query));
+    }
+
+    pub(crate) async fn get_query_by_id(
+        self: &Arc<Self>,
+        query_id: &str,
+    ) -> Option<(UserIdentity, HttpQueryRef)> {
         let queries = self.queries.read().await;
         queries.get(query_id).map(|q| q.to_owned())
     }
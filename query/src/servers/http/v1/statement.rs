@@ -30,28 +30,30 @@ use crate::servers::http::v1::query::HttpSessionConf;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::QueryResponse;
 use crate::sessions::SessionManager;
+use crate::sessions::SessionRef;
 
 #[derive(Deserialize)]
 pub struct StatementHandlerParams {
     db: Option<String>,
-    user: Option<String>,
 }
 
 #[poem::handler]
 pub async fn statement_handler(
     sessions_extension: Data<&Arc<SessionManager>>,
+    session: Data<&SessionRef>,
     sql: String,
     Query(params): Query<StatementHandlerParams>,
 ) -> PoemResult<Json<QueryResponse>> {
     let session_manager = sessions_extension.0;
     let http_query_manager = session_manager.get_http_query_manager();
     let query_id = http_query_manager.next_query_id();
-    let session = HttpSessionConf {
-        database: params.db.filter(|x| !x.is_empty()),
-        user: params.user,
+    let req = HttpQueryRequest {
+        sql,
+        session: HttpSessionConf {
+            database: params.db.filter(|x| !x.is_empty()),
+        },
     };
-    let req = HttpQueryRequest { sql, session };
-    let query = HttpQuery::try_create(query_id.clone(), req, session_manager).await;
+    let query = HttpQuery::try_create(query_id.clone(), req, session.0.clone()).await;
 
     match query {
         Ok(query) => {
@@ -61,6 +61,9 @@ impl ShutdownHandle {
 
     pub async fn shutdown(&mut self, mut signal: SignalStream) {
         self.shutdown_services(true).await;
+        if let Err(cause) = self.sessions.get_table_vacuum().shutdown().await {
+            tracing::error!("{:?}", cause);
+        }
         self.sessions
             .get_cluster_discovery()
             .unregister_to_metastore(&mut signal)
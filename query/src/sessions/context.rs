@@ -57,6 +57,7 @@ use crate::sessions::Session;
 use crate::sessions::SessionRef;
 use crate::sessions::Settings;
 use crate::storages::cache::CacheManager;
+use crate::storages::fuse::VacuumStatus;
 use crate::storages::Table;
 use crate::users::UserApiProvider;
 
@@ -65,14 +66,30 @@ pub struct QueryContext {
     statistics: Arc<RwLock<Statistics>>,
     partition_queue: Arc<RwLock<VecDeque<Part>>>,
     shared: Arc<QueryContextShared>,
+    /// The views being expanded by the analyzer, from the outermost one.
+    expanding_views: Vec<String>,
 }
 
 impl QueryContext {
     pub fn create_from(other: Arc<QueryContext>) -> Arc<QueryContext> {
-        QueryContext::create_from_shared(other.shared.clone())
+        QueryContext::create_with_views(other.shared.clone(), other.expanding_views.clone())
     }
 
     pub fn create_from_shared(shared: Arc<QueryContextShared>) -> Arc<QueryContext> {
+        QueryContext::create_with_views(shared, vec![])
+    }
+
+    /// Create a context to analyze the query of the view, which is expanded in the other context.
+    pub fn create_for_view(other: Arc<QueryContext>, view: String) -> Arc<QueryContext> {
+        let mut expanding_views = other.expanding_views.clone();
+        expanding_views.push(view);
+        QueryContext::create_with_views(other.shared.clone(), expanding_views)
+    }
+
+    fn create_with_views(
+        shared: Arc<QueryContextShared>,
+        expanding_views: Vec<String>,
+    ) -> Arc<QueryContext> {
         shared.increment_ref_count();
 
         tracing::debug!("Create QueryContext");
@@ -85,9 +102,14 @@ impl QueryContext {
                 *crate::configs::DATABEND_COMMIT_VERSION
             ),
             shared,
+            expanding_views,
         })
     }
 
+    pub fn get_expanding_views(&self) -> &[String] {
+        &self.expanding_views
+    }
+
     /// Build a table instance the plan wants to operate on.
     ///
     /// A plan just contains raw information about a table or table function.
@@ -281,6 +303,14 @@ impl QueryContext {
         self.shared.session.get_sessions_manager().processes_info()
     }
 
+    pub fn get_vacuum_statuses(self: &Arc<Self>) -> Vec<VacuumStatus> {
+        self.shared
+            .session
+            .get_sessions_manager()
+            .get_table_vacuum()
+            .statuses()
+    }
+
     /// Get the data accessor metrics.
     pub fn get_dal_metrics(&self) -> DalMetrics {
         self.shared.dal_ctx.get_metrics()
@@ -36,6 +36,7 @@ use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session::Session;
 use crate::sessions::session_ref::SessionRef;
 use crate::storages::cache::CacheManager;
+use crate::storages::fuse::TableVacuum;
 use crate::users::UserApiProvider;
 
 pub struct SessionManager {
@@ -48,6 +49,7 @@ pub struct SessionManager {
     pub(in crate::sessions) max_sessions: usize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Arc<Session>>>>,
     pub(in crate::sessions) storage_cache_manager: Arc<CacheManager>,
+    pub(in crate::sessions) table_vacuum: Arc<TableVacuum>,
 }
 
 impl SessionManager {
@@ -62,6 +64,9 @@ impl SessionManager {
         let user = UserApiProvider::create_global(conf.clone()).await?;
         let http_query_manager = HttpQueryManager::create_global(conf.clone()).await?;
 
+        let table_vacuum =
+            TableVacuum::create(Duration::from_secs(conf.query.table_vacuum_interval_secs));
+
         let max_active_sessions = conf.query.max_active_sessions as usize;
         Ok(Arc::new(SessionManager {
             catalog,
@@ -72,6 +77,7 @@ impl SessionManager {
             max_sessions: max_active_sessions,
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_active_sessions))),
             storage_cache_manager: Arc::new(storage_cache_mgr),
+            table_vacuum: Arc::new(table_vacuum),
         }))
     }
 
@@ -100,6 +106,10 @@ impl SessionManager {
         self.storage_cache_manager.as_ref()
     }
 
+    pub fn get_table_vacuum(self: &Arc<Self>) -> Arc<TableVacuum> {
+        self.table_vacuum.clone()
+    }
+
     pub fn create_session(self: &Arc<Self>, typ: impl Into<String>) -> Result<SessionRef> {
         let mut sessions = self.active_sessions.write();
         match sessions.len() == self.max_sessions {
@@ -25,6 +25,7 @@ use common_planners::SelectPlan;
 use crate::sessions::QueryContext;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::JoinRelation;
 use crate::sql::statements::QueryAnalyzeState;
 use crate::sql::statements::QueryRelation;
 use crate::sql::DfHint;
@@ -86,10 +87,35 @@ impl PlanParser {
     }
 
     fn build_from_plan(data: &QueryAnalyzeState) -> Result<PlanNode> {
-        match &data.relation {
+        Self::build_relation_plan(&data.relation)
+    }
+
+    fn build_relation_plan(relation: &QueryRelation) -> Result<PlanNode> {
+        match relation {
             QueryRelation::None => Err(ErrorCode::LogicalError("Not from in select query")),
             QueryRelation::Nested(data) => Self::build_query_plan(data),
             QueryRelation::FromTable(plan) => Ok(PlanNode::ReadSource(plan.as_ref().clone())),
+            QueryRelation::Projected(relation, exprs) => {
+                let input = Self::build_relation_plan(relation)?;
+                PlanBuilder::from(&input).project(exprs)?.build()
+            }
+            QueryRelation::Join(join) => Self::build_join_plan(join),
+        }
+    }
+
+    fn build_join_plan(join: &JoinRelation) -> Result<PlanNode> {
+        let left = Self::build_relation_plan(&join.left)?;
+        let right = Self::build_relation_plan(&join.right)?;
+        let builder = PlanBuilder::from(&left).join(
+            join.join_type,
+            &join.left_keys,
+            &join.right_keys,
+            &right,
+        )?;
+
+        match &join.filter {
+            None => builder.build(),
+            Some(predicate) => builder.filter(predicate.clone())?.build(),
         }
     }
 
@@ -17,6 +17,7 @@
 
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::str::FromStr;
 use std::time::Instant;
 
 use common_exception::ErrorCode;
@@ -28,6 +29,7 @@ use common_meta_types::StageParams;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
+use common_planners::CopyOnError;
 use common_planners::ExplainType;
 use common_planners::Optimization;
 use metrics::histogram;
@@ -37,6 +39,7 @@ use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOptionDef;
 use sqlparser::ast::Expr;
 use sqlparser::ast::Ident;
+use sqlparser::ast::Query;
 use sqlparser::ast::Statement;
 use sqlparser::ast::TableConstraint;
 use sqlparser::ast::Value;
@@ -52,7 +55,11 @@ use sqlparser::tokenizer::Whitespace;
 use sqlparser::tokenizer::Word;
 
 use super::statements::DfCopy;
+use super::statements::DfCopyIntoStage;
 use super::statements::DfDescribeStage;
+use super::statements::DfListStage;
+use crate::sql::statements::DfAlterTable;
+use crate::sql::statements::DfAlterTableOperation;
 use crate::sql::statements::DfAlterUDF;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCreateDatabase;
@@ -60,19 +67,24 @@ use crate::sql::statements::DfCreateStage;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfCreateView;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropStage;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUDF;
 use crate::sql::statements::DfDropUser;
+use crate::sql::statements::DfDropView;
 use crate::sql::statements::DfExplain;
 use crate::sql::statements::DfGrantObject;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
+use crate::sql::statements::DfNavigationPoint;
 use crate::sql::statements::DfOptimizeTable;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfRevokeStatement;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateDatabase;
@@ -86,7 +98,10 @@ use crate::sql::statements::DfShowSettings;
 use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUDF;
 use crate::sql::statements::DfShowUsers;
+use crate::sql::statements::DfTableNavigation;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUndropTable;
+use crate::sql::statements::DfUpdateStatement;
 use crate::sql::statements::DfUseDatabase;
 use crate::sql::statements::DfUseTenant;
 use crate::sql::DfHint;
@@ -102,6 +117,9 @@ macro_rules! parser_err {
 /// SQL Parser
 pub struct DfParser<'a> {
     parser: Parser<'a>,
+    // The time travel clauses, along with the index of the statement they belong to.
+    navigations: Vec<(usize, DfTableNavigation)>,
+    statement: usize,
 }
 
 impl<'a> DfParser<'a> {
@@ -114,13 +132,137 @@ impl<'a> DfParser<'a> {
     /// Parse the specified tokens with dialect
     pub fn new_with_dialect(sql: &str, dialect: &'a dyn Dialect) -> Result<Self, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, sql);
-        let tokens = tokenizer.tokenize()?;
+        let (tokens, navigations) = Self::parse_navigations(tokenizer.tokenize()?)?;
 
         Ok(DfParser {
             parser: Parser::new(tokens, dialect),
+            navigations,
+            statement: 0,
         })
     }
 
+    // Time travel: "FROM t AT (SNAPSHOT => 'id')" or "FROM t AT (TIMESTAMP => 'time')".
+    // The clause is not known by the sqlparser, so it is parsed and taken out of the tokens
+    // ahead, then attached to the query statement of the table by `query_statement`.
+    fn parse_navigations(
+        tokens: Vec<Token>,
+    ) -> Result<(Vec<Token>, Vec<(usize, DfTableNavigation)>), ParserError> {
+        let mut remains = Vec::with_capacity(tokens.len());
+        let mut navigations: Vec<(usize, DfTableNavigation)> = Vec::new();
+        let (mut depth, mut statement, mut index) = (0, 0, 0);
+
+        while index < tokens.len() {
+            match &tokens[index] {
+                Token::LParen => depth += 1,
+                Token::RParen => depth -= 1,
+                Token::SemiColon if depth == 0 => statement += 1,
+                Token::Word(w) if w.keyword == Keyword::AT && w.quote_style.is_none() => {
+                    if let Some((point, next)) = Self::parse_navigation_point(&tokens, index)? {
+                        if depth != 0 {
+                            return parser_err!(
+                                "Time travel is only supported for the tables in the FROM clause of the outermost query"
+                            );
+                        }
+
+                        let table = match remains
+                            .iter()
+                            .rev()
+                            .find(|token| !matches!(token, Token::Whitespace(_)))
+                        {
+                            Some(Token::Word(w)) => Ident {
+                                value: w.value.clone(),
+                                quote_style: w.quote_style,
+                            },
+                            _ => return parser_err!("Expected table name before AT"),
+                        };
+
+                        if navigations.iter().any(|(s, navigation)| {
+                            *s == statement && navigation.table.value == table.value
+                        }) {
+                            return parser_err!(format!(
+                                "Duplicate time travel of table {}",
+                                table
+                            ));
+                        }
+
+                        navigations.push((statement, DfTableNavigation { table, point }));
+                        index = next;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+
+            remains.push(tokens[index].clone());
+            index += 1;
+        }
+
+        Ok((remains, navigations))
+    }
+
+    // Parse "AT ( SNAPSHOT | TIMESTAMP => '<literal>' )" at the token `at`, returns the point
+    // and the index of the token after the clause, or None if it is not a time travel clause.
+    fn parse_navigation_point(
+        tokens: &[Token],
+        at: usize,
+    ) -> Result<Option<(DfNavigationPoint, usize)>, ParserError> {
+        let mut significant = tokens
+            .iter()
+            .enumerate()
+            .skip(at + 1)
+            .filter(|(_, token)| !matches!(token, Token::Whitespace(_)));
+
+        let kind = match (significant.next(), significant.next()) {
+            (Some((_, Token::LParen)), Some((_, Token::Word(w)))) if w.quote_style.is_none() => {
+                w.value.to_uppercase()
+            }
+            _ => return Ok(None),
+        };
+
+        if kind != "SNAPSHOT" && kind != "TIMESTAMP" {
+            return Ok(None);
+        }
+
+        let value = match (significant.next(), significant.next()) {
+            (Some((_, Token::RArrow)), Some((_, Token::SingleQuotedString(value)))) => {
+                value.clone()
+            }
+            (Some((_, Token::RArrow)), Some((_, unexpected))) => {
+                return parser_err!(format!("Expected literal string, found: {}", unexpected))
+            }
+            (Some((_, unexpected)), _) => {
+                return parser_err!(format!("Expected =>, found: {}", unexpected))
+            }
+            _ => return parser_err!("Expected =>, found: EOF"),
+        };
+
+        match significant.next() {
+            Some((index, Token::RParen)) => {
+                let point = match kind.as_str() {
+                    "SNAPSHOT" => DfNavigationPoint::Snapshot(value),
+                    _ => DfNavigationPoint::Timestamp(value),
+                };
+                Ok(Some((point, index + 1)))
+            }
+            Some((_, unexpected)) => parser_err!(format!("Expected ), found: {}", unexpected)),
+            None => parser_err!("Expected ), found: EOF"),
+        }
+    }
+
+    // The query statement, with the time travel clauses of the current statement.
+    fn query_statement(&mut self, query: Query) -> Result<DfQueryStatement, ParserError> {
+        let mut statement = DfQueryStatement::try_from(query)?;
+        let (navigations, others): (Vec<_>, Vec<_>) = std::mem::take(&mut self.navigations)
+            .into_iter()
+            .partition(|(index, _)| *index == self.statement);
+        self.navigations = others;
+        statement.navigations = navigations
+            .into_iter()
+            .map(|(_, navigation)| navigation)
+            .collect();
+        Ok(statement)
+    }
+
     /// Parse a SQL statement and produce a set of statements with dialect
     pub fn parse_sql(sql: &str) -> Result<(Vec<DfStatement>, Vec<DfHint>), ErrorCode> {
         let dialect = &GenericDialect {};
@@ -143,6 +285,7 @@ impl<'a> DfParser<'a> {
             // ignore empty statements (between successive statement delimiters)
             while parser.parser.consume_token(&Token::SemiColon) {
                 expecting_statement_delimiter = false;
+                parser.statement += 1;
             }
 
             if parser.parser.peek_token() == Token::EOF {
@@ -153,6 +296,13 @@ impl<'a> DfParser<'a> {
             }
 
             let statement = parser.parse_statement()?;
+            if parser
+                .navigations
+                .iter()
+                .any(|(index, _)| *index == parser.statement)
+            {
+                return parser_err!("Time travel is only supported in the query statement");
+            }
             stmts.push(statement);
             expecting_statement_delimiter = true;
         }
@@ -236,6 +386,8 @@ impl<'a> DfParser<'a> {
                     Keyword::TRUNCATE => self.parse_truncate(),
                     Keyword::SET => self.parse_set(),
                     Keyword::INSERT => self.parse_insert(),
+                    Keyword::DELETE => self.parse_delete(),
+                    Keyword::UPDATE => self.parse_update(),
                     Keyword::SELECT | Keyword::WITH | Keyword::VALUES => self.parse_query(),
                     Keyword::GRANT => {
                         self.parser.next_token();
@@ -249,12 +401,18 @@ impl<'a> DfParser<'a> {
                         self.parser.next_token();
                         self.parse_copy()
                     }
+                    Keyword::RENAME => {
+                        self.parser.next_token();
+                        self.parse_rename()
+                    }
                     Keyword::NoKeyword => match w.value.to_uppercase().as_str() {
                         // Use database
                         "USE" => self.parse_use_database(),
                         "KILL" => self.parse_kill_query(),
                         "OPTIMIZE" => self.parse_optimize(),
                         "SUDO" => self.parse_sudo_command(),
+                        "UNDROP" => self.parse_undrop(),
+                        "LIST" => self.parse_list_stage(),
                         _ => self.expected("Keyword", self.parser.peek_token()),
                     },
                     _ => self.expected("an SQL statement", Token::Word(w)),
@@ -268,9 +426,9 @@ impl<'a> DfParser<'a> {
     fn parse_query(&mut self) -> Result<DfStatement, ParserError> {
         // self.parser.prev_token();
         let native_query = self.parser.parse_query()?;
-        Ok(DfStatement::Query(Box::new(DfQueryStatement::try_from(
-            native_query,
-        )?)))
+        Ok(DfStatement::Query(Box::new(
+            self.query_statement(native_query)?,
+        )))
     }
 
     fn parse_set(&mut self) -> Result<DfStatement, ParserError> {
@@ -319,6 +477,49 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    fn parse_delete(&mut self) -> Result<DfStatement, ParserError> {
+        // syntax: "DELETE FROM t [WHERE expr]"
+        self.parser.next_token();
+        self.parser.expect_keyword(Keyword::FROM)?;
+        let name = self.parser.parse_object_name()?;
+        let selection = match self.parser.parse_keyword(Keyword::WHERE) {
+            true => Some(self.parser.parse_expr()?),
+            false => None,
+        };
+
+        Ok(DfStatement::Delete(DfDeleteStatement { name, selection }))
+    }
+
+    fn parse_update(&mut self) -> Result<DfStatement, ParserError> {
+        // syntax: "UPDATE t SET col = expr [, col = expr]* [WHERE expr]"
+        self.parser.next_token();
+        let name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::SET)?;
+
+        let mut assignments = vec![];
+        loop {
+            let column = self.parser.parse_identifier()?;
+            self.parser.expect_token(&Token::Eq)?;
+            let value = self.parser.parse_expr()?;
+            assignments.push((column, value));
+
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+
+        let selection = match self.parser.parse_keyword(Keyword::WHERE) {
+            true => Some(self.parser.parse_expr()?),
+            false => None,
+        };
+
+        Ok(DfStatement::Update(DfUpdateStatement {
+            name,
+            assignments,
+            selection,
+        }))
+    }
+
     /// Parse an SQL EXPLAIN statement.
     pub fn parse_explain(&mut self) -> Result<DfStatement, ParserError> {
         // Parser is at the token immediately after EXPLAIN
@@ -344,6 +545,10 @@ impl<'a> DfParser<'a> {
 
     // parse show tables.
     fn parse_show_tables(&mut self) -> Result<DfStatement, ParserError> {
+        if self.consume_token("HISTORY") {
+            return self.parse_show_tables_history();
+        }
+
         let tok = self.parser.next_token();
         match &tok {
             Token::EOF | Token::SemiColon => Ok(DfStatement::ShowTables(DfShowTables::All)),
@@ -363,6 +568,17 @@ impl<'a> DfParser<'a> {
         }
     }
 
+    // parse show tables history [from|in db].
+    fn parse_show_tables_history(&mut self) -> Result<DfStatement, ParserError> {
+        let database =
+            if self.parser.parse_keyword(Keyword::FROM) || self.parser.parse_keyword(Keyword::IN) {
+                Some(self.parser.parse_object_name()?)
+            } else {
+                None
+            };
+        Ok(DfStatement::ShowTables(DfShowTables::History(database)))
+    }
+
     // parse show databases where database = xxx or where database
     fn parse_show_databases(&mut self) -> Result<DfStatement, ParserError> {
         if self.parser.parse_keyword(Keyword::WHERE) {
@@ -540,6 +756,7 @@ impl<'a> DfParser<'a> {
                 } else {
                     match w.keyword {
                         Keyword::TABLE => self.parse_create_table(),
+                        Keyword::VIEW => self.parse_create_view(),
                         Keyword::DATABASE => self.parse_create_database(),
                         Keyword::USER => self.parse_create_user(),
                         Keyword::FUNCTION => self.parse_create_udf(),
@@ -554,14 +771,48 @@ impl<'a> DfParser<'a> {
     fn parse_alter(&mut self) -> Result<DfStatement, ParserError> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
+                Keyword::TABLE => self.parse_alter_table(),
                 Keyword::USER => self.parse_alter_user(),
                 Keyword::FUNCTION => self.parse_alter_udf(),
-                _ => self.expected("keyword USER or FUNCTION", Token::Word(w)),
+                _ => self.expected("keyword TABLE, USER or FUNCTION", Token::Word(w)),
             },
             unexpected => self.expected("alter statement", unexpected),
         }
     }
 
+    // syntax: "ALTER TABLE t ADD [COLUMN] <column_def> | DROP [COLUMN] c | RENAME COLUMN c TO d"
+    fn parse_alter_table(&mut self) -> Result<DfStatement, ParserError> {
+        let name = self.parser.parse_object_name()?;
+        let operation = match self.parser.next_token() {
+            Token::Word(w) => match w.keyword {
+                Keyword::ADD => {
+                    self.parser.parse_keyword(Keyword::COLUMN);
+                    let column_def = self.parse_column_def()?;
+                    DfAlterTableOperation::AddColumn { column_def }
+                }
+                Keyword::DROP => {
+                    self.parser.parse_keyword(Keyword::COLUMN);
+                    let column_name = self.parser.parse_identifier()?;
+                    DfAlterTableOperation::DropColumn { column_name }
+                }
+                Keyword::RENAME => {
+                    self.parser.expect_keyword(Keyword::COLUMN)?;
+                    let old_column_name = self.parser.parse_identifier()?;
+                    self.parser.expect_keyword(Keyword::TO)?;
+                    let new_column_name = self.parser.parse_identifier()?;
+                    DfAlterTableOperation::RenameColumn {
+                        old_column_name,
+                        new_column_name,
+                    }
+                }
+                _ => return self.expected("one of ADD, DROP, RENAME", Token::Word(w)),
+            },
+            unexpected => return self.expected("one of ADD, DROP, RENAME", unexpected),
+        };
+
+        Ok(DfStatement::AlterTable(DfAlterTable { name, operation }))
+    }
+
     fn parse_create_database(&mut self) -> Result<DfStatement, ParserError> {
         let if_not_exists =
             self.parser
@@ -603,6 +854,7 @@ impl<'a> DfParser<'a> {
                     match w.keyword {
                         Keyword::DATABASE => self.parse_drop_database(),
                         Keyword::TABLE => self.parse_drop_table(),
+                        Keyword::VIEW => self.parse_drop_view(),
                         Keyword::USER => self.parse_drop_user(),
                         Keyword::FUNCTION => self.parse_drop_udf(),
                         _ => self.expected("drop statement", Token::Word(w)),
@@ -639,6 +891,47 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::DropTable(drop))
     }
 
+    /// Rename table.
+    /// syntax: "RENAME TABLE [IF EXISTS] [db.]t TO [db.]t2"
+    fn parse_rename(&mut self) -> Result<DfStatement, ParserError> {
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::TO)?;
+        let new_name = self.parser.parse_object_name()?;
+
+        let rename = DfRenameTable {
+            if_exists,
+            name,
+            new_name,
+        };
+
+        Ok(DfStatement::RenameTable(rename))
+    }
+
+    /// Undrop table, restores the most recently dropped table of the name.
+    /// syntax: "UNDROP TABLE [db.]t"
+    fn parse_undrop(&mut self) -> Result<DfStatement, ParserError> {
+        self.expect_token("UNDROP")?;
+        self.parser.expect_keyword(Keyword::TABLE)?;
+        let name = self.parser.parse_object_name()?;
+
+        Ok(DfStatement::UndropTable(DfUndropTable { name }))
+    }
+
+    /// Drop view.
+    fn parse_drop_view(&mut self) -> Result<DfStatement, ParserError> {
+        let if_exists = self.parser.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let view_name = self.parser.parse_object_name()?;
+
+        let drop = DfDropView {
+            if_exists,
+            name: view_name,
+        };
+
+        Ok(DfStatement::DropView(drop))
+    }
+
     // Parse 'sudo ...'.
     fn parse_sudo_command(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.next_token();
@@ -1039,6 +1332,8 @@ impl<'a> DfParser<'a> {
         }
 
         let engine = self.parse_table_engine()?;
+        let cluster_keys = self.parse_cluster_keys()?;
+        let ttl = self.parse_ttl()?;
 
         // parse table options: https://dev.mysql.com/doc/refman/8.0/en/create-table.html
         let options = self.parse_options()?;
@@ -1052,7 +1347,7 @@ impl<'a> DfParser<'a> {
             }
             if has_query || keyword == Keyword::SELECT {
                 let native = self.parser.parse_query()?;
-                query = Some(Box::new(DfQueryStatement::try_from(native)?))
+                query = Some(Box::new(self.query_statement(native)?))
             }
         }
 
@@ -1061,6 +1356,8 @@ impl<'a> DfParser<'a> {
             name: table_name,
             columns,
             engine,
+            cluster_keys,
+            ttl,
             options,
             like: table_like,
             query,
@@ -1069,6 +1366,24 @@ impl<'a> DfParser<'a> {
         Ok(DfStatement::CreateTable(create))
     }
 
+    // syntax: "CREATE VIEW [IF NOT EXISTS] v AS SELECT ..."
+    fn parse_create_view(&mut self) -> Result<DfStatement, ParserError> {
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let view_name = self.parser.parse_object_name()?;
+        self.parser.expect_keyword(Keyword::AS)?;
+        let query = self.parser.parse_query()?;
+
+        let create = DfCreateView {
+            if_not_exists,
+            name: view_name,
+            query: Box::new(query),
+        };
+
+        Ok(DfStatement::CreateView(create))
+    }
+
     fn parse_database_engine(&mut self) -> Result<(String, HashMap<String, String>), ParserError> {
         // TODO make ENGINE as a keyword
         if !self.consume_token("ENGINE") {
@@ -1098,6 +1413,34 @@ impl<'a> DfParser<'a> {
         Ok(self.parser.next_token().to_string())
     }
 
+    // syntax: "CLUSTER BY (expr, ...)"
+    fn parse_cluster_keys(&mut self) -> Result<Vec<Expr>, ParserError> {
+        if !self.consume_token("CLUSTER") {
+            return Ok(vec![]);
+        }
+
+        self.parser.expect_keyword(Keyword::BY)?;
+        self.parser.expect_token(&Token::LParen)?;
+        let mut cluster_keys = vec![];
+        loop {
+            cluster_keys.push(self.parser.parse_expr()?);
+            if !self.parser.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        self.parser.expect_token(&Token::RParen)?;
+        Ok(cluster_keys)
+    }
+
+    // syntax: "TTL expr", e.g. "TTL d + INTERVAL '30' DAY"
+    fn parse_ttl(&mut self) -> Result<Option<Expr>, ParserError> {
+        if !self.consume_token("TTL") {
+            return Ok(None);
+        }
+
+        Ok(Some(self.parser.parse_expr()?))
+    }
+
     fn parse_show_create(&mut self) -> Result<DfStatement, ParserError> {
         match self.parser.next_token() {
             Token::Word(w) => match w.keyword {
@@ -1286,6 +1629,9 @@ impl<'a> DfParser<'a> {
     // from @my_ext_stage/tutorials/dataloading/contacts1.csv format CSV [options];
     fn parse_copy(&mut self) -> Result<DfStatement, ParserError> {
         self.parser.expect_keyword(Keyword::INTO)?;
+        if let Token::SingleQuotedString(_) = self.parser.peek_token() {
+            return self.parse_copy_into_stage();
+        }
         let name = self.parser.parse_object_name()?;
         let columns = self
             .parser
@@ -1296,17 +1642,90 @@ impl<'a> DfParser<'a> {
         self.parser.expect_keyword(Keyword::FORMAT)?;
         let format = self.parser.next_token().to_string();
 
-        let options = self.parse_options()?;
+        let mut options = self.parse_options()?;
+        // PATTERN and ON_ERROR are written like the format options, in any case
+        let mut take_option = |key: &str| {
+            let name = options
+                .keys()
+                .find(|k| k.eq_ignore_ascii_case(key))
+                .cloned();
+            name.and_then(|name| options.remove(&name))
+        };
+        let pattern = take_option("pattern");
+        let on_error = match take_option("on_error") {
+            None => CopyOnError::default(),
+            Some(on_error) => CopyOnError::from_str(&on_error)
+                .map_err(|e| ParserError::ParserError(e.message()))?,
+        };
 
         Ok(DfStatement::Copy(DfCopy {
             name,
             columns,
             location,
+            pattern,
+            on_error,
             format,
             options,
         }))
     }
 
+    // syntax: "COPY INTO '@stage/path' FROM (SELECT ...) [FILE_FORMAT = (...)] [MAX_FILE_SIZE = n]"
+    fn parse_copy_into_stage(&mut self) -> Result<DfStatement, ParserError> {
+        let location = self.parser.parse_literal_string()?;
+        self.parser.expect_keyword(Keyword::FROM)?;
+        self.parser.expect_token(&Token::LParen)?;
+        let native_query = self.parser.parse_query()?;
+        self.parser.expect_token(&Token::RParen)?;
+        let file_format = self.parse_stage_file_format()?;
+        let options = self.parse_options()?;
+
+        Ok(DfStatement::CopyIntoStage(DfCopyIntoStage {
+            location,
+            query: Box::new(DfQueryStatement::try_from(native_query)?),
+            file_format,
+            options,
+        }))
+    }
+
+    // syntax: "LIST @stage[/path] [PATTERN = '<regex>']", the location may be quoted as in COPY
+    fn parse_list_stage(&mut self) -> Result<DfStatement, ParserError> {
+        self.expect_token("LIST")?;
+        let mut location = match self.parser.next_token() {
+            Token::SingleQuotedString(location) => location,
+            Token::Word(w) if w.value.starts_with('@') => {
+                // the unquoted path is tokenized by '/' and '.', it ends at a whitespace
+                let mut location = w.value;
+                loop {
+                    match self.parser.next_token_no_skip() {
+                        Some(Token::Whitespace(_)) => break,
+                        Some(Token::SemiColon) | Some(Token::EOF) | None => {
+                            self.parser.prev_token();
+                            break;
+                        }
+                        Some(token) => location.push_str(&token.to_string()),
+                    }
+                }
+                location
+            }
+            unexpected => return self.expected("stage location like @stage/path", unexpected),
+        };
+        if !location.contains('/') {
+            location.push('/');
+        }
+
+        let mut options = self.parse_options()?;
+        let pattern = options
+            .keys()
+            .find(|k| k.eq_ignore_ascii_case("pattern"))
+            .cloned()
+            .and_then(|name| options.remove(&name));
+        if let Some(name) = options.keys().next() {
+            return parser_err!(format!("Unknown option of LIST: {}", name));
+        }
+
+        Ok(DfStatement::ListStage(DfListStage { location, pattern }))
+    }
+
     fn parse_options(&mut self) -> Result<HashMap<String, String>, ParserError> {
         let mut options = HashMap::new();
         loop {
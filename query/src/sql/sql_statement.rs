@@ -20,7 +20,10 @@ use nom::character::complete::multispace1;
 use nom::IResult;
 
 use super::statements::DfCopy;
+use super::statements::DfCopyIntoStage;
 use super::statements::DfDescribeStage;
+use super::statements::DfListStage;
+use crate::sql::statements::DfAlterTable;
 use crate::sql::statements::DfAlterUDF;
 use crate::sql::statements::DfAlterUser;
 use crate::sql::statements::DfCreateDatabase;
@@ -28,18 +31,22 @@ use crate::sql::statements::DfCreateStage;
 use crate::sql::statements::DfCreateTable;
 use crate::sql::statements::DfCreateUDF;
 use crate::sql::statements::DfCreateUser;
+use crate::sql::statements::DfCreateView;
+use crate::sql::statements::DfDeleteStatement;
 use crate::sql::statements::DfDescribeTable;
 use crate::sql::statements::DfDropDatabase;
 use crate::sql::statements::DfDropStage;
 use crate::sql::statements::DfDropTable;
 use crate::sql::statements::DfDropUDF;
 use crate::sql::statements::DfDropUser;
+use crate::sql::statements::DfDropView;
 use crate::sql::statements::DfExplain;
 use crate::sql::statements::DfGrantStatement;
 use crate::sql::statements::DfInsertStatement;
 use crate::sql::statements::DfKillStatement;
 use crate::sql::statements::DfOptimizeTable;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfRenameTable;
 use crate::sql::statements::DfRevokeStatement;
 use crate::sql::statements::DfSetVariable;
 use crate::sql::statements::DfShowCreateDatabase;
@@ -54,6 +61,8 @@ use crate::sql::statements::DfShowTables;
 use crate::sql::statements::DfShowUDF;
 use crate::sql::statements::DfShowUsers;
 use crate::sql::statements::DfTruncateTable;
+use crate::sql::statements::DfUndropTable;
+use crate::sql::statements::DfUpdateStatement;
 use crate::sql::statements::DfUseDatabase;
 use crate::sql::statements::DfUseTenant;
 
@@ -78,9 +87,17 @@ pub enum DfStatement {
     CreateTable(DfCreateTable),
     DescribeTable(DfDescribeTable),
     DescribeStage(DfDescribeStage),
+    ListStage(DfListStage),
     DropTable(DfDropTable),
+    RenameTable(DfRenameTable),
+    UndropTable(DfUndropTable),
     TruncateTable(DfTruncateTable),
     OptimizeTable(DfOptimizeTable),
+    AlterTable(DfAlterTable),
+
+    // Views.
+    CreateView(DfCreateView),
+    DropView(DfDropView),
 
     // Settings.
     ShowSettings(DfShowSettings),
@@ -103,6 +120,12 @@ pub enum DfStatement {
     // Insert
     InsertQuery(DfInsertStatement),
 
+    // Delete
+    Delete(DfDeleteStatement),
+
+    // Update
+    Update(DfUpdateStatement),
+
     // User
     CreateUser(DfCreateUser),
     AlterUser(DfAlterUser),
@@ -111,6 +134,7 @@ pub enum DfStatement {
 
     // Copy
     Copy(DfCopy),
+    CopyIntoStage(DfCopyIntoStage),
 
     // Grant
     GrantPrivilege(DfGrantStatement),
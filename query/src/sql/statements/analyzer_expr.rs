@@ -328,9 +328,11 @@ impl ExpressionAnalyzer {
             ));
         }
 
-        let expression = args.remove(0);
-        let low_expression = args.remove(0);
-        let high_expression = args.remove(0);
+        // the operands are on the top of the stack
+        let mut operands = args.split_off(args.len() - 3);
+        let high_expression = operands.remove(2);
+        let low_expression = operands.remove(1);
+        let expression = operands.remove(0);
 
         match negated {
             false => args.push(
@@ -21,6 +21,7 @@ use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_planners::ExplainType;
 use common_planners::Expression;
+use common_planners::JoinType;
 use common_planners::PlanNode;
 use common_planners::ReadDataSourcePlan;
 
@@ -39,6 +40,19 @@ pub enum QueryRelation {
     None,
     FromTable(Box<ReadDataSourcePlan>),
     Nested(Box<QueryAnalyzeState>),
+    // Rename the columns of the relation, e.g. the ambiguous columns in join.
+    Projected(Box<QueryRelation>, Vec<Expression>),
+    Join(Box<JoinRelation>),
+}
+
+#[derive(Clone)]
+pub struct JoinRelation {
+    pub join_type: JoinType,
+    pub left: QueryRelation,
+    pub right: QueryRelation,
+    pub left_keys: Vec<Expression>,
+    pub right_keys: Vec<Expression>,
+    pub filter: Option<Expression>,
 }
 
 #[derive(Clone)]
@@ -155,9 +169,15 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::CreateTable(v) => v.analyze(ctx).await,
             DfStatement::DescribeTable(v) => v.analyze(ctx).await,
             DfStatement::DescribeStage(v) => v.analyze(ctx).await,
+            DfStatement::ListStage(v) => v.analyze(ctx).await,
             DfStatement::DropTable(v) => v.analyze(ctx).await,
+            DfStatement::RenameTable(v) => v.analyze(ctx).await,
+            DfStatement::UndropTable(v) => v.analyze(ctx).await,
+            DfStatement::CreateView(v) => v.analyze(ctx).await,
+            DfStatement::DropView(v) => v.analyze(ctx).await,
             DfStatement::TruncateTable(v) => v.analyze(ctx).await,
             DfStatement::OptimizeTable(v) => v.analyze(ctx).await,
+            DfStatement::AlterTable(v) => v.analyze(ctx).await,
             DfStatement::UseDatabase(v) => v.analyze(ctx).await,
             DfStatement::UseTenant(v) => v.analyze(ctx).await,
             DfStatement::ShowCreateTable(v) => v.analyze(ctx).await,
@@ -168,6 +188,8 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::ShowGrants(v) => v.analyze(ctx).await,
             DfStatement::KillStatement(v) => v.analyze(ctx).await,
             DfStatement::InsertQuery(v) => v.analyze(ctx).await,
+            DfStatement::Delete(v) => v.analyze(ctx).await,
+            DfStatement::Update(v) => v.analyze(ctx).await,
             DfStatement::SetVariable(v) => v.analyze(ctx).await,
             DfStatement::CreateUser(v) => v.analyze(ctx).await,
             DfStatement::AlterUser(v) => v.analyze(ctx).await,
@@ -176,6 +198,7 @@ impl AnalyzableStatement for DfStatement {
             DfStatement::RevokePrivilege(v) => v.analyze(ctx).await,
             DfStatement::DropUser(v) => v.analyze(ctx).await,
             DfStatement::Copy(v) => v.analyze(ctx).await,
+            DfStatement::CopyIntoStage(v) => v.analyze(ctx).await,
             DfStatement::CreateStage(v) => v.analyze(ctx).await,
             DfStatement::ShowFunctions(v) => v.analyze(ctx).await,
             DfStatement::DropStage(v) => v.analyze(ctx).await,
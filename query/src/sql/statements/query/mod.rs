@@ -25,6 +25,8 @@ pub use query_collect_push_downs::QueryCollectPushDowns;
 pub use query_normalizer::QueryNormalizer;
 pub use query_qualified_rewriter::QualifiedRewriter;
 pub use query_schema_joined::JoinedColumnDesc;
+pub use query_schema_joined::JoinedRelation;
+pub use query_schema_joined::JoinedRelationJoin;
 pub use query_schema_joined::JoinedSchema;
 pub use query_schema_joined::JoinedTableDesc;
 pub use query_schema_joined_analyzer::JoinedSchemaAnalyzer;
@@ -17,8 +17,10 @@ use std::collections::HashSet;
 use common_exception::Result;
 use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::RewriteHelper;
 
 use crate::sql::statements::query::query_ast_ir::QueryASTIRVisitor;
+use crate::sql::statements::query::JoinedRelation;
 use crate::sql::statements::query::JoinedSchema;
 use crate::sql::statements::query::JoinedTableDesc;
 use crate::sql::statements::QueryASTIR;
@@ -55,17 +57,43 @@ impl QueryCollectPushDowns {
             require_filters: vec![],
         };
         QueryCollectPushDowns::visit(ir, &mut push_downs_data)?;
+        push_downs_data.collect_join_columns(schema.get_relation())?;
         push_downs_data.collect_push_downs(schema)
     }
 
+    // The columns of the join conditions are required too.
+    fn collect_join_columns(&mut self, relation: &JoinedRelation) -> Result<()> {
+        if let JoinedRelation::Join(join) = relation {
+            self.collect_join_columns(&join.left)?;
+            self.collect_join_columns(&join.right)?;
+
+            let keys = join.left_keys.iter().chain(join.right_keys.iter());
+            for expr in keys.chain(join.filter.iter()) {
+                for column in RewriteHelper::expression_plan_columns(expr)? {
+                    if let Expression::Column(name) = column {
+                        self.require_columns.insert(name);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn collect_push_downs(mut self, schema: &mut JoinedSchema) -> Result<()> {
+        // The filters can only be pushed down to the table which is not joined.
+        let filters = match schema.get_tables_desc().len() {
+            1 => self.require_filters.clone(),
+            _ => vec![],
+        };
+
         for index in 0..schema.get_tables_desc().len() {
             let table_desc = &schema.get_tables_desc()[index];
             let projection = self.collect_table_require_columns(table_desc);
 
             schema.set_table_push_downs(index, Extras {
                 projection: Some(projection),
-                filters: self.require_filters.clone(),
+                filters: filters.clone(),
                 limit: None,
                 order_by: vec![],
             });
@@ -77,7 +105,11 @@ impl QueryCollectPushDowns {
     fn collect_table_require_columns(&mut self, table_desc: &JoinedTableDesc) -> Vec<usize> {
         match self.require_columns.is_empty() {
             true => Self::collect_table_smallest_column(table_desc),
-            false => self.collect_table_projection_columns(table_desc),
+            false => match self.collect_table_projection_columns(table_desc) {
+                // SELECT a.number FROM numbers(10) AS a, numbers(10) AS b
+                columns if columns.is_empty() => Self::collect_table_smallest_column(table_desc),
+                columns => columns,
+            },
         }
     }
 
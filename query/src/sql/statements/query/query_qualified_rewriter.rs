@@ -82,6 +82,18 @@ impl QualifiedRewriter {
         QualifiedRewriter::visit(ir, &mut rewriter)
     }
 
+    pub fn rewrite_expr(
+        schema: &JoinedSchema,
+        ctx: Arc<QueryContext>,
+        expr: &mut Expression,
+    ) -> Result<()> {
+        let mut rewriter = QualifiedRewriter {
+            tables_schema: schema.clone(),
+            ctx,
+        };
+        QualifiedRewriter::visit_recursive_expr(expr, &mut rewriter)
+    }
+
     fn expand_wildcard(&self, columns_expression: &mut Vec<Expression>) {
         for table_desc in self.tables_schema.get_tables_desc() {
             for column_desc in table_desc.get_columns_desc() {
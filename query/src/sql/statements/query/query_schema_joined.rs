@@ -24,7 +24,9 @@ use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::JoinType;
 
 use crate::sql::statements::QueryAnalyzeState;
 use crate::storages::Table;
@@ -35,6 +37,8 @@ pub struct JoinedSchema {
     short_name_columns: HashMap<String, JoinedColumnDesc>,
     // Reference by full name, short name may be ambiguous.
     tables_long_name_columns: Vec<JoinedTableDesc>,
+    // How the tables are joined.
+    relation: JoinedRelation,
 }
 
 impl JoinedSchema {
@@ -42,6 +46,7 @@ impl JoinedSchema {
         JoinedSchema {
             short_name_columns: HashMap::new(),
             tables_long_name_columns: Vec::new(),
+            relation: JoinedRelation::Table(0),
         }
     }
 
@@ -78,6 +83,7 @@ impl JoinedSchema {
         Ok(JoinedSchema {
             short_name_columns,
             tables_long_name_columns: vec![table_desc],
+            relation: JoinedRelation::Table(0),
         })
     }
 
@@ -96,8 +102,65 @@ impl JoinedSchema {
         }
     }
 
-    pub fn take_tables_desc(self) -> Vec<JoinedTableDesc> {
-        self.tables_long_name_columns
+    pub fn take_tables_desc(self) -> (Vec<JoinedTableDesc>, JoinedRelation) {
+        (self.tables_long_name_columns, self.relation)
+    }
+
+    pub fn get_relation(&self) -> &JoinedRelation {
+        &self.relation
+    }
+
+    /// The name of the column in the joined schema, ambiguous column is named by the full name.
+    pub fn column_name(table_desc: &JoinedTableDesc, column_desc: &JoinedColumnDesc) -> String {
+        match column_desc.is_ambiguity {
+            true => format!(
+                "{}.{}",
+                table_desc.get_name_parts().join("."),
+                column_desc.short_name
+            ),
+            false => column_desc.short_name.clone(),
+        }
+    }
+
+    /// Find the position of the table which the column(named in the joined schema) belongs to.
+    pub fn column_table_position(&self, column_name: &str) -> Option<usize> {
+        for (pos, table_desc) in self.tables_long_name_columns.iter().enumerate() {
+            for column_desc in table_desc.get_columns_desc() {
+                if Self::column_name(table_desc, column_desc) == column_name {
+                    return Some(pos);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Get the join of the FROM clause by the post-order(the order in SQL text).
+    pub fn get_join_mut(&mut self, index: usize) -> Option<&mut JoinedRelationJoin> {
+        fn find_join(
+            relation: &mut JoinedRelation,
+            index: usize,
+        ) -> Option<&mut JoinedRelationJoin> {
+            match relation {
+                JoinedRelation::Table(_) => None,
+                JoinedRelation::Join(join) => {
+                    let left_joins = join.left.joins_count();
+                    let right_joins = join.right.joins_count();
+
+                    if index < left_joins {
+                        find_join(&mut join.left, index)
+                    } else if index < left_joins + right_joins {
+                        find_join(&mut join.right, index - left_joins)
+                    } else if index == left_joins + right_joins {
+                        Some(join.as_mut())
+                    } else {
+                        None
+                    }
+                }
+            }
+        }
+
+        find_join(&mut self.relation, index)
     }
 
     pub fn to_data_schema(&self) -> DataSchemaRef {
@@ -129,8 +192,127 @@ impl JoinedSchema {
         Arc::new(DataSchema::new(fields))
     }
 
-    pub fn join(&self, _joined_schema: &JoinedSchema) -> Result<Arc<JoinedSchema>> {
-        unimplemented!("")
+    pub fn join(self, right: JoinedSchema, join_type: JoinType) -> Result<JoinedSchema> {
+        let right_offset = self.tables_long_name_columns.len();
+        let mut tables_desc = self.tables_long_name_columns;
+
+        for mut table_desc in right.tables_long_name_columns {
+            let name_parts = table_desc.get_name_parts();
+            if tables_desc.iter().any(|v| v.get_name_parts() == name_parts) {
+                return Err(match name_parts.is_empty() {
+                    true => ErrorCode::SyntaxException("Subquery in JOIN must have an alias"),
+                    false => ErrorCode::SyntaxException(format!(
+                        "Table {} is duplicated in JOIN, please use alias",
+                        name_parts.join(".")
+                    )),
+                });
+            }
+
+            if join_type.preserve_left() {
+                table_desc.set_columns_nullable();
+            }
+
+            tables_desc.push(table_desc);
+        }
+
+        if join_type.preserve_right() {
+            for table_desc in &mut tables_desc[..right_offset] {
+                table_desc.set_columns_nullable();
+            }
+        }
+
+        let relation = JoinedRelation::Join(Box::new(JoinedRelationJoin {
+            join_type,
+            left: self.relation,
+            right: right.relation.shift_tables(right_offset),
+            left_keys: vec![],
+            right_keys: vec![],
+            filter: None,
+        }));
+
+        Self::from_tables_desc(tables_desc, relation)
+    }
+
+    // Columns with the same short name in different tables are ambiguous.
+    fn from_tables_desc(
+        mut tables_desc: Vec<JoinedTableDesc>,
+        relation: JoinedRelation,
+    ) -> Result<JoinedSchema> {
+        let mut short_names_count = HashMap::new();
+        for table_desc in &tables_desc {
+            for column_desc in table_desc.get_columns_desc() {
+                *short_names_count
+                    .entry(column_desc.short_name.clone())
+                    .or_insert(0) += 1;
+            }
+        }
+
+        let mut short_name_columns = HashMap::new();
+        for table_desc in &mut tables_desc {
+            for column_desc in table_desc.get_columns_desc_mut() {
+                column_desc.is_ambiguity = short_names_count[&column_desc.short_name] > 1;
+
+                if !column_desc.is_ambiguity {
+                    short_name_columns.insert(column_desc.short_name.clone(), column_desc.clone());
+                }
+            }
+        }
+
+        Ok(JoinedSchema {
+            short_name_columns,
+            tables_long_name_columns: tables_desc,
+            relation,
+        })
+    }
+}
+
+/// The join tree of the FROM clause, the leaves refer to the tables by position.
+#[derive(Clone)]
+pub enum JoinedRelation {
+    Table(usize),
+    Join(Box<JoinedRelationJoin>),
+}
+
+#[derive(Clone)]
+pub struct JoinedRelationJoin {
+    pub join_type: JoinType,
+    pub left: JoinedRelation,
+    pub right: JoinedRelation,
+    pub left_keys: Vec<Expression>,
+    pub right_keys: Vec<Expression>,
+    // The non-equi conditions of the inner join, applied after the join.
+    pub filter: Option<Expression>,
+}
+
+impl JoinedRelation {
+    fn shift_tables(self, offset: usize) -> JoinedRelation {
+        match self {
+            JoinedRelation::Table(pos) => JoinedRelation::Table(pos + offset),
+            JoinedRelation::Join(mut join) => {
+                join.left = join.left.shift_tables(offset);
+                join.right = join.right.shift_tables(offset);
+                JoinedRelation::Join(join)
+            }
+        }
+    }
+
+    pub fn joins_count(&self) -> usize {
+        match self {
+            JoinedRelation::Table(_) => 0,
+            JoinedRelation::Join(join) => join.left.joins_count() + join.right.joins_count() + 1,
+        }
+    }
+
+    /// The positions of the tables under the relation.
+    pub fn tables_position(&self) -> Vec<usize> {
+        match self {
+            JoinedRelation::Table(pos) => vec![*pos],
+            JoinedRelation::Join(join) => {
+                let mut positions = join.left.tables_position();
+                positions.extend(join.right.tables_position());
+                positions
+            }
+        }
     }
 }
 
@@ -226,6 +408,20 @@ impl JoinedTableDesc {
             JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
         }
     }
+
+    fn get_columns_desc_mut(&mut self) -> &mut [JoinedColumnDesc] {
+        match self {
+            JoinedTableDesc::Table { columns_desc, .. } => columns_desc,
+            JoinedTableDesc::Subquery { columns_desc, .. } => columns_desc,
+        }
+    }
+
+    // The columns may be filled with NULL by the outer join.
+    fn set_columns_nullable(&mut self) {
+        for column_desc in self.get_columns_desc_mut() {
+            column_desc.nullable = true;
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -12,12 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
+use common_planners::JoinType;
+use common_planners::RewriteHelper;
 use sqlparser::ast::FunctionArg;
 use sqlparser::ast::Ident;
+use sqlparser::ast::JoinConstraint;
 use sqlparser::ast::JoinOperator;
 use sqlparser::ast::ObjectName;
 use sqlparser::ast::Query;
@@ -29,9 +37,19 @@ use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
 use crate::sql::statements::query::query_schema_joined::JoinedSchema;
+use crate::sql::statements::query::QualifiedRewriter;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfNavigationPoint;
 use crate::sql::statements::DfQueryStatement;
+use crate::sql::statements::DfTableNavigation;
+use crate::sql::DfParser;
+use crate::sql::DfStatement;
+use crate::storages::view::ViewTable;
+use crate::storages::view::MAX_VIEW_NESTING_DEPTH;
+use crate::storages::view::VIEW_ENGINE;
+use crate::storages::NavigationPoint;
+use crate::storages::Table;
 
 pub struct JoinedSchemaAnalyzer {
     ctx: Arc<QueryContext>,
@@ -44,13 +62,26 @@ impl JoinedSchemaAnalyzer {
 
     pub async fn analyze(&self, query: &DfQueryStatement) -> Result<JoinedSchema> {
         let mut analyzed_tables = Vec::new();
+        let mut join_constraints = Vec::new();
 
         // Build RPN for tables. because async function unsupported recursion
-        let rpn = RelationRPNBuilder::build(&query.from)?;
+        let rpn = RelationRPNBuilder::build(&query.from, &query.navigations)?;
         for rpn_item in &rpn {
             match rpn_item {
-                RelationRPNItem::Join(_) => {
-                    return Err(ErrorCode::UnImplement("Unimplemented SELECT JOIN yet."));
+                RelationRPNItem::Join(join_operator) => {
+                    let (join_type, constraint) = Self::join_operator(join_operator)?;
+
+                    match (analyzed_tables.pop(), analyzed_tables.pop()) {
+                        (Some(right), Some(left)) => {
+                            analyzed_tables.push(left.join(right, join_type)?);
+                            join_constraints.push(constraint);
+                        }
+                        _ => {
+                            return Err(ErrorCode::LogicalError(
+                                "Logical error: this is relation rpn bug.",
+                            ));
+                        }
+                    }
                 }
                 RelationRPNItem::Table(v) => {
                     let schema = self.table(v);
@@ -73,7 +104,80 @@ impl JoinedSchemaAnalyzer {
             ));
         }
 
-        Ok(analyzed_tables.remove(0))
+        // The join conditions are analyzed with the final schema, because the ambiguity
+        // of the columns is decided by all the tables in the FROM clause.
+        let mut joined_schema = analyzed_tables.remove(0);
+        for (index, constraint) in join_constraints.iter().enumerate() {
+            self.join_condition(&mut joined_schema, index, constraint)
+                .await?;
+        }
+
+        Ok(joined_schema)
+    }
+
+    fn join_operator(join_operator: &JoinOperator) -> Result<(JoinType, Option<JoinConstraint>)> {
+        match join_operator {
+            JoinOperator::Inner(constraint) => Ok((JoinType::Inner, Some(constraint.clone()))),
+            JoinOperator::LeftOuter(constraint) => Ok((JoinType::Left, Some(constraint.clone()))),
+            JoinOperator::RightOuter(constraint) => Ok((JoinType::Right, Some(constraint.clone()))),
+            JoinOperator::FullOuter(constraint) => Ok((JoinType::Full, Some(constraint.clone()))),
+            JoinOperator::CrossJoin => Ok((JoinType::Cross, None)),
+            _ => Err(ErrorCode::UnImplement(format!(
+                "Unsupported join operator: {:?}",
+                join_operator
+            ))),
+        }
+    }
+
+    async fn join_condition(
+        &self,
+        schema: &mut JoinedSchema,
+        index: usize,
+        constraint: &Option<JoinConstraint>,
+    ) -> Result<()> {
+        let (join_type, left_tables, right_tables) = match schema.get_join_mut(index) {
+            None => {
+                return Err(ErrorCode::LogicalError(
+                    "Logical error: cannot find the join, it's a bug.",
+                ))
+            }
+            Some(join) => (
+                join.join_type,
+                join.left.tables_position(),
+                join.right.tables_position(),
+            ),
+        };
+
+        let condition = match constraint {
+            None | Some(JoinConstraint::None) => JoinCondition::default(),
+            Some(JoinConstraint::On(expr)) => {
+                let analyzer = ExpressionAnalyzer::create(self.ctx.clone());
+                let mut expr = analyzer.analyze(expr).await?;
+                QualifiedRewriter::rewrite_expr(schema, self.ctx.clone(), &mut expr)?;
+                JoinCondition::from_on(schema, expr, &left_tables, &right_tables)?
+            }
+            Some(JoinConstraint::Using(idents)) => {
+                JoinCondition::from_using(schema, idents, &left_tables, &right_tables)?
+            }
+            Some(JoinConstraint::Natural) => {
+                return Err(ErrorCode::UnImplement("Unsupported NATURAL JOIN yet."));
+            }
+        };
+
+        if condition.filter.is_some() && join_type != JoinType::Inner {
+            return Err(ErrorCode::UnImplement(format!(
+                "Unsupported non-equi condition in {} JOIN yet.",
+                join_type
+            )));
+        }
+
+        if let Some(join) = schema.get_join_mut(index) {
+            join.left_keys = condition.left_keys;
+            join.right_keys = condition.right_keys;
+            join.filter = condition.filter;
+        }
+
+        Ok(())
     }
 
     async fn subquery(&self, v: &DerivedRPNItem) -> Result<JoinedSchema> {
@@ -96,17 +200,73 @@ impl JoinedSchemaAnalyzer {
     async fn table(&self, item: &TableRPNItem) -> Result<JoinedSchema> {
         // TODO(Winter): await query_context.get_table
         let (database, table) = self.resolve_table(&item.name)?;
-        let read_table = self.ctx.get_table(&database, &table).await?;
-
-        match &item.alias {
-            None => {
-                let name_prefix = vec![database, table];
-                JoinedSchema::from_table(read_table, name_prefix)
-            }
-            Some(table_alias) => {
-                let name_prefix = vec![table_alias.name.value.clone()];
-                JoinedSchema::from_table(read_table, name_prefix)
+        let mut read_table = self.ctx.get_table(&database, &table).await?;
+
+        let name_prefix = match &item.alias {
+            None => vec![database.clone(), table],
+            Some(table_alias) => vec![table_alias.name.value.clone()],
+        };
+
+        if read_table.engine() == VIEW_ENGINE {
+            if item.navigation.is_some() {
+                return Err(ErrorCode::SyntaxException(
+                    "Time travel is unsupported for view",
+                ));
             }
+            return self.view(read_table, &database, name_prefix).await;
+        }
+
+        if let Some(point) = &item.navigation {
+            read_table = read_table.navigate_to(self.ctx.clone(), point).await?;
+        }
+
+        JoinedSchema::from_table(read_table, name_prefix)
+    }
+
+    // The view is expanded as a subquery.
+    async fn view(
+        &self,
+        table: Arc<dyn Table>,
+        database: &str,
+        name_prefix: Vec<String>,
+    ) -> Result<JoinedSchema> {
+        let view = table
+            .as_any()
+            .downcast_ref::<ViewTable>()
+            .ok_or_else(|| ErrorCode::LogicalError("Logical error: the table is not a view"))?;
+
+        // The views referring to each other(e.g. renamed after created) are never expanded.
+        let view_name = format!("{}.{}", database, view.name());
+        let expanding_views = self.ctx.get_expanding_views();
+        if expanding_views.contains(&view_name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Cyclic reference of views: {} -> {}",
+                expanding_views.join(" -> "),
+                view_name
+            )));
+        }
+        if expanding_views.len() >= MAX_VIEW_NESTING_DEPTH {
+            return Err(ErrorCode::SemanticError(format!(
+                "The views are nested deeper than {}: {}",
+                MAX_VIEW_NESTING_DEPTH, view_name
+            )));
+        }
+        let ctx = QueryContext::create_for_view(self.ctx.clone(), view_name);
+
+        let (mut statements, _) = DfParser::parse_sql(view.query()?)?;
+        match (statements.pop(), statements.is_empty()) {
+            (Some(DfStatement::Query(query)), true) => match query.analyze(ctx).await? {
+                AnalyzedResult::SelectQuery(state) => {
+                    JoinedSchema::from_subquery(state, name_prefix)
+                }
+                _ => Err(ErrorCode::LogicalError(
+                    "Logical error, view analyzed data must be SelectQuery, it's a bug.",
+                )),
+            },
+            _ => Err(ErrorCode::LogicalError(format!(
+                "Logical error: the query of view {} is not a SELECT statement",
+                view.name()
+            ))),
         }
     }
 
@@ -151,9 +311,149 @@ impl JoinedSchemaAnalyzer {
     }
 }
 
+#[derive(Default)]
+struct JoinCondition {
+    left_keys: Vec<Expression>,
+    right_keys: Vec<Expression>,
+    filter: Option<Expression>,
+}
+
+impl JoinCondition {
+    fn from_on(
+        schema: &JoinedSchema,
+        expr: Expression,
+        left_tables: &[usize],
+        right_tables: &[usize],
+    ) -> Result<JoinCondition> {
+        let mut conjunctions = vec![];
+        Self::split_conjunctions(expr, &mut conjunctions);
+
+        let mut condition = JoinCondition::default();
+        for conjunction in conjunctions {
+            let mut equi_keys = None;
+            if let Expression::BinaryExpression { op, left, right } = &conjunction {
+                if op == "=" {
+                    let left_side = Self::tables_of_expr(schema, left)?;
+                    let right_side = Self::tables_of_expr(schema, right)?;
+
+                    if Self::is_subset(&left_side, left_tables)
+                        && Self::is_subset(&right_side, right_tables)
+                    {
+                        equi_keys = Some((left.as_ref().clone(), right.as_ref().clone()));
+                    } else if Self::is_subset(&left_side, right_tables)
+                        && Self::is_subset(&right_side, left_tables)
+                    {
+                        equi_keys = Some((right.as_ref().clone(), left.as_ref().clone()));
+                    }
+                }
+            }
+
+            match equi_keys {
+                Some((left_key, right_key)) => {
+                    condition.left_keys.push(left_key);
+                    condition.right_keys.push(right_key);
+                }
+                None => {
+                    // The condition can't reference the tables out of the join.
+                    for table in Self::tables_of_expr(schema, &conjunction)? {
+                        if !left_tables.contains(&table) && !right_tables.contains(&table) {
+                            return Err(ErrorCode::SyntaxException(format!(
+                                "JOIN condition {:?} references the table out of the JOIN",
+                                conjunction
+                            )));
+                        }
+                    }
+
+                    condition.filter = Some(match condition.filter.take() {
+                        None => conjunction,
+                        Some(filter) => filter.and(conjunction),
+                    });
+                }
+            }
+        }
+
+        Ok(condition)
+    }
+
+    fn from_using(
+        schema: &JoinedSchema,
+        idents: &[Ident],
+        left_tables: &[usize],
+        right_tables: &[usize],
+    ) -> Result<JoinCondition> {
+        let mut condition = JoinCondition::default();
+        for ident in idents {
+            let name = &ident.value;
+            condition
+                .left_keys
+                .push(Self::using_column(schema, name, left_tables)?);
+            condition
+                .right_keys
+                .push(Self::using_column(schema, name, right_tables)?);
+        }
+
+        Ok(condition)
+    }
+
+    fn using_column(schema: &JoinedSchema, name: &str, tables: &[usize]) -> Result<Expression> {
+        let tables_desc = schema.get_tables_desc();
+        let mut columns = tables.iter().flat_map(|pos| {
+            let table_desc = &tables_desc[*pos];
+            table_desc
+                .get_columns_desc()
+                .iter()
+                .filter(|column_desc| column_desc.short_name == name)
+                .map(move |column_desc| JoinedSchema::column_name(table_desc, column_desc))
+        });
+
+        match (columns.next(), columns.next()) {
+            (Some(column), None) => Ok(Expression::Column(column)),
+            (None, _) => Err(ErrorCode::UnknownColumn(format!(
+                "Unknown column {} in USING clause",
+                name
+            ))),
+            (Some(_), Some(_)) => Err(ErrorCode::SyntaxException(format!(
+                "Column {} in USING clause is ambiguous",
+                name
+            ))),
+        }
+    }
+
+    fn split_conjunctions(expr: Expression, conjunctions: &mut Vec<Expression>) {
+        match expr {
+            Expression::BinaryExpression { op, left, right } if op.to_lowercase() == "and" => {
+                Self::split_conjunctions(*left, conjunctions);
+                Self::split_conjunctions(*right, conjunctions);
+            }
+            other => conjunctions.push(other),
+        }
+    }
+
+    fn tables_of_expr(schema: &JoinedSchema, expr: &Expression) -> Result<HashSet<usize>> {
+        let mut tables = HashSet::new();
+        for column in RewriteHelper::expression_plan_columns(expr)? {
+            if let Expression::Column(name) = &column {
+                match schema.column_table_position(name) {
+                    Some(pos) => tables.insert(pos),
+                    None => {
+                        return Err(ErrorCode::UnknownColumn(format!("Unknown column {}", name)))
+                    }
+                };
+            }
+        }
+
+        Ok(tables)
+    }
+
+    fn is_subset(side: &HashSet<usize>, tables: &[usize]) -> bool {
+        !side.is_empty() && side.iter().all(|pos| tables.contains(pos))
+    }
+}
+
 struct TableRPNItem {
     name: ObjectName,
     alias: Option<TableAlias>,
+    navigation: Option<NavigationPoint>,
 }
 
 struct DerivedRPNItem {
@@ -174,18 +474,46 @@ enum RelationRPNItem {
     Join(JoinOperator),
 }
 
-struct RelationRPNBuilder {
+struct RelationRPNBuilder<'a> {
     rpn: Vec<RelationRPNItem>,
+    navigations: &'a [DfTableNavigation],
+    navigated: Vec<usize>,
 }
 
-impl RelationRPNBuilder {
-    pub fn build(exprs: &[TableWithJoins]) -> Result<Vec<RelationRPNItem>> {
-        let mut builder = RelationRPNBuilder { rpn: Vec::new() };
+impl<'a> RelationRPNBuilder<'a> {
+    pub fn build(
+        exprs: &[TableWithJoins],
+        navigations: &'a [DfTableNavigation],
+    ) -> Result<Vec<RelationRPNItem>> {
+        let mut builder = RelationRPNBuilder {
+            rpn: Vec::new(),
+            navigations,
+            navigated: Vec::new(),
+        };
         match exprs.is_empty() {
             true => builder.visit_dummy_table(),
             false => builder.visit(exprs)?,
         }
 
+        // Each time travel clause is of exactly one table in the FROM clause.
+        for (index, navigation) in navigations.iter().enumerate() {
+            match builder.navigated.iter().filter(|i| **i == index).count() {
+                1 => {}
+                0 => {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Time travel of unknown table {} in the FROM clause",
+                        navigation.table
+                    )))
+                }
+                _ => {
+                    return Err(ErrorCode::SyntaxException(format!(
+                        "Time travel of table {} is ambiguous",
+                        navigation.table
+                    )))
+                }
+            }
+        }
+
         Ok(builder.rpn)
     }
 
@@ -193,6 +521,7 @@ impl RelationRPNBuilder {
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: ObjectName(vec![Ident::new("system"), Ident::new("one")]),
             alias: None,
+            navigation: None,
         }));
     }
 
@@ -239,9 +568,14 @@ impl RelationRPNBuilder {
                     ));
                 }
 
-                match args.is_empty() {
-                    true => self.visit_table(name, alias),
-                    false => self.visit_table_function(name, args, alias),
+                let navigation = self.navigation_point(name, alias)?;
+
+                match (args.is_empty(), navigation) {
+                    (true, navigation) => self.visit_table(name, alias, navigation),
+                    (false, None) => self.visit_table_function(name, args, alias),
+                    (false, Some(_)) => Err(ErrorCode::SyntaxException(
+                        "Time travel is unsupported for table function",
+                    )),
                 }
             }
             TableFactor::Derived {
@@ -253,6 +587,14 @@ impl RelationRPNBuilder {
                     return Err(ErrorCode::UnImplement("Cannot SELECT LATERAL subquery."));
                 }
 
+                if let Some(alias) = alias {
+                    if self.navigation(&alias.name).is_some() {
+                        return Err(ErrorCode::SyntaxException(
+                            "Time travel is unsupported for subquery",
+                        ));
+                    }
+                }
+
                 self.rpn.push(RelationRPNItem::Derived(DerivedRPNItem {
                     subquery: subquery.clone(),
                     alias: alias.clone(),
@@ -266,10 +608,63 @@ impl RelationRPNBuilder {
         }
     }
 
-    fn visit_table(&mut self, name: &ObjectName, alias: &Option<TableAlias>) -> Result<()> {
+    // The time travel clause of the table, which is referenced by its alias if aliased.
+    fn navigation_point(
+        &mut self,
+        name: &ObjectName,
+        alias: &Option<TableAlias>,
+    ) -> Result<Option<NavigationPoint>> {
+        let reference = match (alias, name.0.last()) {
+            (Some(alias), _) => &alias.name,
+            (None, Some(table)) => table,
+            (None, None) => return Ok(None),
+        };
+
+        match self.navigation(reference) {
+            None => Ok(None),
+            Some(index) => {
+                self.navigated.push(index);
+                match &self.navigations[index].point {
+                    DfNavigationPoint::Snapshot(id) => {
+                        Ok(Some(NavigationPoint::SnapshotID(id.clone())))
+                    }
+                    DfNavigationPoint::Timestamp(time) => Ok(Some(NavigationPoint::TimePoint(
+                        Self::parse_time_point(time)?,
+                    ))),
+                }
+            }
+        }
+    }
+
+    fn navigation(&self, reference: &Ident) -> Option<usize> {
+        self.navigations
+            .iter()
+            .position(|navigation| navigation.table.value == reference.value)
+    }
+
+    fn parse_time_point(value: &str) -> Result<DateTime<Utc>> {
+        match NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f") {
+            Ok(time_point) => Ok(DateTime::from_utc(time_point, Utc)),
+            Err(_) => match DateTime::parse_from_rfc3339(value) {
+                Ok(time_point) => Ok(time_point.with_timezone(&Utc)),
+                Err(cause) => Err(ErrorCode::BadArguments(format!(
+                    "Cannot parse time travel point '{}': {}",
+                    value, cause
+                ))),
+            },
+        }
+    }
+
+    fn visit_table(
+        &mut self,
+        name: &ObjectName,
+        alias: &Option<TableAlias>,
+        navigation: Option<NavigationPoint>,
+    ) -> Result<()> {
         self.rpn.push(RelationRPNItem::Table(TableRPNItem {
             name: name.clone(),
             alias: alias.clone(),
+            navigation,
         }));
         Ok(())
     }
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::AlterTableOperation;
+use common_planners::AlterTablePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::ColumnDef;
+use sqlparser::ast::ColumnOption;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use super::analyzer_expr::ExpressionAnalyzer;
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::SQLCommon;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfAlterTableOperation {
+    AddColumn {
+        column_def: ColumnDef,
+    },
+    DropColumn {
+        column_name: Ident,
+    },
+    RenameColumn {
+        old_column_name: Ident,
+        new_column_name: Ident,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfAlterTable {
+    pub name: ObjectName,
+    pub operation: DfAlterTableOperation,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfAlterTable {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (database, table) = self.resolve_table(ctx.clone())?;
+        let operation = match &self.operation {
+            DfAlterTableOperation::AddColumn { column_def } => AlterTableOperation::AddColumn {
+                field: Self::column_field(ctx, column_def).await?,
+            },
+            DfAlterTableOperation::DropColumn { column_name } => AlterTableOperation::DropColumn {
+                column_name: column_name.value.clone(),
+            },
+            DfAlterTableOperation::RenameColumn {
+                old_column_name,
+                new_column_name,
+            } => AlterTableOperation::RenameColumn {
+                old_column_name: old_column_name.value.clone(),
+                new_column_name: new_column_name.value.clone(),
+            },
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::AlterTable(
+            AlterTablePlan {
+                database,
+                table,
+                operation,
+            },
+        ))))
+    }
+}
+
+impl DfAlterTable {
+    fn resolve_table(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let DfAlterTable {
+            name: ObjectName(idents),
+            ..
+        } = self;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Alter table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Alter table name must be [`db`].`table`",
+            )),
+        }
+    }
+
+    async fn column_field(ctx: Arc<QueryContext>, column: &ColumnDef) -> Result<DataField> {
+        let expr_analyzer = ExpressionAnalyzer::create(ctx);
+        let mut nullable = true;
+        let mut default_expr = None;
+        for opt in &column.options {
+            match &opt.option {
+                ColumnOption::NotNull => {
+                    nullable = false;
+                }
+                ColumnOption::Default(expr) => {
+                    let expr = expr_analyzer.analyze(expr).await?;
+                    default_expr = Some(serde_json::to_vec(&expr)?);
+                }
+                _ => {}
+            }
+        }
+
+        SQLCommon::make_data_type(&column.data_type).map(|data_type| {
+            DataField::new(&column.name.value, data_type, nullable).with_default_expr(default_expr)
+        })
+    }
+}
@@ -16,9 +16,12 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::CopyOnError;
 use common_planners::CopyPlan;
 use common_planners::PlanNode;
+use regex::Regex;
 use sqlparser::ast::Ident;
 use sqlparser::ast::ObjectName;
 
@@ -31,6 +34,8 @@ pub struct DfCopy {
     pub name: ObjectName,
     pub columns: Vec<Ident>,
     pub location: String,
+    pub pattern: Option<String>,
+    pub on_error: CopyOnError,
     pub format: String,
     pub options: HashMap<String, String>,
 }
@@ -60,12 +65,19 @@ impl AnalyzableStatement for DfCopy {
             schema = DataSchemaRefExt::create(fields);
         }
 
+        if let Some(pattern) = &self.pattern {
+            Regex::new(pattern)
+                .map_err(|e| ErrorCode::BadOption(format!("Invalid PATTERN {}: {}", pattern, e)))?;
+        }
+
         let plan_node = CopyPlan {
             db_name,
             tbl_name,
             tbl_id,
             schema,
             location: self.location.clone(),
+            pattern: self.pattern.clone(),
+            on_error: self.on_error,
             format: self.format.clone(),
             options: self.options.clone(),
         };
@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::Compression;
+use common_meta_types::FileFormat;
+use common_planners::CopyIntoStagePlan;
+use common_planners::PlanNode;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+
+const DEFAULT_MAX_FILE_SIZE: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCopyIntoStage {
+    pub location: String,
+    pub query: Box<DfQueryStatement>,
+    pub file_format: FileFormat,
+    pub options: HashMap<String, String>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCopyIntoStage {
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if !matches!(
+            self.file_format.compression,
+            Compression::None | Compression::Auto
+        ) {
+            return Err(ErrorCode::UnImplement(format!(
+                "Unloading to {:?} compressed files is not supported yet",
+                self.file_format.compression
+            )));
+        }
+
+        let mut max_file_size = DEFAULT_MAX_FILE_SIZE;
+        for (name, value) in &self.options {
+            match name.to_lowercase().as_str() {
+                "max_file_size" => {
+                    max_file_size =
+                        value
+                            .parse::<u64>()
+                            .ok()
+                            .filter(|v| *v > 0)
+                            .ok_or_else(|| {
+                                ErrorCode::BadOption(format!(
+                                    "Invalid MAX_FILE_SIZE {}, expect a positive number of bytes",
+                                    value
+                                ))
+                            })?;
+                }
+                _ => return Err(ErrorCode::BadOption(format!("Unknown option: {}", name))),
+            }
+        }
+
+        let statements = vec![DfStatement::Query(self.query.clone())];
+        let query = PlanParser::build_plan(statements, ctx).await?;
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CopyIntoStage(CopyIntoStagePlan {
+                location: self.location.clone(),
+                query: Box::new(query),
+                file_format: self.file_format.clone(),
+                max_file_size,
+            }),
+        )))
+    }
+}
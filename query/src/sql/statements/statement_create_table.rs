@@ -23,9 +23,11 @@ use common_exception::Result;
 use common_meta_types::TableMeta;
 use common_planners::CreateTablePlan;
 use common_planners::PlanNode;
+use common_planners::RewriteHelper;
 use common_tracing::tracing;
 use sqlparser::ast::ColumnDef;
 use sqlparser::ast::ColumnOption;
+use sqlparser::ast::Expr;
 use sqlparser::ast::ObjectName;
 
 use super::analyzer_expr::ExpressionAnalyzer;
@@ -36,6 +38,9 @@ use crate::sql::statements::DfQueryStatement;
 use crate::sql::DfStatement;
 use crate::sql::PlanParser;
 use crate::sql::SQLCommon;
+use crate::storages::fuse::io::BlockWriteOptions;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::view::VIEW_ENGINE;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct DfCreateTable {
@@ -44,6 +49,10 @@ pub struct DfCreateTable {
     pub name: ObjectName,
     pub columns: Vec<ColumnDef>,
     pub engine: String,
+    /// The expressions of "CLUSTER BY", empty if the table is not clustered.
+    pub cluster_keys: Vec<Expr>,
+    /// The expression of "TTL", the rows of which it is earlier than now are expired.
+    pub ttl: Option<Expr>,
     pub options: HashMap<String, String>,
 
     // The table name after "create .. like" statement.
@@ -66,7 +75,7 @@ impl AnalyzableStatement for DfCreateTable {
             // CTAS
             Some(query_statement) => {
                 let statements = vec![DfStatement::Query(query_statement.clone())];
-                let select_plan = PlanParser::build_plan(statements, ctx).await?;
+                let select_plan = PlanParser::build_plan(statements, ctx.clone()).await?;
 
                 // The schema contains two parts: create table (if specified) and select.
                 let mut fields = table_meta.schema.fields().to_vec();
@@ -85,6 +94,9 @@ impl AnalyzableStatement for DfCreateTable {
             // Query doesn't contain 'As Select' statement
             None => None,
         };
+        table_meta.cluster_keys = self.cluster_keys(ctx.clone(), &table_meta.schema).await?;
+        table_meta.ttl = self.ttl(ctx, &table_meta.schema).await?;
+        self.validate_write_options(&table_meta)?;
 
         Ok(AnalyzedResult::SimpleQuery(Box::new(
             PlanNode::CreateTable(CreateTablePlan {
@@ -113,6 +125,12 @@ impl DfCreateTable {
     }
 
     async fn table_meta(&self, ctx: Arc<QueryContext>) -> Result<TableMeta> {
+        if self.engine.eq_ignore_ascii_case(VIEW_ENGINE) {
+            return Err(ErrorCode::BadArguments(
+                "The VIEW engine is reserved for views, use CREATE VIEW instead",
+            ));
+        }
+
         let engine = self.engine.clone();
         let schema = self.table_schema(ctx).await?;
         Ok(TableMeta {
@@ -123,6 +141,99 @@ impl DfCreateTable {
         })
     }
 
+    /// Analyzes the cluster keys, and serializes them to be kept in the table meta.
+    async fn cluster_keys(
+        &self,
+        ctx: Arc<QueryContext>,
+        schema: &DataSchemaRef,
+    ) -> Result<Option<String>> {
+        if self.cluster_keys.is_empty() {
+            return Ok(None);
+        }
+
+        if !self.engine.eq_ignore_ascii_case("FUSE") {
+            return Err(ErrorCode::BadArguments(format!(
+                "cluster keys are not supported by the {} engine",
+                self.engine
+            )));
+        }
+
+        let expr_analyzer = ExpressionAnalyzer::create(ctx);
+        let mut cluster_keys = Vec::with_capacity(self.cluster_keys.len());
+        for key in &self.cluster_keys {
+            let expr = expr_analyzer.analyze(key).await?;
+            for column in RewriteHelper::expression_plan_columns(&expr)? {
+                let column_name = column.column_name();
+                if schema.field_with_name(&column_name).is_err() {
+                    return Err(ErrorCode::UnknownColumn(format!(
+                        "Unknown column {} in cluster keys",
+                        column_name
+                    )));
+                }
+            }
+            cluster_keys.push(expr);
+        }
+        Ok(Some(serde_json::to_string(&cluster_keys)?))
+    }
+
+    /// Validates the options of how the blocks of a fuse table are written, e.g. `compression`,
+    /// which would otherwise fail the first insertion.
+    fn validate_write_options(&self, table_meta: &TableMeta) -> Result<()> {
+        if !self.engine.eq_ignore_ascii_case("FUSE") {
+            return Ok(());
+        }
+
+        // the columns of a new table are identified by their positions
+        let column_ids = (0..table_meta.schema.fields().len() as ColumnId).collect::<Vec<_>>();
+        BlockWriteOptions::try_create(&table_meta.options, &table_meta.schema, &column_ids)?;
+        Ok(())
+    }
+
+    /// Analyzes the TTL expression, and serializes it to be kept in the table meta.
+    ///
+    /// The expression refers to one column, and is expected to increase with it, e.g.
+    /// `d + INTERVAL '30' DAY`, so that the expiration of a block is decided by its statistics.
+    async fn ttl(&self, ctx: Arc<QueryContext>, schema: &DataSchemaRef) -> Result<Option<String>> {
+        let ttl = match &self.ttl {
+            None => return Ok(None),
+            Some(ttl) => ttl,
+        };
+
+        if !self.engine.eq_ignore_ascii_case("FUSE") {
+            return Err(ErrorCode::BadArguments(format!(
+                "TTL is not supported by the {} engine",
+                self.engine
+            )));
+        }
+
+        let expr = ExpressionAnalyzer::create(ctx).analyze(ttl).await?;
+        let columns = RewriteHelper::expression_plan_columns(&expr)?;
+        if columns.len() != 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "TTL {} must refer to exactly one column",
+                expr.column_name()
+            )));
+        }
+
+        let column_name = columns[0].column_name();
+        if schema.field_with_name(&column_name).is_err() {
+            return Err(ErrorCode::UnknownColumn(format!(
+                "Unknown column {} in TTL",
+                column_name
+            )));
+        }
+
+        let data_type = expr.to_data_type(schema)?;
+        if !data_type.is_date_or_date_time() {
+            return Err(ErrorCode::BadArguments(format!(
+                "TTL {} must be of a date or datetime type, but got {}",
+                expr.column_name(),
+                data_type
+            )));
+        }
+        Ok(Some(serde_json::to_string(&expr)?))
+    }
+
     async fn table_schema(&self, ctx: Arc<QueryContext>) -> Result<DataSchemaRef> {
         match &self.like {
             // For create table like statement, for example 'CREATE TABLE test2 LIKE db1.test1',
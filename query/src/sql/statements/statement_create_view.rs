@@ -0,0 +1,237 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableMeta;
+use common_planners::CreateTablePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::FunctionArg;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+use sqlparser::ast::Query;
+use sqlparser::ast::SelectItem;
+use sqlparser::ast::SetExpr;
+use sqlparser::ast::TableFactor;
+use sqlparser::ast::TableWithJoins;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::DfQueryStatement;
+use crate::sql::DfStatement;
+use crate::sql::PlanParser;
+use crate::storages::view::QUERY;
+use crate::storages::view::VIEW_ENGINE;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfCreateView {
+    pub if_not_exists: bool,
+    /// View name
+    pub name: ObjectName,
+    /// The query of "create view .. as select" statement.
+    pub query: Box<Query>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfCreateView {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let if_not_exists = self.if_not_exists;
+        let tenant = ctx.get_tenant();
+        let (db, view) = self.resolve_view(ctx.clone())?;
+
+        // The unqualified tables of the view are resolved in the current database when the view
+        // is created, rather than the current database when the view is queried.
+        let mut query = self.query.as_ref().clone();
+        ViewQualifier::qualify_query(&mut query, &ctx.get_current_database());
+
+        // Validate the query, and use its schema as the schema of the view.
+        let statement = DfQueryStatement::try_from(query.clone())?;
+        let statements = vec![DfStatement::Query(Box::new(statement))];
+        let select_plan = PlanParser::build_plan(statements, ctx).await?;
+
+        let mut options = HashMap::new();
+        options.insert(QUERY.to_string(), query.to_string());
+        let table_meta = TableMeta {
+            schema: select_plan.schema(),
+            engine: VIEW_ENGINE.to_string(),
+            options,
+            ..Default::default()
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(
+            PlanNode::CreateTable(CreateTablePlan {
+                if_not_exists,
+                tenant,
+                db,
+                table: view,
+                table_meta,
+                as_select: None,
+            }),
+        )))
+    }
+}
+
+impl DfCreateView {
+    fn resolve_view(&self, ctx: Arc<QueryContext>) -> Result<(String, String)> {
+        let idents = &self.name.0;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Create view name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Create view name must be [`db`].`view`",
+            )),
+        }
+    }
+}
+
+/// Qualifies the unqualified tables of the query(including the subqueries, the branches of set
+/// operations and the CTEs) with the database. The references to the CTEs are not qualified.
+pub struct ViewQualifier {
+    database: String,
+    /// The names of the CTEs in scope.
+    ctes: Vec<String>,
+}
+
+impl ViewQualifier {
+    pub fn qualify_query(query: &mut Query, database: &str) {
+        let mut qualifier = ViewQualifier {
+            database: database.to_string(),
+            ctes: vec![],
+        };
+        qualifier.query(query);
+    }
+
+    fn query(&mut self, query: &mut Query) {
+        let scope = self.ctes.len();
+        if let Some(with) = &mut query.with {
+            for cte in &mut with.cte_tables {
+                // A CTE is visible to the CTEs after it, and to itself if it is recursive.
+                if with.recursive {
+                    self.ctes.push(cte.alias.name.value.clone());
+                    self.query(&mut cte.query);
+                } else {
+                    self.query(&mut cte.query);
+                    self.ctes.push(cte.alias.name.value.clone());
+                }
+            }
+        }
+
+        self.set_expr(&mut query.body);
+        self.ctes.truncate(scope);
+    }
+
+    fn set_expr(&mut self, set_expr: &mut SetExpr) {
+        match set_expr {
+            SetExpr::Select(select) => {
+                for table in &mut select.from {
+                    self.joins(table);
+                }
+
+                for item in &mut select.projection {
+                    match item {
+                        SelectItem::UnnamedExpr(expr) => self.expr(expr),
+                        SelectItem::ExprWithAlias { expr, .. } => self.expr(expr),
+                        _ => {}
+                    }
+                }
+
+                if let Some(selection) = &mut select.selection {
+                    self.expr(selection);
+                }
+
+                if let Some(having) = &mut select.having {
+                    self.expr(having);
+                }
+            }
+            SetExpr::Query(query) => self.query(query),
+            SetExpr::SetOperation { left, right, .. } => {
+                self.set_expr(left);
+                self.set_expr(right);
+            }
+            _ => {}
+        }
+    }
+
+    fn joins(&mut self, table: &mut TableWithJoins) {
+        self.table_factor(&mut table.relation);
+        for join in &mut table.joins {
+            self.table_factor(&mut join.relation);
+        }
+    }
+
+    fn table_factor(&mut self, factor: &mut TableFactor) {
+        match factor {
+            // Table functions and the references to the CTEs are not qualified.
+            TableFactor::Table { name, args, .. }
+                if args.is_empty()
+                    && name.0.len() == 1
+                    && !self.ctes.contains(&name.0[0].value) =>
+            {
+                name.0.insert(0, Ident::with_quote('`', &self.database));
+            }
+            TableFactor::Derived { subquery, .. } => self.query(subquery),
+            TableFactor::NestedJoin(joins) => self.joins(joins),
+            _ => {}
+        }
+    }
+
+    fn expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Exists(subquery) | Expr::Subquery(subquery) => self.query(subquery),
+            Expr::InSubquery { expr, subquery, .. } => {
+                self.expr(expr);
+                self.query(subquery);
+            }
+            Expr::Nested(expr)
+            | Expr::IsNull(expr)
+            | Expr::IsNotNull(expr)
+            | Expr::UnaryOp { expr, .. }
+            | Expr::Cast { expr, .. } => self.expr(expr),
+            Expr::BinaryOp { left, right, .. } => {
+                self.expr(left);
+                self.expr(right);
+            }
+            Expr::Between {
+                expr, low, high, ..
+            } => {
+                self.expr(expr);
+                self.expr(low);
+                self.expr(high);
+            }
+            Expr::InList { expr, list, .. } => {
+                self.expr(expr);
+                list.iter_mut().for_each(|expr| self.expr(expr));
+            }
+            Expr::Tuple(exprs) => exprs.iter_mut().for_each(|expr| self.expr(expr)),
+            Expr::Function(function) => {
+                for arg in &mut function.args {
+                    match arg {
+                        FunctionArg::Named { arg, .. } => self.expr(arg),
+                        FunctionArg::Unnamed(arg) => self.expr(arg),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_planners::PlanNode;
+use common_planners::RewriteHelper;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::query::JoinedSchema;
+use crate::sql::statements::query::QualifiedRewriter;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfDeleteStatement {
+    pub name: ObjectName,
+    pub selection: Option<Expr>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfDeleteStatement {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (database_name, table_name) = self.resolve_table(&ctx)?;
+        let table = ctx.get_table(&database_name, &table_name).await?;
+        let table_id = table.get_id();
+
+        let selection = match &self.selection {
+            None => None,
+            Some(expr) => {
+                let name_prefix = vec![database_name.clone(), table_name.clone()];
+                let schema = JoinedSchema::from_table(table, name_prefix)?;
+
+                let analyzer = ExpressionAnalyzer::create(ctx.clone());
+                let mut selection = analyzer.analyze(expr).await?;
+                QualifiedRewriter::rewrite_expr(&schema, ctx.clone(), &mut selection)?;
+
+                let sub_queries = RewriteHelper::collect_exprs_sub_queries(&[selection.clone()])?;
+                if !sub_queries.is_empty() {
+                    return Err(ErrorCode::SyntaxException(
+                        "Unsupported subquery in DELETE statement.",
+                    ));
+                }
+
+                Some(selection)
+            }
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Delete(
+            DeletePlan {
+                database_name,
+                table_name,
+                table_id,
+                selection,
+            },
+        ))))
+    }
+}
+
+impl DfDeleteStatement {
+    fn resolve_table(&self, ctx: &QueryContext) -> Result<(String, String)> {
+        let DfDeleteStatement {
+            name: ObjectName(idents),
+            ..
+        } = self;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Delete table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Delete table name must be [`db`].`table`",
+            )),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::ListStagePlan;
+use common_planners::PlanNode;
+use common_tracing::tracing;
+use regex::Regex;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfListStage {
+    pub location: String,
+    pub pattern: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfListStage {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        if let Some(pattern) = &self.pattern {
+            Regex::new(pattern)
+                .map_err(|e| ErrorCode::BadOption(format!("Invalid PATTERN {}: {}", pattern, e)))?;
+        }
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::ListStage(
+            ListStagePlan {
+                location: self.location.clone(),
+                pattern: self.pattern.clone(),
+            },
+        ))))
+    }
+}
@@ -25,6 +25,7 @@ use common_planners::rebase_expr;
 use common_planners::Expression;
 use common_tracing::tracing;
 use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
 use sqlparser::ast::Offset;
 use sqlparser::ast::OrderByExpr;
 use sqlparser::ast::SelectItem;
@@ -32,6 +33,7 @@ use sqlparser::ast::TableWithJoins;
 
 use crate::sessions::QueryContext;
 use crate::sql::statements::analyzer_statement::QueryAnalyzeState;
+use crate::sql::statements::query::JoinedRelation;
 use crate::sql::statements::query::JoinedSchema;
 use crate::sql::statements::query::JoinedSchemaAnalyzer;
 use crate::sql::statements::query::JoinedTableDesc;
@@ -41,6 +43,7 @@ use crate::sql::statements::query::QueryCollectPushDowns;
 use crate::sql::statements::query::QueryNormalizer;
 use crate::sql::statements::AnalyzableStatement;
 use crate::sql::statements::AnalyzedResult;
+use crate::sql::statements::JoinRelation;
 use crate::sql::statements::QueryRelation;
 use crate::storages::ToReadDataSourcePlan;
 
@@ -54,6 +57,21 @@ pub struct DfQueryStatement {
     pub order_by: Vec<OrderByExpr>,
     pub limit: Option<Expr>,
     pub offset: Option<Offset>,
+    pub navigations: Vec<DfTableNavigation>,
+}
+
+/// The time travel clause `AT (SNAPSHOT => 'id')` or `AT (TIMESTAMP => 'time')` of a table
+/// in the FROM clause, the table is referenced by its alias, or by its name if not aliased.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfTableNavigation {
+    pub table: Ident,
+    pub point: DfNavigationPoint,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DfNavigationPoint {
+    Snapshot(String),
+    Timestamp(String),
 }
 
 #[async_trait::async_trait]
@@ -201,29 +219,101 @@ impl DfQueryStatement {
         let dry_run_res = Self::verify_with_dry_run(&schema, &state)?;
         state.finalize_schema = dry_run_res.schema().clone();
 
-        let mut tables_desc = schema.take_tables_desc();
-
-        if tables_desc.len() != 1 {
-            return Err(ErrorCode::UnImplement("Select join unimplemented yet."));
+        let (tables_desc, relation) = schema.take_tables_desc();
+        let mut tables_relation = Vec::with_capacity(tables_desc.len());
+        for table_desc in &tables_desc {
+            let table_relation = Self::table_relation(table_desc, ctx.clone()).await?;
+            tables_relation.push(match tables_desc.len() {
+                1 => table_relation,
+                _ => Self::rename_ambiguous_columns(table_desc, table_relation)?,
+            });
         }
 
-        match tables_desc.remove(0) {
+        state.relation = Self::joined_relation(&relation, &mut tables_relation)?;
+        Ok(AnalyzedResult::SelectQuery(Box::new(state)))
+    }
+
+    async fn table_relation(
+        table_desc: &JoinedTableDesc,
+        ctx: Arc<QueryContext>,
+    ) -> Result<QueryRelation> {
+        match table_desc {
             JoinedTableDesc::Table {
                 table, push_downs, ..
             } => {
-                let source_plan = table.read_plan(ctx.clone(), push_downs).await?;
-                state.relation = QueryRelation::FromTable(Box::new(source_plan));
+                let source_plan = table.read_plan(ctx, push_downs.clone()).await?;
+                Ok(QueryRelation::FromTable(Box::new(source_plan)))
             }
             JoinedTableDesc::Subquery {
                 state: subquery_state,
                 ..
             } => {
                 // TODO: maybe need reanalyze subquery.
-                state.relation = QueryRelation::Nested(subquery_state);
+                Ok(QueryRelation::Nested(subquery_state.clone()))
             }
         }
+    }
 
-        Ok(AnalyzedResult::SelectQuery(Box::new(state)))
+    // The ambiguous columns are renamed by the full name, so that the joined columns are unique.
+    fn rename_ambiguous_columns(
+        table_desc: &JoinedTableDesc,
+        relation: QueryRelation,
+    ) -> Result<QueryRelation> {
+        let columns_desc = table_desc.get_columns_desc();
+        if columns_desc
+            .iter()
+            .all(|column_desc| !column_desc.is_ambiguity)
+        {
+            return Ok(relation);
+        }
+
+        let relation_schema = match &relation {
+            QueryRelation::FromTable(plan) => plan.schema(),
+            QueryRelation::Nested(state) => state.finalize_schema.clone(),
+            _ => {
+                return Err(ErrorCode::LogicalError(
+                    "Logical error: table relation must be table or subquery.",
+                ))
+            }
+        };
+
+        let mut exprs = Vec::with_capacity(relation_schema.fields().len());
+        for field in relation_schema.fields() {
+            let column = Expression::Column(field.name().clone());
+            match columns_desc.iter().find(|v| v.short_name == *field.name()) {
+                Some(column_desc) if column_desc.is_ambiguity => {
+                    let alias = JoinedSchema::column_name(table_desc, column_desc);
+                    exprs.push(Expression::Alias(alias, Box::new(column)));
+                }
+                _ => exprs.push(column),
+            }
+        }
+
+        Ok(QueryRelation::Projected(Box::new(relation), exprs))
+    }
+
+    fn joined_relation(
+        relation: &JoinedRelation,
+        tables_relation: &mut [QueryRelation],
+    ) -> Result<QueryRelation> {
+        match relation {
+            JoinedRelation::Table(pos) => {
+                match std::mem::replace(&mut tables_relation[*pos], QueryRelation::None) {
+                    QueryRelation::None => Err(ErrorCode::LogicalError(
+                        "Logical error: table is referenced twice in join, it's a bug.",
+                    )),
+                    table_relation => Ok(table_relation),
+                }
+            }
+            JoinedRelation::Join(join) => Ok(QueryRelation::Join(Box::new(JoinRelation {
+                join_type: join.join_type,
+                left: Self::joined_relation(&join.left, tables_relation)?,
+                right: Self::joined_relation(&join.right, tables_relation)?,
+                left_keys: join.left_keys.clone(),
+                right_keys: join.right_keys.clone(),
+                filter: join.filter.clone(),
+            }))),
+        }
     }
 
     fn verify_with_dry_run(schema: &JoinedSchema, state: &QueryAnalyzeState) -> Result<DataBlock> {
@@ -31,6 +31,8 @@ pub enum DfShowTables {
     Like(Ident),
     Where(Expr),
     FromOrIn(ObjectName),
+    // The dropped tables are listed along with the current ones, the current database if None.
+    History(Option<ObjectName>),
 }
 
 #[async_trait::async_trait]
@@ -75,12 +77,24 @@ impl DfShowTables {
         )
     }
 
+    fn show_tables_history(name: &Option<ObjectName>, ctx: Arc<QueryContext>) -> String {
+        let database = match name {
+            Some(name) => name.0[0].value.clone(),
+            None => ctx.get_current_database(),
+        };
+        format!(
+            "SELECT created_on, name, dropped_on FROM system.tables_with_history where database = '{}' ORDER BY database, name, dropped_on",
+            database
+        )
+    }
+
     fn rewritten_query(&self, ctx: Arc<QueryContext>) -> String {
         match self {
             DfShowTables::All => self.show_all_tables(ctx),
             DfShowTables::Like(i) => self.show_tables_with_like(i, ctx),
             DfShowTables::Where(e) => self.show_tables_with_predicate(e, ctx),
             DfShowTables::FromOrIn(name) => DfShowTables::show_tables_from_db(name),
+            DfShowTables::History(name) => DfShowTables::show_tables_history(name, ctx),
         }
     }
 }
@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::find_aggregate_exprs_in_expr;
+use common_planners::Expression;
+use common_planners::PlanNode;
+use common_planners::RewriteHelper;
+use common_planners::UpdatePlan;
+use common_tracing::tracing;
+use sqlparser::ast::Expr;
+use sqlparser::ast::Ident;
+use sqlparser::ast::ObjectName;
+
+use crate::sessions::QueryContext;
+use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
+use crate::sql::statements::query::JoinedSchema;
+use crate::sql::statements::query::QualifiedRewriter;
+use crate::sql::statements::AnalyzableStatement;
+use crate::sql::statements::AnalyzedResult;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DfUpdateStatement {
+    pub name: ObjectName,
+    pub assignments: Vec<(Ident, Expr)>,
+    pub selection: Option<Expr>,
+}
+
+#[async_trait::async_trait]
+impl AnalyzableStatement for DfUpdateStatement {
+    #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn analyze(&self, ctx: Arc<QueryContext>) -> Result<AnalyzedResult> {
+        let (database_name, table_name) = self.resolve_table(&ctx)?;
+        let table = ctx.get_table(&database_name, &table_name).await?;
+        let table_id = table.get_id();
+        let table_schema = table.schema();
+
+        let name_prefix = vec![database_name.clone(), table_name.clone()];
+        let schema = JoinedSchema::from_table(table, name_prefix)?;
+
+        let mut columns = HashSet::with_capacity(self.assignments.len());
+        let mut assignments = Vec::with_capacity(self.assignments.len());
+        for (ident, expr) in &self.assignments {
+            let column_name = ident.value.clone();
+            if !columns.insert(column_name.clone()) {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Duplicated column {} in UPDATE statement.",
+                    column_name
+                )));
+            }
+
+            if !table_schema.has_field(&column_name) {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Unknown column {}",
+                    column_name
+                )));
+            }
+
+            let value = Self::analyze_expr(&schema, &ctx, expr).await?;
+            if !find_aggregate_exprs_in_expr(&value).is_empty() {
+                return Err(ErrorCode::SyntaxException(
+                    "Unsupported aggregate function in UPDATE statement.",
+                ));
+            }
+
+            let field = table_schema.field_with_name(&column_name)?;
+            let value_field = value.to_data_field(&table_schema)?;
+            if value_field.is_nullable() && !field.is_nullable() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Column {} is not nullable, can not be updated by a nullable value.",
+                    column_name
+                )));
+            }
+
+            // the new value must be of the same type as the column
+            let value = match value_field.data_type() == field.data_type() {
+                true => value,
+                false => Expression::Cast {
+                    expr: Box::new(value),
+                    data_type: field.data_type().clone(),
+                },
+            };
+
+            assignments.push((column_name, value));
+        }
+
+        let selection = match &self.selection {
+            None => None,
+            Some(expr) => Some(Self::analyze_expr(&schema, &ctx, expr).await?),
+        };
+
+        Ok(AnalyzedResult::SimpleQuery(Box::new(PlanNode::Update(
+            UpdatePlan {
+                database_name,
+                table_name,
+                table_id,
+                assignments,
+                selection,
+            },
+        ))))
+    }
+}
+
+impl DfUpdateStatement {
+    fn resolve_table(&self, ctx: &QueryContext) -> Result<(String, String)> {
+        let DfUpdateStatement {
+            name: ObjectName(idents),
+            ..
+        } = self;
+        match idents.len() {
+            0 => Err(ErrorCode::SyntaxException("Update table name is empty")),
+            1 => Ok((ctx.get_current_database(), idents[0].value.clone())),
+            2 => Ok((idents[0].value.clone(), idents[1].value.clone())),
+            _ => Err(ErrorCode::SyntaxException(
+                "Update table name must be [`db`].`table`",
+            )),
+        }
+    }
+
+    async fn analyze_expr(
+        schema: &JoinedSchema,
+        ctx: &Arc<QueryContext>,
+        expr: &Expr,
+    ) -> Result<Expression> {
+        let analyzer = ExpressionAnalyzer::create(ctx.clone());
+        let mut expression = analyzer.analyze(expr).await?;
+        QualifiedRewriter::rewrite_expr(schema, ctx.clone(), &mut expression)?;
+
+        let sub_queries = RewriteHelper::collect_exprs_sub_queries(&[expression.clone()])?;
+        if !sub_queries.is_empty() {
+            return Err(ErrorCode::SyntaxException(
+                "Unsupported subquery in UPDATE statement.",
+            ));
+        }
+
+        Ok(expression)
+    }
+}
@@ -16,9 +16,46 @@
 pub const TBL_OPT_KEY_SNAPSHOT_LOC: &str = "SNAPSHOT_LOC";
 pub const TBL_OPT_KEY_CHUNK_BLOCK_NUM: &str = "CHUNK_BLOCK_NUM";
 pub const TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD: &str = "BLOCK_SIZE_THRESHOLD";
+pub const TBL_OPT_KEY_ROW_PER_BLOCK: &str = "ROW_PER_BLOCK";
+// stable ids of the columns, kept once the columns of the table are altered
+pub const TBL_OPT_KEY_COLUMN_IDS: &str = "COLUMN_IDS";
+pub const TBL_OPT_KEY_NEXT_COLUMN_ID: &str = "NEXT_COLUMN_ID";
+// retention policy of the table history, enforced by the background vacuum
+pub const TBL_OPT_KEY_SNAPSHOT_RETENTION_PERIOD: &str = "snapshot_retention_period";
+pub const TBL_OPT_KEY_MAX_SNAPSHOTS: &str = "max_snapshots";
+// comma separated names of the columns, of which the bloom filters are written with each block
+pub const TBL_OPT_KEY_BLOOM_FILTER_COLUMNS: &str = "bloom_filter_columns";
+// compression of the blocks, one of lz4(default), zstd, snappy and none
+pub const TBL_OPT_KEY_COMPRESSION: &str = "compression";
+// comma separated "column:compression" pairs, which override the compression of the columns
+pub const TBL_OPT_KEY_COLUMN_COMPRESSION: &str = "column_compression";
+// comma separated names of the columns, which are dictionary encoded
+pub const TBL_OPT_KEY_DICTIONARY_COLUMNS: &str = "dictionary_columns";
+// number of the rows of each row group(page) of the blocks
+pub const TBL_OPT_KEY_ROW_GROUP_SIZE: &str = "row_group_size";
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_SEGMENT_PREFIX: &str = "_sg";
 pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 
+// key of the parquet key-value metadata, which records the column ids of a block
+pub const FUSE_BLOCK_META_KEY_COLUMN_IDS: &str = "fuse.column_ids";
+// key of the parquet key-value metadata, which records the min/max of the columns of each page
+pub const FUSE_BLOCK_META_KEY_SPARSE_INDEX: &str = "fuse.sparse_index";
+
+// format of the snapshot timestamps, in UTC
+pub const SNAPSHOT_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.6f";
+
 pub const DEFAULT_CHUNK_BLOCK_NUM: usize = 1000;
 pub const DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD: usize = 100 * 1024 * 1024;
+pub const DEFAULT_ROW_PER_BLOCK: usize = 1000 * 1000;
+// each page of a block is written as a row group of the parquet file
+pub const DEFAULT_ROW_PER_PAGE: usize = 64 * 1024;
+pub const DEFAULT_BLOOM_FILTER_FALSE_POSITIVE_RATE: f64 = 0.01;
+// the overlapping blocks are re-clustered in batches, of which the in-memory size is at most
+// this many times of the block size threshold
+pub const RECLUSTER_BATCH_SIZE_FACTOR: usize = 8;
+
+// retries of committing a snapshot, if the table is changed concurrently
+pub const OCC_DEFAULT_MAX_RETRIES: usize = 10;
+pub const OCC_DEFAULT_BACKOFF_INIT_DELAY_MS: u64 = 5;
+pub const OCC_DEFAULT_BACKOFF_MAX_DELAY_MS: u64 = 1000;
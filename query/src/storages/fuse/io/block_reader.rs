@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::DataType;
@@ -19,15 +20,21 @@ use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::read::decompress;
 use common_arrow::arrow::io::parquet::read::page_stream_to_array;
 use common_arrow::parquet::metadata::ColumnChunkMetaData;
+use common_arrow::parquet::metadata::FileMetaData;
 use common_arrow::parquet::read::get_page_stream;
 use common_dal::DataAccessor;
 use common_dal::InputStream;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::DataColumn;
 use common_datavalues::prelude::IntoSeries;
+use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType as DataValueType;
+use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
 use common_tracing::tracing;
 use common_tracing::tracing::debug_span;
 use common_tracing::tracing::Instrument;
@@ -35,17 +42,33 @@ use futures::io::BufReader;
 use futures::StreamExt;
 use futures::TryStreamExt;
 
+use crate::pipelines::transforms::ExpressionExecutor;
 use crate::storages::fuse::io::meta_readers::BlockMetaReader;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::FUSE_BLOCK_META_KEY_COLUMN_IDS;
+use crate::storages::fuse::FUSE_BLOCK_META_KEY_SPARSE_INDEX;
+use crate::storages::index::RangeFilter;
+use crate::storages::index::SparseIndex;
 
+/// Reads the projected columns of a block.
+///
+/// Columns are matched by their ids, the columns which are absent in the block (added to the
+/// table after the block is written) are filled with the default values of them.
+///
+/// If a page filter is given, only the pages (row groups) of which the sparse index tells
+/// that some rows may match are read, by range reads of their column chunks.
 pub struct BlockReader {
     data_accessor: Arc<dyn DataAccessor>,
     path: String,
+    table_schema: DataSchemaRef,
     block_schema: DataSchemaRef,
     arrow_table_schema: ArrowSchema,
     projection: Vec<usize>,
+    column_ids: Vec<ColumnId>,
     file_len: u64,
     read_buffer_size: u64,
     metadata_reader: BlockMetaReader,
+    page_filter: Option<Arc<RangeFilter>>,
 }
 
 impl BlockReader {
@@ -53,6 +76,7 @@ impl BlockReader {
         data_accessor: Arc<dyn DataAccessor>,
         path: String,
         table_schema: DataSchemaRef,
+        column_ids: Vec<ColumnId>,
         projection: Vec<usize>,
         file_len: u64,
         read_buffer_size: u64,
@@ -64,35 +88,64 @@ impl BlockReader {
             path,
             block_schema,
             arrow_table_schema: table_schema.to_arrow(),
+            table_schema,
             projection,
+            column_ids,
             file_len,
             read_buffer_size,
             metadata_reader: reader,
+            page_filter: None,
         }
     }
 
+    /// Skips the pages which cannot match the filter, the columns of the filter should be
+    /// identified by their ids.
+    #[must_use]
+    pub fn with_page_filter(mut self, filter: Option<Arc<RangeFilter>>) -> Self {
+        self.page_filter = filter;
+        self
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn read(&mut self) -> Result<DataBlock> {
         let block_meta = &self.metadata_reader.read(self.path.as_str()).await?;
         let metadata = block_meta.inner();
 
-        // FUSE uses exact one "row group"
-        let num_row_groups = metadata.row_groups.len();
-        let row_group = if num_row_groups != 1 {
-            return Err(ErrorCode::LogicalError(format!(
-                "invalid parquet file, expect exact one row group insides, but got {}",
-                num_row_groups
-            )));
-        } else {
-            &metadata.row_groups[0]
-        };
+        // FUSE writes each page of the block as a row group
+        if metadata.row_groups.is_empty() {
+            return Err(ErrorCode::LogicalError(
+                "invalid parquet file, expect at least one row group insides, but got 0",
+            ));
+        }
+        let row_groups = self.row_groups_to_read(metadata)?;
 
-        let col_num = self.projection.len();
-        let cols = self
+        // positions of the projected columns in the block, None if absent in the block
+        let num_columns = metadata.row_groups[0].columns().len();
+        let block_column_ids = Self::block_column_ids(metadata, num_columns)?;
+        let positions = self
             .projection
-            .clone()
-            .into_iter()
-            .map(|idx| (row_group.column(idx).clone(), idx));
+            .iter()
+            .map(|idx| {
+                let column_id = self.column_ids[*idx];
+                block_column_ids.iter().position(|id| *id == column_id)
+            })
+            .collect::<Vec<_>>();
+
+        let projection = &self.projection;
+        let projected_positions = &positions;
+        let cols = row_groups
+            .iter()
+            .flat_map(move |row_group| {
+                let row_group = &metadata.row_groups[*row_group];
+                projection
+                    .iter()
+                    .zip(projected_positions.iter())
+                    .filter_map(move |(idx, pos)| {
+                        pos.map(|pos| (row_group.column(pos).clone(), *idx))
+                    })
+            })
+            .collect::<Vec<_>>();
+        let col_num = cols.len();
 
         let fields = self.arrow_table_schema.fields();
         let stream_len = self.file_len;
@@ -112,11 +165,133 @@ impl BlockReader {
 
         // TODO configuration of the buffer size
         let buffer_size = 10;
-        let n = std::cmp::min(buffer_size, col_num);
-        let data_cols = stream.buffered(n).try_collect().await?;
+        let n = std::cmp::max(std::cmp::min(buffer_size, col_num), 1);
+        let read_cols: Vec<DataColumn> = stream.buffered(n).try_collect().await?;
+
+        let mut read_cols = read_cols.into_iter();
+        let mut pages = Vec::with_capacity(row_groups.len());
+        for row_group in row_groups {
+            let num_rows = metadata.row_groups[row_group].num_rows() as usize;
+            let mut data_cols = Vec::with_capacity(self.projection.len());
+            for (idx, pos) in self.projection.iter().zip(positions.iter()) {
+                let col = match pos {
+                    Some(_) => read_cols.next().ok_or_else(|| {
+                        ErrorCode::LogicalError("the number of the read columns mismatch")
+                    })?,
+                    None => Self::default_column(self.table_schema.field(*idx), num_rows)?,
+                };
+                data_cols.push(col);
+            }
+            pages.push(DataBlock::create(self.block_schema.clone(), data_cols));
+        }
+
+        match pages.len() {
+            0 => Ok(DataBlock::empty_with_schema(self.block_schema.clone())),
+            1 => Ok(pages.remove(0)),
+            _ => DataBlock::concat_blocks(&pages),
+        }
+    }
+
+    // Indexes of the row groups to be read, all of them if no page filter is given, or the
+    // block is written without the sparse index.
+    fn row_groups_to_read(&self, metadata: &FileMetaData) -> Result<Vec<usize>> {
+        let num_row_groups = metadata.row_groups.len();
+        let all = (0..num_row_groups).collect::<Vec<_>>();
+        let filter = match &self.page_filter {
+            None => return Ok(all),
+            Some(filter) => filter,
+        };
+
+        let sparse_index = Self::block_sparse_index(metadata)?;
+        if sparse_index
+            .values()
+            .any(|idx| idx.values.len() != num_row_groups)
+        {
+            return Ok(all);
+        }
+
+        match SparseIndex::apply_index(&sparse_index, filter)? {
+            (true, _) => Ok(all),
+            (false, pages) => Ok(pages.into_iter().map(|page| page as usize).collect()),
+        }
+    }
+
+    fn block_sparse_index(metadata: &FileMetaData) -> Result<HashMap<ColumnId, SparseIndex>> {
+        let index = metadata.key_value_metadata.as_ref().and_then(|kvs| {
+            kvs.iter()
+                .find(|kv| kv.key == FUSE_BLOCK_META_KEY_SPARSE_INDEX)
+                .and_then(|kv| kv.value.as_ref())
+        });
+
+        match index {
+            None => Ok(HashMap::new()),
+            Some(index) => {
+                let index: Vec<SparseIndex> = serde_json::from_str(index)?;
+                Ok(index.into_iter().map(|idx| (idx.column_id, idx)).collect())
+            }
+        }
+    }
+
+    // Ids of the columns of the block, blocks written before the ids are recorded
+    // identify a column by its position.
+    fn block_column_ids(metadata: &FileMetaData, num_columns: usize) -> Result<Vec<ColumnId>> {
+        let ids = metadata.key_value_metadata.as_ref().and_then(|kvs| {
+            kvs.iter()
+                .find(|kv| kv.key == FUSE_BLOCK_META_KEY_COLUMN_IDS)
+                .and_then(|kv| kv.value.as_ref())
+        });
+
+        match ids {
+            None => Ok((0..num_columns as ColumnId).collect()),
+            Some(ids) if ids.is_empty() => Ok(vec![]),
+            Some(ids) => ids
+                .split(',')
+                .map(|id| id.parse::<ColumnId>())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| {
+                    ErrorCode::ParquetError(format!("invalid column ids {} of block, {}", ids, e))
+                }),
+        }
+    }
+
+    // Fills a column which is absent in the block with its default value, or the default
+    // value of its data type if no default expression is specified.
+    fn default_column(field: &DataField, num_rows: usize) -> Result<DataColumn> {
+        let value = match field.default_expr() {
+            None => DataValue::new_from_data_type(field.data_type(), field.is_nullable()),
+            Some(expr) => {
+                let expression: Expression = serde_json::from_slice::<Expression>(expr)?;
+                let expression = Expression::Alias(
+                    field.name().to_string(),
+                    Box::new(Expression::Cast {
+                        expr: Box::new(expression),
+                        data_type: field.data_type().clone(),
+                    }),
+                );
+
+                // the default expression is evaluated against a dummy block of one row
+                let input_schema = DataSchemaRefExt::create(vec![DataField::new(
+                    "_dummy",
+                    DataValueType::UInt8,
+                    false,
+                )]);
+                let input = DataBlock::create(input_schema.clone(), vec![DataColumn::Constant(
+                    DataValue::UInt8(Some(0)),
+                    1,
+                )]);
+                let executor = ExpressionExecutor::try_create(
+                    "block reader default value",
+                    input_schema,
+                    DataSchemaRefExt::create(vec![field.clone()]),
+                    vec![expression],
+                    true,
+                )?;
+                executor.execute(&input)?.column(0).try_get(0)?
+            }
+        };
 
-        let block = DataBlock::create(self.block_schema.clone(), data_cols);
-        Ok(block)
+        let column = DataColumn::Constant(value, num_rows).to_array()?;
+        Ok(DataColumn::Array(column))
     }
 
     async fn read_column(
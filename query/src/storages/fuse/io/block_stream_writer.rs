@@ -20,15 +20,21 @@ use common_dal::DataAccessor;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchema;
 use common_exception::Result;
+use common_planners::Expression;
 use common_streams::SendableDataBlockStream;
 use futures::stream::TryChunksError;
 use futures::StreamExt;
 use futures::TryStreamExt;
 
 use super::block_writer;
+use super::block_writer::BlockWriteOptions;
 use crate::storages::fuse::io::locations::gen_block_location;
+use crate::storages::fuse::io::write_bloom_filter_index;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::MetaFormat;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::statistics::BlockClusterer;
 use crate::storages::fuse::statistics::StatisticsAccumulator;
 
 pub type SegmentInfoStream =
@@ -37,10 +43,16 @@ pub type SegmentInfoStream =
 pub struct BlockStreamWriter;
 
 impl BlockStreamWriter {
+    #[allow(clippy::too_many_arguments)]
     pub async fn write_block_stream(
         data_accessor: Arc<dyn DataAccessor>,
+        location_prefix: String,
         stream: SendableDataBlockStream,
         data_schema: Arc<DataSchema>,
+        column_ids: Vec<ColumnId>,
+        cluster_keys: Vec<Expression>,
+        bloom_filter_column_ids: Vec<ColumnId>,
+        write_options: BlockWriteOptions,
         chunk_block_num: usize,
         block_size_threshold: usize,
     ) -> SegmentInfoStream {
@@ -56,7 +68,7 @@ impl BlockStreamWriter {
                 match item.map_err(|TryChunksError(_, e)| e) {
                     Err(e) => yield(Err(e)),
                     Ok(blocks) => {
-                        let seg = Self::generate_segment(data_accessor.clone(), data_schema.clone(), blocks, block_size_threshold).await;
+                        let seg = Self::generate_segment(data_accessor.clone(), &location_prefix, data_schema.clone(), &column_ids, &cluster_keys, &bloom_filter_column_ids, &write_options, blocks, block_size_threshold).await;
                         yield(seg);
                     }
                 }
@@ -65,28 +77,64 @@ impl BlockStreamWriter {
         Box::pin(s)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn generate_segment(
         data_accessor: Arc<dyn DataAccessor>,
+        location_prefix: &str,
         data_schema: Arc<DataSchema>,
+        column_ids: &[ColumnId],
+        cluster_keys: &[Expression],
+        bloom_filter_column_ids: &[ColumnId],
+        write_options: &BlockWriteOptions,
         blocks: Vec<DataBlock>,
         block_size_threshold: usize,
     ) -> Result<SegmentInfo> {
         // re-shape the blocks
         let blocks = Self::reshape_blocks(blocks, block_size_threshold)?;
-        let mut acc = StatisticsAccumulator::new();
 
-        for block in blocks.into_iter() {
-            let partial_acc = acc.begin(&block)?;
+        // sort the rows by the cluster key, if any
+        let blocks = if cluster_keys.is_empty() {
+            blocks.into_iter().map(|block| (block, None)).collect()
+        } else {
+            BlockClusterer::new(cluster_keys.to_vec())
+                .cluster_blocks(&blocks)?
+                .into_iter()
+                .map(|(block, stats)| (block, Some(stats)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut acc = StatisticsAccumulator::new();
+        for (block, cluster_stats) in blocks.into_iter() {
+            let location = gen_block_location(location_prefix);
+            let bloom_filter_index = write_bloom_filter_index(
+                &block,
+                column_ids,
+                bloom_filter_column_ids,
+                &data_accessor,
+                &location,
+            )
+            .await?;
+            let partial_acc = acc
+                .begin(&block, column_ids, cluster_stats)?
+                .with_bloom_filter_index(bloom_filter_index)
+                .with_col_encodings(write_options.column_encodings(column_ids));
             let schema = block.schema().to_arrow();
-            let location = gen_block_location();
-            let file_size =
-                block_writer::write_block(&schema, block, &data_accessor, &location).await?;
+            let file_size = block_writer::write_block(
+                &schema,
+                block,
+                column_ids,
+                write_options,
+                &data_accessor,
+                &location,
+            )
+            .await?;
             acc = partial_acc.end(file_size, location);
         }
 
         // summary and generate a segment
-        let summary = acc.summary(data_schema.as_ref())?;
+        let summary = acc.summary(data_schema.as_ref(), column_ids)?;
         let seg = SegmentInfo {
+            format_version: SegmentInfo::FORMAT_VERSION,
             blocks: acc.blocks_metas,
             summary: Statistics {
                 row_count: acc.summary_row_count,
@@ -13,39 +13,251 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::DictionaryArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::compute::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::IntegerType;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::error::ArrowError;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
 use common_arrow::arrow::io::parquet::write::*;
 use common_arrow::arrow::record_batch::RecordBatch;
 use common_arrow::parquet::encoding::Encoding;
 use common_dal::DataAccessor;
 use common_datablocks::DataBlock;
+use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use parquet_format_async_temp::KeyValue;
+
+use crate::storages::fuse::meta::BlockCompression;
+use crate::storages::fuse::meta::ColumnEncoding;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::table::encode_column_ids;
+use crate::storages::fuse::DEFAULT_ROW_PER_PAGE;
+use crate::storages::fuse::FUSE_BLOCK_META_KEY_COLUMN_IDS;
+use crate::storages::fuse::FUSE_BLOCK_META_KEY_SPARSE_INDEX;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_COMPRESSION;
+use crate::storages::fuse::TBL_OPT_KEY_COMPRESSION;
+use crate::storages::fuse::TBL_OPT_KEY_DICTIONARY_COLUMNS;
+use crate::storages::fuse::TBL_OPT_KEY_ROW_GROUP_SIZE;
+use crate::storages::index::SparseIndex;
+
+/// How the blocks of a table are written, according to the table options `compression`,
+/// `column_compression`, `dictionary_columns` and `row_group_size`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockWriteOptions {
+    /// The compression of the columns, unless it is overridden by `column_compressions`
+    pub compression: BlockCompression,
+    pub column_compressions: HashMap<ColumnId, BlockCompression>,
+    pub dictionary_column_ids: HashSet<ColumnId>,
+    /// The number of the rows of each page, which is written as a row group
+    pub row_group_size: usize,
+}
+
+impl Default for BlockWriteOptions {
+    fn default() -> Self {
+        Self {
+            compression: BlockCompression::Lz4,
+            column_compressions: HashMap::new(),
+            dictionary_column_ids: HashSet::new(),
+            row_group_size: DEFAULT_ROW_PER_PAGE,
+        }
+    }
+}
+
+impl BlockWriteOptions {
+    /// Parses the write options out of the table options, in which the columns are referred by
+    /// their names, they are identified by the given column ids.
+    pub fn try_create(
+        options: &HashMap<String, String>,
+        schema: &DataSchema,
+        column_ids: &[ColumnId],
+    ) -> Result<Self> {
+        let mut write_options = Self::default();
+        if let Some(compression) = options.get(TBL_OPT_KEY_COMPRESSION) {
+            write_options.compression = parse_compression(compression)?;
+        }
+
+        if let Some(pairs) = options.get(TBL_OPT_KEY_COLUMN_COMPRESSION) {
+            for pair in split_names(pairs) {
+                let (name, compression) = pair.split_once(':').ok_or_else(|| {
+                    ErrorCode::BadOption(format!(
+                        "invalid {} {}, expect column:compression",
+                        TBL_OPT_KEY_COLUMN_COMPRESSION, pair
+                    ))
+                })?;
+                let idx = column_index(schema, name.trim(), TBL_OPT_KEY_COLUMN_COMPRESSION)?;
+                write_options
+                    .column_compressions
+                    .insert(column_ids[idx], parse_compression(compression.trim())?);
+            }
+        }
+
+        if let Some(names) = options.get(TBL_OPT_KEY_DICTIONARY_COLUMNS) {
+            for name in split_names(names) {
+                let idx = column_index(schema, name, TBL_OPT_KEY_DICTIONARY_COLUMNS)?;
+                let data_type = schema.field(idx).data_type();
+                if !(data_type.is_numeric()
+                    || data_type.is_string()
+                    || data_type.is_date_or_date_time())
+                {
+                    return Err(ErrorCode::BadOption(format!(
+                        "column {} of type {:?} can not be dictionary encoded",
+                        name, data_type
+                    )));
+                }
+                write_options.dictionary_column_ids.insert(column_ids[idx]);
+            }
+        }
 
+        if let Some(size) = options.get(TBL_OPT_KEY_ROW_GROUP_SIZE) {
+            write_options.row_group_size = match size.trim().parse::<usize>() {
+                Ok(size) if size > 0 => size,
+                _ => {
+                    return Err(ErrorCode::BadOption(format!(
+                        "invalid {} {}, expect a positive integer",
+                        TBL_OPT_KEY_ROW_GROUP_SIZE, size
+                    )))
+                }
+            };
+        }
+        Ok(write_options)
+    }
+
+    pub fn column_encoding(&self, column_id: ColumnId) -> ColumnEncoding {
+        ColumnEncoding {
+            compression: *self
+                .column_compressions
+                .get(&column_id)
+                .unwrap_or(&self.compression),
+            dictionary: self.dictionary_column_ids.contains(&column_id),
+        }
+    }
+
+    /// The encodings of the given columns, which are recorded in the meta of the blocks.
+    pub fn column_encodings(&self, column_ids: &[ColumnId]) -> HashMap<ColumnId, ColumnEncoding> {
+        column_ids
+            .iter()
+            .map(|column_id| (*column_id, self.column_encoding(*column_id)))
+            .collect()
+    }
+
+    /// Formats the options of the columns `column_compression` and `dictionary_columns` by the
+    /// names of the given columns, the options of the columns which are absent are left out.
+    pub fn column_options(
+        &self,
+        schema: &DataSchema,
+        column_ids: &[ColumnId],
+    ) -> Vec<(String, String)> {
+        let mut compressions = vec![];
+        let mut dictionary_columns = vec![];
+        for (field, column_id) in schema.fields().iter().zip(column_ids.iter()) {
+            if let Some(compression) = self.column_compressions.get(column_id) {
+                compressions.push(format!(
+                    "{}:{}",
+                    field.name(),
+                    compression_name(*compression)
+                ));
+            }
+            if self.dictionary_column_ids.contains(column_id) {
+                dictionary_columns.push(field.name().to_string());
+            }
+        }
+        vec![
+            (
+                TBL_OPT_KEY_COLUMN_COMPRESSION.to_owned(),
+                compressions.join(","),
+            ),
+            (
+                TBL_OPT_KEY_DICTIONARY_COLUMNS.to_owned(),
+                dictionary_columns.join(","),
+            ),
+        ]
+    }
+}
+
+/// Writes the block as a parquet file, the ids of the columns are kept in the
+/// key-value metadata of the file.
+///
+/// Each page of the block is written as a row group, the min/max of the columns of the
+/// pages (the sparse index) are also kept in the key-value metadata, so that the pages
+/// which cannot match the filters can be skipped while reading.
+///
+/// The column chunks are compressed and encoded as the write options tell, which are recorded
+/// in the meta of the block as well, see `BlockWriteOptions::column_encodings`.
 pub async fn write_block(
     arrow_schema: &ArrowSchema,
     block: DataBlock,
+    column_ids: &[ColumnId],
+    write_options: &BlockWriteOptions,
     data_accessor: impl AsRef<dyn DataAccessor>,
     location: &str,
 ) -> Result<u64> {
     let data_accessor = data_accessor.as_ref();
     let options = WriteOptions {
         write_statistics: true,
-        compression: Compression::Lz4, // let's begin with lz4
+        compression: write_options.compression.into(),
         version: Version::V2,
     };
-    let batch = RecordBatch::try_from(block)?;
-    let encodings: Vec<_> = arrow_schema
+    let pages = if block.num_rows() == 0 {
+        vec![block]
+    } else {
+        DataBlock::split_block_by_size(&block, write_options.row_group_size)?
+    };
+    let sparse_index = SparseIndex::create_index(column_ids, &pages)?;
+    let batches = pages
+        .into_iter()
+        .map(RecordBatch::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    // the dictionary encoded columns are written as arrow dictionaries
+    let encodings = column_ids
+        .iter()
+        .map(|column_id| write_options.column_encoding(*column_id))
+        .collect::<Vec<_>>();
+    let fields = arrow_schema
         .fields()
         .iter()
-        .map(|f| col_encoding(&f.data_type))
-        .collect();
+        .zip(encodings.iter())
+        .map(|(field, encoding)| match encoding.dictionary {
+            true => ArrowField::new(
+                field.name.as_str(),
+                dictionary_data_type(&field.data_type),
+                field.nullable,
+            ),
+            false => field.clone(),
+        })
+        .collect::<Vec<_>>();
+    let write_schema = ArrowSchema::new_from(fields, arrow_schema.metadata().clone());
+    let parquet_schema = to_parquet_schema(&write_schema)?;
 
-    let iter = vec![Ok(batch)];
-    let row_groups = RowGroupIterator::try_new(iter.into_iter(), arrow_schema, options, encodings)?;
-    let parquet_schema = row_groups.parquet_schema().clone();
+    let mut row_groups = Vec::with_capacity(batches.len());
+    for batch in batches {
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(encodings.iter())
+            .map(|(array, encoding)| match encoding.dictionary {
+                true => to_dictionary(array),
+                false => Ok(array.clone()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        row_groups.push(row_group_iter(
+            columns,
+            parquet_schema.columns().to_vec(),
+            encodings.clone(),
+        ));
+    }
 
     // PutObject in S3 need to know the content-length in advance
     // multipart upload may intimidate this, but let's fit things together first
@@ -55,13 +267,24 @@ pub async fn write_block(
     // we need a configuration of block size threshold here
     let mut writer = Vec::with_capacity(100 * 1024 * 1024).writer();
 
+    let key_value_metadata = vec![
+        KeyValue {
+            key: FUSE_BLOCK_META_KEY_COLUMN_IDS.to_owned(),
+            value: Some(encode_column_ids(column_ids)),
+        },
+        KeyValue {
+            key: FUSE_BLOCK_META_KEY_SPARSE_INDEX.to_owned(),
+            value: Some(serde_json::to_string(&sparse_index)?),
+        },
+    ];
+
     let len = common_arrow::parquet::write::write_file(
         &mut writer,
-        row_groups,
+        row_groups.into_iter(),
         parquet_schema,
         options,
         None,
-        None,
+        Some(key_value_metadata),
     )
     .map_err(|e| ErrorCode::ParquetError(e.to_string()))?;
 
@@ -75,16 +298,124 @@ pub async fn write_block(
     Ok(len)
 }
 
-fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
-    // Although encoding does work, parquet2 has not implemented decoding of DeltaLengthByteArray yet, we fallback to Plain
-    // From parquet2: Decoding "DeltaLengthByteArray"-encoded required V2 pages is not yet implemented for Binary.
-    //
-    //match data_type {
-    //    ArrowDataType::Binary
-    //    | ArrowDataType::LargeBinary
-    //    | ArrowDataType::Utf8
-    //    | ArrowDataType::LargeUtf8 => Encoding::DeltaLengthByteArray,
-    //    _ => Encoding::Plain,
-    //}
-    Encoding::Plain
+// Encodes and compresses the columns of a row group, each of them by its own encoding.
+fn row_group_iter(
+    columns: Vec<Arc<dyn Array>>,
+    descriptors: Vec<ColumnDescriptor>,
+    encodings: Vec<ColumnEncoding>,
+) -> std::result::Result<RowGroupIter<'static, ArrowError>, ArrowError> {
+    Ok(DynIter::new(
+        columns
+            .into_iter()
+            .zip(descriptors.into_iter())
+            .zip(encodings.into_iter())
+            .map(|((array, descriptor), encoding)| {
+                let options = WriteOptions {
+                    write_statistics: true,
+                    compression: encoding.compression.into(),
+                    version: Version::V2,
+                };
+                let page_encoding = match encoding.dictionary {
+                    true => Encoding::RleDictionary,
+                    false => Encoding::Plain,
+                };
+                array_to_pages(array, descriptor, options, page_encoding).map(move |pages| {
+                    let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
+                    let compressed_pages =
+                        Compressor::new(encoded_pages, options.compression, vec![])
+                            .map_err(ArrowError::from);
+                    DynStreamingIterator::new(compressed_pages)
+                })
+            }),
+    ))
+}
+
+fn dictionary_data_type(data_type: &ArrowDataType) -> ArrowDataType {
+    ArrowDataType::Dictionary(IntegerType::UInt32, Box::new(data_type.clone()))
+}
+
+// Converts the column to an arrow dictionary of which the keys are u32.
+fn to_dictionary(array: &Arc<dyn Array>) -> Result<Arc<dyn Array>> {
+    match array.data_type() {
+        // strings, which arrow can not cast to dictionaries
+        ArrowDataType::LargeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .ok_or_else(|| ErrorCode::LogicalError("expect a large binary array"))?;
+            let mut positions = HashMap::new();
+            let mut values = vec![];
+            let keys = array
+                .iter()
+                .map(|value| {
+                    value.map(|value| {
+                        *positions.entry(value).or_insert_with(|| {
+                            values.push(value);
+                            (values.len() - 1) as u32
+                        })
+                    })
+                })
+                .collect::<Vec<_>>();
+            let keys = PrimitiveArray::<u32>::from(keys);
+            let values = BinaryArray::<i64>::from_slice(&values);
+            Ok(Arc::new(DictionaryArray::<u32>::from_data(
+                keys,
+                Arc::new(values),
+            )))
+        }
+        data_type => {
+            let array = cast::cast(
+                array.as_ref(),
+                &dictionary_data_type(data_type),
+                CastOptions::default(),
+            )?;
+            Ok(Arc::from(array))
+        }
+    }
+}
+
+impl From<BlockCompression> for Compression {
+    fn from(compression: BlockCompression) -> Self {
+        match compression {
+            BlockCompression::None => Compression::Uncompressed,
+            BlockCompression::Lz4 => Compression::Lz4,
+            BlockCompression::Zstd => Compression::Zstd,
+            BlockCompression::Snappy => Compression::Snappy,
+        }
+    }
+}
+
+fn parse_compression(name: &str) -> Result<BlockCompression> {
+    match name.to_lowercase().as_str() {
+        "none" => Ok(BlockCompression::None),
+        "lz4" => Ok(BlockCompression::Lz4),
+        "zstd" => Ok(BlockCompression::Zstd),
+        "snappy" => Ok(BlockCompression::Snappy),
+        _ => Err(ErrorCode::BadOption(format!(
+            "unknown compression {}, expect one of lz4, zstd, snappy and none",
+            name
+        ))),
+    }
+}
+
+fn compression_name(compression: BlockCompression) -> &'static str {
+    match compression {
+        BlockCompression::None => "none",
+        BlockCompression::Lz4 => "lz4",
+        BlockCompression::Zstd => "zstd",
+        BlockCompression::Snappy => "snappy",
+    }
+}
+
+fn split_names(names: &str) -> impl Iterator<Item = &str> {
+    names
+        .split(',')
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+}
+
+fn column_index(schema: &DataSchema, name: &str, option: &str) -> Result<usize> {
+    schema.index_of(name).map_err(|_| {
+        ErrorCode::BadOption(format!("unknown column {} in the option {}", name, option))
+    })
 }
@@ -0,0 +1,65 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_dal::DataAccessor;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+
+use crate::storages::fuse::io::locations::bloom_filter_index_location;
+use crate::storages::fuse::meta::BloomFilterIndexLocation;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::DEFAULT_BLOOM_FILTER_FALSE_POSITIVE_RATE;
+use crate::storages::index::BloomFilterIndex;
+
+/// Writes the bloom filters of the indexed columns of the block, next to the block file.
+///
+/// Returns `None` if none of the indexed columns is present in the block.
+pub async fn write_bloom_filter_index(
+    block: &DataBlock,
+    column_ids: &[ColumnId],
+    indexed_column_ids: &[ColumnId],
+    data_accessor: impl AsRef<dyn DataAccessor>,
+    block_location: &str,
+) -> Result<Option<BloomFilterIndexLocation>> {
+    let columns = column_ids
+        .iter()
+        .enumerate()
+        .filter(|(_, id)| indexed_column_ids.contains(id))
+        .map(|(idx, id)| (idx, *id))
+        .collect::<Vec<_>>();
+    if columns.is_empty() {
+        return Ok(None);
+    }
+
+    let index =
+        BloomFilterIndex::create_index(block, &columns, DEFAULT_BLOOM_FILTER_FALSE_POSITIVE_RATE)?;
+    if index.filters.is_empty() {
+        return Ok(None);
+    }
+
+    let bytes = index.encode()?;
+    let size = bytes.len() as u64;
+    let path = bloom_filter_index_location(block_location);
+    data_accessor.as_ref().put(&path, bytes).await?;
+    Ok(Some(BloomFilterIndexLocation { path, size }))
+}
+
+pub async fn read_bloom_filter_index(
+    data_accessor: &dyn DataAccessor,
+    location: &BloomFilterIndexLocation,
+) -> Result<BloomFilterIndex> {
+    let bytes = data_accessor.read(&location.path).await?;
+    BloomFilterIndex::decode(&bytes)
+}
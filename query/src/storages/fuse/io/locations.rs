@@ -19,16 +19,45 @@ use crate::storages::fuse::constants::FUSE_TBL_BLOCK_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 
-pub fn gen_block_location() -> String {
+// The objects of a table are kept under the prefix of the table, e.g. `<table_id>/_b/<uuid>.parquet`.
+// The tables created by the older versions keep theirs under the root, i.e. the prefix is empty.
+fn object_location(prefix: &str, kind: &str, name: &str) -> String {
+    match prefix.is_empty() {
+        true => format!("{}/{}", kind, name),
+        false => format!("{}/{}/{}", prefix, kind, name),
+    }
+}
+
+pub fn gen_block_location(prefix: &str) -> String {
     let part_uuid = Uuid::new_v4().simple().to_string() + ".parquet";
-    format!("{}/{}", FUSE_TBL_BLOCK_PREFIX, part_uuid)
+    object_location(prefix, FUSE_TBL_BLOCK_PREFIX, &part_uuid)
+}
+
+/// The bloom filter index of a block shares the name of the block, with a different extension.
+pub fn bloom_filter_index_location(block_location: &str) -> String {
+    let stem = block_location
+        .strip_suffix(".parquet")
+        .unwrap_or(block_location);
+    format!("{}.bloom", stem)
 }
 
-pub fn gen_segment_info_location() -> String {
+pub fn gen_segment_info_location(prefix: &str) -> String {
     let segment_uuid = Uuid::new_v4().simple().to_string();
-    format!("{}/{}", FUSE_TBL_SEGMENT_PREFIX, segment_uuid)
+    object_location(prefix, FUSE_TBL_SEGMENT_PREFIX, &segment_uuid)
+}
+
+pub fn snapshot_location(prefix: &str, id: &Uuid) -> String {
+    object_location(prefix, FUSE_TBL_SNAPSHOT_PREFIX, &id.simple().to_string())
 }
 
-pub fn snapshot_location(id: &Uuid) -> String {
-    format!("{}/{}", FUSE_TBL_SNAPSHOT_PREFIX, id.simple())
+/// Returns the prefix of the table, under which the snapshot is kept.
+pub fn table_prefix_of(snapshot_location: &str) -> &str {
+    let dir = match snapshot_location.rsplit_once('/') {
+        Some((dir, _)) => dir,
+        None => "",
+    };
+    match dir.rsplit_once('/') {
+        Some((prefix, _)) => prefix,
+        None => "",
+    }
 }
@@ -22,7 +22,6 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_tracing::tracing::debug_span;
 use common_tracing::tracing::Instrument;
-use serde::de::DeserializeOwned;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::cache::CachedReader;
@@ -31,6 +30,8 @@ use crate::storages::fuse::cache::Loader;
 use crate::storages::fuse::cache::MemoryCache;
 use crate::storages::fuse::cache::TenantLabel;
 use crate::storages::fuse::io::snapshot_location;
+use crate::storages::fuse::io::table_prefix_of;
+use crate::storages::fuse::meta::MetaFormat;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::TableSnapshot;
 
@@ -102,6 +103,8 @@ impl<'a> TableSnapshotReader<'a> {
         latest_snapshot_location: Option<&String>,
     ) -> Result<Vec<Arc<TableSnapshot>>> {
         let mut snapshots = vec![];
+        // the snapshots of a table are kept under the same prefix
+        let prefix = latest_snapshot_location.map_or("", |loc| table_prefix_of(loc));
         let mut current_snapshot_location = latest_snapshot_location.cloned();
         while let Some(loc) = current_snapshot_location {
             let r = self.read(loc).await;
@@ -114,7 +117,7 @@ impl<'a> TableSnapshotReader<'a> {
             };
             let prev = snapshot.prev_snapshot_id;
             snapshots.push(snapshot);
-            current_snapshot_location = prev.map(|id| snapshot_location(&id));
+            current_snapshot_location = prev.map(|id| snapshot_location(prefix, &id));
         }
         Ok(snapshots)
     }
@@ -124,7 +127,7 @@ impl<'a> TableSnapshotReader<'a> {
 impl<T, V> Loader<V> for T
 where
     T: InputStreamProvider + Sync,
-    V: DeserializeOwned,
+    V: MetaFormat,
 {
     async fn load(&self, key: &str, length_hint: Option<u64>) -> Result<V> {
         let mut reader = self.input_stream(key, length_hint)?;
@@ -139,8 +142,8 @@ where
                 ErrorCode::DalTransportError(msg)
             }
         })?;
-        let r = serde_json::from_slice::<V>(&buffer)?;
-        Ok(r)
+        // dispatches on the format version of the meta file
+        V::decode(&buffer)
     }
 }
 
@@ -15,14 +15,22 @@
 mod block_reader;
 mod block_stream_writer;
 mod block_writer;
+mod bloom_filter_index;
 mod locations;
 mod meta_readers;
 
 pub use block_reader::BlockReader;
 pub use block_stream_writer::BlockStreamWriter;
 pub use block_stream_writer::SegmentInfoStream;
+pub use block_writer::write_block;
+pub use block_writer::BlockWriteOptions;
+pub use bloom_filter_index::read_bloom_filter_index;
+pub use bloom_filter_index::write_bloom_filter_index;
+pub use locations::bloom_filter_index_location;
+pub use locations::gen_block_location;
 pub use locations::gen_segment_info_location;
 pub use locations::snapshot_location;
+pub use locations::table_prefix_of;
 pub use meta_readers::BlockMetaCache;
 pub use meta_readers::MetaReaders;
 pub use meta_readers::SegmentInfoCache;
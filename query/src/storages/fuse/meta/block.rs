@@ -15,6 +15,8 @@
 
 use std::collections::HashMap;
 
+use common_datavalues::DataValue;
+
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::index::ColumnStatistics;
 
@@ -27,6 +29,48 @@ pub struct BlockMeta {
     pub file_size: u64,
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub location: BlockLocation,
+    /// The range of the cluster key, `None` if the table is not clustered
+    #[serde(default)]
+    pub cluster_stats: Option<ClusterStatistics>,
+    /// The bloom filters of the columns, `None` if no column is indexed
+    #[serde(default)]
+    pub bloom_filter_index: Option<BloomFilterIndexLocation>,
+    /// The compression and the encoding of the columns, empty if the block is written before
+    /// they are configurable, i.e. all the columns are lz4 compressed and plain encoded
+    #[serde(default)]
+    pub col_encodings: HashMap<ColumnId, ColumnEncoding>,
+}
+
+/// The min and max values of the cluster key of a block, the rows of a block are sorted
+/// by the cluster key, thus they are the cluster key of the first and the last row.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct ClusterStatistics {
+    pub min: Vec<DataValue>,
+    pub max: Vec<DataValue>,
+}
+
+/// Location of the bloom filter index file of a block, which is written alongside the block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BloomFilterIndexLocation {
+    pub path: String,
+    pub size: u64,
+}
+
+/// The compression codec of the column chunks of a block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockCompression {
+    None,
+    Lz4,
+    Zstd,
+    Snappy,
+}
+
+/// How a column of a block is written.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColumnEncoding {
+    pub compression: BlockCompression,
+    /// The column is dictionary encoded if true, otherwise plain encoded
+    pub dictionary: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -0,0 +1,304 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::TableSnapshot;
+
+/// Encoding of the meta files of fuse table.
+///
+/// Meta files are always encoded in the current format version, while all the known versions
+/// can be decoded, thus tables written by the early versions keep working.
+pub trait MetaFormat: Sized {
+    /// The format version used in encoding.
+    const FORMAT_VERSION: u64;
+
+    fn encode(&self) -> Result<Vec<u8>>;
+
+    fn decode(bytes: &[u8]) -> Result<Self>;
+}
+
+// Versions of snapshot:
+// - 0: json, without the `format_version` field
+// - 1: json
+impl MetaFormat for TableSnapshot {
+    const FORMAT_VERSION: u64 = 1;
+
+    fn encode(&self) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        match json_format_version(bytes)? {
+            0 | 1 => Ok(serde_json::from_slice(bytes)?),
+            v => Err(unsupported_version("snapshot", v)),
+        }
+    }
+}
+
+// Versions of segment:
+// - 0: json, without the `format_version` field
+// - 1: version (u64, little endian), followed by the lz4 compressed bincode of the segment
+// - 2: same as 1, with the `cluster_stats` of blocks
+// - 3: same as 2, with the `bloom_filter_index` of blocks
+// - 4: same as 3, with the `col_encodings` of blocks
+impl MetaFormat for SegmentInfo {
+    const FORMAT_VERSION: u64 = 4;
+
+    fn encode(&self) -> Result<Vec<u8>> {
+        let mut payload = BytesMut::new();
+        serialize_into_buf(&mut payload, self)?;
+        let compressed = lz4::block::compress(&payload, None, true)?;
+
+        let mut bytes = Vec::with_capacity(VERSION_HEADER_SIZE + compressed.len());
+        bytes.extend_from_slice(&Self::FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&compressed);
+        Ok(bytes)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        if is_json(bytes) {
+            return match json_format_version(bytes)? {
+                0 => Ok(serde_json::from_slice(bytes)?),
+                v => Err(unsupported_version("segment", v)),
+            };
+        }
+
+        match binary_format_version(bytes)? {
+            1 => {
+                let payload = lz4::block::decompress(&bytes[VERSION_HEADER_SIZE..], None)?;
+                let segment: v1::SegmentInfo = deserialize_from_slice(&mut payload.as_slice())?;
+                Ok(segment.into())
+            }
+            2 => {
+                let payload = lz4::block::decompress(&bytes[VERSION_HEADER_SIZE..], None)?;
+                let segment: v2::SegmentInfo = deserialize_from_slice(&mut payload.as_slice())?;
+                Ok(segment.into())
+            }
+            3 => {
+                let payload = lz4::block::decompress(&bytes[VERSION_HEADER_SIZE..], None)?;
+                let segment: v3::SegmentInfo = deserialize_from_slice(&mut payload.as_slice())?;
+                Ok(segment.into())
+            }
+            4 => {
+                let payload = lz4::block::decompress(&bytes[VERSION_HEADER_SIZE..], None)?;
+                deserialize_from_slice(&mut payload.as_slice())
+            }
+            v => Err(unsupported_version("segment", v)),
+        }
+    }
+}
+
+const VERSION_HEADER_SIZE: usize = std::mem::size_of::<u64>();
+
+#[derive(Deserialize)]
+struct JsonFormatVersion {
+    #[serde(default)]
+    format_version: u64,
+}
+
+fn is_json(bytes: &[u8]) -> bool {
+    bytes.first() == Some(&b'{')
+}
+
+fn json_format_version(bytes: &[u8]) -> Result<u64> {
+    let probe: JsonFormatVersion = serde_json::from_slice(bytes)?;
+    Ok(probe.format_version)
+}
+
+fn binary_format_version(bytes: &[u8]) -> Result<u64> {
+    match bytes.get(..VERSION_HEADER_SIZE) {
+        Some(header) => {
+            let mut version = [0u8; VERSION_HEADER_SIZE];
+            version.copy_from_slice(header);
+            Ok(u64::from_le_bytes(version))
+        }
+        None => Err(ErrorCode::InvalidMetaBinaryFormat(format!(
+            "meta file is too short, {} bytes",
+            bytes.len()
+        ))),
+    }
+}
+
+fn unsupported_version(kind: &str, version: u64) -> ErrorCode {
+    ErrorCode::UnsupportedFormatVersion(format!(
+        "unsupported format version of {}: {}, please upgrade the query node",
+        kind, version
+    ))
+}
+
+/// Layouts of the meta files of version 1, bincode can not skip the absent fields,
+/// thus the binary formats of the early versions are decoded by their own layouts.
+mod v1 {
+    use std::collections::HashMap;
+
+    use crate::storages::fuse::meta::BlockLocation;
+    use crate::storages::fuse::meta::ColumnId;
+    use crate::storages::fuse::meta::Statistics;
+    use crate::storages::index::ColumnStatistics;
+
+    #[derive(serde::Deserialize)]
+    pub struct SegmentInfo {
+        pub format_version: u64,
+        pub blocks: Vec<BlockMeta>,
+        pub summary: Statistics,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct BlockMeta {
+        pub row_count: u64,
+        pub block_size: u64,
+        pub file_size: u64,
+        pub col_stats: HashMap<ColumnId, ColumnStatistics>,
+        pub location: BlockLocation,
+    }
+
+    impl From<SegmentInfo> for super::SegmentInfo {
+        fn from(s: SegmentInfo) -> Self {
+            Self {
+                format_version: s.format_version,
+                blocks: s.blocks.into_iter().map(|b| b.into()).collect(),
+                summary: s.summary,
+            }
+        }
+    }
+
+    impl From<BlockMeta> for crate::storages::fuse::meta::BlockMeta {
+        fn from(b: BlockMeta) -> Self {
+            Self {
+                row_count: b.row_count,
+                block_size: b.block_size,
+                file_size: b.file_size,
+                col_stats: b.col_stats,
+                location: b.location,
+                cluster_stats: None,
+                bloom_filter_index: None,
+                col_encodings: HashMap::new(),
+            }
+        }
+    }
+}
+
+/// Layouts of the meta files of version 2.
+mod v2 {
+    use std::collections::HashMap;
+
+    use crate::storages::fuse::meta::BlockLocation;
+    use crate::storages::fuse::meta::ClusterStatistics;
+    use crate::storages::fuse::meta::ColumnId;
+    use crate::storages::fuse::meta::Statistics;
+    use crate::storages::index::ColumnStatistics;
+
+    #[derive(serde::Deserialize)]
+    pub struct SegmentInfo {
+        pub format_version: u64,
+        pub blocks: Vec<BlockMeta>,
+        pub summary: Statistics,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct BlockMeta {
+        pub row_count: u64,
+        pub block_size: u64,
+        pub file_size: u64,
+        pub col_stats: HashMap<ColumnId, ColumnStatistics>,
+        pub location: BlockLocation,
+        pub cluster_stats: Option<ClusterStatistics>,
+    }
+
+    impl From<SegmentInfo> for super::SegmentInfo {
+        fn from(s: SegmentInfo) -> Self {
+            Self {
+                format_version: s.format_version,
+                blocks: s.blocks.into_iter().map(|b| b.into()).collect(),
+                summary: s.summary,
+            }
+        }
+    }
+
+    impl From<BlockMeta> for crate::storages::fuse::meta::BlockMeta {
+        fn from(b: BlockMeta) -> Self {
+            Self {
+                row_count: b.row_count,
+                block_size: b.block_size,
+                file_size: b.file_size,
+                col_stats: b.col_stats,
+                location: b.location,
+                cluster_stats: b.cluster_stats,
+                bloom_filter_index: None,
+                col_encodings: HashMap::new(),
+            }
+        }
+    }
+}
+
+/// Layouts of the meta files of version 3.
+mod v3 {
+    use std::collections::HashMap;
+
+    use crate::storages::fuse::meta::BlockLocation;
+    use crate::storages::fuse::meta::BloomFilterIndexLocation;
+    use crate::storages::fuse::meta::ClusterStatistics;
+    use crate::storages::fuse::meta::ColumnId;
+    use crate::storages::fuse::meta::Statistics;
+    use crate::storages::index::ColumnStatistics;
+
+    #[derive(serde::Deserialize)]
+    pub struct SegmentInfo {
+        pub format_version: u64,
+        pub blocks: Vec<BlockMeta>,
+        pub summary: Statistics,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub struct BlockMeta {
+        pub row_count: u64,
+        pub block_size: u64,
+        pub file_size: u64,
+        pub col_stats: HashMap<ColumnId, ColumnStatistics>,
+        pub location: BlockLocation,
+        pub cluster_stats: Option<ClusterStatistics>,
+        pub bloom_filter_index: Option<BloomFilterIndexLocation>,
+    }
+
+    impl From<SegmentInfo> for super::SegmentInfo {
+        fn from(s: SegmentInfo) -> Self {
+            Self {
+                format_version: s.format_version,
+                blocks: s.blocks.into_iter().map(|b| b.into()).collect(),
+                summary: s.summary,
+            }
+        }
+    }
+
+    impl From<BlockMeta> for crate::storages::fuse::meta::BlockMeta {
+        fn from(b: BlockMeta) -> Self {
+            Self {
+                row_count: b.row_count,
+                block_size: b.block_size,
+                file_size: b.file_size,
+                col_stats: b.col_stats,
+                location: b.location,
+                cluster_stats: b.cluster_stats,
+                bloom_filter_index: b.bloom_filter_index,
+                col_encodings: HashMap::new(),
+            }
+        }
+    }
+}
@@ -14,11 +14,17 @@
 //
 
 mod block;
+mod format;
 mod segment;
 mod snapshot;
 
+pub use block::BlockCompression;
 pub use block::BlockLocation;
 pub use block::BlockMeta;
+pub use block::BloomFilterIndexLocation;
+pub use block::ClusterStatistics;
+pub use block::ColumnEncoding;
+pub use format::MetaFormat;
 pub use segment::SegmentInfo;
 pub use snapshot::ColumnId;
 pub use snapshot::Location;
@@ -19,8 +19,10 @@ use crate::storages::fuse::meta::Statistics;
 /// A segment comprised of one or more blocks
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct SegmentInfo {
-    // TODO format_version
-    // pub format_version: u32,
+    /// Version of the format, see `MetaFormat`
+    #[serde(default)]
+    pub format_version: u64,
+
     /// blocks belong to this segment
     pub blocks: Vec<BlockMeta>,
 
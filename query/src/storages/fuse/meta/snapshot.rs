@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_datavalues::DataSchema;
 use serde::Deserialize;
 use serde::Serialize;
@@ -27,16 +29,27 @@ pub type Location = String;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TableSnapshot {
-    // TODO format_version
-    // pub format_version: u32,
+    /// Version of the format, see `MetaFormat`
+    #[serde(default)]
+    pub format_version: u64,
+
     /// id of snapshot
     pub snapshot_id: SnapshotId,
 
     pub prev_snapshot_id: Option<SnapshotId>,
 
+    /// The time when the snapshot is created, None for snapshots of the early versions
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+
     /// For each snapshot, we keep a schema for it (in case of schema evolution)
     pub schema: DataSchema,
 
+    /// Stable ids of the columns, in the order of the fields of `schema`,
+    /// empty for snapshots of the early versions
+    #[serde(default)]
+    pub column_ids: Vec<ColumnId>,
+
     /// Summary Statistics
     pub summary: Statistics,
 
@@ -48,6 +61,16 @@ pub struct TableSnapshot {
 }
 
 impl TableSnapshot {
+    /// Returns the ids of the columns, for snapshots of the early versions, a column
+    /// is identified by its position in the schema.
+    pub fn column_ids(&self) -> Vec<ColumnId> {
+        if self.column_ids.is_empty() {
+            (0..self.schema.fields().len() as ColumnId).collect()
+        } else {
+            self.column_ids.clone()
+        }
+    }
+
     #[allow(dead_code)]
     #[must_use]
     pub fn append_segment(mut self, location: Location) -> TableSnapshot {
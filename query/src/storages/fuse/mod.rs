@@ -21,8 +21,16 @@ pub mod pruning;
 pub mod statistics;
 mod table;
 mod table_functions;
+mod ttl;
+mod vacuum;
 
 pub use constants::*;
 pub use table::FuseTable;
+pub use table_functions::ClusteringInformationTable;
 pub use table_functions::FuseHistoryTable;
+pub use table_functions::FUSE_FUNC_CLUSTERING;
 pub use table_functions::FUSE_FUNC_HIST;
+pub use ttl::BlockExpiration;
+pub use ttl::TableTtl;
+pub use vacuum::TableVacuum;
+pub use vacuum::VacuumStatus;
@@ -0,0 +1,222 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::UpdateTableMetaReq;
+use common_planners::AlterTableOperation;
+use common_planners::AlterTablePlan;
+use common_planners::Expression;
+use common_planners::RewriteHelper;
+use uuid::Uuid;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::meta::MetaFormat;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::table::encode_column_ids;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_BLOOM_FILTER_COLUMNS;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_IDS;
+use crate::storages::fuse::TBL_OPT_KEY_NEXT_COLUMN_ID;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+
+impl FuseTable {
+    /// Alters the columns of the table.
+    ///
+    /// Only the metadata is changed: blocks written before the alteration are left as they are,
+    /// and the columns of them are matched against the new schema by column id while reading.
+    pub async fn do_alter_table(
+        &self,
+        ctx: Arc<QueryContext>,
+        plan: &AlterTablePlan,
+    ) -> Result<()> {
+        let mut fields = self.table_info.schema().fields().clone();
+        let mut column_ids = self.column_ids()?;
+        let mut next_column_id = self.next_column_id()?;
+
+        match &plan.operation {
+            AlterTableOperation::AddColumn { field } => {
+                self.check_column_absent(&fields, field.name())?;
+                fields.push(field.clone());
+                column_ids.push(next_column_id);
+                next_column_id += 1;
+            }
+            AlterTableOperation::DropColumn { column_name } => {
+                let idx = self.column_position(&fields, column_name)?;
+                if fields.len() == 1 {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "can not drop the only column {} of table {}",
+                        column_name, self.table_info.desc
+                    )));
+                }
+                self.check_not_cluster_key(column_name)?;
+                self.check_not_ttl_column(column_name)?;
+                fields.remove(idx);
+                column_ids.remove(idx);
+            }
+            AlterTableOperation::RenameColumn {
+                old_column_name,
+                new_column_name,
+            } => {
+                let idx = self.column_position(&fields, old_column_name)?;
+                self.check_column_absent(&fields, new_column_name)?;
+                self.check_not_cluster_key(old_column_name)?;
+                self.check_not_ttl_column(old_column_name)?;
+                let field = &fields[idx];
+                fields[idx] = DataField::new(
+                    new_column_name,
+                    field.data_type().clone(),
+                    field.is_nullable(),
+                )
+                .with_default_expr(field.default_expr().clone());
+            }
+        }
+
+        let schema = DataSchemaRefExt::create(fields);
+        let mut new_table_meta = self.table_info.meta.clone();
+        new_table_meta.schema = schema.clone();
+        new_table_meta.options.insert(
+            TBL_OPT_KEY_COLUMN_IDS.to_owned(),
+            encode_column_ids(&column_ids),
+        );
+        new_table_meta.options.insert(
+            TBL_OPT_KEY_NEXT_COLUMN_ID.to_owned(),
+            next_column_id.to_string(),
+        );
+
+        // the indexed columns are kept by name, follow the renamed and dropped columns
+        if new_table_meta
+            .options
+            .contains_key(TBL_OPT_KEY_BLOOM_FILTER_COLUMNS)
+        {
+            let indexed_column_ids = self.bloom_filter_column_ids()?;
+            let indexed_columns = schema
+                .fields()
+                .iter()
+                .zip(column_ids.iter())
+                .filter(|(_, id)| indexed_column_ids.contains(id))
+                .map(|(field, _)| field.name().as_str())
+                .collect::<Vec<_>>();
+            new_table_meta.options.insert(
+                TBL_OPT_KEY_BLOOM_FILTER_COLUMNS.to_owned(),
+                indexed_columns.join(","),
+            );
+        }
+
+        // so are the options of the columns
+        let column_options = self
+            .block_write_options()?
+            .column_options(&schema, &column_ids);
+        for (key, value) in column_options {
+            if new_table_meta.options.contains_key(&key) {
+                new_table_meta.options.insert(key, value);
+            }
+        }
+
+        // the snapshot keeps the schema as well, so that time travel sees the columns
+        // as they were, a new snapshot referring to the same segments is committed
+        if let Some(prev_snapshot) = self.read_table_snapshot(ctx.as_ref()).await? {
+            let mut summary = prev_snapshot.summary.clone();
+            summary.col_stats.retain(|id, _| column_ids.contains(id));
+
+            let new_snapshot = TableSnapshot {
+                format_version: TableSnapshot::FORMAT_VERSION,
+                snapshot_id: Uuid::new_v4(),
+                prev_snapshot_id: Some(prev_snapshot.snapshot_id),
+                timestamp: Some(Utc::now()),
+                schema: schema.as_ref().clone(),
+                column_ids: column_ids.clone(),
+                summary,
+                segments: prev_snapshot.segments.clone(),
+            };
+            let new_snapshot_loc =
+                io::snapshot_location(&self.storage_prefix(), &new_snapshot.snapshot_id);
+            let da = ctx.get_storage_accessor()?;
+            da.put(&new_snapshot_loc, new_snapshot.encode()?).await?;
+            new_table_meta
+                .options
+                .insert(TBL_OPT_KEY_SNAPSHOT_LOC.to_owned(), new_snapshot_loc);
+        }
+
+        ctx.get_catalog()
+            .update_table_meta(UpdateTableMetaReq::new(
+                &self.table_info.ident,
+                new_table_meta,
+            ))
+            .await?;
+        Ok(())
+    }
+
+    fn column_position(&self, fields: &[DataField], column_name: &str) -> Result<usize> {
+        fields
+            .iter()
+            .position(|f| f.name() == column_name)
+            .ok_or_else(|| {
+                ErrorCode::UnknownColumn(format!(
+                    "Unknown column {} in table {}",
+                    column_name, self.table_info.desc
+                ))
+            })
+    }
+
+    // the cluster keys refer to the columns by name
+    fn check_not_cluster_key(&self, column_name: &str) -> Result<()> {
+        for key in self.cluster_keys()? {
+            let key_columns = RewriteHelper::expression_plan_columns(&key)?;
+            if key_columns.iter().any(|c| c.column_name() == column_name) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "column {} is used by the cluster keys of table {}",
+                    column_name, self.table_info.desc
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // so does the TTL
+    fn check_not_ttl_column(&self, column_name: &str) -> Result<()> {
+        let ttl = match &self.table_info.meta.ttl {
+            None => return Ok(()),
+            Some(ttl) => ttl,
+        };
+        let expr: Expression = serde_json::from_str(ttl)
+            .map_err(|e| ErrorCode::LogicalError(format!("invalid ttl {}, {}", ttl, e)))?;
+        let ttl_columns = RewriteHelper::expression_plan_columns(&expr)?;
+        if ttl_columns.iter().any(|c| c.column_name() == column_name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "column {} is used by the TTL of table {}",
+                column_name, self.table_info.desc
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_column_absent(&self, fields: &[DataField], column_name: &str) -> Result<()> {
+        match fields.iter().any(|f| f.name() == column_name) {
+            true => Err(ErrorCode::BadArguments(format!(
+                "column {} already exists in table {}",
+                column_name, self.table_info.desc
+            ))),
+            false => Ok(()),
+        }
+    }
+}
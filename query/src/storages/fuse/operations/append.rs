@@ -24,6 +24,7 @@ use futures::StreamExt;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
 use crate::storages::fuse::io::BlockStreamWriter;
+use crate::storages::fuse::meta::MetaFormat;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::FuseTable;
 use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
@@ -48,11 +49,17 @@ impl FuseTable {
         );
 
         let da = ctx.get_storage_accessor()?;
+        let location_prefix = self.storage_prefix();
 
         let mut segment_stream = BlockStreamWriter::write_block_stream(
             da.clone(),
+            location_prefix.clone(),
             stream,
             self.table_info.schema().clone(),
+            self.column_ids()?,
+            self.cluster_keys()?,
+            self.bloom_filter_column_ids()?,
+            self.block_write_options()?,
             chunk_block_num,
             block_size_threshold,
         )
@@ -62,8 +69,8 @@ impl FuseTable {
             while let Some(segment) = segment_stream.next().await {
                 let log_entry_res = match segment {
                     Ok(seg) => {
-                        let seg_loc = io::gen_segment_info_location();
-                        let bytes = serde_json::to_vec(&seg)?;
+                        let seg_loc = io::gen_segment_info_location(&location_prefix);
+                        let bytes = seg.encode()?;
                         da.put(&seg_loc, bytes).await?;
                         let log_entry = AppendOperationLogEntry::new(seg_loc, seg);
                         Ok(log_entry)
@@ -76,7 +83,7 @@ impl FuseTable {
         Ok(Box::pin(log_entries))
     }
 
-    fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
+    pub(crate) fn get_option<T: FromStr>(&self, opt_key: &str, default: T) -> T {
         self.table_info
             .options()
             .get(opt_key)
@@ -14,24 +14,35 @@
 //
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::Utc;
+use common_base::tokio;
 use common_datavalues::DataSchema;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
 use common_meta_types::UpsertTableOptionReply;
 use common_meta_types::UpsertTableOptionReq;
+use common_tracing::tracing;
+use rand::Rng;
 use uuid::Uuid;
 
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::MetaFormat;
 use crate::storages::fuse::meta::Statistics;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::operations::TableOperationLog;
 use crate::storages::fuse::statistics;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::OCC_DEFAULT_BACKOFF_INIT_DELAY_MS;
+use crate::storages::fuse::OCC_DEFAULT_BACKOFF_MAX_DELAY_MS;
+use crate::storages::fuse::OCC_DEFAULT_MAX_RETRIES;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 
 impl FuseTable {
@@ -42,43 +53,164 @@ impl FuseTable {
         operation_log: TableOperationLog,
         overwrite: bool,
     ) -> Result<()> {
-        // TODO OCC retry & resolves conflicts if applicable
-
-        let prev = self.read_table_snapshot(ctx.as_ref()).await?;
         let schema = self.table_info.meta.schema.as_ref().clone();
-        let (segments, summary) = Self::merge_append_operations(&schema, operation_log)?;
+        let column_ids = self.column_ids()?;
+        let (segments, summary) =
+            Self::merge_append_operations(&schema, &column_ids, operation_log)?;
         let rows_written = summary.row_count;
-        let new_snapshot = if overwrite {
-            TableSnapshot {
+
+        // Appending never conflicts with other operations: if the table has been changed
+        // since it was loaded, the appended segments are merged on top of the latest snapshot,
+        // and the commit is retried with backoff.
+        // Overwriting does conflict, it would discard the concurrent changes silently otherwise.
+        let mut latest: Option<FuseTable> = None;
+        let mut retries = 0;
+        let mut backoff_ms = OCC_DEFAULT_BACKOFF_INIT_DELAY_MS;
+        loop {
+            let table = latest.as_ref().unwrap_or(self);
+            let new_snapshot = table
+                .append_to_snapshot(ctx.as_ref(), segments.clone(), summary.clone(), overwrite)
+                .await?;
+
+            match table.try_commit_snapshot(ctx.as_ref(), &new_snapshot).await {
+                Ok(_) => break,
+                Err(e) if e.code() == ErrorCode::TableVersionMissMatch("").code() => {
+                    if overwrite {
+                        return Err(ErrorCode::TableCommitConflict(format!(
+                            "table {} is changed concurrently, {}",
+                            self.table_info.desc, e
+                        )));
+                    }
+                    if retries >= OCC_DEFAULT_MAX_RETRIES {
+                        return Err(ErrorCode::TableCommitConflict(format!(
+                            "commit to table {} failed after {} retries, {}",
+                            self.table_info.desc, retries, e
+                        )));
+                    }
+                    retries += 1;
+
+                    let delay_ms = backoff_ms + rand::thread_rng().gen_range(0..=backoff_ms);
+                    tracing::warn!(
+                        "table {} is changed concurrently, retry committing in {} ms ({}/{})",
+                        self.table_info.desc,
+                        delay_ms,
+                        retries,
+                        OCC_DEFAULT_MAX_RETRIES
+                    );
+                    tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                    backoff_ms = std::cmp::min(backoff_ms * 2, OCC_DEFAULT_BACKOFF_MAX_DELAY_MS);
+
+                    let refreshed = table.refresh(ctx.as_ref()).await?;
+                    latest = Some(refreshed);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        ctx.get_dal_context().inc_write_rows(rows_written as usize);
+        Ok(())
+    }
+
+    /// Saves the new snapshot, and makes it the current snapshot of the table.
+    ///
+    /// The snapshot is based on the version of the table that has been loaded, if the table is
+    /// changed concurrently, the commit fails with `TableCommitConflict`.
+    pub(crate) async fn commit_snapshot(
+        &self,
+        ctx: &QueryContext,
+        new_snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        self.try_commit_snapshot(ctx, new_snapshot)
+            .await
+            .map_err(
+                |e| match e.code() == ErrorCode::TableVersionMissMatch("").code() {
+                    true => ErrorCode::TableCommitConflict(format!(
+                        "table {} is changed concurrently, {}",
+                        self.table_info.desc, e
+                    )),
+                    false => e,
+                },
+            )
+    }
+
+    async fn try_commit_snapshot(
+        &self,
+        ctx: &QueryContext,
+        new_snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        let snapshot_loc = io::snapshot_location(&self.storage_prefix(), &new_snapshot.snapshot_id);
+        let bytes = new_snapshot.encode()?;
+        let da = ctx.get_storage_accessor()?;
+        da.put(&snapshot_loc, bytes).await?;
+
+        match self.commit_to_meta_server(ctx, snapshot_loc.clone()).await {
+            Ok(_) => Ok(()),
+            // the snapshot is known to be not referenced by anyone, best effort to clean it up.
+            // on other errors, e.g., a timeout, the commit may have succeeded, the snapshot is kept
+            Err(e) if e.code() == ErrorCode::TableVersionMissMatch("").code() => {
+                if let Err(remove_err) = da.remove(&snapshot_loc).await {
+                    tracing::warn!("failed to remove snapshot {}, {}", snapshot_loc, remove_err);
+                }
+                Err(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn append_to_snapshot(
+        &self,
+        ctx: &QueryContext,
+        segments: Vec<String>,
+        summary: Statistics,
+        overwrite: bool,
+    ) -> Result<TableSnapshot> {
+        let prev = self.read_table_snapshot(ctx).await?;
+        let schema = self.table_info.meta.schema.as_ref().clone();
+        let column_ids = self.column_ids()?;
+        if overwrite {
+            Ok(TableSnapshot {
+                format_version: TableSnapshot::FORMAT_VERSION,
                 snapshot_id: Uuid::new_v4(),
                 prev_snapshot_id: prev.as_ref().map(|v| v.snapshot_id),
+                timestamp: Some(Utc::now()),
                 schema,
+                column_ids,
                 summary,
                 segments,
-            }
+            })
         } else {
-            Self::merge_table_operations(
-                self.table_info.meta.schema.as_ref(),
-                prev,
-                segments,
-                summary,
-            )?
-        };
+            Self::merge_table_operations(&schema, column_ids, prev, segments, summary)
+        }
+    }
 
-        let uuid = new_snapshot.snapshot_id;
-        let snapshot_loc = io::snapshot_location(&uuid);
-        let bytes = serde_json::to_vec(&new_snapshot)?;
-        let da = ctx.get_storage_accessor()?;
-        da.put(&snapshot_loc, bytes).await?;
+    // Loads the latest version of the table.
+    async fn refresh(&self, ctx: &QueryContext) -> Result<FuseTable> {
+        let table_id = self.table_info.ident.table_id;
+        let (ident, meta) = ctx.get_catalog().get_table_meta_by_id(table_id).await?;
 
-        self.commit_to_meta_server(ctx.as_ref(), snapshot_loc)
-            .await?;
-        ctx.get_dal_context().inc_write_rows(rows_written as usize);
-        Ok(())
+        // the appended blocks are written in the schema of the loaded table
+        let latest = FuseTable {
+            table_info: TableInfo {
+                ident,
+                meta: meta.as_ref().clone(),
+                ..self.table_info.clone()
+            },
+        };
+        if meta.schema != self.table_info.meta.schema
+            || latest.column_ids()? != self.column_ids()?
+        {
+            return Err(ErrorCode::TableCommitConflict(format!(
+                "schema of table {} is changed concurrently",
+                self.table_info.desc
+            )));
+        }
+
+        Ok(latest)
     }
 
     fn merge_table_operations(
         schema: &DataSchema,
+        column_ids: Vec<ColumnId>,
         previous: Option<Arc<TableSnapshot>>,
         mut new_segments: Vec<String>,
         statistics: Statistics,
@@ -86,7 +218,7 @@ impl FuseTable {
         // 1. merge stats with previous snapshot, if any
         let stats = if let Some(snapshot) = &previous {
             let summary = &snapshot.summary;
-            statistics::merge_statistics(schema, &statistics, summary)?
+            statistics::merge_statistics(schema, &column_ids, &statistics, summary)?
         } else {
             statistics
         };
@@ -99,9 +231,12 @@ impl FuseTable {
         };
 
         let new_snapshot = TableSnapshot {
+            format_version: TableSnapshot::FORMAT_VERSION,
             snapshot_id: Uuid::new_v4(),
             prev_snapshot_id,
+            timestamp: Some(Utc::now()),
             schema: schema.clone(),
+            column_ids,
             summary: stats,
             segments: new_segments,
         };
@@ -130,6 +265,7 @@ impl FuseTable {
 
     pub fn merge_append_operations(
         schema: &DataSchema,
+        column_ids: &[ColumnId],
         append_log_entries: Vec<AppendOperationLogEntry>,
     ) -> Result<(Vec<String>, Statistics)> {
         let (s, seg_locs) = append_log_entries.iter().try_fold(
@@ -144,8 +280,11 @@ impl FuseTable {
                 acc.block_count += stats.block_count;
                 acc.uncompressed_byte_size += stats.uncompressed_byte_size;
                 acc.compressed_byte_size += stats.compressed_byte_size;
-                acc.col_stats =
-                    statistics::reduce_block_stats(&[&acc.col_stats, &stats.col_stats], schema)?;
+                acc.col_stats = statistics::reduce_block_stats(
+                    &[&acc.col_stats, &stats.col_stats],
+                    schema,
+                    column_ids,
+                )?;
                 seg_acc.push(loc.clone());
                 Ok::<_, ErrorCode>((acc, seg_acc))
             },
@@ -0,0 +1,286 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::Result;
+use uuid::Uuid;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::BlockWriteOptions;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ClusterStatistics;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::MetaFormat;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::operations::mutation;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::statistics::cluster;
+use crate::storages::fuse::statistics::BlockClusterer;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
+use crate::storages::fuse::DEFAULT_ROW_PER_BLOCK;
+use crate::storages::fuse::RECLUSTER_BATCH_SIZE_FACTOR;
+use crate::storages::fuse::TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
+use crate::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
+use crate::storages::fuse::TBL_OPT_KEY_ROW_PER_BLOCK;
+
+impl FuseTable {
+    /// Merges the under-sized blocks and the segments of the current snapshot.
+    ///
+    /// - a block is under-sized if both its row count and its size are less than the thresholds,
+    ///   successive under-sized blocks are merged until one of the thresholds is reached
+    /// - all the blocks are re-organized into segments of `CHUNK_BLOCK_NUM` blocks
+    /// - if the table is clustered, the blocks of which the ranges of the cluster key overlap are
+    ///   re-clustered first, so that the blocks are ordered by the cluster key without overlapping,
+    ///   they are re-clustered in batches of bounded size, see `group_overlapped_blocks`
+    pub async fn do_compact(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            // nothing to compact
+            None => return Ok(()),
+            Some(snapshot) => snapshot,
+        };
+
+        let location_prefix = self.storage_prefix();
+        let schema = self.table_info.schema();
+        let column_ids = self.column_ids()?;
+        let bloom_filter_column_ids = self.bloom_filter_column_ids()?;
+        let write_options = self.block_write_options()?;
+        let chunk_block_num = self.get_option(TBL_OPT_KEY_CHUNK_BLOCK_NUM, DEFAULT_CHUNK_BLOCK_NUM);
+        let row_per_block = self.get_option(TBL_OPT_KEY_ROW_PER_BLOCK, DEFAULT_ROW_PER_BLOCK);
+        let block_size_threshold = self.get_option(
+            TBL_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD,
+            DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD,
+        );
+
+        let reader = MetaReaders::segment_info_reader(ctx.as_ref());
+        let mut block_metas = vec![];
+        for segment_location in &snapshot.segments {
+            let segment = reader.read(segment_location).await?;
+            block_metas.extend(segment.blocks.iter().cloned());
+        }
+
+        let cluster_keys = self.cluster_keys()?;
+        let mut reclustered_blocks = 0;
+        if !cluster_keys.is_empty() {
+            let clusterer = BlockClusterer::new(cluster_keys);
+            let max_group_size = block_size_threshold.saturating_mul(RECLUSTER_BATCH_SIZE_FACTOR);
+            let (groups, mut unclustered) =
+                cluster::group_overlapped_blocks(block_metas, max_group_size);
+            block_metas = Vec::with_capacity(unclustered.len() + groups.len());
+            for group in groups {
+                if group.len() == 1 {
+                    block_metas.extend(group);
+                    continue;
+                }
+
+                reclustered_blocks += group.len();
+                block_metas.extend(
+                    Self::recluster_blocks(
+                        &ctx,
+                        &location_prefix,
+                        &schema,
+                        &column_ids,
+                        &bloom_filter_column_ids,
+                        &write_options,
+                        &clusterer,
+                        &group,
+                    )
+                    .await?,
+                );
+            }
+            // the blocks written before the table was clustered are kept at the end
+            block_metas.append(&mut unclustered);
+        }
+
+        let mut compacted = Vec::with_capacity(block_metas.len());
+        let mut merged_blocks = 0;
+
+        // the under-sized blocks that are waiting to be merged
+        let mut pending = vec![];
+        let mut pending_rows = 0;
+        let mut pending_size = 0;
+        for block_meta in block_metas {
+            if block_meta.row_count as usize >= row_per_block
+                || block_meta.block_size as usize >= block_size_threshold
+            {
+                compacted.push(block_meta);
+                continue;
+            }
+
+            pending_rows += block_meta.row_count as usize;
+            pending_size += block_meta.block_size as usize;
+            pending.push(block_meta);
+            if pending_rows >= row_per_block || pending_size >= block_size_threshold {
+                merged_blocks += pending.len();
+                let blocks = std::mem::take(&mut pending);
+                compacted.push(
+                    Self::merge_blocks(
+                        &ctx,
+                        &location_prefix,
+                        &schema,
+                        &column_ids,
+                        &bloom_filter_column_ids,
+                        &write_options,
+                        &blocks,
+                    )
+                    .await?,
+                );
+                pending_rows = 0;
+                pending_size = 0;
+            }
+        }
+
+        match pending.len() {
+            0 => {}
+            1 => compacted.append(&mut pending),
+            _ => {
+                merged_blocks += pending.len();
+                compacted.push(
+                    Self::merge_blocks(
+                        &ctx,
+                        &location_prefix,
+                        &schema,
+                        &column_ids,
+                        &bloom_filter_column_ids,
+                        &write_options,
+                        &pending,
+                    )
+                    .await?,
+                );
+            }
+        }
+
+        let chunk_block_num = std::cmp::max(chunk_block_num, 1);
+        let segment_num = (compacted.len() + chunk_block_num - 1) / chunk_block_num;
+        if merged_blocks == 0 && reclustered_blocks == 0 && snapshot.segments.len() <= segment_num {
+            // already compacted
+            return Ok(());
+        }
+
+        let mut segments = Vec::with_capacity(segment_num);
+        for blocks in compacted.chunks(chunk_block_num) {
+            let segment = SegmentInfo {
+                format_version: SegmentInfo::FORMAT_VERSION,
+                blocks: blocks.to_vec(),
+                summary: statistics::reduce_block_metas(blocks, schema.as_ref(), &column_ids)?,
+            };
+            segments.push(mutation::write_segment(&ctx, &location_prefix, &segment).await?);
+        }
+
+        let new_snapshot = TableSnapshot {
+            format_version: TableSnapshot::FORMAT_VERSION,
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(snapshot.snapshot_id),
+            timestamp: Some(Utc::now()),
+            schema: schema.as_ref().clone(),
+            summary: statistics::reduce_block_metas(&compacted, schema.as_ref(), &column_ids)?,
+            column_ids,
+            segments,
+        };
+        self.commit_snapshot(ctx.as_ref(), &new_snapshot).await
+    }
+
+    async fn merge_blocks(
+        ctx: &Arc<QueryContext>,
+        location_prefix: &str,
+        schema: &DataSchemaRef,
+        column_ids: &[ColumnId],
+        bloom_filter_column_ids: &[ColumnId],
+        write_options: &BlockWriteOptions,
+        block_metas: &[BlockMeta],
+    ) -> Result<BlockMeta> {
+        let mut blocks = Vec::with_capacity(block_metas.len());
+        for block_meta in block_metas {
+            blocks.push(mutation::read_block(ctx, schema, column_ids, block_meta).await?);
+        }
+
+        let block = DataBlock::concat_blocks(&blocks)?;
+        let cluster_stats = Self::merge_cluster_stats(block_metas);
+        mutation::write_block(
+            ctx,
+            location_prefix,
+            block,
+            column_ids,
+            cluster_stats,
+            bloom_filter_column_ids,
+            write_options,
+        )
+        .await
+    }
+
+    // blocks are merged in the order of the cluster key, the merged block keeps sorted
+    // only if all of them are clustered
+    fn merge_cluster_stats(block_metas: &[BlockMeta]) -> Option<ClusterStatistics> {
+        let first = block_metas.first()?.cluster_stats.as_ref()?;
+        let last = block_metas.last()?.cluster_stats.as_ref()?;
+        let sorted = block_metas.windows(2).all(|pair| {
+            match (&pair[0].cluster_stats, &pair[1].cluster_stats) {
+                (Some(l), Some(r)) => {
+                    cluster::compare_cluster_keys(&l.max, &r.min) != Ordering::Greater
+                }
+                _ => false,
+            }
+        });
+        match sorted {
+            true => Some(ClusterStatistics {
+                min: first.min.clone(),
+                max: last.max.clone(),
+            }),
+            false => None,
+        }
+    }
+
+    /// Sorts the rows of the overlapping blocks as a whole, and writes them into the same
+    /// number of blocks, which do not overlap with each other.
+    #[allow(clippy::too_many_arguments)]
+    async fn recluster_blocks(
+        ctx: &Arc<QueryContext>,
+        location_prefix: &str,
+        schema: &DataSchemaRef,
+        column_ids: &[ColumnId],
+        bloom_filter_column_ids: &[ColumnId],
+        write_options: &BlockWriteOptions,
+        clusterer: &BlockClusterer,
+        block_metas: &[BlockMeta],
+    ) -> Result<Vec<BlockMeta>> {
+        let mut blocks = Vec::with_capacity(block_metas.len());
+        for block_meta in block_metas {
+            blocks.push(mutation::read_block(ctx, schema, column_ids, block_meta).await?);
+        }
+
+        let mut reclustered = Vec::with_capacity(block_metas.len());
+        for (block, cluster_stats) in clusterer.cluster_blocks(&blocks)? {
+            let block_meta = mutation::write_block(
+                ctx,
+                location_prefix,
+                block,
+                column_ids,
+                Some(cluster_stats),
+                bloom_filter_column_ids,
+                write_options,
+            )
+            .await?;
+            reclustered.push(block_meta);
+        }
+        Ok(reclustered)
+    }
+}
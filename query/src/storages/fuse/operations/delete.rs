@@ -0,0 +1,151 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::DeletePlan;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::RewriteHelper;
+use uuid::Uuid;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::MetaFormat;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::operations::mutation::MutationFilter;
+use crate::storages::fuse::operations::mutation::SnapshotMutator;
+use crate::storages::fuse::pruning::BlockPruner;
+use crate::storages::fuse::FuseTable;
+use crate::storages::index::range_filter::negate_expr;
+use crate::storages::index::RangeFilter;
+
+impl FuseTable {
+    pub async fn do_delete(&self, ctx: Arc<QueryContext>, plan: &DeletePlan) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            // nothing to delete
+            None => return Ok(()),
+            Some(snapshot) => snapshot,
+        };
+
+        let new_snapshot = match &plan.selection {
+            None => TableSnapshot {
+                format_version: TableSnapshot::FORMAT_VERSION,
+                snapshot_id: Uuid::new_v4(),
+                prev_snapshot_id: Some(snapshot.snapshot_id),
+                timestamp: Some(Utc::now()),
+                schema: snapshot.schema.clone(),
+                column_ids: snapshot.column_ids(),
+                summary: Default::default(),
+                segments: vec![],
+            },
+            Some(filter) => self.delete_rows(&ctx, snapshot, filter).await?,
+        };
+
+        self.commit_snapshot(ctx.as_ref(), &new_snapshot).await
+    }
+
+    async fn delete_rows(
+        &self,
+        ctx: &Arc<QueryContext>,
+        snapshot: Arc<TableSnapshot>,
+        filter: &Expression,
+    ) -> Result<TableSnapshot> {
+        let schema = self.table_info.schema();
+        let column_ids = self.column_ids()?;
+
+        // blocks that can not match the filter are pruned
+        let push_downs = Some(Extras {
+            filters: vec![filter.clone()],
+            ..Extras::default()
+        });
+        let block_metas = BlockPruner::new(&snapshot)
+            .apply(schema.clone(), &push_downs, ctx.as_ref())
+            .await?;
+
+        let full_match_checker = FullMatchChecker::try_create(filter, schema.clone(), &column_ids)?;
+        let mutation_filter = MutationFilter::try_create(filter, &schema)?;
+
+        let mut mutator = SnapshotMutator::new(
+            ctx,
+            self.storage_prefix(),
+            schema,
+            column_ids,
+            self.cluster_keys()?,
+            self.bloom_filter_column_ids()?,
+            self.block_write_options()?,
+            snapshot,
+        );
+        for block_meta in &block_metas {
+            if full_match_checker.check(block_meta) {
+                mutator.remove_block(block_meta);
+                continue;
+            }
+
+            let block = mutator.read_block(block_meta).await?;
+            // the rows of which the filter is evaluated to false or NULL are kept
+            let remains = mutation_filter.remains(&block)?;
+            if remains.num_rows() != block.num_rows() {
+                mutator.replace_block(block_meta, remains).await?;
+            }
+        }
+
+        mutator.into_snapshot().await
+    }
+}
+
+/// Checks if all the rows of a block match the filter, by the statistics of the block.
+///
+/// A block matches completely if the negation of the filter can not be satisfied by any row,
+/// and there are no NULLs in the columns of the filter (NULLs satisfy neither the filter nor its negation).
+struct FullMatchChecker {
+    negation: RangeFilter,
+    columns: Vec<u32>,
+}
+
+impl FullMatchChecker {
+    fn try_create(
+        filter: &Expression,
+        schema: DataSchemaRef,
+        column_ids: &[ColumnId],
+    ) -> Result<Self> {
+        let mut columns = vec![];
+        for column in RewriteHelper::expression_plan_columns(filter)? {
+            if let Expression::Column(name) = column {
+                columns.push(column_ids[schema.index_of(&name)?]);
+            }
+        }
+
+        // the atoms which can not be negated are turned into `true`, the negation is relaxed so
+        // that the blocks are never treated as full matched wrongly.
+        let negation =
+            RangeFilter::try_create(&negate_expr(filter), schema)?.with_column_ids(column_ids);
+        Ok(FullMatchChecker { negation, columns })
+    }
+
+    fn check(&self, block_meta: &BlockMeta) -> bool {
+        let no_nulls = self
+            .columns
+            .iter()
+            .all(|id| matches!(block_meta.col_stats.get(id), Some(stats) if stats.null_count == 0));
+
+        // if the statistics can not be evaluated, the block is treated as partial matched
+        no_nulls && !self.negation.eval(&block_meta.col_stats).unwrap_or(true)
+    }
+}
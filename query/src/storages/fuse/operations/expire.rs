@@ -0,0 +1,83 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::MutationFilter;
+use crate::storages::fuse::operations::mutation::SnapshotMutator;
+use crate::storages::fuse::pruning::BlockPruner;
+use crate::storages::fuse::BlockExpiration;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    /// Removes the rows expired by the TTL of the table, returns the number of blocks removed
+    /// or rewritten.
+    ///
+    /// The blocks of which all the rows are expired are removed without being read, and the
+    /// blocks which are partially expired are rewritten with the remaining rows.
+    pub async fn do_expire(&self, ctx: &Arc<QueryContext>) -> Result<u64> {
+        let ttl = match self.ttl(Utc::now())? {
+            None => return Ok(0),
+            Some(ttl) => ttl,
+        };
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            None => return Ok(0),
+            Some(snapshot) => snapshot,
+        };
+
+        let schema = self.table_info.schema();
+        let block_metas = BlockPruner::new(&snapshot)
+            .apply(schema.clone(), &None, ctx.as_ref())
+            .await?;
+        let mutation_filter = MutationFilter::try_create(ttl.filter(), &schema)?;
+
+        let mut mutator = SnapshotMutator::new(
+            ctx,
+            self.storage_prefix(),
+            schema,
+            self.column_ids()?,
+            self.cluster_keys()?,
+            self.bloom_filter_column_ids()?,
+            self.block_write_options()?,
+            snapshot,
+        );
+        let mut blocks_expired = 0;
+        for block_meta in &block_metas {
+            match ttl.check(block_meta)? {
+                BlockExpiration::Live => continue,
+                BlockExpiration::Expired => mutator.remove_block(block_meta),
+                BlockExpiration::Partial => {
+                    let block = mutator.read_block(block_meta).await?;
+                    let remains = mutation_filter.remains(&block)?;
+                    if remains.num_rows() == block.num_rows() {
+                        continue;
+                    }
+                    mutator.replace_block(block_meta, remains).await?;
+                }
+            }
+            blocks_expired += 1;
+        }
+
+        if blocks_expired > 0 {
+            let new_snapshot = mutator.into_snapshot().await?;
+            self.commit_snapshot(ctx.as_ref(), &new_snapshot).await?;
+        }
+        Ok(blocks_expired)
+    }
+}
@@ -0,0 +1,311 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use uuid::Uuid;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::io::BlockReader;
+use crate::storages::fuse::io::BlockWriteOptions;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ClusterStatistics;
+use crate::storages::fuse::meta::ColumnId;
+use crate::storages::fuse::meta::MetaFormat;
+use crate::storages::fuse::meta::SegmentInfo;
+use crate::storages::fuse::meta::Statistics;
+use crate::storages::fuse::meta::TableSnapshot;
+use crate::storages::fuse::statistics;
+use crate::storages::fuse::statistics::BlockClusterer;
+use crate::storages::fuse::statistics::StatisticsAccumulator;
+
+/// Builds a new snapshot from a base snapshot, in a copy-on-write manner:
+///
+/// - blocks are removed or replaced by new blocks, the original blocks are left intact
+/// - segments that contain the mutated blocks are re-generated, others are shared with the base snapshot
+pub struct SnapshotMutator<'a> {
+    ctx: &'a Arc<QueryContext>,
+    location_prefix: String,
+    schema: DataSchemaRef,
+    column_ids: Vec<ColumnId>,
+    // sorts the replaced blocks, None if the table is not clustered
+    clusterer: Option<BlockClusterer>,
+    bloom_filter_column_ids: Vec<ColumnId>,
+    write_options: BlockWriteOptions,
+    base_snapshot: Arc<TableSnapshot>,
+    // location of the mutated block => the new block meta, None if it is removed
+    mutations: HashMap<String, Option<BlockMeta>>,
+}
+
+impl<'a> SnapshotMutator<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        ctx: &'a Arc<QueryContext>,
+        location_prefix: String,
+        schema: DataSchemaRef,
+        column_ids: Vec<ColumnId>,
+        cluster_keys: Vec<Expression>,
+        bloom_filter_column_ids: Vec<ColumnId>,
+        write_options: BlockWriteOptions,
+        base_snapshot: Arc<TableSnapshot>,
+    ) -> Self {
+        let clusterer = match cluster_keys.is_empty() {
+            true => None,
+            false => Some(BlockClusterer::new(cluster_keys)),
+        };
+        Self {
+            ctx,
+            location_prefix,
+            schema,
+            column_ids,
+            clusterer,
+            bloom_filter_column_ids,
+            write_options,
+            base_snapshot,
+            mutations: HashMap::new(),
+        }
+    }
+
+    /// Reads all the columns of the given block.
+    pub async fn read_block(&self, block_meta: &BlockMeta) -> Result<DataBlock> {
+        read_block(self.ctx, &self.schema, &self.column_ids, block_meta).await
+    }
+
+    pub fn remove_block(&mut self, block_meta: &BlockMeta) {
+        self.mutations
+            .insert(block_meta.location.path.clone(), None);
+    }
+
+    /// Replaces the given block by `block`, the block is removed if `block` is empty.
+    pub async fn replace_block(&mut self, block_meta: &BlockMeta, block: DataBlock) -> Result<()> {
+        if block.num_rows() == 0 {
+            self.remove_block(block_meta);
+            return Ok(());
+        }
+
+        let (block, cluster_stats) = match &self.clusterer {
+            None => (block, None),
+            Some(clusterer) => {
+                let (block, stats) = clusterer.cluster(block)?;
+                (block, Some(stats))
+            }
+        };
+        let new_block_meta = write_block(
+            self.ctx,
+            &self.location_prefix,
+            block,
+            &self.column_ids,
+            cluster_stats,
+            &self.bloom_filter_column_ids,
+            &self.write_options,
+        )
+        .await?;
+        self.mutations
+            .insert(block_meta.location.path.clone(), Some(new_block_meta));
+        Ok(())
+    }
+
+    /// Generates the mutated segments, and returns the new snapshot(not committed yet).
+    pub async fn into_snapshot(self) -> Result<TableSnapshot> {
+        let schema = self.schema.as_ref();
+        let column_ids = self.column_ids.as_slice();
+        let reader = MetaReaders::segment_info_reader(self.ctx.as_ref());
+
+        let mut segments = Vec::with_capacity(self.base_snapshot.segments.len());
+        let mut summary = Statistics::default();
+        for segment_location in &self.base_snapshot.segments {
+            let segment = reader.read(segment_location).await?;
+
+            let mutated = segment
+                .blocks
+                .iter()
+                .any(|block_meta| self.mutations.contains_key(&block_meta.location.path));
+            if !mutated {
+                // shared with the base snapshot
+                summary =
+                    statistics::merge_statistics(schema, column_ids, &summary, &segment.summary)?;
+                segments.push(segment_location.clone());
+                continue;
+            }
+
+            let blocks = segment
+                .blocks
+                .iter()
+                .filter_map(
+                    |block_meta| match self.mutations.get(&block_meta.location.path) {
+                        None => Some(block_meta.clone()),
+                        Some(new_block_meta) => new_block_meta.clone(),
+                    },
+                )
+                .collect::<Vec<_>>();
+
+            if blocks.is_empty() {
+                // all the blocks of this segment are removed
+                continue;
+            }
+
+            let new_segment = SegmentInfo {
+                format_version: SegmentInfo::FORMAT_VERSION,
+                summary: statistics::reduce_block_metas(&blocks, schema, column_ids)?,
+                blocks,
+            };
+            summary =
+                statistics::merge_statistics(schema, column_ids, &summary, &new_segment.summary)?;
+            segments.push(write_segment(self.ctx, &self.location_prefix, &new_segment).await?);
+        }
+
+        Ok(TableSnapshot {
+            format_version: TableSnapshot::FORMAT_VERSION,
+            snapshot_id: Uuid::new_v4(),
+            prev_snapshot_id: Some(self.base_snapshot.snapshot_id),
+            timestamp: Some(Utc::now()),
+            schema: schema.clone(),
+            column_ids: self.column_ids.clone(),
+            summary,
+            segments,
+        })
+    }
+}
+
+/// Reads all the columns of the given block.
+pub async fn read_block(
+    ctx: &Arc<QueryContext>,
+    schema: &DataSchemaRef,
+    column_ids: &[ColumnId],
+    block_meta: &BlockMeta,
+) -> Result<DataBlock> {
+    let projection = (0..schema.fields().len()).collect::<Vec<_>>();
+    let read_buffer_size = ctx.get_settings().get_storage_read_buffer_size()?;
+    let mut block_reader = BlockReader::new(
+        ctx.get_storage_accessor()?,
+        block_meta.location.path.clone(),
+        schema.clone(),
+        column_ids.to_vec(),
+        projection,
+        block_meta.file_size,
+        read_buffer_size,
+        MetaReaders::block_meta_reader(ctx.clone()),
+    );
+
+    block_reader.read().await.map_err(|e| {
+        ErrorCode::ParquetError(format!(
+            "fail to read block {}, {}",
+            block_meta.location.path, e
+        ))
+    })
+}
+
+/// Writes the block, and returns the meta of the written block.
+///
+/// `cluster_stats` is the range of the cluster key if the block is sorted by it, and the bloom
+/// filters of the columns of `bloom_filter_column_ids` are written along with the block.
+pub async fn write_block(
+    ctx: &Arc<QueryContext>,
+    location_prefix: &str,
+    block: DataBlock,
+    column_ids: &[ColumnId],
+    cluster_stats: Option<ClusterStatistics>,
+    bloom_filter_column_ids: &[ColumnId],
+    write_options: &BlockWriteOptions,
+) -> Result<BlockMeta> {
+    let location = io::gen_block_location(location_prefix);
+    let da = ctx.get_storage_accessor()?;
+    let bloom_filter_index =
+        io::write_bloom_filter_index(&block, column_ids, bloom_filter_column_ids, &da, &location)
+            .await?;
+    let acc = StatisticsAccumulator::new()
+        .begin(&block, column_ids, cluster_stats)?
+        .with_bloom_filter_index(bloom_filter_index)
+        .with_col_encodings(write_options.column_encodings(column_ids));
+    let arrow_schema = block.schema().to_arrow();
+    let file_size = io::write_block(
+        &arrow_schema,
+        block,
+        column_ids,
+        write_options,
+        da,
+        &location,
+    )
+    .await?;
+    let mut acc = acc.end(file_size, location);
+    acc.blocks_metas.pop().ok_or_else(|| {
+        ErrorCode::LogicalError("Block meta must be generated after writing a block")
+    })
+}
+
+/// Writes the segment, and returns the location of it.
+pub async fn write_segment(
+    ctx: &Arc<QueryContext>,
+    location_prefix: &str,
+    segment: &SegmentInfo,
+) -> Result<String> {
+    let location = io::gen_segment_info_location(location_prefix);
+    let bytes = segment.encode()?;
+    ctx.get_storage_accessor()?.put(&location, bytes).await?;
+    Ok(location)
+}
+
+/// Evaluates the selection of a mutation(DELETE/UPDATE) against the rows of a block.
+pub struct MutationFilter {
+    executor: ExpressionExecutor,
+}
+
+impl MutationFilter {
+    pub fn try_create(filter: &Expression, schema: &DataSchemaRef) -> Result<Self> {
+        let filter_field = filter.to_data_field(schema)?;
+        let executor = ExpressionExecutor::try_create(
+            "mutation filter executor",
+            schema.clone(),
+            DataSchemaRefExt::create(vec![filter_field]),
+            vec![filter.clone()],
+            false,
+        )?;
+        executor.validate()?;
+        Ok(MutationFilter { executor })
+    }
+
+    /// Returns the rows of which the filter is evaluated to true, NULL is treated as false.
+    pub fn matched_rows(&self, block: &DataBlock) -> Result<Vec<bool>> {
+        let filter_result = self.executor.execute(block)?;
+        let filter_column = filter_result.column(0).cast_with_type(&DataType::Boolean)?;
+        Ok(filter_column
+            .to_array()?
+            .bool()?
+            .collect_values()
+            .iter()
+            .map(|v| v.unwrap_or(false))
+            .collect::<Vec<_>>())
+    }
+
+    /// Returns the rows of which the filter is evaluated to false or NULL.
+    pub fn remains(&self, block: &DataBlock) -> Result<DataBlock> {
+        let remains = self
+            .matched_rows(block)?
+            .iter()
+            .map(|matched| !matched)
+            .collect::<Vec<_>>();
+        DataBlock::filter_block(block, &Series::new(remains).into())
+    }
+}
@@ -0,0 +1,76 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use uuid::Uuid;
+
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::table::encode_column_ids;
+use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_IDS;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use crate::storages::NavigationPoint;
+use crate::storages::Table;
+
+impl FuseTable {
+    pub async fn do_navigate(
+        &self,
+        ctx: Arc<QueryContext>,
+        point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+        let snapshots = reader
+            .read_snapshot_history(self.snapshot_loc().as_ref())
+            .await?;
+
+        // the history is ordered from the latest snapshot to the earliest one
+        let snapshot = match point {
+            NavigationPoint::SnapshotID(id) => {
+                let id = Uuid::parse_str(id).map_err(|e| {
+                    ErrorCode::BadArguments(format!("invalid snapshot id {}, {}", id, e))
+                })?;
+                snapshots.into_iter().find(|s| s.snapshot_id == id)
+            }
+            NavigationPoint::TimePoint(time_point) => snapshots
+                .into_iter()
+                .find(|s| matches!(s.timestamp, Some(ts) if ts <= *time_point)),
+        };
+
+        let snapshot = snapshot.ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "no historical data of table {} is found at {:?}",
+                self.table_info.desc, point
+            ))
+        })?;
+
+        // the table is read in the schema of that snapshot
+        let mut table_info = self.table_info.clone();
+        table_info.meta.schema = Arc::new(snapshot.schema.clone());
+        table_info.meta.options.insert(
+            TBL_OPT_KEY_COLUMN_IDS.to_owned(),
+            encode_column_ids(&snapshot.column_ids()),
+        );
+        table_info.meta.options.insert(
+            TBL_OPT_KEY_SNAPSHOT_LOC.to_owned(),
+            io::snapshot_location(&self.storage_prefix(), &snapshot.snapshot_id),
+        );
+
+        Ok(Arc::new(FuseTable { table_info }))
+    }
+}
@@ -16,114 +16,347 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
 use common_cache::Cache;
 use common_dal::DataAccessor;
+use common_dal::ObjectMeta;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_tracing::tracing;
+use futures::TryStreamExt;
+use uuid::Uuid;
 
+use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io::snapshot_location;
 use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::MetaFormat;
+use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::FuseTable;
+use crate::storages::fuse::FUSE_TBL_BLOCK_PREFIX;
+use crate::storages::fuse::FUSE_TBL_SEGMENT_PREFIX;
+use crate::storages::fuse::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::storages::fuse::TBL_OPT_KEY_MAX_SNAPSHOTS;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
-use crate::storages::Table;
+use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_RETENTION_PERIOD;
 
+// The garbage collection of fuse table.
+//
+// 1. a new root snapshot, which has no previous snapshot, is committed first; after that, the
+//    history of the table is no longer reachable from the table.
+// 2. the unreachable snapshots, segments and blocks are removed, from top to bottom. removing
+//    a missing object is not an error, thus the removal is idempotent.
+// 3. objects under the prefix of the table, which are not reachable from the latest snapshot of
+//    the table, are removed, if they are older than the `table_orphan_retention_secs` of the
+//    server config. this resumes the removals interrupted previously, and cleans up the files
+//    left by failed insertions. the objects of the other tables are never touched.
+//
+// A dropped table is purged from the meta by the vacuum after the `table_dropped_retention_secs`
+// of the server config, and then all of its objects are removed, see `do_purge`.
 impl FuseTable {
     pub async fn do_optimize(
         &self,
         ctx: Arc<QueryContext>,
         keep_last_snapshot: bool,
     ) -> Result<()> {
-        let da = ctx.get_storage_accessor()?;
-        let tbl_info = self.get_table_info();
-        let snapshot_loc = tbl_info.meta.options.get(TBL_OPT_KEY_SNAPSHOT_LOC);
         let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
-        let mut snapshots = reader.read_snapshot_history(snapshot_loc).await?;
+        let snapshots = reader
+            .read_snapshot_history(self.snapshot_loc().as_ref())
+            .await?;
 
-        let min_history_len = if !keep_last_snapshot { 0 } else { 1 };
+        if !keep_last_snapshot {
+            // the table is dropped or truncated, nothing is reachable from this version of table
+            return self.remove_history(ctx.as_ref(), &[], &snapshots).await;
+        }
 
-        // short cut
-        if snapshots.len() <= min_history_len {
-            return Ok(());
+        if snapshots.len() > 1 {
+            // the content of the new root is exactly the same as the current snapshot
+            let root = TableSnapshot {
+                format_version: TableSnapshot::FORMAT_VERSION,
+                snapshot_id: Uuid::new_v4(),
+                prev_snapshot_id: None,
+                ..snapshots[0].as_ref().clone()
+            };
+            self.commit_snapshot(ctx.as_ref(), &root).await?;
+            self.remove_history(ctx.as_ref(), &[Arc::new(root)], &snapshots)
+                .await?;
         }
 
-        let current_segments: HashSet<&String>;
-        let current_snapshot;
-        if !keep_last_snapshot {
-            // if truncate_all requested, gc root contains nothing;
-            current_segments = HashSet::new();
-        } else {
-            current_snapshot = snapshots.remove(0);
-            current_segments = HashSet::from_iter(&current_snapshot.segments);
+        self.remove_orphans(ctx.as_ref()).await
+    }
+
+    // Removes the snapshots which are expired according to the table options
+    // `snapshot_retention_period` (in seconds) and `max_snapshots`, returns the number of
+    // snapshots removed. The current snapshot is always kept.
+    pub async fn do_vacuum(&self, ctx: &QueryContext) -> Result<u64> {
+        if !self.vacuum_enabled() {
+            return Ok(0);
         }
 
-        let prevs = snapshots.iter().fold(HashSet::new(), |mut acc, s| {
+        let max_snapshots = self
+            .get_option(TBL_OPT_KEY_MAX_SNAPSHOTS, usize::MAX)
+            .max(1);
+        let expire_before = self
+            .table_info
+            .options()
+            .get(TBL_OPT_KEY_SNAPSHOT_RETENTION_PERIOD)
+            .and_then(|s| s.parse::<i64>().ok())
+            .map(|secs| Utc::now() - Duration::seconds(secs));
+
+        let reader = MetaReaders::table_snapshot_reader(ctx);
+        let snapshots = reader
+            .read_snapshot_history(self.snapshot_loc().as_ref())
+            .await?;
+
+        let retained = snapshots
+            .iter()
+            .enumerate()
+            .take_while(|(idx, snapshot)| {
+                *idx == 0
+                    || (*idx < max_snapshots
+                        && match expire_before {
+                            None => true,
+                            Some(t) => matches!(snapshot.timestamp, Some(ts) if ts >= t),
+                        })
+            })
+            .count();
+
+        if retained == snapshots.len() {
+            return Ok(0);
+        }
+
+        let (retained, expired) = snapshots.split_at(retained);
+        self.remove_history(ctx, retained, expired).await?;
+        Ok(expired.len() as u64)
+    }
+
+    // Removes all the objects of the table, which has been purged from the meta, thus nothing is
+    // reachable from it any more.
+    pub async fn do_purge(&self, ctx: &QueryContext) -> Result<()> {
+        let reader = MetaReaders::table_snapshot_reader(ctx);
+        let snapshots = reader
+            .read_snapshot_history(self.snapshot_loc().as_ref())
+            .await?;
+        self.remove_history(ctx, &[], &snapshots).await?;
+
+        // the objects not reachable from the history, e.g., left by the failed insertions
+        self.remove_unreachable(ctx, Utc::now(), false).await
+    }
+
+    pub(crate) fn vacuum_enabled(&self) -> bool {
+        let options = self.table_info.options();
+        options.contains_key(TBL_OPT_KEY_MAX_SNAPSHOTS)
+            || options.contains_key(TBL_OPT_KEY_SNAPSHOT_RETENTION_PERIOD)
+    }
+
+    // Removes the objects of the history, which are not referenced by the retained snapshots.
+    async fn remove_history(
+        &self,
+        ctx: &QueryContext,
+        retained: &[Arc<TableSnapshot>],
+        history: &[Arc<TableSnapshot>],
+    ) -> Result<()> {
+        let root_segments = retained.iter().fold(HashSet::new(), |mut acc, s| {
+            acc.extend(&s.segments);
+            acc
+        });
+        let history_segments = history.iter().fold(HashSet::new(), |mut acc, s| {
             acc.extend(&s.segments);
             acc
         });
 
         // segments which no longer need to be kept
-        let seg_delta = prevs.difference(&current_segments).collect::<Vec<_>>();
+        let seg_delta = history_segments
+            .difference(&root_segments)
+            .collect::<Vec<_>>();
 
         // blocks to be removed
-        let prev_blocks: HashSet<String> = self.blocks_of(seg_delta.iter(), ctx.clone()).await?;
-        let current_blocks: HashSet<String> =
-            self.blocks_of(current_segments.iter(), ctx.clone()).await?;
+        let prev_blocks = self.blocks_of(ctx, seg_delta.iter()).await?;
+        let current_blocks = self.blocks_of(ctx, root_segments.iter()).await?;
         let block_delta = prev_blocks.difference(&current_blocks);
 
-        // NOTE: the following actions are NOT transactional yet
+        let da = ctx.get_storage_accessor()?;
+        let prefix = self.storage_prefix();
 
-        // 1. remove blocks
-        for x in block_delta {
-            self.remove_location(da.clone(), x).await?;
-            if let Some(c) = ctx.get_storage_cache_manager().get_block_meta_cache() {
-                let cache = &mut *c.write().await;
-                cache.pop(x.as_str());
-            }
+        // 1. remove the snapshots, from the oldest one. if interrupted, the remaining history
+        //    is still linked to the retained snapshots.
+        for x in history.iter().rev() {
+            let loc = snapshot_location(&prefix, &x.snapshot_id);
+            Self::remove_snapshot(ctx, da.as_ref(), loc.as_str()).await?;
         }
 
         // 2. remove the segments
         for x in seg_delta {
-            self.remove_location(da.clone(), x.as_str()).await?;
-            if let Some(c) = ctx.get_storage_cache_manager().get_table_segment_cache() {
-                let cache = &mut *c.write().await;
-                cache.pop(x.as_str());
-            }
+            Self::remove_segment(ctx, da.as_ref(), x.as_str()).await?;
+        }
+
+        // 3. remove blocks
+        for x in block_delta {
+            Self::remove_block(ctx, da.as_ref(), x.as_str()).await?;
         }
 
-        // 3. remove the snapshots
-        for x in snapshots.iter().rev() {
-            let loc = snapshot_location(&x.snapshot_id);
-            self.remove_location(da.clone(), loc.as_str()).await?;
-            if let Some(c) = ctx.get_storage_cache_manager().get_table_snapshot_cache() {
-                let cache = &mut *c.write().await;
-                cache.pop(loc.as_str());
+        Ok(())
+    }
+
+    // Removes the objects under the prefix of the table, which are not reachable from it, and
+    // older than the `table_orphan_retention_secs` of the server config.
+    pub(crate) async fn remove_orphans(&self, ctx: &QueryContext) -> Result<()> {
+        let retention_secs = ctx.get_config().query.table_orphan_retention_secs;
+        let expire_before = Utc::now() - Duration::seconds(retention_secs as i64);
+        self.remove_unreachable(ctx, expire_before, true).await
+    }
+
+    // Removes the objects under the prefix of the table, which are older than `expire_before`,
+    // except the ones reachable from the latest version of the table if `keep_reachable`.
+    async fn remove_unreachable(
+        &self,
+        ctx: &QueryContext,
+        expire_before: DateTime<Utc>,
+        keep_reachable: bool,
+    ) -> Result<()> {
+        let prefix = self.storage_prefix();
+        if prefix.is_empty() {
+            // the objects of the tables created by older versions are mixed under the root
+            tracing::warn!(
+                "orphan files of table {} are not removed, it has no prefix of its own",
+                self.table_info.desc
+            );
+            return Ok(());
+        }
+
+        let expired =
+            |object: &ObjectMeta| matches!(object.last_modified, Some(t) if t < expire_before);
+
+        // objects are listed before the reachable ones are collected, objects created after the
+        // listing are not considered.
+        let da = ctx.get_storage_accessor()?;
+        let mut candidates = vec![];
+        for kind in [
+            FUSE_TBL_SNAPSHOT_PREFIX,
+            FUSE_TBL_SEGMENT_PREFIX,
+            FUSE_TBL_BLOCK_PREFIX,
+        ] {
+            let objects = match da.list(&format!("{}/{}/", prefix, kind)).await {
+                Ok(objects) => objects,
+                Err(e) if e.code() == ErrorCode::UnImplement("").code() => {
+                    tracing::warn!("orphan files are not removed, {}", e);
+                    return Ok(());
+                }
+                Err(e) => return Err(e),
+            };
+            let expired_objects = objects
+                .try_filter(|o| futures::future::ready(expired(o)))
+                .map_ok(|o| (kind, o.path))
+                .try_collect::<Vec<_>>()
+                .await?;
+            candidates.extend(expired_objects);
+        }
+
+        if candidates.is_empty() {
+            return Ok(());
+        }
+
+        let reachable = match keep_reachable {
+            true => self.reachable_objects(ctx, &prefix).await?,
+            false => HashSet::new(),
+        };
+        for (kind, path) in candidates {
+            if reachable.contains(&path) {
+                continue;
+            }
+
+            tracing::info!("removing unreachable file {}", path);
+            match kind {
+                FUSE_TBL_SNAPSHOT_PREFIX => Self::remove_snapshot(ctx, da.as_ref(), &path).await?,
+                FUSE_TBL_SEGMENT_PREFIX => Self::remove_segment(ctx, da.as_ref(), &path).await?,
+                _ => Self::remove_block(ctx, da.as_ref(), &path).await?,
             }
         }
 
         Ok(())
     }
 
+    // Collects all the objects which are reachable from the latest version of the table, which
+    // may be committed after this version is loaded.
+    async fn reachable_objects(&self, ctx: &QueryContext, prefix: &str) -> Result<HashSet<String>> {
+        let table_id = self.table_info.ident.table_id;
+        let (_, meta) = ctx.get_catalog().get_table_meta_by_id(table_id).await?;
+        let reader = MetaReaders::table_snapshot_reader(ctx);
+        let snapshots = reader
+            .read_snapshot_history(meta.options.get(TBL_OPT_KEY_SNAPSHOT_LOC))
+            .await?;
+
+        let mut reachable = HashSet::new();
+        let mut segments = HashSet::new();
+        for snapshot in &snapshots {
+            reachable.insert(snapshot_location(prefix, &snapshot.snapshot_id));
+            segments.extend(snapshot.segments.iter());
+        }
+
+        reachable.extend(self.blocks_of(ctx, segments.iter()).await?);
+        reachable.extend(segments.into_iter().cloned());
+        Ok(reachable)
+    }
+
+    // Collects the locations of the blocks of the segments, with their bloom filter indexes
     async fn blocks_of(
         &self,
+        ctx: &QueryContext,
         locations: impl Iterator<Item = impl AsRef<str>>,
-        ctx: Arc<QueryContext>,
     ) -> Result<HashSet<String>> {
         let mut result = HashSet::new();
-        let reader = MetaReaders::segment_info_reader(ctx.as_ref());
+        let reader = MetaReaders::segment_info_reader(ctx);
         for location in locations {
-            let res = reader.read(location).await?;
+            let res = match reader.read(location).await {
+                Ok(res) => res,
+                // removed by the previous garbage collection
+                Err(e) if e.code() == ErrorCode::dal_path_not_found_code() => continue,
+                Err(e) => return Err(e),
+            };
             for block_meta in &res.blocks {
                 result.insert(block_meta.location.path.clone());
+                if let Some(bloom_filter_index) = &block_meta.bloom_filter_index {
+                    result.insert(bloom_filter_index.path.clone());
+                }
             }
         }
         Ok(result)
     }
 
-    async fn remove_location(
-        &self,
-        data_accessor: Arc<dyn DataAccessor>,
-        location: impl AsRef<str>,
-    ) -> Result<()> {
-        data_accessor.remove(location.as_ref()).await
+    async fn remove_snapshot(ctx: &QueryContext, da: &dyn DataAccessor, loc: &str) -> Result<()> {
+        Self::remove_location(da, loc).await?;
+        if let Some(c) = ctx.get_storage_cache_manager().get_table_snapshot_cache() {
+            let cache = &mut *c.write().await;
+            cache.pop(loc);
+        }
+        Ok(())
+    }
+
+    async fn remove_segment(ctx: &QueryContext, da: &dyn DataAccessor, loc: &str) -> Result<()> {
+        Self::remove_location(da, loc).await?;
+        if let Some(c) = ctx.get_storage_cache_manager().get_table_segment_cache() {
+            let cache = &mut *c.write().await;
+            cache.pop(loc);
+        }
+        Ok(())
+    }
+
+    async fn remove_block(ctx: &QueryContext, da: &dyn DataAccessor, loc: &str) -> Result<()> {
+        Self::remove_location(da, loc).await?;
+        if let Some(c) = ctx.get_storage_cache_manager().get_block_meta_cache() {
+            let cache = &mut *c.write().await;
+            cache.pop(loc);
+        }
+        Ok(())
+    }
+
+    async fn remove_location(da: &dyn DataAccessor, location: &str) -> Result<()> {
+        match da.remove(location).await {
+            // it has been removed, maybe by an interrupted garbage collection
+            Err(e) if e.code() == ErrorCode::dal_path_not_found_code() => Ok(()),
+            other => other,
+        }
     }
 }
@@ -15,7 +15,8 @@
 
 use std::sync::Arc;
 
-use common_datavalues::DataSchema;
+use chrono::Utc;
+use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Extras;
@@ -27,7 +28,9 @@ use super::part_info::PartInfo;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io::BlockReader;
 use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::operations::mutation::MutationFilter;
 use crate::storages::fuse::FuseTable;
+use crate::storages::index::RangeFilter;
 
 impl FuseTable {
     #[inline]
@@ -60,8 +63,41 @@ impl FuseTable {
             )
             .flatten();
         let da = ctx.get_storage_accessor()?;
-        let arrow_schema = self.table_info.schema().to_arrow();
-        let table_schema = Arc::new(DataSchema::from(arrow_schema)); // TODO is this self.table_info?
+        // the default expressions of the columns are kept in the table schema
+        let table_schema = self.table_info.schema();
+        let column_ids = self.column_ids()?;
+
+        // the pages of the blocks are pruned by the filters as well
+        let page_filter = match push_downs {
+            Some(Extras { filters, .. }) if !filters.is_empty() => {
+                let filter = filters[1..]
+                    .iter()
+                    .fold(filters[0].clone(), |acc, expr| acc.and(expr.clone()));
+                let range_filter = RangeFilter::try_create(&filter, table_schema.clone())?
+                    .with_column_ids(&column_ids);
+                Some(Arc::new(range_filter))
+            }
+            _ => None,
+        };
+
+        // the rows expired by the TTL are filtered out, before they are actually removed,
+        // the column of the TTL is read along with the projection for that
+        let output_schema = Arc::new(table_schema.project(projection.clone()));
+        let mut read_projection = projection.clone();
+        let ttl_filter = match self.ttl(Utc::now())? {
+            None => None,
+            Some(ttl) => {
+                let ttl_column = table_schema.index_of(ttl.column_name())?;
+                if !read_projection.contains(&ttl_column) {
+                    read_projection.push(ttl_column);
+                }
+                let read_schema = Arc::new(table_schema.project(read_projection.clone()));
+                Some(Arc::new(MutationFilter::try_create(
+                    ttl.filter(),
+                    &read_schema,
+                )?))
+            }
+        };
 
         let part_stream = futures::stream::iter(iter);
 
@@ -70,7 +106,11 @@ impl FuseTable {
             .map(move |part| {
                 let da = da.clone();
                 let table_schema = table_schema.clone();
-                let projection = projection.clone();
+                let column_ids = column_ids.clone();
+                let projection = read_projection.clone();
+                let page_filter = page_filter.clone();
+                let ttl_filter = ttl_filter.clone();
+                let output_schema = output_schema.clone();
                 let reader = MetaReaders::block_meta_reader(ctx.clone());
                 async move {
                     let part_info = PartInfo::decode(&part.name)?;
@@ -81,17 +121,29 @@ impl FuseTable {
                         da,
                         part_info.location().to_owned(),
                         table_schema,
+                        column_ids,
                         projection,
                         part_len,
                         read_buffer_size,
                         reader,
-                    );
-                    block_reader.read().await.map_err(|e| {
+                    )
+                    .with_page_filter(page_filter);
+                    let block = block_reader.read().await.map_err(|e| {
                         ErrorCode::ParquetError(format!(
                             "fail to read block {}, {}",
                             part_location, e
                         ))
-                    })
+                    })?;
+
+                    match ttl_filter {
+                        None => Ok(block),
+                        Some(ttl_filter) => {
+                            let remains = ttl_filter.remains(&block)?;
+                            let columns =
+                                remains.columns()[..output_schema.fields().len()].to_vec();
+                            Ok(DataBlock::create(output_schema, columns))
+                        }
+                    }
                 }
             })
             .buffer_unordered(bite_size as usize)
@@ -16,6 +16,7 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_exception::Result;
 use common_planners::Extras;
 use common_planners::Part;
@@ -24,6 +25,7 @@ use common_planners::Statistics;
 
 use crate::sessions::QueryContext;
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::operations::part_info::PartInfo;
 use crate::storages::fuse::pruning::BlockPruner;
 use crate::storages::fuse::FuseTable;
@@ -39,12 +41,18 @@ impl FuseTable {
         match snapshot {
             Some(snapshot) => {
                 let schema = self.table_info.schema();
-                let block_metas = BlockPruner::new(&snapshot)
+                let mut block_metas = BlockPruner::new(&snapshot)
                     .apply(schema, &push_downs, ctx.as_ref())
                     .await?;
+                // the blocks expired by the TTL are skipped, before they are actually removed
+                if let Some(ttl) = self.ttl(Utc::now())? {
+                    block_metas = ttl.prune(block_metas)?;
+                }
                 ctx.get_dal_context()
                     .inc_partitions_scanned(block_metas.len());
-                let (statistics, parts) = Self::to_partitions(&block_metas, push_downs);
+                let column_ids = self.column_ids()?;
+                let (statistics, parts) =
+                    Self::to_partitions(&block_metas, &column_ids, push_downs);
                 Ok((statistics, parts))
             }
             None => Ok((Statistics::default(), vec![])),
@@ -53,10 +61,16 @@ impl FuseTable {
 
     pub fn to_partitions(
         blocks_metas: &[BlockMeta], // TODO is &[&BlockMeta] enough?
+        column_ids: &[ColumnId],
         push_downs: Option<Extras>,
     ) -> (Statistics, Partitions) {
-        let proj_cols =
-            push_downs.and_then(|extras| extras.projection.map(HashSet::<usize>::from_iter));
+        let proj_cols = push_downs.and_then(|extras| {
+            extras.projection.map(|proj| {
+                proj.iter()
+                    .map(|idx| column_ids[*idx])
+                    .collect::<HashSet<_>>()
+            })
+        });
         blocks_metas.iter().fold(
             (Statistics::default(), Partitions::default()),
             |(mut stats, mut parts), block_meta| {
@@ -70,7 +84,7 @@ impl FuseTable {
                         stats.read_bytes += block_meta
                             .col_stats
                             .iter()
-                            .filter(|(cid, _)| proj.contains(&(**cid as usize)))
+                            .filter(|(cid, _)| proj.contains(*cid))
                             .map(|(_, col_stats)| col_stats.in_memory_size)
                             .sum::<u64>() as usize
                     }
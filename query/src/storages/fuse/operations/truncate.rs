@@ -15,6 +15,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use common_exception::Result;
 use common_meta_types::UpsertTableOptionReq;
 use common_planners::TruncateTablePlan;
@@ -23,6 +24,7 @@ use uuid::Uuid;
 use crate::catalogs::Catalog;
 use crate::sessions::QueryContext;
 use crate::storages::fuse::io;
+use crate::storages::fuse::meta::MetaFormat;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::FuseTable;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
@@ -31,24 +33,27 @@ impl FuseTable {
     #[inline]
     pub async fn do_truncate(&self, ctx: Arc<QueryContext>, plan: TruncateTablePlan) -> Result<()> {
         if let Some(prev_snapshot) = self.read_table_snapshot(ctx.as_ref()).await? {
-            let prev_id = prev_snapshot.snapshot_id;
+            // if purge is requested, the new snapshot is the root of the history
+            let prev_id = match plan.purge {
+                true => None,
+                false => Some(prev_snapshot.snapshot_id),
+            };
 
             let new_snapshot = TableSnapshot {
+                format_version: TableSnapshot::FORMAT_VERSION,
                 snapshot_id: Uuid::new_v4(),
-                prev_snapshot_id: Some(prev_id),
+                prev_snapshot_id: prev_id,
+                timestamp: Some(Utc::now()),
                 schema: prev_snapshot.schema.clone(),
+                column_ids: prev_snapshot.column_ids(),
                 summary: Default::default(),
                 segments: vec![],
             };
-            let new_snapshot_loc = io::snapshot_location(&new_snapshot.snapshot_id);
+            let new_snapshot_loc =
+                io::snapshot_location(&self.storage_prefix(), &new_snapshot.snapshot_id);
             let da = ctx.get_storage_accessor()?;
-            let bytes = serde_json::to_vec(&new_snapshot)?;
+            let bytes = new_snapshot.encode()?;
             da.put(&new_snapshot_loc, bytes).await?;
-
-            if plan.purge {
-                let keep_last_snapshot = false;
-                self.do_optimize(ctx.clone(), keep_last_snapshot).await?
-            }
             ctx.get_catalog()
                 .upsert_table_option(UpsertTableOptionReq::new(
                     &self.table_info.ident,
@@ -56,6 +61,12 @@ impl FuseTable {
                     new_snapshot_loc,
                 ))
                 .await?;
+
+            // the previous history is removed after the new snapshot is committed
+            if plan.purge {
+                let keep_last_snapshot = false;
+                self.do_optimize(ctx.clone(), keep_last_snapshot).await?
+            }
         }
 
         Ok(())
@@ -0,0 +1,124 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::UpdatePlan;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::operations::mutation::MutationFilter;
+use crate::storages::fuse::operations::mutation::SnapshotMutator;
+use crate::storages::fuse::pruning::BlockPruner;
+use crate::storages::fuse::FuseTable;
+
+impl FuseTable {
+    pub async fn do_update(&self, ctx: Arc<QueryContext>, plan: &UpdatePlan) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            // nothing to update
+            None => return Ok(()),
+            Some(snapshot) => snapshot,
+        };
+
+        let schema = self.table_info.schema();
+
+        // blocks that can not match the selection are pruned
+        let push_downs = Some(Extras {
+            filters: plan.selection.iter().cloned().collect(),
+            ..Extras::default()
+        });
+        let block_metas = BlockPruner::new(&snapshot)
+            .apply(schema.clone(), &push_downs, ctx.as_ref())
+            .await?;
+
+        let mutation_filter = match &plan.selection {
+            None => None,
+            Some(selection) => Some(MutationFilter::try_create(selection, &schema)?),
+        };
+        let update_executor = Self::update_executor(&schema, plan)?;
+
+        let mut mutator = SnapshotMutator::new(
+            &ctx,
+            self.storage_prefix(),
+            schema,
+            self.column_ids()?,
+            self.cluster_keys()?,
+            self.bloom_filter_column_ids()?,
+            self.block_write_options()?,
+            snapshot,
+        );
+        for block_meta in &block_metas {
+            let block = mutator.read_block(block_meta).await?;
+            if let Some(mutation_filter) = &mutation_filter {
+                // blocks without any matched rows are left intact
+                if !mutation_filter.matched_rows(&block)?.contains(&true) {
+                    continue;
+                }
+            }
+
+            let new_block = update_executor.execute(&block)?;
+            mutator.replace_block(block_meta, new_block).await?;
+        }
+
+        let new_snapshot = mutator.into_snapshot().await?;
+        self.commit_snapshot(ctx.as_ref(), &new_snapshot).await
+    }
+
+    // Projects the rows of a block to the updated rows, of the same schema as the table:
+    //
+    // - the column which is not assigned is kept as it is
+    // - the assigned column is projected to `if(selection, value, column)`, or `value` if
+    //   there is no selection
+    fn update_executor(schema: &DataSchemaRef, plan: &UpdatePlan) -> Result<ExpressionExecutor> {
+        let assignments = plan
+            .assignments
+            .iter()
+            .map(|(column_name, value)| (column_name.as_str(), value))
+            .collect::<HashMap<_, _>>();
+
+        let mut exprs = Vec::with_capacity(schema.fields().len());
+        for field in schema.fields() {
+            let column = Expression::Column(field.name().clone());
+            let expr = match assignments.get(field.name().as_str()) {
+                None => column,
+                Some(value) => {
+                    let value = match &plan.selection {
+                        None => (*value).clone(),
+                        Some(selection) => Expression::ScalarFunction {
+                            op: "if".to_string(),
+                            args: vec![selection.clone(), (*value).clone(), column],
+                        },
+                    };
+                    Expression::Alias(field.name().clone(), Box::new(value))
+                }
+            };
+            exprs.push(expr);
+        }
+
+        let executor = ExpressionExecutor::try_create(
+            "update executor",
+            schema.clone(),
+            schema.clone(),
+            exprs,
+            true,
+        )?;
+        executor.validate()?;
+        Ok(executor)
+    }
+}
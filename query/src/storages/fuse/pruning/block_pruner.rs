@@ -14,6 +14,7 @@
 //
 
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Extras;
 use common_tracing::tracing;
@@ -21,23 +22,27 @@ use futures::StreamExt;
 use futures::TryStreamExt;
 
 use crate::sessions::QueryContext;
-use crate::storages::fuse::io::snapshot_location;
+use crate::storages::fuse::io::read_bloom_filter_index;
 use crate::storages::fuse::io::MetaReaders;
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::SegmentInfo;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::index::BlockStatistics;
+use crate::storages::index::BloomFilterPredicate;
 use crate::storages::index::RangeFilter;
 
 pub struct BlockPruner {
-    table_snapshot_location: String,
+    segment_locations: Vec<String>,
+    column_ids: Vec<ColumnId>,
 }
 
 type Pred = Box<dyn Fn(&BlockStatistics) -> Result<bool> + Send + Sync + Unpin>;
 impl BlockPruner {
     pub fn new(table_snapshot: &TableSnapshot) -> Self {
         Self {
-            table_snapshot_location: snapshot_location(&table_snapshot.snapshot_id),
+            segment_locations: table_snapshot.segments.clone(),
+            column_ids: table_snapshot.column_ids(),
         }
     }
 
@@ -48,19 +53,26 @@ impl BlockPruner {
         push_down: &Option<Extras>,
         ctx: &QueryContext,
     ) -> Result<Vec<BlockMeta>> {
-        let block_pred: Pred = match push_down {
+        let (block_pred, bloom_pred): (Pred, _) = match push_down {
             Some(exprs) if !exprs.filters.is_empty() => {
-                // for the time being, we only handle the first expr
-                let verifiable_expression = RangeFilter::try_create(&exprs.filters[0], schema)?;
-                Box::new(move |v: &BlockStatistics| verifiable_expression.eval(v))
+                // the filters are conjuncts
+                let filter = exprs.filters[1..]
+                    .iter()
+                    .fold(exprs.filters[0].clone(), |acc, expr| acc.and(expr.clone()));
+                let bloom_pred = BloomFilterPredicate::try_create(&filter, &schema)?
+                    .map(|pred| pred.with_column_ids(&self.column_ids));
+                let verifiable_expression =
+                    RangeFilter::try_create(&filter, schema)?.with_column_ids(&self.column_ids);
+                (
+                    Box::new(move |v: &BlockStatistics| verifiable_expression.eval(v)),
+                    bloom_pred,
+                )
             }
-            _ => Box::new(|_: &BlockStatistics| Ok(true)),
+            _ => (Box::new(|_: &BlockStatistics| Ok(true)), None),
         };
 
-        let reader = MetaReaders::table_snapshot_reader(ctx);
-        let snapshot = reader.read(self.table_snapshot_location.as_str()).await?;
-        let segment_num = snapshot.segments.len();
-        let segment_locs = snapshot.segments.clone();
+        let segment_num = self.segment_locations.len();
+        let segment_locs = self.segment_locations.clone();
 
         if segment_locs.is_empty() {
             return Ok(vec![]);
@@ -79,7 +91,44 @@ impl BlockPruner {
             .into_iter()
             .flatten();
 
-        Ok(res.collect())
+        match bloom_pred {
+            None => Ok(res.collect()),
+            Some(bloom_pred) => {
+                Self::filter_by_bloom_filters(res.collect(), &bloom_pred, ctx).await
+            }
+        }
+    }
+
+    // Removes the blocks of which the bloom filters tell that no row can match. Blocks without
+    // bloom filters are kept.
+    async fn filter_by_bloom_filters(
+        block_metas: Vec<BlockMeta>,
+        pred: &BloomFilterPredicate,
+        ctx: &QueryContext,
+    ) -> Result<Vec<BlockMeta>> {
+        if block_metas.is_empty() {
+            return Ok(block_metas);
+        }
+
+        let da = ctx.get_storage_accessor()?;
+        let block_num = block_metas.len();
+        let res = futures::stream::iter(block_metas)
+            .map(|block_meta| async {
+                let matched = match &block_meta.bloom_filter_index {
+                    None => true,
+                    Some(location) => {
+                        let index = read_bloom_filter_index(da.as_ref(), location).await?;
+                        pred.eval(&index)
+                    }
+                };
+                Ok::<_, ErrorCode>(matched.then(|| block_meta))
+            })
+            // configuration of the max size of buffered futures
+            .buffered(std::cmp::min(10, block_num))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(res.into_iter().flatten().collect())
     }
 
     #[inline]
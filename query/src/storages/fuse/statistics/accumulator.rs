@@ -21,6 +21,9 @@ use common_datavalues::DataSchema;
 
 use crate::storages::fuse::meta::BlockLocation;
 use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::BloomFilterIndexLocation;
+use crate::storages::fuse::meta::ClusterStatistics;
+use crate::storages::fuse::meta::ColumnEncoding;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
@@ -40,30 +43,47 @@ impl StatisticsAccumulator {
         Default::default()
     }
 
-    pub fn begin(mut self, block: &DataBlock) -> common_exception::Result<PartiallyAccumulated> {
+    pub fn begin(
+        mut self,
+        block: &DataBlock,
+        column_ids: &[ColumnId],
+        cluster_stats: Option<ClusterStatistics>,
+    ) -> common_exception::Result<PartiallyAccumulated> {
         let row_count = block.num_rows() as u64;
         let block_in_memory_size = block.memory_size() as u64;
 
         self.summary_block_count += 1;
         self.summary_row_count += row_count;
         self.in_memory_size += block_in_memory_size;
-        let block_stats = Self::acc_columns(block)?;
+        let block_stats = Self::acc_columns(block, column_ids)?;
         self.blocks_statistics.push(block_stats.clone());
         Ok(PartiallyAccumulated {
             accumulator: self,
             block_row_count: block.num_rows() as u64,
             block_size: block.memory_size() as u64,
             block_column_statistics: block_stats,
+            block_cluster_statistics: cluster_stats,
+            block_bloom_filter_index: None,
+            block_col_encodings: HashMap::new(),
         })
     }
 
-    pub fn summary(&self, schema: &DataSchema) -> common_exception::Result<BlockStatistics> {
-        super::reduce_block_stats(&self.blocks_statistics, schema)
+    pub fn summary(
+        &self,
+        schema: &DataSchema,
+        column_ids: &[ColumnId],
+    ) -> common_exception::Result<BlockStatistics> {
+        super::reduce_block_stats(&self.blocks_statistics, schema, column_ids)
     }
 
-    pub fn acc_columns(data_block: &DataBlock) -> common_exception::Result<BlockStatistics> {
-        (0..)
-            .into_iter()
+    /// Collects the statistics of the columns of the block, keyed by the given column ids.
+    pub fn acc_columns(
+        data_block: &DataBlock,
+        column_ids: &[ColumnId],
+    ) -> common_exception::Result<BlockStatistics> {
+        column_ids
+            .iter()
+            .copied()
             .zip(data_block.columns().iter())
             .map(|(idx, col)| {
                 let min = match col {
@@ -107,9 +127,24 @@ pub struct PartiallyAccumulated {
     block_row_count: u64,
     block_size: u64,
     block_column_statistics: HashMap<ColumnId, ColumnStatistics>,
+    block_cluster_statistics: Option<ClusterStatistics>,
+    block_bloom_filter_index: Option<BloomFilterIndexLocation>,
+    block_col_encodings: HashMap<ColumnId, ColumnEncoding>,
 }
 
 impl PartiallyAccumulated {
+    #[must_use]
+    pub fn with_bloom_filter_index(mut self, location: Option<BloomFilterIndexLocation>) -> Self {
+        self.block_bloom_filter_index = location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_col_encodings(mut self, col_encodings: HashMap<ColumnId, ColumnEncoding>) -> Self {
+        self.block_col_encodings = col_encodings;
+        self
+    }
+
     pub fn end(mut self, file_size: u64, location: String) -> StatisticsAccumulator {
         let mut stats = &mut self.accumulator;
         stats.file_size += file_size;
@@ -122,6 +157,9 @@ impl PartiallyAccumulated {
             block_size: self.block_size,
             file_size,
             col_stats: self.block_column_statistics,
+            cluster_stats: self.block_cluster_statistics,
+            bloom_filter_index: self.block_bloom_filter_index,
+            col_encodings: self.block_col_encodings,
         };
         stats.blocks_metas.push(block_meta);
         self.accumulator
@@ -0,0 +1,308 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::cmp::Ordering;
+
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_planners::Expression;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ClusterStatistics;
+
+/// Sorts the rows of blocks by the cluster key of a table.
+pub struct BlockClusterer {
+    cluster_keys: Vec<Expression>,
+}
+
+impl BlockClusterer {
+    pub fn new(cluster_keys: Vec<Expression>) -> Self {
+        Self { cluster_keys }
+    }
+
+    /// Returns the block sorted by the cluster key, along with the range of the cluster key.
+    pub fn cluster(&self, block: DataBlock) -> Result<(DataBlock, ClusterStatistics)> {
+        let schema = block.schema().clone();
+        let num_columns = block.num_columns();
+        let keys = self.eval_cluster_keys(&block)?;
+
+        // the cluster keys are appended to the block, so that the rows can be sorted by them
+        let mut sort_block = block;
+        let mut sort_columns = Vec::with_capacity(keys.num_columns());
+        for (idx, field) in keys.schema().fields().iter().enumerate() {
+            let name = format!("_cluster_key_{}", idx);
+            let key_field = DataField::new(&name, field.data_type().clone(), field.is_nullable());
+            sort_block = sort_block.add_column(keys.column(idx).clone(), key_field)?;
+            sort_columns.push(SortColumnDescription {
+                column_name: name,
+                asc: true,
+                nulls_first: true,
+            });
+        }
+
+        let sorted = DataBlock::sort_block(&sort_block, &sort_columns, None)?;
+        let last_row = sorted.num_rows().saturating_sub(1);
+        let key_columns = &sorted.columns()[num_columns..];
+        let min = key_columns
+            .iter()
+            .map(|column| column.try_get(0))
+            .collect::<Result<Vec<_>>>()?;
+        let max = key_columns
+            .iter()
+            .map(|column| column.try_get(last_row))
+            .collect::<Result<Vec<_>>>()?;
+
+        let columns = sorted.columns()[..num_columns].to_vec();
+        Ok((DataBlock::create(schema, columns), ClusterStatistics {
+            min,
+            max,
+        }))
+    }
+
+    /// Sorts the rows of all the blocks by the cluster key as a whole, and splits them into
+    /// the same number of blocks, each with the range of the cluster key.
+    pub fn cluster_blocks(
+        &self,
+        blocks: &[DataBlock],
+    ) -> Result<Vec<(DataBlock, ClusterStatistics)>> {
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let (sorted, _) = self.cluster(DataBlock::concat_blocks(blocks)?)?;
+        let num_rows = sorted.num_rows();
+        let rows_per_block = (num_rows + blocks.len() - 1) / blocks.len();
+        DataBlock::split_block_by_size(&sorted, rows_per_block.max(1))?
+            .into_iter()
+            .map(|block| {
+                let stats = self.cluster_stats(&block)?;
+                Ok((block, stats))
+            })
+            .collect()
+    }
+
+    /// Returns the range of the cluster key of a block which is already sorted by the key.
+    pub fn cluster_stats(&self, sorted: &DataBlock) -> Result<ClusterStatistics> {
+        let keys = self.eval_cluster_keys(sorted)?;
+        let last_row = keys.num_rows().saturating_sub(1);
+        let min = keys
+            .columns()
+            .iter()
+            .map(|column| column.try_get(0))
+            .collect::<Result<Vec<_>>>()?;
+        let max = keys
+            .columns()
+            .iter()
+            .map(|column| column.try_get(last_row))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ClusterStatistics { min, max })
+    }
+
+    fn eval_cluster_keys(&self, block: &DataBlock) -> Result<DataBlock> {
+        let schema = block.schema();
+        let fields = self
+            .cluster_keys
+            .iter()
+            .map(|key| key.to_data_field(schema))
+            .collect::<Result<Vec<_>>>()?;
+        let executor = ExpressionExecutor::try_create(
+            "cluster key executor",
+            schema.clone(),
+            DataSchemaRefExt::create(fields),
+            self.cluster_keys.clone(),
+            false,
+        )?;
+        executor.validate()?;
+        executor.execute(block)
+    }
+}
+
+/// Compares two cluster keys, NULL is less than any other value.
+pub fn compare_cluster_keys(l: &[DataValue], r: &[DataValue]) -> Ordering {
+    l.iter()
+        .zip(r.iter())
+        .map(|(l, r)| compare_values(l, r))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| l.len().cmp(&r.len()))
+}
+
+fn compare_values(l: &DataValue, r: &DataValue) -> Ordering {
+    match (l.is_null(), r.is_null()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        (false, false) => {}
+    }
+
+    match (l, r) {
+        (DataValue::String(Some(l)), DataValue::String(Some(r))) => l.cmp(r),
+        (DataValue::Boolean(Some(l)), DataValue::Boolean(Some(r))) => l.cmp(r),
+        _ if l.is_signed_integer() && r.is_signed_integer() => {
+            l.as_i64().ok().cmp(&r.as_i64().ok())
+        }
+        _ if l.is_unsigned_integer() && r.is_unsigned_integer() => {
+            l.as_u64().ok().cmp(&r.as_u64().ok())
+        }
+        _ => match (l.as_f64(), r.as_f64()) {
+            (Ok(l), Ok(r)) => l.partial_cmp(&r).unwrap_or(Ordering::Equal),
+            _ => Ordering::Equal,
+        },
+    }
+}
+
+/// Returns true if the ranges of the cluster key of the two blocks overlap.
+pub fn is_overlapped(l: &ClusterStatistics, r: &ClusterStatistics) -> bool {
+    compare_cluster_keys(&l.min, &r.max) != Ordering::Greater
+        && compare_cluster_keys(&r.min, &l.max) != Ordering::Greater
+}
+
+/// Splits the blocks into groups of overlapping blocks, ordered by the cluster key.
+///
+/// Two blocks are in the same group if their ranges overlap, directly or through other blocks
+/// of the group. The blocks without cluster statistics are not grouped.
+///
+/// A group is re-clustered in memory as a whole, thus it is closed once the in-memory size of
+/// its blocks would exceed `max_group_size`, and the following overlapping blocks start another
+/// group. The groups split this way may still overlap with each other, which are re-clustered
+/// by the next compactions.
+pub fn group_overlapped_blocks(
+    block_metas: Vec<BlockMeta>,
+    max_group_size: usize,
+) -> (Vec<Vec<BlockMeta>>, Vec<BlockMeta>) {
+    let (mut clustered, unclustered): (Vec<_>, Vec<_>) = block_metas
+        .into_iter()
+        .partition(|block_meta| block_meta.cluster_stats.is_some());
+    clustered.sort_by(|l, r| compare_cluster_keys(&min_key(l), &min_key(r)));
+
+    let mut groups: Vec<Vec<BlockMeta>> = vec![];
+    // the max cluster key and the in-memory size of the last group
+    let mut group_max: Vec<DataValue> = vec![];
+    let mut group_size = 0;
+    for block_meta in clustered {
+        let stats = block_meta
+            .cluster_stats
+            .clone()
+            .unwrap_or_else(|| ClusterStatistics {
+                min: vec![],
+                max: vec![],
+            });
+        let block_size = block_meta.block_size as usize;
+        match groups.last_mut() {
+            Some(group)
+                if compare_cluster_keys(&stats.min, &group_max) != Ordering::Greater
+                    && group_size + block_size <= max_group_size =>
+            {
+                if compare_cluster_keys(&stats.max, &group_max) == Ordering::Greater {
+                    group_max = stats.max;
+                }
+                group_size += block_size;
+                group.push(block_meta);
+            }
+            _ => {
+                group_max = stats.max;
+                group_size = block_size;
+                groups.push(vec![block_meta]);
+            }
+        }
+    }
+    (groups, unclustered)
+}
+
+fn min_key(block_meta: &BlockMeta) -> Vec<DataValue> {
+    block_meta
+        .cluster_stats
+        .as_ref()
+        .map(|stats| stats.min.clone())
+        .unwrap_or_default()
+}
+
+/// How well the blocks of a table are clustered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClusteringInformation {
+    pub total_block_count: u64,
+    /// Blocks of which the min and the max of the cluster key are the same
+    pub total_constant_block_count: u64,
+    /// The average number of other blocks that a block overlaps with
+    pub average_overlaps: f64,
+    /// The average number of blocks that overlap at the boundaries of blocks
+    pub average_depth: f64,
+}
+
+impl ClusteringInformation {
+    /// Collects the clustering information, the blocks without cluster statistics
+    /// are counted but do not contribute to the overlaps and the depth.
+    pub fn from_block_metas(block_metas: &[BlockMeta]) -> Self {
+        let stats = block_metas
+            .iter()
+            .filter_map(|block_meta| block_meta.cluster_stats.as_ref())
+            .collect::<Vec<_>>();
+
+        let total_constant_block_count = stats
+            .iter()
+            .filter(|s| compare_cluster_keys(&s.min, &s.max) == Ordering::Equal)
+            .count() as u64;
+
+        let mut mins = stats.iter().map(|s| &s.min).collect::<Vec<_>>();
+        let mut maxs = stats.iter().map(|s| &s.max).collect::<Vec<_>>();
+        mins.sort_by(|l, r| compare_cluster_keys(l, r));
+        maxs.sort_by(|l, r| compare_cluster_keys(l, r));
+
+        // blocks i < j (ordered by min) overlap iff min(j) <= max(i)
+        let mut sorted_stats = stats.clone();
+        sorted_stats.sort_by(|l, r| compare_cluster_keys(&l.min, &r.min));
+        let overlapped_pairs = sorted_stats
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let overlapped = mins
+                    .partition_point(|min| compare_cluster_keys(min, &s.max) != Ordering::Greater);
+                overlapped.saturating_sub(i + 1)
+            })
+            .sum::<usize>();
+
+        // the depth at a point is the number of blocks with min <= point <= max,
+        // which is evaluated at the boundaries of all the blocks
+        let mut points = mins.iter().chain(maxs.iter()).copied().collect::<Vec<_>>();
+        points.sort_by(|l, r| compare_cluster_keys(l, r));
+        points.dedup_by(|l, r| compare_cluster_keys(l, r) == Ordering::Equal);
+        let total_depth = points
+            .iter()
+            .map(|point| {
+                let started = mins
+                    .partition_point(|min| compare_cluster_keys(min, point) != Ordering::Greater);
+                let ended =
+                    maxs.partition_point(|max| compare_cluster_keys(max, point) == Ordering::Less);
+                started - ended
+            })
+            .sum::<usize>();
+
+        let average = |total: usize, count: usize| match count {
+            0 => 0.0,
+            _ => total as f64 / count as f64,
+        };
+
+        ClusteringInformation {
+            total_block_count: block_metas.len() as u64,
+            total_constant_block_count,
+            average_overlaps: average(overlapped_pairs * 2, stats.len()),
+            average_depth: average(total_depth, points.len()),
+        }
+    }
+}
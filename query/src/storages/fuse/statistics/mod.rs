@@ -13,9 +13,13 @@
 //  limitations under the License.
 
 pub mod accumulator;
+pub mod cluster;
 pub mod reducers;
 
 pub use accumulator::PartiallyAccumulated;
 pub use accumulator::StatisticsAccumulator;
+pub use cluster::BlockClusterer;
+pub use cluster::ClusteringInformation;
 pub use reducers::merge_statistics;
+pub use reducers::reduce_block_metas;
 pub use reducers::reduce_block_stats;
@@ -20,20 +20,33 @@ use std::collections::HashMap;
 use common_datavalues::DataSchema;
 use common_exception::Result;
 
+use crate::storages::fuse::meta::BlockMeta;
 use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::Statistics;
 use crate::storages::index::BlockStatistics;
 use crate::storages::index::ColumnStatistics;
 
+/// Reduces the statistics of the given blocks.
+///
+/// - the statistics of columns that are not in `column_ids` (dropped columns) are discarded
+/// - a column that is absent in some of the statistics (e.g. a column added after some of the
+///   blocks are written) has no statistics, since the values of it are unknown
+/// - empty statistics, e.g. the initial value of an accumulation, are ignored
 pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
     stats: &[T],
     schema: &DataSchema,
+    column_ids: &[ColumnId],
 ) -> Result<BlockStatistics> {
+    let stats = stats
+        .iter()
+        .map(|item| item.borrow())
+        .filter(|item| !item.is_empty())
+        .collect::<Vec<_>>();
     let len = stats.len();
 
     // transpose Vec<HashMap<_,(_,_)>> to HashMap<_, (_, Vec<_>)>
     let col_stat_list = stats.iter().fold(HashMap::new(), |acc, item| {
-        item.borrow().iter().fold(
+        item.iter().fold(
             acc,
             |mut acc: HashMap<ColumnId, Vec<&ColumnStatistics>>, (col_id, stats)| {
                 let entry = acc.entry(*col_id);
@@ -53,6 +66,15 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
     col_stat_list
         .iter()
         .try_fold(HashMap::with_capacity(len), |mut acc, (id, stats)| {
+            if stats.len() != len {
+                return Ok(acc);
+            }
+
+            let data_type = match column_ids.iter().position(|column_id| column_id == id) {
+                Some(idx) => schema.field(idx).data_type(),
+                None => return Ok(acc),
+            };
+
             let mut min_stats = Vec::with_capacity(stats.len());
             let mut max_stats = Vec::with_capacity(stats.len());
             let mut null_count = 0;
@@ -68,9 +90,6 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
-            // TODO panic
-            let data_type = schema.field((*id) as usize).data_type();
-
             // TODO
             // for some data types, we shall balance the accuracy and the length
             // e.g. for a string col, which max value is "abcdef....", we record the max as something like "b"
@@ -92,13 +111,34 @@ pub fn reduce_block_stats<T: Borrow<BlockStatistics>>(
         })
 }
 
-pub fn merge_statistics(schema: &DataSchema, l: &Statistics, r: &Statistics) -> Result<Statistics> {
+pub fn merge_statistics(
+    schema: &DataSchema,
+    column_ids: &[ColumnId],
+    l: &Statistics,
+    r: &Statistics,
+) -> Result<Statistics> {
     let s = Statistics {
         row_count: l.row_count + r.row_count,
         block_count: l.block_count + r.block_count,
         uncompressed_byte_size: l.uncompressed_byte_size + r.uncompressed_byte_size,
         compressed_byte_size: l.compressed_byte_size + r.compressed_byte_size,
-        col_stats: reduce_block_stats(&[&l.col_stats, &r.col_stats], schema)?,
+        col_stats: reduce_block_stats(&[&l.col_stats, &r.col_stats], schema, column_ids)?,
     };
     Ok(s)
 }
+
+/// Summarizes the statistics of the given blocks.
+pub fn reduce_block_metas(
+    block_metas: &[BlockMeta],
+    schema: &DataSchema,
+    column_ids: &[ColumnId],
+) -> Result<Statistics> {
+    let blocks_stats = block_metas.iter().map(|b| &b.col_stats).collect::<Vec<_>>();
+    Ok(Statistics {
+        row_count: block_metas.iter().map(|b| b.row_count).sum(),
+        block_count: block_metas.len() as u64,
+        uncompressed_byte_size: block_metas.iter().map(|b| b.block_size).sum(),
+        compressed_byte_size: block_metas.iter().map(|b| b.file_size).sum(),
+        col_stats: reduce_block_stats(&blocks_stats, schema, column_ids)?,
+    })
+}
@@ -18,23 +18,38 @@ use std::any::TypeId;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_datablocks::DataBlock;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::TableInfo;
+use common_planners::AlterTablePlan;
+use common_planners::DeletePlan;
+use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_streams::SendableDataBlockStream;
 use common_tracing::tracing;
 use futures::StreamExt;
 
 use crate::sessions::QueryContext;
+use crate::storages::fuse::io;
+use crate::storages::fuse::io::BlockWriteOptions;
 use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::meta::ColumnId;
 use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::TableTtl;
+use crate::storages::fuse::TBL_OPT_KEY_BLOOM_FILTER_COLUMNS;
+use crate::storages::fuse::TBL_OPT_KEY_COLUMN_IDS;
+use crate::storages::fuse::TBL_OPT_KEY_NEXT_COLUMN_ID;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use crate::storages::NavigationPoint;
 use crate::storages::StorageContext;
 use crate::storages::Table;
 
@@ -121,9 +136,33 @@ impl Table for FuseTable {
         self.do_truncate(ctx, truncate_plan).await
     }
 
+    async fn delete(&self, ctx: Arc<QueryContext>, delete_plan: DeletePlan) -> Result<()> {
+        self.do_delete(ctx, &delete_plan).await
+    }
+
+    async fn update(&self, ctx: Arc<QueryContext>, update_plan: UpdatePlan) -> Result<()> {
+        self.do_update(ctx, &update_plan).await
+    }
+
+    async fn alter_table(&self, ctx: Arc<QueryContext>, alter_plan: AlterTablePlan) -> Result<()> {
+        self.do_alter_table(ctx, &alter_plan).await
+    }
+
     async fn optimize(&self, ctx: Arc<QueryContext>, keep_last_snapshot: bool) -> Result<()> {
         self.do_optimize(ctx, keep_last_snapshot).await
     }
+
+    async fn compact(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        self.do_compact(ctx).await
+    }
+
+    async fn navigate_to(
+        &self,
+        ctx: Arc<QueryContext>,
+        point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        self.do_navigate(ctx, point).await
+    }
 }
 
 impl FuseTable {
@@ -134,6 +173,120 @@ impl FuseTable {
             .cloned()
     }
 
+    /// Returns the prefix under which the objects of the table are kept, which is the id of the
+    /// table, or the one of the current snapshot, e.g. empty for the tables of older versions.
+    pub(crate) fn storage_prefix(&self) -> String {
+        match self.snapshot_loc() {
+            Some(loc) => io::table_prefix_of(&loc).to_string(),
+            None => self.table_info.ident.table_id.to_string(),
+        }
+    }
+
+    /// Returns the stable ids of the columns, in the order of the fields of the table schema.
+    ///
+    /// The ids are kept in the table options once the columns are altered, before that,
+    /// a column is identified by its position in the schema.
+    pub fn column_ids(&self) -> Result<Vec<ColumnId>> {
+        let num_fields = self.table_info.schema().fields().len();
+        let column_ids = match self.table_info.options().get(TBL_OPT_KEY_COLUMN_IDS) {
+            None => return Ok((0..num_fields as ColumnId).collect()),
+            Some(ids) if ids.is_empty() => vec![],
+            Some(ids) => ids
+                .split(',')
+                .map(|id| id.trim().parse::<ColumnId>())
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| ErrorCode::BadOption(format!("invalid column ids {}, {}", ids, e)))?,
+        };
+
+        if column_ids.len() != num_fields {
+            return Err(ErrorCode::LogicalError(format!(
+                "column ids {:?} do not match the schema of table {}",
+                column_ids, self.table_info.desc
+            )));
+        }
+        Ok(column_ids)
+    }
+
+    /// Returns the expressions of the cluster key, empty if the table is not clustered.
+    pub fn cluster_keys(&self) -> Result<Vec<Expression>> {
+        match &self.table_info.meta.cluster_keys {
+            None => Ok(vec![]),
+            Some(keys) => serde_json::from_str(keys).map_err(|e| {
+                ErrorCode::LogicalError(format!("invalid cluster keys {}, {}", keys, e))
+            }),
+        }
+    }
+
+    /// Returns the TTL of the rows evaluated at the given time, `None` if the rows never expire.
+    pub fn ttl(&self, now: DateTime<Utc>) -> Result<Option<TableTtl>> {
+        let ttl = match &self.table_info.meta.ttl {
+            None => return Ok(None),
+            Some(ttl) => ttl,
+        };
+
+        let expr: Expression = serde_json::from_str(ttl)
+            .map_err(|e| ErrorCode::LogicalError(format!("invalid ttl {}, {}", ttl, e)))?;
+        let schema = self.table_info.schema();
+        let ttl = TableTtl::try_create(&expr, &schema, &self.column_ids()?, now)?;
+        Ok(Some(ttl))
+    }
+
+    pub(crate) fn ttl_enabled(&self) -> bool {
+        self.table_info.meta.ttl.is_some()
+    }
+
+    /// Returns the ids of the columns which have bloom filters, according to the table option
+    /// `bloom_filter_columns`.
+    pub fn bloom_filter_column_ids(&self) -> Result<Vec<ColumnId>> {
+        let names = match self
+            .table_info
+            .options()
+            .get(TBL_OPT_KEY_BLOOM_FILTER_COLUMNS)
+        {
+            None => return Ok(vec![]),
+            Some(names) => names,
+        };
+
+        let schema = self.table_info.schema();
+        let column_ids = self.column_ids()?;
+        names
+            .split(',')
+            .map(|name| name.trim())
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                schema
+                    .index_of(name)
+                    .map(|idx| column_ids[idx])
+                    .map_err(|_| {
+                        ErrorCode::BadOption(format!(
+                            "unknown column {} in the option {}",
+                            name, TBL_OPT_KEY_BLOOM_FILTER_COLUMNS
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns how the blocks are written, according to the table options `compression`,
+    /// `column_compression`, `dictionary_columns` and `row_group_size`.
+    pub fn block_write_options(&self) -> Result<BlockWriteOptions> {
+        BlockWriteOptions::try_create(
+            self.table_info.options(),
+            &self.table_info.schema(),
+            &self.column_ids()?,
+        )
+    }
+
+    /// Returns the id that will be assigned to the next added column.
+    pub(crate) fn next_column_id(&self) -> Result<ColumnId> {
+        match self.table_info.options().get(TBL_OPT_KEY_NEXT_COLUMN_ID) {
+            Some(id) => id
+                .parse::<ColumnId>()
+                .map_err(|e| ErrorCode::BadOption(format!("invalid next column id {}, {}", id, e))),
+            None => Ok(self.table_info.schema().fields().len() as ColumnId),
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     pub(crate) async fn read_table_snapshot(
         &self,
@@ -148,6 +301,16 @@ impl FuseTable {
     }
 }
 
+/// Formats the column ids as a comma separated list, which is how they are kept
+/// in the table options and the metadata of blocks.
+pub(crate) fn encode_column_ids(column_ids: &[ColumnId]) -> String {
+    column_ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 pub fn is_fuse_table(table: &dyn Table) -> bool {
     let tid = table.as_any().type_id();
     tid == TypeId::of::<FuseTable>()
@@ -0,0 +1,183 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::Expression;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::statistics::ClusteringInformation;
+use crate::storages::fuse::table_functions::table_arg_util::parse_func_history_args;
+use crate::storages::fuse::table_functions::table_arg_util::string_literal;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+use crate::table_functions::TableArgs;
+use crate::table_functions::TableFunction;
+
+pub const FUSE_FUNC_CLUSTERING: &str = "clustering_information";
+
+pub struct ClusteringInformationTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl ClusteringInformationTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("cluster_by_keys", DataType::String, false),
+            DataField::new("total_block_count", DataType::UInt64, false),
+            DataField::new("total_constant_block_count", DataType::UInt64, false),
+            DataField::new("average_overlaps", DataType::Float64, false),
+            DataField::new("average_depth", DataType::Float64, false),
+        ]);
+
+        let (arg_database_name, arg_table_name) = parse_func_history_args(&table_args)?;
+
+        let engine = FUSE_FUNC_CLUSTERING.to_owned();
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine,
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(ClusteringInformationTable {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+
+    fn to_block(&self, cluster_keys: &[Expression], info: ClusteringInformation) -> DataBlock {
+        let keys = cluster_keys
+            .iter()
+            .map(|key| key.column_name())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        DataBlock::create_by_array(self.table_info.schema(), vec![
+            Series::new(vec![format!("({})", keys).into_bytes()]),
+            Series::new(vec![info.total_block_count]),
+            Series::new(vec![info.total_constant_block_count]),
+            Series::new(vec![info.average_overlaps]),
+            Series::new(vec![info.average_depth]),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ClusteringInformationTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        Some(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ])
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let tenant_id = ctx.get_tenant();
+        let tbl = ctx
+            .get_catalog()
+            .get_table(
+                tenant_id.as_str(),
+                self.arg_database_name.as_str(),
+                self.arg_table_name.as_str(),
+            )
+            .await?;
+
+        let fuse_table = tbl.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "expecting fuse table, but got table of engine type: {}",
+                tbl.get_table_info().meta.engine
+            ))
+        })?;
+
+        let cluster_keys = fuse_table.cluster_keys()?;
+        if cluster_keys.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "table {} has no cluster keys",
+                tbl.get_table_info().desc
+            )));
+        }
+
+        let mut block_metas = vec![];
+        if let Some(snapshot) = fuse_table.read_table_snapshot(ctx.as_ref()).await? {
+            let reader = MetaReaders::segment_info_reader(ctx.as_ref());
+            for segment_location in &snapshot.segments {
+                let segment = reader.read(segment_location).await?;
+                block_metas.extend(segment.blocks.iter().cloned());
+            }
+        }
+
+        let info = ClusteringInformation::from_block_metas(&block_metas);
+        let blocks = vec![self.to_block(&cluster_keys, info)];
+        Ok(Box::pin(DataBlockStream::create(
+            self.table_info.schema(),
+            None,
+            blocks,
+        )))
+    }
+}
+
+impl TableFunction for ClusteringInformationTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -39,6 +39,7 @@ use crate::storages::fuse::meta::TableSnapshot;
 use crate::storages::fuse::table::is_fuse_table;
 use crate::storages::fuse::table_functions::table_arg_util::parse_func_history_args;
 use crate::storages::fuse::table_functions::table_arg_util::string_literal;
+use crate::storages::fuse::SNAPSHOT_TIMESTAMP_FORMAT;
 use crate::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
 use crate::storages::Table;
 use crate::table_functions::TableArgs;
@@ -67,6 +68,7 @@ impl FuseHistoryTable {
             DataField::new("row_count", DataType::UInt64, false),
             DataField::new("bytes_uncompressed", DataType::UInt64, false),
             DataField::new("bytes_compressed", DataType::UInt64, false),
+            DataField::new("timestamp", DataType::String, true),
         ]);
 
         let (arg_database_name, arg_table_name) = parse_func_history_args(&table_args)?;
@@ -100,6 +102,7 @@ impl FuseHistoryTable {
         let mut row_count: Vec<u64> = Vec::with_capacity(len);
         let mut compressed: Vec<u64> = Vec::with_capacity(len);
         let mut uncompressed: Vec<u64> = Vec::with_capacity(len);
+        let mut timestamps: Vec<Option<Vec<u8>>> = Vec::with_capacity(len);
         for s in snapshots {
             snapshot_ids.push(s.snapshot_id.simple().to_string().into_bytes());
             prev_snapshot_ids.push(
@@ -111,6 +114,10 @@ impl FuseHistoryTable {
             row_count.push(s.summary.row_count);
             compressed.push(s.summary.compressed_byte_size);
             uncompressed.push(s.summary.uncompressed_byte_size);
+            timestamps.push(
+                s.timestamp
+                    .map(|v| v.format(SNAPSHOT_TIMESTAMP_FORMAT).to_string().into_bytes()),
+            );
         }
 
         DataBlock::create_by_array(self.table_info.schema(), vec![
@@ -121,6 +128,7 @@ impl FuseHistoryTable {
             Series::new(row_count),
             Series::new(uncompressed),
             Series::new(compressed),
+            Series::new(timestamps),
         ])
     }
 }
@@ -0,0 +1,171 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::RewriteHelper;
+
+use crate::pipelines::transforms::ExpressionExecutor;
+use crate::storages::fuse::meta::BlockMeta;
+use crate::storages::fuse::meta::ColumnId;
+
+/// The expiration of the rows of a block.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockExpiration {
+    /// None of the rows is expired.
+    Live,
+    /// All the rows are expired, the block can be removed without being read.
+    Expired,
+    /// Some of the rows may be expired, the block has to be rewritten.
+    Partial,
+}
+
+/// The TTL of the rows of a table, evaluated at a given time.
+///
+/// A row is expired if its TTL is earlier than the time, and never expires if the TTL is NULL.
+/// The TTL is expected to increase with the column it refers to, e.g. `d + INTERVAL '30' DAY`,
+/// so that the expiration of a block is decided by the min/max statistics of the column.
+pub struct TableTtl {
+    column_name: String,
+    column_id: ColumnId,
+    // the rows of which the TTL is expired, i.e. "ttl < now"
+    filter: Expression,
+    stat_schema: DataSchemaRef,
+    stat_executor: ExpressionExecutor,
+}
+
+impl TableTtl {
+    pub fn try_create(
+        ttl: &Expression,
+        schema: &DataSchemaRef,
+        column_ids: &[ColumnId],
+        now: DateTime<Utc>,
+    ) -> Result<Self> {
+        let columns = RewriteHelper::expression_plan_columns(ttl)?;
+        let column_name = match columns.as_slice() {
+            [Expression::Column(name)] => name.clone(),
+            _ => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "TTL {} must refer to exactly one column",
+                    ttl.column_name()
+                )))
+            }
+        };
+        let column_idx = schema.index_of(&column_name)?;
+
+        let cutoff = Self::cutoff(&ttl.to_data_type(schema)?, now)?;
+        let filter = Expression::BinaryExpression {
+            op: "<".to_string(),
+            left: Box::new(ttl.clone()),
+            right: Box::new(cutoff),
+        };
+
+        let stat_schema = DataSchemaRefExt::create(vec![schema.field(column_idx).clone()]);
+        let stat_executor = ExpressionExecutor::try_create(
+            "ttl executor",
+            stat_schema.clone(),
+            DataSchemaRefExt::create(vec![filter.to_data_field(&stat_schema)?]),
+            vec![filter.clone()],
+            false,
+        )?;
+
+        Ok(TableTtl {
+            column_name,
+            column_id: column_ids[column_idx],
+            filter,
+            stat_schema,
+            stat_executor,
+        })
+    }
+
+    /// The name of the column the TTL refers to.
+    pub fn column_name(&self) -> &str {
+        self.column_name.as_str()
+    }
+
+    /// The filter of the expired rows.
+    pub fn filter(&self) -> &Expression {
+        &self.filter
+    }
+
+    pub fn check(&self, block_meta: &BlockMeta) -> Result<BlockExpiration> {
+        let stats = match block_meta.col_stats.get(&self.column_id) {
+            // the column is added after the block is written, decided by its default values
+            None => return Ok(BlockExpiration::Partial),
+            Some(stats) => stats,
+        };
+
+        if stats.null_count == 0 && self.is_expired(&stats.max)? {
+            Ok(BlockExpiration::Expired)
+        } else if self.is_expired(&stats.min)? {
+            Ok(BlockExpiration::Partial)
+        } else {
+            Ok(BlockExpiration::Live)
+        }
+    }
+
+    /// Removes the blocks of which all the rows are expired.
+    pub fn prune(&self, block_metas: Vec<BlockMeta>) -> Result<Vec<BlockMeta>> {
+        let mut live = Vec::with_capacity(block_metas.len());
+        for block_meta in block_metas {
+            if self.check(&block_meta)? != BlockExpiration::Expired {
+                live.push(block_meta);
+            }
+        }
+        Ok(live)
+    }
+
+    fn is_expired(&self, value: &DataValue) -> Result<bool> {
+        if value.is_null() {
+            return Ok(false);
+        }
+
+        let block = DataBlock::create(self.stat_schema.clone(), vec![DataColumn::Constant(
+            value.clone(),
+            1,
+        )]);
+        let expired = self.stat_executor.execute(&block)?.column(0).try_get(0)?;
+        Ok(matches!(expired, DataValue::Boolean(Some(true))))
+    }
+
+    // the time as a literal of the type of the TTL
+    fn cutoff(data_type: &DataType, now: DateTime<Utc>) -> Result<Expression> {
+        let secs = now.timestamp();
+        let value = match data_type {
+            DataType::Date16 => DataValue::UInt16(Some((secs / 86400) as u16)),
+            DataType::Date32 => DataValue::Int32(Some((secs / 86400) as i32)),
+            DataType::DateTime32(_) => DataValue::UInt32(Some(secs as u32)),
+            DataType::DateTime64(precision, _) => {
+                let nanos = now.timestamp_nanos() as u64;
+                DataValue::UInt64(Some(nanos / 10u64.pow(9 - (*precision).min(9))))
+            }
+            other => {
+                return Err(ErrorCode::LogicalError(format!(
+                    "TTL must be of a date or datetime type, but got {}",
+                    other
+                )))
+            }
+        };
+        Ok(Expression::create_literal_with_type(
+            value,
+            data_type.clone(),
+        ))
+    }
+}
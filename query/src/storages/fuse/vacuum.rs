@@ -0,0 +1,284 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::tokio;
+use common_base::tokio::sync::Notify;
+use common_base::tokio::task::JoinHandle;
+use common_base::tokio::time::sleep as tokio_async_sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_infallible::Mutex;
+use common_infallible::RwLock;
+use common_meta_types::PurgeDroppedTableReq;
+use common_tracing::tracing;
+use futures::future::select;
+use futures::future::Either;
+use futures::Future;
+
+use crate::catalogs::Catalog;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+
+#[derive(Clone, Debug)]
+pub struct VacuumStatus {
+    pub database: String,
+    pub table: String,
+    pub state: String,
+    pub blocks_expired: u64,
+    pub snapshots_removed: u64,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub error: Option<String>,
+}
+
+/// Enforces the snapshot retention policy and the TTL of the fuse tables periodically.
+///
+/// Only the tables with the `snapshot_retention_period` or `max_snapshots` options, or with TTL
+/// are vacuumed, the status of the latest vacuum of each table is kept in memory, see `system.vacuum`.
+/// The tables dropped out of the `table_dropped_retention_secs` are purged along with their data.
+pub struct TableVacuum {
+    interval: Duration,
+    statuses: Arc<RwLock<BTreeMap<(String, String), VacuumStatus>>>,
+    shutdown: Arc<AtomicBool>,
+    shutdown_notify: Arc<Notify>,
+    shutdown_handler: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl TableVacuum {
+    pub fn create(interval: Duration) -> TableVacuum {
+        TableVacuum {
+            interval,
+            statuses: Arc::new(RwLock::new(BTreeMap::new())),
+            shutdown: Arc::new(AtomicBool::new(false)),
+            shutdown_notify: Arc::new(Notify::new()),
+            shutdown_handler: Mutex::new(None),
+        }
+    }
+
+    pub fn statuses(&self) -> Vec<VacuumStatus> {
+        self.statuses.read().values().cloned().collect()
+    }
+
+    /// Starts the background vacuum, zero interval disables it.
+    pub fn start(&self, sessions: Arc<SessionManager>) {
+        if self.interval.is_zero() {
+            return;
+        }
+
+        let mut shutdown_handler = self.shutdown_handler.lock();
+        if shutdown_handler.is_none() {
+            *shutdown_handler = Some(tokio::spawn(self.vacuum_loop(sessions)));
+        }
+    }
+
+    pub async fn shutdown(&self) -> Result<()> {
+        let shutdown_handler = self.shutdown_handler.lock().take();
+        if let Some(shutdown_handler) = shutdown_handler {
+            self.shutdown.store(true, Ordering::Relaxed);
+            self.shutdown_notify.notify_waiters();
+            if let Err(shutdown_failure) = shutdown_handler.await {
+                return Err(ErrorCode::TokioError(format!(
+                    "Cannot shutdown table vacuum, cause {:?}",
+                    shutdown_failure
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn vacuum_loop(&self, sessions: Arc<SessionManager>) -> impl Future<Output = ()> + 'static {
+        let interval = self.interval;
+        let statuses = self.statuses.clone();
+        let shutdown = self.shutdown.clone();
+        let shutdown_notify = self.shutdown_notify.clone();
+
+        async move {
+            let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+
+            while !shutdown.load(Ordering::Relaxed) {
+                let sleep = tokio_async_sleep(interval);
+
+                match select(shutdown_notified, Box::pin(sleep)).await {
+                    Either::Left((_, _)) => {
+                        break;
+                    }
+                    Either::Right((_, new_shutdown_notified)) => {
+                        shutdown_notified = new_shutdown_notified;
+                        let vacuum = Self::vacuum_tables(&sessions, &statuses, &shutdown);
+                        if let Err(failure) = vacuum.await {
+                            tracing::error!("Table vacuum failure: {:?}", failure);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn vacuum_tables(
+        sessions: &Arc<SessionManager>,
+        statuses: &RwLock<BTreeMap<(String, String), VacuumStatus>>,
+        shutdown: &AtomicBool,
+    ) -> Result<()> {
+        let session = sessions.create_session("TableVacuum")?;
+        let ctx = session.create_context().await?;
+
+        // the tables are shared by the nodes of the cluster, only one of them vacuums
+        let cluster = ctx.get_cluster();
+        let first_node = cluster.get_nodes().into_iter().map(|n| n.id.clone()).min();
+        if matches!(first_node, Some(id) if id != cluster.local_id()) {
+            return Ok(());
+        }
+
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog();
+        let retention_secs = ctx.get_config().query.table_dropped_retention_secs;
+        let purge_before = Utc::now() - chrono::Duration::seconds(retention_secs as i64);
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            for table in catalog
+                .list_tables_history(tenant.as_str(), database.name())
+                .await?
+            {
+                if shutdown.load(Ordering::Relaxed) {
+                    return Ok(());
+                }
+
+                if !matches!(table.get_table_info().meta.drop_on, Some(t) if t < purge_before) {
+                    continue;
+                }
+
+                let purge = Self::purge_dropped_table(&ctx, database.name(), table.as_ref());
+                if let Err(cause) = purge.await {
+                    tracing::warn!(
+                        "Cannot purge dropped table {}.{}, {}",
+                        database.name(),
+                        table.name(),
+                        cause
+                    );
+                }
+            }
+
+            for table in catalog
+                .list_tables(tenant.as_str(), database.name())
+                .await?
+            {
+                if shutdown.load(Ordering::Relaxed) {
+                    return Ok(());
+                }
+
+                let fuse_table = match table.as_any().downcast_ref::<FuseTable>() {
+                    Some(fuse_table) if fuse_table.vacuum_enabled() || fuse_table.ttl_enabled() => {
+                        fuse_table
+                    }
+                    _ => continue,
+                };
+
+                let key = (database.name().to_string(), table.name().to_string());
+                let mut status = VacuumStatus {
+                    database: key.0.clone(),
+                    table: key.1.clone(),
+                    state: "Running".to_string(),
+                    blocks_expired: 0,
+                    snapshots_removed: 0,
+                    start_time: Utc::now(),
+                    end_time: None,
+                    error: None,
+                };
+                statuses.write().insert(key.clone(), status.clone());
+
+                match Self::vacuum_table(&ctx, &key.0, fuse_table).await {
+                    Ok((blocks_expired, snapshots_removed)) => {
+                        status.state = "Finished".to_string();
+                        status.blocks_expired = blocks_expired;
+                        status.snapshots_removed = snapshots_removed;
+                    }
+                    Err(cause) => {
+                        tracing::warn!("Cannot vacuum table {}.{}, {}", key.0, key.1, cause);
+                        status.state = "Failed".to_string();
+                        status.error = Some(cause.to_string());
+                    }
+                }
+                status.end_time = Some(Utc::now());
+                statuses.write().insert(key, status);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Removes the dropped table from the meta first, so that it can not be restored while its data
+    // is being removed. The data is leaked if the removal is interrupted.
+    async fn purge_dropped_table(
+        ctx: &Arc<QueryContext>,
+        database: &str,
+        table: &dyn Table,
+    ) -> Result<()> {
+        let req = PurgeDroppedTableReq {
+            tenant: ctx.get_tenant(),
+            db: database.to_string(),
+            table: table.name().to_string(),
+            table_id: table.get_table_info().ident.table_id,
+        };
+        match ctx.get_catalog().purge_dropped_table(req).await {
+            // restored or purged by others meanwhile
+            Err(e) if e.code() == ErrorCode::UnknownTable("").code() => return Ok(()),
+            other => other?,
+        };
+
+        if let Some(fuse_table) = table.as_any().downcast_ref::<FuseTable>() {
+            fuse_table.do_purge(ctx.as_ref()).await?;
+        }
+        Ok(())
+    }
+
+    /// Removes the rows expired by the TTL, then the snapshots expired by the retention policy,
+    /// and then the orphan files of the table, returns the numbers of blocks expired and snapshots removed.
+    pub async fn vacuum_table(
+        ctx: &Arc<QueryContext>,
+        database: &str,
+        fuse_table: &FuseTable,
+    ) -> Result<(u64, u64)> {
+        let blocks_expired = fuse_table.do_expire(ctx).await?;
+        if blocks_expired == 0 {
+            let snapshots_removed = fuse_table.do_vacuum(ctx.as_ref()).await?;
+            fuse_table.remove_orphans(ctx.as_ref()).await?;
+            return Ok((0, snapshots_removed));
+        }
+
+        // the table is reloaded, so that the snapshot just committed is the current one
+        let table = ctx
+            .get_catalog()
+            .get_table(ctx.get_tenant().as_str(), database, fuse_table.name())
+            .await?;
+        let snapshots_removed = match table.as_any().downcast_ref::<FuseTable>() {
+            Some(fuse_table) => {
+                let snapshots_removed = fuse_table.do_vacuum(ctx.as_ref()).await?;
+                fuse_table.remove_orphans(ctx.as_ref()).await?;
+                snapshots_removed
+            }
+            None => 0,
+        };
+        Ok((blocks_expired, snapshots_removed))
+    }
+}
@@ -0,0 +1,302 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::hash::Hasher;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_io::prelude::*;
+use common_planners::Expression;
+use twox_hash::XxHash64;
+
+use crate::storages::index::IndexSchemaVersion;
+
+/// A bloom filter of the values of a column.
+///
+/// The bit positions are derived from a stable hash (xxhash64) of the values, thus the filters
+/// written by one node can be checked by the others.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BloomFilter {
+    num_hashes: u32,
+    bits: Vec<u64>,
+}
+
+impl BloomFilter {
+    /// Creates an empty filter for `num_items` values, with the expected false positive rate.
+    pub fn with_capacity(num_items: usize, false_positive_rate: f64) -> Self {
+        let num_items = num_items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let num_bits = (-num_items * false_positive_rate.ln() / (ln2 * ln2))
+            .ceil()
+            .max(64.0);
+        let num_hashes = ((num_bits / num_items) * ln2).round().max(1.0) as u32;
+        BloomFilter {
+            num_hashes,
+            bits: vec![0; (num_bits as usize + 63) / 64],
+        }
+    }
+
+    pub fn add(&mut self, key: &[u8]) {
+        for pos in self.positions(key) {
+            self.bits[pos / 64] |= 1 << (pos % 64);
+        }
+    }
+
+    /// Returns false if the key is definitely absent.
+    pub fn contains(&self, key: &[u8]) -> bool {
+        self.positions(key)
+            .all(|pos| self.bits[pos / 64] & (1 << (pos % 64)) != 0)
+    }
+
+    // double hashing: the i-th position is `h1 + i * h2`
+    fn positions(&self, key: &[u8]) -> impl Iterator<Item = usize> {
+        let mut hasher = XxHash64::with_seed(0);
+        hasher.write(key);
+        let hash = hasher.finish();
+        let (h1, h2) = (hash as u32 as u64, hash >> 32);
+        let num_bits = (self.bits.len() * 64) as u64;
+        (0..self.num_hashes as u64)
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % num_bits) as usize)
+    }
+}
+
+/// The bloom filters of the columns of a block, keyed by the column ids.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BloomFilterIndex {
+    pub filters: HashMap<u32, BloomFilter>,
+    pub version: IndexSchemaVersion,
+}
+
+impl BloomFilterIndex {
+    pub fn typ(&self) -> &str {
+        "bloom_filter"
+    }
+
+    /// Creates the filters of the given columns of the block, columns of which the type is
+    /// not supported are skipped.
+    ///
+    /// `columns` are the positions of the columns in the block, and their ids.
+    pub fn create_index(
+        block: &DataBlock,
+        columns: &[(usize, u32)],
+        false_positive_rate: f64,
+    ) -> Result<Self> {
+        let mut filters = HashMap::with_capacity(columns.len());
+        for (idx, column_id) in columns {
+            let data_type = block.schema().field(*idx).data_type();
+            if !is_bloom_filter_supported(data_type) {
+                continue;
+            }
+
+            let column = block.column(*idx);
+            let mut filter = BloomFilter::with_capacity(block.num_rows(), false_positive_rate);
+            for row in 0..block.num_rows() {
+                if let Some(key) = bloom_key(&column.try_get(row)?, data_type) {
+                    filter.add(&key);
+                }
+            }
+            filters.insert(*column_id, filter);
+        }
+
+        Ok(BloomFilterIndex {
+            filters,
+            version: IndexSchemaVersion::V1,
+        })
+    }
+
+    /// Returns false if the column definitely does not contain the value, the value is
+    /// assumed to be present if the column has no filter or the value can not be checked.
+    pub fn may_contain(&self, column_id: u32, value: &DataValue, data_type: &DataType) -> bool {
+        match (self.filters.get(&column_id), bloom_key(value, data_type)) {
+            (Some(filter), Some(key)) => filter.contains(&key),
+            _ => true,
+        }
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut bytes = BytesMut::new();
+        serialize_into_buf(&mut bytes, self)?;
+        Ok(bytes.to_vec())
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        deserialize_from_slice(&mut &bytes[..])
+    }
+}
+
+/// The equality conditions of a filter expression, which can be checked against
+/// the bloom filters of a block.
+#[derive(Debug, Clone)]
+pub struct BloomFilterPredicate {
+    condition: BloomCondition,
+}
+
+#[derive(Debug, Clone)]
+enum BloomCondition {
+    // the column (by position or id) equals one of the values
+    Equals(u32, DataType, Vec<DataValue>),
+    And(Box<BloomCondition>, Box<BloomCondition>),
+    Or(Box<BloomCondition>, Box<BloomCondition>),
+    Unknown,
+}
+
+impl BloomFilterPredicate {
+    /// Returns `None` if there are no `=` or `IN` conditions in the expression.
+    pub fn try_create(expr: &Expression, schema: &DataSchemaRef) -> Result<Option<Self>> {
+        let condition = BloomCondition::build(expr, schema);
+        match condition.is_checkable() {
+            true => Ok(Some(Self { condition })),
+            false => Ok(None),
+        }
+    }
+
+    /// Identifies the columns by the given ids instead of their positions in the schema.
+    #[must_use]
+    pub fn with_column_ids(mut self, column_ids: &[u32]) -> Self {
+        self.condition.map_columns(column_ids);
+        self
+    }
+
+    /// Returns false if no row of the block can match the expression.
+    pub fn eval(&self, index: &BloomFilterIndex) -> bool {
+        self.condition.eval(index)
+    }
+}
+
+impl BloomCondition {
+    fn build(expr: &Expression, schema: &DataSchemaRef) -> Self {
+        match expr {
+            Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
+                "and" => BloomCondition::And(
+                    Box::new(Self::build(left, schema)),
+                    Box::new(Self::build(right, schema)),
+                ),
+                "or" => BloomCondition::Or(
+                    Box::new(Self::build(left, schema)),
+                    Box::new(Self::build(right, schema)),
+                ),
+                "=" => match (left.as_ref(), right.as_ref()) {
+                    (Expression::Column(name), Expression::Literal { value, .. })
+                    | (Expression::Literal { value, .. }, Expression::Column(name)) => {
+                        Self::equals(schema, name, vec![value.clone()])
+                    }
+                    _ => BloomCondition::Unknown,
+                },
+                _ => BloomCondition::Unknown,
+            },
+            Expression::ScalarFunction { op, args } if op.eq_ignore_ascii_case("in") => {
+                let values = args
+                    .iter()
+                    .skip(1)
+                    .map(|arg| match arg {
+                        Expression::Literal { value, .. } => Some(value.clone()),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+                match (args.first(), values) {
+                    (Some(Expression::Column(name)), Some(values)) => {
+                        Self::equals(schema, name, values)
+                    }
+                    _ => BloomCondition::Unknown,
+                }
+            }
+            _ => BloomCondition::Unknown,
+        }
+    }
+
+    fn equals(schema: &DataSchemaRef, column_name: &str, values: Vec<DataValue>) -> Self {
+        match schema.index_of(column_name) {
+            Ok(idx) => {
+                let data_type = schema.field(idx).data_type().clone();
+                match is_bloom_filter_supported(&data_type) {
+                    true => BloomCondition::Equals(idx as u32, data_type, values),
+                    false => BloomCondition::Unknown,
+                }
+            }
+            Err(_) => BloomCondition::Unknown,
+        }
+    }
+
+    fn is_checkable(&self) -> bool {
+        match self {
+            BloomCondition::Equals(..) => true,
+            BloomCondition::And(l, r) => l.is_checkable() || r.is_checkable(),
+            BloomCondition::Or(l, r) => l.is_checkable() && r.is_checkable(),
+            BloomCondition::Unknown => false,
+        }
+    }
+
+    fn map_columns(&mut self, column_ids: &[u32]) {
+        match self {
+            BloomCondition::Equals(column, _, _) => *column = column_ids[*column as usize],
+            BloomCondition::And(l, r) | BloomCondition::Or(l, r) => {
+                l.map_columns(column_ids);
+                r.map_columns(column_ids);
+            }
+            BloomCondition::Unknown => {}
+        }
+    }
+
+    fn eval(&self, index: &BloomFilterIndex) -> bool {
+        match self {
+            BloomCondition::Equals(column_id, data_type, values) => values
+                .iter()
+                .any(|v| index.may_contain(*column_id, v, data_type)),
+            BloomCondition::And(l, r) => l.eval(index) && r.eval(index),
+            BloomCondition::Or(l, r) => l.eval(index) || r.eval(index),
+            BloomCondition::Unknown => true,
+        }
+    }
+}
+
+pub fn is_bloom_filter_supported(data_type: &DataType) -> bool {
+    data_type.is_integer() || matches!(data_type, DataType::String | DataType::Boolean)
+}
+
+// The key of a value of a column of the given type, `None` if the value is NULL or it can
+// not be compared with the column without casting, e.g. a string literal against a date column.
+fn bloom_key(value: &DataValue, data_type: &DataType) -> Option<Vec<u8>> {
+    match value {
+        DataValue::String(Some(v)) if *data_type == DataType::String => {
+            let mut key = Vec::with_capacity(v.len() + 1);
+            key.push(b's');
+            key.extend_from_slice(v);
+            Some(key)
+        }
+        DataValue::Boolean(Some(v)) if *data_type == DataType::Boolean => {
+            Some(vec![b'b', *v as u8])
+        }
+        // integers of different types are hashed alike if they are equal
+        v if data_type.is_integer() && v.is_signed_integer() => v.as_i64().ok().map(integer_key),
+        v if data_type.is_integer() && v.is_unsigned_integer() => match v.as_u64().ok()? {
+            u if u <= i64::MAX as u64 => Some(integer_key(u as i64)),
+            u => {
+                let mut key = vec![b'u'];
+                key.extend_from_slice(&u.to_le_bytes());
+                Some(key)
+            }
+        },
+        _ => None,
+    }
+}
+
+fn integer_key(v: i64) -> Vec<u8> {
+    let mut key = vec![b'i'];
+    key.extend_from_slice(&v.to_le_bytes());
+    key
+}
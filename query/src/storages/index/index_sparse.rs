@@ -15,11 +15,14 @@
 use std::collections::HashMap;
 
 use common_datablocks::DataBlock;
+use common_datavalues::prelude::DataColumn;
 use common_datavalues::DataValue;
 use common_exception::Result;
-use common_planners::Expression;
 
+use crate::storages::index::BlockStatistics;
+use crate::storages::index::ColumnStatistics;
 use crate::storages::index::IndexSchemaVersion;
+use crate::storages::index::RangeFilter;
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct SparseIndexValue {
@@ -27,6 +30,8 @@ pub struct SparseIndexValue {
     pub min: DataValue,
     // Max value of this granule.
     pub max: DataValue,
+    // Number of the nulls of this granule.
+    pub null_count: u64,
     // The page number to read in the data file.
     pub page_no: i64,
 }
@@ -34,18 +39,18 @@ pub struct SparseIndexValue {
 /// Sparse index.
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct SparseIndex {
-    pub col: String,
+    // Id of the indexed column.
+    pub column_id: u32,
     // Sparse index.
     pub values: Vec<SparseIndexValue>,
     // Version.
     pub version: IndexSchemaVersion,
 }
 
-#[allow(dead_code)]
 impl SparseIndex {
-    fn create(col: String) -> Self {
+    fn create(column_id: u32) -> Self {
         SparseIndex {
-            col,
+            column_id,
             values: vec![],
             version: IndexSchemaVersion::V1,
         }
@@ -61,17 +66,25 @@ impl SparseIndex {
         Ok(())
     }
 
-    pub fn create_index(keys: &[String], blocks: &[DataBlock]) -> Result<Vec<SparseIndex>> {
+    /// Create the indexes of the columns for the pages of one data file, the columns of
+    /// the pages are identified by the given ids, in order.
+    pub fn create_index(column_ids: &[u32], pages: &[DataBlock]) -> Result<Vec<SparseIndex>> {
         let mut keys_idx = vec![];
 
-        for key in keys {
-            let mut sparse = SparseIndex::create(key.clone());
-            for (page_no, page) in blocks.iter().enumerate() {
-                let min = page.first(key.as_str())?;
-                let max = page.last(key.as_str())?;
+        for (pos, column_id) in column_ids.iter().enumerate() {
+            let mut sparse = SparseIndex::create(*column_id);
+            for (page_no, page) in pages.iter().enumerate() {
+                let (min, max, null_count) = match page.column(pos) {
+                    DataColumn::Array(s) => (s.min()?, s.max()?, s.null_count() as u64),
+                    DataColumn::Constant(v, rows) => {
+                        let null_count = if v.is_null() { *rows as u64 } else { 0 };
+                        (v.clone(), v.clone(), null_count)
+                    }
+                };
                 sparse.push(SparseIndexValue {
                     min,
                     max,
+                    null_count,
                     page_no: page_no as i64,
                 })?;
             }
@@ -83,11 +96,40 @@ impl SparseIndex {
     /// Apply the index and get the result:
     /// (true, ...) : need read the whole file
     /// (false, [0, 3]) : need to read the page-0 and page-3 only.
+    ///
+    /// The filter is expected to identify the columns by their ids.
     pub fn apply_index(
-        _idx_map: HashMap<String, SparseIndex>,
-        _expr: &Expression,
+        idx_map: &HashMap<u32, SparseIndex>,
+        filter: &RangeFilter,
     ) -> Result<(bool, Vec<i64>)> {
-        // TODO(bohu): expression check.
-        Ok((true, vec![]))
+        let page_num = match idx_map.values().next() {
+            None => return Ok((true, vec![])),
+            Some(idx) => idx.values.len(),
+        };
+
+        let mut pages = Vec::with_capacity(page_num);
+        for page in 0..page_num {
+            let mut stats = BlockStatistics::with_capacity(idx_map.len());
+            for (column_id, idx) in idx_map {
+                if let Some(value) = idx.values.get(page) {
+                    stats.insert(*column_id, ColumnStatistics {
+                        min: value.min.clone(),
+                        max: value.max.clone(),
+                        null_count: value.null_count,
+                        in_memory_size: 0,
+                    });
+                }
+            }
+
+            if filter.eval(&stats)? {
+                pages.push(page as i64);
+            }
+        }
+
+        if pages.len() == page_num {
+            Ok((true, vec![]))
+        } else {
+            Ok((false, pages))
+        }
     }
 }
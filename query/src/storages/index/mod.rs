@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod index_bloom;
 mod index_min_max;
 mod index_sparse;
 pub mod range_filter;
 
+pub use index_bloom::BloomFilter;
+pub use index_bloom::BloomFilterIndex;
+pub use index_bloom::BloomFilterPredicate;
 pub use index_min_max::MinMaxIndex;
 pub use index_sparse::SparseIndex;
 pub use index_sparse::SparseIndexValue;
@@ -76,12 +76,41 @@ impl RangeFilter {
         })
     }
 
+    /// Identifies the columns by the given ids instead of their positions in the schema,
+    /// that is, the statistics to be evaluated are keyed by the ids.
+    #[must_use]
+    pub fn with_column_ids(mut self, column_ids: &[u32]) -> Self {
+        for stat_column in self.stat_columns.iter_mut() {
+            stat_column.column_fields = stat_column
+                .column_fields
+                .drain()
+                .map(|(idx, field)| (column_ids[idx as usize], field))
+                .collect();
+        }
+        self
+    }
+
     pub fn eval(&self, stats: &BlockStatistics) -> Result<bool> {
-        let columns = self
-            .stat_columns
-            .iter()
-            .map(|c| c.apply_stat_value(stats, self.origin.clone())?.to_array())
-            .collect::<Result<Vec<_>>>()?;
+        // the statistics of a column may be absent, e.g. the column is added after the block
+        // is written, the values of the column are unknown then
+        let stats_absent = self.stat_columns.iter().any(|c| {
+            c.column_fields
+                .keys()
+                .any(|column_id| !stats.contains_key(column_id))
+        });
+        if stats_absent {
+            return Ok(true);
+        }
+
+        let mut columns = Vec::with_capacity(self.stat_columns.len());
+        for stat_column in &self.stat_columns {
+            match stat_column.apply_stat_value(stats, self.origin.clone()) {
+                Ok(value) => columns.push(value.to_array()?),
+                // the expression is not monotonic within the range of the block,
+                // e.g. abs(a) with a in [-10, 10], the block may match
+                Err(_) => return Ok(true),
+            }
+        }
         let data_block = DataBlock::create_by_array(self.schema.clone(), columns);
         let executed_data_block = self.executor.execute(&data_block)?;
 
@@ -100,7 +129,16 @@ pub fn build_verifiable_expr(
 
     let (exprs, op) = match expr {
         Expression::Literal { .. } => return expr.clone(),
-        Expression::ScalarFunction { op, args } => (args.clone(), op.clone()),
+        Expression::UnaryExpression { op, expr } if op.to_lowercase() == "not" => {
+            return build_verifiable_expr(&negate_expr(expr), schema, stat_columns);
+        }
+        Expression::ScalarFunction { op, args } => match op.to_lowercase().as_str() {
+            "in" | "not_in" => {
+                return build_inlist_verifiable_expr(op, args, schema, stat_columns)
+                    .unwrap_or(unhandled);
+            }
+            _ => (args.clone(), op.clone()),
+        },
         Expression::BinaryExpression { left, op, right } => match op.to_lowercase().as_str() {
             "and" => {
                 let left = build_verifiable_expr(left, schema, stat_columns);
@@ -124,6 +162,89 @@ pub fn build_verifiable_expr(
         .map_or(unhandled.clone(), |mut v| v.build().unwrap_or(unhandled))
 }
 
+// e.g. a in (1, 3) => (a = 1) or (a = 3), a not in (1, 3) => (a != 1) and (a != 3),
+// each of which is then checked against the min/max of the column.
+fn build_inlist_verifiable_expr(
+    op: &str,
+    args: &[Expression],
+    schema: &DataSchemaRef,
+    stat_columns: &mut StatColumns,
+) -> Option<Expression> {
+    let negated = op.to_lowercase() == "not_in";
+    let (expr, list) = args.split_first()?;
+    let mut values = Vec::with_capacity(list.len());
+    for arg in list {
+        match arg {
+            // null never equals to anything
+            Expression::Literal { value, .. } if value.is_null() => {
+                if negated {
+                    return None;
+                }
+            }
+            Expression::Literal { .. } => values.push(arg.clone()),
+            _ => return None,
+        }
+    }
+
+    values
+        .into_iter()
+        .map(|value| match negated {
+            true => expr.not_eq(value),
+            false => expr.eq(value),
+        })
+        .map(|atom| build_verifiable_expr(&atom, schema, stat_columns))
+        .reduce(|acc, atom| match negated {
+            true => acc.and(atom),
+            false => acc.or(atom),
+        })
+}
+
+/// Pushes the NOT down to the atoms of the expression, e.g. not (a > 1 and b like 'x%')
+/// => (a <= 1) or (b not like 'x%'). The atoms that cannot be negated are turned into
+/// `true`, that is, they are treated as unhandled.
+pub(crate) fn negate_expr(expr: &Expression) -> Expression {
+    let unhandled = lit(true);
+    match expr {
+        Expression::Literal {
+            value: DataValue::Boolean(Some(v)),
+            ..
+        } => lit(!*v),
+        Expression::UnaryExpression { op, expr } if op.to_lowercase() == "not" => {
+            expr.as_ref().clone()
+        }
+        Expression::BinaryExpression { left, op, right } => {
+            let negated_op = match op.to_lowercase().as_str() {
+                "and" => return negate_expr(left).or(negate_expr(right)),
+                "or" => return negate_expr(left).and(negate_expr(right)),
+                "=" => "!=",
+                "!=" | "<>" => "=",
+                "<" => ">=",
+                "<=" => ">",
+                ">" => "<=",
+                ">=" => "<",
+                "like" => "not like",
+                "not like" => "like",
+                _ => return unhandled,
+            };
+            Expression::create_binary_expression(negated_op, vec![
+                left.as_ref().clone(),
+                right.as_ref().clone(),
+            ])
+        }
+        Expression::ScalarFunction { op, args } => {
+            let negated_op = match op.to_lowercase().as_str() {
+                "in" => "NOT_IN",
+                "not_in" => "IN",
+                "isnull" => "isNotNull",
+                "isnotnull" => "isNull",
+                _ => return unhandled,
+            };
+            Expression::create_scalar_function(negated_op, args.clone())
+        }
+        _ => unhandled,
+    }
+}
+
 fn inverse_operator(op: &str) -> Result<&str> {
     match op {
         "<" => Ok(">"),
@@ -374,7 +495,6 @@ impl<'a> VerifiableExprBuilder<'a> {
     }
 
     fn build(&mut self) -> Result<Expression> {
-        // TODO: support in/not in.
         match self.op {
             "isnull" => {
                 let nulls_expr = self.nulls_column_expr(0)?;
@@ -405,7 +525,7 @@ impl<'a> VerifiableExprBuilder<'a> {
 
                 Ok(left_min.lt_eq(right_max).and(left_max.gt_eq(right_min)))
             }
-            "!=" => {
+            "!=" | "<>" => {
                 let left_min = self.min_column_expr(0)?;
                 let left_max = self.max_column_expr(0)?;
                 Ok(left_min
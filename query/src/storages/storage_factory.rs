@@ -25,6 +25,8 @@ use crate::storages::fuse::FuseTable;
 use crate::storages::github::GithubTable;
 use crate::storages::memory::MemoryTable;
 use crate::storages::null::NullTable;
+use crate::storages::view::ViewTable;
+use crate::storages::view::VIEW_ENGINE;
 use crate::storages::StorageContext;
 use crate::storages::Table;
 
@@ -67,6 +69,9 @@ impl StorageFactory {
         // Register FUSE table engine.
         creators.insert("FUSE".to_string(), Arc::new(FuseTable::try_create));
 
+        // Register VIEW table engine.
+        creators.insert(VIEW_ENGINE.to_string(), Arc::new(ViewTable::try_create));
+
         StorageFactory {
             creators: RwLock::new(creators),
         }
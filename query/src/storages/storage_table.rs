@@ -16,12 +16,16 @@ use std::any::Any;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::MetaId;
 use common_meta_types::TableInfo;
+use common_planners::AlterTablePlan;
+use common_planners::DeletePlan;
 use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Part;
@@ -29,10 +33,18 @@ use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
 use common_streams::SendableDataBlockStream;
 
 use crate::sessions::QueryContext;
 
+/// A point in the history of a table.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NavigationPoint {
+    SnapshotID(String),
+    TimePoint(DateTime<Utc>),
+}
+
 #[async_trait::async_trait]
 pub trait Table: Sync + Send {
     fn name(&self) -> &str {
@@ -123,7 +135,52 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    async fn delete(&self, _ctx: Arc<QueryContext>, _delete_plan: DeletePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "delete for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    async fn update(&self, _ctx: Arc<QueryContext>, _update_plan: UpdatePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "update for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
+    async fn alter_table(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _alter_plan: AlterTablePlan,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "alter for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
     async fn optimize(&self, _ctx: Arc<QueryContext>, _keep_last_snapshot: bool) -> Result<()> {
         Ok(())
     }
+
+    async fn compact(&self, _ctx: Arc<QueryContext>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Returns the table as of the given point of its history(time travel).
+    async fn navigate_to(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _point: &NavigationPoint,
+    ) -> Result<Arc<dyn Table>> {
+        Err(ErrorCode::UnImplement(format!(
+            "time travel for table {} is not implemented, table engine is {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
 }
@@ -15,6 +15,8 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use chrono::Duration;
+use chrono::Utc;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
@@ -31,6 +33,8 @@ use crate::storages::Table;
 
 pub struct TablesTable {
     table_info: TableInfo,
+    // Whether the dropped tables, which can be restored by `UNDROP TABLE`, are listed.
+    with_history: bool,
 }
 
 impl TablesTable {
@@ -54,7 +58,37 @@ impl TablesTable {
             },
         };
 
-        TablesTable { table_info }
+        TablesTable {
+            table_info,
+            with_history: false,
+        }
+    }
+
+    pub fn create_with_history(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", DataType::String, false),
+            DataField::new("name", DataType::String, false),
+            DataField::new("engine", DataType::String, false),
+            DataField::new("created_on", DataType::String, false),
+            DataField::new("dropped_on", DataType::String, true),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tables_with_history'".to_string(),
+            name: "tables_with_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTablesWithHistory".to_string(),
+
+                ..Default::default()
+            },
+        };
+
+        TablesTable {
+            table_info,
+            with_history: true,
+        }
     }
 }
 
@@ -80,11 +114,22 @@ impl Table for TablesTable {
         let mut database_tables = vec![];
         for database in databases {
             let name = database.name();
-            for table in catalog.list_tables(tenant.as_str(), name).await? {
+            let tables = match self.with_history {
+                true => catalog.list_tables_history(tenant.as_str(), name).await?,
+                false => catalog.list_tables(tenant.as_str(), name).await?,
+            };
+            for table in tables {
                 database_tables.push((name.to_string(), table));
             }
         }
 
+        // the dropped tables out of the retention can not be restored any more
+        let retention_secs = ctx.get_config().query.table_dropped_retention_secs;
+        let purge_before = Utc::now() - Duration::seconds(retention_secs as i64);
+        database_tables.retain(
+            |(_, v)| !matches!(v.get_table_info().meta.drop_on, Some(t) if t < purge_before),
+        );
+
         let databases: Vec<&[u8]> = database_tables.iter().map(|(d, _)| d.as_bytes()).collect();
         let names: Vec<&[u8]> = database_tables
             .iter()
@@ -106,12 +151,27 @@ impl Table for TablesTable {
             .collect();
         let created_ons: Vec<&[u8]> = created_ons.iter().map(|s| s.as_bytes()).collect();
 
-        let block = DataBlock::create_by_array(self.table_info.schema(), vec![
+        let mut columns = vec![
             Series::new(databases),
             Series::new(names),
             Series::new(engines),
             Series::new(created_ons),
-        ]);
+        ];
+        if self.with_history {
+            let dropped_ons: Vec<Option<Vec<u8>>> = database_tables
+                .iter()
+                .map(|(_, v)| {
+                    v.get_table_info().meta.drop_on.map(|t| {
+                        t.format("%Y-%m-%d %H:%M:%S.%3f %z")
+                            .to_string()
+                            .into_bytes()
+                    })
+                })
+                .collect();
+            columns.push(Series::new(dropped_ons));
+        }
+
+        let block = DataBlock::create_by_array(self.table_info.schema(), columns);
 
         Ok(Box::pin(DataBlockStream::create(
             self.table_info.schema(),
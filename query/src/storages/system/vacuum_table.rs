@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_exception::Result;
+use common_meta_types::TableIdent;
+use common_meta_types::TableInfo;
+use common_meta_types::TableMeta;
+use common_planners::ReadDataSourcePlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::Table;
+
+pub struct VacuumTable {
+    table_info: TableInfo,
+}
+
+impl VacuumTable {
+    pub fn create(table_id: u64) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", DataType::String, false),
+            DataField::new("table", DataType::String, false),
+            DataField::new("state", DataType::String, false),
+            DataField::new("blocks_expired", DataType::UInt64, false),
+            DataField::new("snapshots_removed", DataType::UInt64, false),
+            DataField::new("start_time", DataType::DateTime32(None), false),
+            DataField::new("end_time", DataType::DateTime32(None), true),
+            DataField::new("error", DataType::String, true),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'vacuum'".to_string(),
+            name: "vacuum".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemVacuum".to_string(),
+
+                ..Default::default()
+            },
+        };
+        VacuumTable { table_info }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for VacuumTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        let statuses = ctx.get_vacuum_statuses();
+
+        let mut databases = Vec::with_capacity(statuses.len());
+        let mut tables = Vec::with_capacity(statuses.len());
+        let mut states = Vec::with_capacity(statuses.len());
+        let mut blocks_expired = Vec::with_capacity(statuses.len());
+        let mut snapshots_removed = Vec::with_capacity(statuses.len());
+        let mut start_times = Vec::with_capacity(statuses.len());
+        let mut end_times = Vec::with_capacity(statuses.len());
+        let mut errors = Vec::with_capacity(statuses.len());
+
+        for status in &statuses {
+            databases.push(status.database.clone().into_bytes());
+            tables.push(status.table.clone().into_bytes());
+            states.push(status.state.clone().into_bytes());
+            blocks_expired.push(status.blocks_expired);
+            snapshots_removed.push(status.snapshots_removed);
+            start_times.push(status.start_time.timestamp() as u32);
+            end_times.push(status.end_time.map(|t| t.timestamp() as u32));
+            errors.push(status.error.clone().map(|e| e.into_bytes()));
+        }
+
+        let schema = self.table_info.schema();
+        let block = DataBlock::create_by_array(schema.clone(), vec![
+            Series::new(databases),
+            Series::new(tables),
+            Series::new(states),
+            Series::new(blocks_expired),
+            Series::new(snapshots_removed),
+            Series::new(start_times),
+            Series::new(end_times),
+            Series::new(errors),
+        ]);
+
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::TableInfo;
+use common_planners::ReadDataSourcePlan;
+use common_streams::SendableDataBlockStream;
+
+use crate::sessions::QueryContext;
+use crate::storages::StorageContext;
+use crate::storages::Table;
+
+/// The engine of the views.
+pub const VIEW_ENGINE: &str = "VIEW";
+/// The table option which holds the SQL of the view.
+pub const QUERY: &str = "query";
+/// The max depth of the nested views expanded by a query.
+pub const MAX_VIEW_NESTING_DEPTH: usize = 32;
+
+/// A view holds no data, it is expanded as a subquery by the analyzer.
+pub struct ViewTable {
+    table_info: TableInfo,
+}
+
+impl ViewTable {
+    pub fn try_create(_ctx: StorageContext, table_info: TableInfo) -> Result<Box<dyn Table>> {
+        Ok(Box::new(Self { table_info }))
+    }
+
+    /// Returns the SQL of the view.
+    pub fn query(&self) -> Result<&str> {
+        match self.table_info.options().get(QUERY) {
+            Some(query) => Ok(query.as_str()),
+            None => Err(ErrorCode::LogicalError(format!(
+                "Logical error: the query of view {} is missing",
+                self.table_info.name
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ViewTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read(
+        &self,
+        _ctx: Arc<QueryContext>,
+        _plan: &ReadDataSourcePlan,
+    ) -> Result<SendableDataBlockStream> {
+        Err(ErrorCode::LogicalError(format!(
+            "Logical error: view {} must be expanded before reading, it's a bug.",
+            self.table_info.name
+        )))
+    }
+}
@@ -24,7 +24,9 @@ use common_planners::Expression;
 
 use crate::catalogs::SYS_TBL_FUC_ID_END;
 use crate::catalogs::SYS_TBL_FUNC_ID_BEGIN;
+use crate::storages::fuse::ClusteringInformationTable;
 use crate::storages::fuse::FuseHistoryTable;
+use crate::storages::fuse::FUSE_FUNC_CLUSTERING;
 use crate::storages::fuse::FUSE_FUNC_HIST;
 use crate::table_functions::NumbersTable;
 use crate::table_functions::TableFunction;
@@ -100,6 +102,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(FuseHistoryTable::create)),
         );
 
+        creators.insert(
+            FUSE_FUNC_CLUSTERING.to_string(),
+            (next_id(), Arc::new(ClusteringInformationTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
@@ -14,6 +14,7 @@
 
 mod user;
 mod user_api;
+mod user_copied_file;
 mod user_mgr;
 mod user_stage;
 mod user_udf;
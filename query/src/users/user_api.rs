@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_management::CopiedFileMgr;
+use common_management::CopiedFileMgrApi;
 use common_management::StageMgr;
 use common_management::StageMgrApi;
 use common_management::UdfMgr;
@@ -58,4 +60,8 @@ impl UserApiProvider {
     pub fn get_udf_api_client(&self, tenant: &str) -> Arc<dyn UdfMgrApi> {
         Arc::new(UdfMgr::new(self.client.clone(), tenant))
     }
+
+    pub fn get_copied_file_api_client(&self, tenant: &str) -> Arc<dyn CopiedFileMgrApi> {
+        Arc::new(CopiedFileMgr::new(self.client.clone(), tenant))
+    }
 }
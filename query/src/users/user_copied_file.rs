@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_meta_types::CopiedFileInfo;
+use common_meta_types::MetaId;
+
+use crate::users::UserApiProvider;
+
+/// load history of COPY.
+impl UserApiProvider {
+    // Record the files loaded into a table.
+    pub async fn add_copied_files(
+        &self,
+        tenant: &str,
+        table_id: MetaId,
+        files: Vec<(String, CopiedFileInfo)>,
+    ) -> Result<()> {
+        let copied_file_api_provider = self.get_copied_file_api_client(tenant);
+        let add_copied_files = copied_file_api_provider.add_copied_files(table_id, files);
+        match add_copied_files.await {
+            Err(e) => Err(e.add_message_back("(while add copied files).")),
+            Ok(res) => Ok(res),
+        }
+    }
+
+    // Get the files loaded into a table, by the file path.
+    pub async fn get_copied_files(
+        &self,
+        tenant: &str,
+        table_id: MetaId,
+    ) -> Result<HashMap<String, CopiedFileInfo>> {
+        let copied_file_api_provider = self.get_copied_file_api_client(tenant);
+        let get_copied_files = copied_file_api_provider.get_copied_files(table_id);
+        match get_copied_files.await {
+            Err(e) => Err(e.add_message_back("(while get copied files).")),
+            Ok(res) => Ok(res),
+        }
+    }
+}
@@ -52,6 +52,7 @@ metric_api_address = \"127.0.0.1:7070\"
 http_handler_tls_server_cert = \"\"
 http_handler_tls_server_key = \"\"
 http_handler_tls_server_root_ca_cert = \"\"
+http_handler_jwt_secret_file = \"\"
 api_tls_server_cert = \"\"
 api_tls_server_key = \"\"
 api_tls_server_root_ca_cert = \"\"
@@ -72,6 +73,9 @@ table_cache_block_meta_count = 102400
 table_memory_cache_mb_size = 256
 table_disk_cache_root = \"_cache\"
 table_disk_cache_mb_size = 1024
+table_vacuum_interval_secs = 3600
+table_orphan_retention_secs = 86400
+table_dropped_retention_secs = 86400
 proxy_mode = false
 
 [log]
@@ -213,6 +217,23 @@ fn test_env_config() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_config_check() -> Result<()> {
+    let mut conf = Config::default();
+    conf.query.table_orphan_retention_secs = 0;
+    assert!(conf.query.check().is_err());
+
+    // the values loaded from the config file are checked as well
+    let toml_str = toml::to_string(&conf).unwrap();
+    assert!(Config::load_from_toml_str(&toml_str).is_err());
+
+    conf.query.table_orphan_retention_secs = 3600;
+    let toml_str = toml::to_string(&conf).unwrap();
+    let loaded = Config::load_from_toml_str(&toml_str)?;
+    assert_eq!(3600, loaded.query.table_orphan_retention_secs);
+    Ok(())
+}
+
 #[test]
 fn test_fuse_commit_version() -> Result<()> {
     let v = &databend_query::configs::DATABEND_COMMIT_VERSION;
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::*;
+use databend_query::storages::view::MAX_VIEW_NESTING_DEPTH;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
@@ -76,3 +78,55 @@ async fn test_select_interpreter() -> Result<()> {
     }
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_select_nested_views_interpreter() -> Result<()> {
+    common_tracing::init_default_ut_tracing();
+    let ctx = crate::tests::create_query_context()?;
+
+    let queries = (1..=MAX_VIEW_NESTING_DEPTH).map(|i| {
+        format!(
+            "CREATE VIEW default.v{} AS SELECT * FROM default.v{}",
+            i,
+            i - 1
+        )
+    });
+    let queries = std::iter::once("CREATE VIEW default.v0 AS SELECT * FROM system.one".to_string())
+        .chain(queries);
+    for query in queries {
+        let plan = PlanParser::parse(&query, ctx.clone()).await?;
+        let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+        executor
+            .execute(None)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+    }
+
+    // Views nested no deeper than the limit are expanded.
+    let query = format!("SELECT * FROM default.v{}", MAX_VIEW_NESTING_DEPTH - 1);
+    let plan = PlanParser::parse(&query, ctx.clone()).await?;
+    let executor = InterpreterFactory::get(ctx.clone(), plan)?;
+    let result = executor
+        .execute(None)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let expected = vec![
+        "+-------+",
+        "| dummy |",
+        "+-------+",
+        "| 1     |",
+        "+-------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    let query = format!("SELECT * FROM default.v{}", MAX_VIEW_NESTING_DEPTH);
+    let result = PlanParser::parse(&query, ctx.clone()).await;
+    assert_eq!(
+        result.err().map(|e| e.code()),
+        Some(ErrorCode::SemanticError("").code())
+    );
+
+    Ok(())
+}
@@ -18,15 +18,25 @@ use std::sync::Arc;
 
 use common_base::tokio;
 use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::PasswordType;
+use common_meta_types::UserInfo;
+use common_meta_types::UserPrivilegeSet;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
 use databend_query::servers::http::v1::query_route;
 use databend_query::servers::http::v1::ExecuteStateName;
+use databend_query::servers::http::v1::HTTPSessionMiddleware;
 use databend_query::servers::http::v1::QueryResponse;
 use databend_query::servers::HttpHandler;
 use databend_query::sessions::SessionManager;
+use databend_query::users::User;
 use hyper::header;
+use jwt_simple::algorithms::HS256Key;
+use jwt_simple::algorithms::MACLike;
+use jwt_simple::claims::Claims;
+use jwt_simple::prelude::Duration;
 use poem::http::Method;
 use poem::http::StatusCode;
 use poem::middleware::AddDataEndpoint;
@@ -61,6 +71,11 @@ pub fn get_page_uri(query_id: &str, page_no: usize, wait_time: u32) -> String {
 
 type RouteWithData = AddDataEndpoint<Route, Arc<SessionManager>>;
 
+// root with an empty password
+const ROOT_AUTH: &str = "Basic cm9vdDo=";
+// u1:abc123
+const U1_AUTH: &str = "Basic dTE6YWJjMTIz";
+
 #[tokio::test]
 async fn test_simple_sql() -> Result<()> {
     let sql = "select * from system.tables limit 10";
@@ -91,7 +106,7 @@ async fn test_bad_sql() -> Result<()> {
 #[tokio::test]
 async fn test_async() -> Result<()> {
     let sessions = SessionManagerBuilder::create().build()?;
-    let route = Route::new().nest("/v1/query", query_route()).data(sessions);
+    let route = create_router_with(sessions)?;
     let sql = "select sleep(2)";
     let json = serde_json::json!({"sql": sql.to_string()});
 
@@ -151,7 +166,7 @@ async fn test_async() -> Result<()> {
 #[tokio::test]
 async fn test_multi_page() -> Result<()> {
     let sessions = SessionManagerBuilder::create().build()?;
-    let route = Route::new().nest("/v1/query", query_route()).data(sessions);
+    let route = create_router_with(sessions)?;
 
     let max_block_size = 10000;
     let num_parts = num_cpus::get();
@@ -185,7 +200,20 @@ async fn test_multi_page() -> Result<()> {
 #[tokio::test]
 async fn test_insert() -> Result<()> {
     let sessions = SessionManagerBuilder::create().build()?;
-    let route = Route::new().nest("/v1/query", query_route()).data(sessions);
+    // the requests called in process have no client address, only the users of host '%' match
+    let tenant = sessions.get_conf().query.tenant_id.clone();
+    let mut user_info: UserInfo = User::new("u1", "%", "abc123", PasswordType::PlainText).into();
+    user_info.grants.grant_privileges(
+        "u1",
+        "%",
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    sessions
+        .get_user_manager()
+        .add_user(&tenant, user_info)
+        .await?;
+    let route = create_router_with(sessions)?;
 
     let sqls = vec![
         ("create table t(a int) engine=fuse", 0),
@@ -195,7 +223,8 @@ async fn test_insert() -> Result<()> {
 
     for (sql, data_len) in sqls {
         let json = serde_json::json!({"sql": sql.to_string()});
-        let (status, result) = post_json_to_router(&route, &json, 3).await?;
+        let response = post_json_with_auth(&route, &json, 3, Some(U1_AUTH)).await;
+        let (status, result) = check_response(response).await?;
         assert_eq!(status, StatusCode::OK);
         assert!(result.error.is_none(), "{:?}", result.error);
         assert_eq!(result.data.len(), data_len);
@@ -204,6 +233,135 @@ async fn test_insert() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_auth_basic() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let tenant = sessions.get_conf().query.tenant_id.clone();
+    let user_info = User::new("u1", "%", "abc123", PasswordType::PlainText);
+    sessions
+        .get_user_manager()
+        .add_user(&tenant, user_info.into())
+        .await?;
+    let route = create_router_with(sessions)?;
+
+    let json = serde_json::json!({"sql": "select 1"});
+    // no credentials
+    let response = post_json_with_auth(&route, &json, 3, None).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // u1:wrong
+    let response = post_json_with_auth(&route, &json, 3, Some("Basic dTE6d3Jvbmc=")).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // unknown:
+    let response = post_json_with_auth(&route, &json, 3, Some("Basic dW5rbm93bjo=")).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = post_json_with_auth(&route, &json, 3, Some(U1_AUTH)).await;
+    let (status, result) = check_response(response).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.data.len(), 1);
+
+    // u1 has no privilege to create table
+    let json = serde_json::json!({"sql": "create table t(a int)"});
+    let response = post_json_with_auth(&route, &json, 3, Some(U1_AUTH)).await;
+    let (status, result) = check_response(response).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_some());
+    assert_eq!(result.state, ExecuteStateName::Failed);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_auth_without_client_address() -> Result<()> {
+    let route = create_router();
+
+    // root is only granted from the local host, which a request without the address is not
+    let json = serde_json::json!({"sql": "create table t(a int)"});
+    let (status, result) = post_json_to_router(&route, &json, 3).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_some());
+    assert_eq!(result.state, ExecuteStateName::Failed);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_owner() -> Result<()> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let tenant = sessions.get_conf().query.tenant_id.clone();
+    let user_info = User::new("u1", "%", "abc123", PasswordType::PlainText);
+    sessions
+        .get_user_manager()
+        .add_user(&tenant, user_info.into())
+        .await?;
+    let route = create_router_with(sessions)?;
+
+    let json = serde_json::json!({"sql": "select sleep(2)"});
+    let (status, result) = post_json_to_router(&route, &json, 0).await?;
+    assert_eq!(status, StatusCode::OK);
+    let query_id = result.id;
+
+    // the query of root is not accessible by u1
+    for uri in [
+        make_state_uri(&query_id),
+        get_page_uri(&query_id, 0, 3),
+        make_final_uri(&query_id),
+    ] {
+        let response = get_uri_with_auth(&route, &uri, U1_AUTH).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN, "{}", uri);
+    }
+
+    let response = get_uri_with_auth(&route, &make_state_uri("unknown"), U1_AUTH).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // the query is still running for root
+    let (status, result) = get_uri_checked(&route, &make_state_uri(&query_id)).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_auth_bearer() -> Result<()> {
+    let secret = "databend-secret";
+    let dir = tempfile::tempdir()?;
+    let secret_file = dir.path().join("jwt_secret");
+    std::fs::write(&secret_file, secret)?;
+
+    let json = serde_json::json!({"sql": "select 1"});
+    let claims = || Claims::create(Duration::from_hours(1)).with_subject("root");
+    let token = HS256Key::from_bytes(secret.as_bytes())
+        .authenticate(claims())
+        .unwrap();
+    let auth = format!("Bearer {}", token);
+
+    // bearer tokens are disabled without the secret file
+    {
+        let route = create_router();
+        let response = post_json_with_auth(&route, &json, 3, Some(&auth)).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let sessions = SessionManagerBuilder::create()
+        .http_handler_jwt_secret_file(secret_file.to_str().unwrap())
+        .build()?;
+    let route = create_router_with(sessions)?;
+
+    let response = post_json_with_auth(&route, &json, 3, Some(&auth)).await;
+    let (status, result) = check_response(response).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.data.len(), 1);
+
+    // signed by another key
+    let token = HS256Key::generate().authenticate(claims()).unwrap();
+    let auth = format!("Bearer {}", token);
+    let response = post_json_with_auth(&route, &json, 3, Some(&auth)).await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
 async fn delete_query(route: &RouteWithData, query_id: String) -> StatusCode {
     let uri = make_final_uri(&query_id);
     let resp = get_uri(route, &uri).await;
@@ -224,11 +382,16 @@ async fn check_response(response: Response) -> Result<(StatusCode, QueryResponse
 }
 
 async fn get_uri(route: &RouteWithData, uri: &str) -> Response {
+    get_uri_with_auth(route, uri, ROOT_AUTH).await
+}
+
+async fn get_uri_with_auth(route: &RouteWithData, uri: &str, auth: &str) -> Response {
     route
         .call(
             Request::builder()
                 .uri(uri.parse().unwrap())
                 .method(Method::GET)
+                .header(header::AUTHORIZATION, auth)
                 .finish(),
         )
         .await
@@ -247,7 +410,14 @@ async fn post_sql(sql: &'static str, wait_time: i32) -> Result<(StatusCode, Quer
 
 pub fn create_router() -> RouteWithData {
     let sessions = SessionManagerBuilder::create().build().unwrap();
-    Route::new().nest("/v1/query", query_route()).data(sessions)
+    create_router_with(sessions).unwrap()
+}
+
+fn create_router_with(sessions: Arc<SessionManager>) -> Result<RouteWithData> {
+    let session_middleware = HTTPSessionMiddleware::try_create(sessions.get_conf())?;
+    Ok(Route::new()
+        .nest("/v1/query", query_route().with(session_middleware))
+        .data(sessions))
 }
 
 async fn post_json(
@@ -263,23 +433,32 @@ async fn post_json_to_router(
     json: &serde_json::Value,
     wait_time: i32,
 ) -> Result<(StatusCode, QueryResponse)> {
+    let response = post_json_with_auth(route, json, wait_time, Some(ROOT_AUTH)).await;
+    check_response(response).await
+}
+
+async fn post_json_with_auth(
+    route: &RouteWithData,
+    json: &serde_json::Value,
+    wait_time: i32,
+    auth: Option<&str>,
+) -> Response {
     let path = "/v1/query";
     let uri = format!("{}?wait_time={}", path, wait_time);
     let content_type = "application/json";
-    let body = serde_json::to_vec(&json)?;
-
-    let response = route
-        .call(
-            Request::builder()
-                .uri(uri.parse().unwrap())
-                .method(Method::POST)
-                .header(header::CONTENT_TYPE, content_type)
-                .body(body),
-        )
+    let body = serde_json::to_vec(&json).unwrap();
+
+    let mut request = Request::builder()
+        .uri(uri.parse().unwrap())
+        .method(Method::POST)
+        .header(header::CONTENT_TYPE, content_type);
+    if let Some(auth) = auth {
+        request = request.header(header::AUTHORIZATION, auth);
+    }
+    route
+        .call(request.body(body))
         .await
-        .unwrap();
-
-    check_response(response).await
+        .unwrap_or_else(|err| err.as_response())
 }
 
 // need to support local_addr, but axum_server do not have local_addr callback
@@ -309,7 +488,12 @@ async fn test_http_handler_tls_server() -> Result<()> {
         .add_root_certificate(cert)
         .build()
         .unwrap();
-    let resp = client.post(&url).json(&json).send().await;
+    let resp = client
+        .post(&url)
+        .basic_auth("root", None::<&str>)
+        .json(&json)
+        .send()
+        .await;
     assert!(resp.is_ok(), "{:?}", resp.err());
     let resp = resp.unwrap();
     assert!(resp.status().is_success());
@@ -374,7 +558,12 @@ async fn test_http_service_tls_server_mutual_tls() -> Result<()> {
         .add_root_certificate(cert)
         .build()
         .expect("preconfigured rustls tls");
-    let resp = client.post(&url).json(&json).send().await;
+    let resp = client
+        .post(&url)
+        .basic_auth("root", None::<&str>)
+        .json(&json)
+        .send()
+        .await;
     assert!(resp.is_ok(), "{:?}", resp.err());
     let resp = resp.unwrap();
     assert!(resp.status().is_success());
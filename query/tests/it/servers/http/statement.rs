@@ -15,7 +15,9 @@
 use common_base::tokio;
 use common_exception::Result;
 use databend_query::servers::http::v1::statement_handler;
+use databend_query::servers::http::v1::HTTPSessionMiddleware;
 use databend_query::servers::http::v1::QueryResponse;
+use hyper::header;
 use poem::http::Method;
 use poem::http::StatusCode;
 use poem::post;
@@ -68,8 +70,9 @@ async fn test_sql(
 ) -> Result<(StatusCode, QueryResponse)> {
     let path = "/v1/statement";
     let sessions = SessionManagerBuilder::create().build()?;
+    let session_middleware = HTTPSessionMiddleware::try_create(sessions.get_conf())?;
     let cluster_router = Route::new()
-        .at(path, post(statement_handler))
+        .at(path, post(statement_handler).with(session_middleware))
         .data(sessions);
     let uri = match database {
         Some(db) => format!("{}?db={:}", path, db),
@@ -80,6 +83,7 @@ async fn test_sql(
             Request::builder()
                 .uri(uri.parse().unwrap())
                 .method(Method::POST)
+                .header(header::AUTHORIZATION, "Basic cm9vdDo=")
                 .body(sql),
         )
         .await
@@ -24,25 +24,35 @@ use common_meta_types::StageParams;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
+use common_planners::CopyOnError;
 use common_planners::Optimization;
+use databend_query::sql::statements::DfAlterTable;
+use databend_query::sql::statements::DfAlterTableOperation;
 use databend_query::sql::statements::DfAlterUDF;
 use databend_query::sql::statements::DfAlterUser;
 use databend_query::sql::statements::DfCopy;
+use databend_query::sql::statements::DfCopyIntoStage;
 use databend_query::sql::statements::DfCreateDatabase;
 use databend_query::sql::statements::DfCreateStage;
 use databend_query::sql::statements::DfCreateTable;
 use databend_query::sql::statements::DfCreateUDF;
 use databend_query::sql::statements::DfCreateUser;
+use databend_query::sql::statements::DfCreateView;
+use databend_query::sql::statements::DfDeleteStatement;
 use databend_query::sql::statements::DfDescribeTable;
 use databend_query::sql::statements::DfDropDatabase;
 use databend_query::sql::statements::DfDropStage;
 use databend_query::sql::statements::DfDropTable;
 use databend_query::sql::statements::DfDropUDF;
 use databend_query::sql::statements::DfDropUser;
+use databend_query::sql::statements::DfDropView;
 use databend_query::sql::statements::DfGrantObject;
 use databend_query::sql::statements::DfGrantStatement;
+use databend_query::sql::statements::DfListStage;
+use databend_query::sql::statements::DfNavigationPoint;
 use databend_query::sql::statements::DfOptimizeTable;
 use databend_query::sql::statements::DfQueryStatement;
+use databend_query::sql::statements::DfRenameTable;
 use databend_query::sql::statements::DfRevokeStatement;
 use databend_query::sql::statements::DfShowCreateDatabase;
 use databend_query::sql::statements::DfShowCreateTable;
@@ -50,7 +60,10 @@ use databend_query::sql::statements::DfShowDatabases;
 use databend_query::sql::statements::DfShowGrants;
 use databend_query::sql::statements::DfShowTables;
 use databend_query::sql::statements::DfShowUDF;
+use databend_query::sql::statements::DfTableNavigation;
 use databend_query::sql::statements::DfTruncateTable;
+use databend_query::sql::statements::DfUndropTable;
+use databend_query::sql::statements::DfUpdateStatement;
 use databend_query::sql::statements::DfUseDatabase;
 use databend_query::sql::statements::DfUseTenant;
 use databend_query::sql::*;
@@ -112,6 +125,14 @@ fn parse_sql_to_expr(query_expr: &str) -> Expr {
     parser.parse_expr().unwrap()
 }
 
+fn parse_sql_to_query(query: &str) -> Box<Query> {
+    let dialect = GenericDialect {};
+    let mut tokenizer = Tokenizer::new(&dialect, query);
+    let tokens = tokenizer.tokenize().unwrap();
+    let mut parser = Parser::new(tokens, &dialect);
+    Box::new(parser.parse_query().unwrap())
+}
+
 #[test]
 fn create_database() -> Result<()> {
     {
@@ -184,6 +205,8 @@ fn create_table() -> Result<()> {
         name: ObjectName(vec![Ident::new("t")]),
         columns: vec![make_column_def("c1", DataType::Int(None))],
         engine: "Fuse".to_string(),
+        cluster_keys: vec![],
+        ttl: None,
         options: maplit::hashmap! {"location".into() => "/data/33.csv".into()},
         like: None,
         query: None,
@@ -201,6 +224,8 @@ fn create_table() -> Result<()> {
             make_column_def("c3", DataType::Varchar(Some(255))),
         ],
         engine: "Fuse".to_string(),
+        cluster_keys: vec![],
+        ttl: None,
 
         options: maplit::hashmap! {
             "location".into() => "foo.parquet".into(),
@@ -218,6 +243,8 @@ fn create_table() -> Result<()> {
         name: ObjectName(vec![Ident::new("db1"), Ident::new("test1")]),
         columns: vec![],
         engine: "Parquet".to_string(),
+        cluster_keys: vec![],
+        ttl: None,
 
         options: maplit::hashmap! {"location".into() => "batcave".into()},
         like: Some(ObjectName(vec![Ident::new("db2"), Ident::new("test2")])),
@@ -235,6 +262,8 @@ fn create_table() -> Result<()> {
             make_column_def("c2", DataType::Varchar(Some(255))),
         ],
         engine: "Parquet".to_string(),
+        cluster_keys: vec![],
+        ttl: None,
 
         options: maplit::hashmap! {"location".into() => "batcave".into()},
         like: None,
@@ -255,10 +284,54 @@ fn create_table() -> Result<()> {
             order_by: vec![],
             limit: None,
             offset: None,
+            navigations: vec![],
         })),
     });
     expect_parse_ok(sql, expected)?;
 
+    // create table with cluster keys
+    let sql = "CREATE TABLE t(c1 int, c2 int) CLUSTER BY (c1, c2 + 1)";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def("c1", DataType::Int(None)),
+            make_column_def("c2", DataType::Int(None)),
+        ],
+        engine: "FUSE".to_string(),
+        cluster_keys: vec![Expr::Identifier(Ident::new("c1")), Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("c2"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+        }],
+        ttl: None,
+        options: maplit::hashmap! {},
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    // create table with ttl
+    let sql = "CREATE TABLE t(c1 date, c2 int) TTL c1";
+    let expected = DfStatement::CreateTable(DfCreateTable {
+        if_not_exists: false,
+        name: ObjectName(vec![Ident::new("t")]),
+        columns: vec![
+            make_column_def("c1", DataType::Date),
+            make_column_def("c2", DataType::Int(None)),
+        ],
+        engine: "FUSE".to_string(),
+        cluster_keys: vec![],
+        ttl: Some(Expr::Identifier(Ident::new("c1"))),
+        options: maplit::hashmap! {},
+        like: None,
+        query: None,
+    });
+    expect_parse_ok(sql, expected)?;
+
+    let sql = "CREATE TABLE t(c1 int) CLUSTER (c1)";
+    expect_parse_err(sql, "sql parser error: Expected BY, found: (".to_string())?;
+
     Ok(())
 }
 
@@ -285,6 +358,113 @@ fn drop_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rename_table() -> Result<()> {
+    {
+        let sql = "RENAME TABLE t1 TO t2";
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("t1")]),
+            new_name: ObjectName(vec![Ident::new("t2")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "RENAME TABLE IF EXISTS db1.t1 TO db2.t2";
+        let expected = DfStatement::RenameTable(DfRenameTable {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            new_name: ObjectName(vec![Ident::new("db2"), Ident::new("t2")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "RENAME TABLE t1 t2";
+        expect_parse_err(sql, "sql parser error: Expected TO, found: t2".to_string())?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn undrop_table() -> Result<()> {
+    {
+        let sql = "UNDROP TABLE t1";
+        let expected = DfStatement::UndropTable(DfUndropTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UNDROP TABLE db1.t1";
+        let expected = DfStatement::UndropTable(DfUndropTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn create_view() -> Result<()> {
+    {
+        let sql = "CREATE VIEW v1 AS SELECT a, b FROM t1 WHERE a > 1";
+        let expected = DfStatement::CreateView(DfCreateView {
+            if_not_exists: false,
+            name: ObjectName(vec![Ident::new("v1")]),
+            query: parse_sql_to_query("SELECT a, b FROM t1 WHERE a > 1"),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "CREATE VIEW IF NOT EXISTS db1.v1 AS SELECT * FROM t1";
+        let expected = DfStatement::CreateView(DfCreateView {
+            if_not_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v1")]),
+            query: parse_sql_to_query("SELECT * FROM t1"),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "CREATE VIEW v1 SELECT * FROM t1";
+        expect_parse_err(
+            sql,
+            "sql parser error: Expected AS, found: SELECT".to_string(),
+        )?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn drop_view() -> Result<()> {
+    {
+        let sql = "DROP VIEW v1";
+        let expected = DfStatement::DropView(DfDropView {
+            if_exists: false,
+            name: ObjectName(vec![Ident::new("v1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DROP VIEW IF EXISTS db1.v1";
+        let expected = DfStatement::DropView(DfDropView {
+            if_exists: true,
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("v1")]),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    Ok(())
+}
+
 #[test]
 fn describe_table() -> Result<()> {
     {
@@ -346,6 +526,18 @@ fn show_queries() -> Result<()> {
         ))),
     )?;
 
+    expect_parse_ok(
+        "SHOW TABLES HISTORY",
+        DfStatement::ShowTables(DfShowTables::History(None)),
+    )?;
+
+    expect_parse_ok(
+        "SHOW TABLES HISTORY FROM db1",
+        DfStatement::ShowTables(DfShowTables::History(Some(ObjectName(vec![Ident::new(
+            "db1",
+        )])))),
+    )?;
+
     Ok(())
 }
 
@@ -510,6 +702,160 @@ fn truncate_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn delete_from() -> Result<()> {
+    {
+        let sql = "DELETE FROM t1";
+        let expected = DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "DELETE FROM db1.t1 WHERE a > 1";
+        let expected = DfStatement::Delete(DfDeleteStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            }),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err(
+        "DELETE t1",
+        String::from("sql parser error: Expected FROM, found: t1"),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn update() -> Result<()> {
+    {
+        let sql = "UPDATE t1 SET a = 1";
+        let expected = DfStatement::Update(DfUpdateStatement {
+            name: ObjectName(vec![Ident::new("t1")]),
+            assignments: vec![(
+                Ident::new("a"),
+                Expr::Value(Value::Number("1".to_string(), false)),
+            )],
+            selection: None,
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "UPDATE db1.t1 SET a = b, b = 'x' WHERE a > 1";
+        let expected = DfStatement::Update(DfUpdateStatement {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            assignments: vec![
+                (Ident::new("a"), Expr::Identifier(Ident::new("b"))),
+                (
+                    Ident::new("b"),
+                    Expr::Value(Value::SingleQuotedString("x".to_string())),
+                ),
+            ],
+            selection: Some(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            }),
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    expect_parse_err(
+        "UPDATE t1 a = 1",
+        String::from("sql parser error: Expected SET, found: a"),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn time_travel() -> Result<()> {
+    {
+        let query = verified_query("SELECT * FROM t1 AT (SNAPSHOT => 'abc')")?;
+        let expected = TableFactor::Table {
+            name: ObjectName(vec![Ident::new("t1")]),
+            alias: None,
+            args: vec![],
+            with_hints: vec![],
+        };
+        assert_eq!(query.from[0].relation, expected);
+        assert_eq!(query.navigations, vec![DfTableNavigation {
+            table: Ident::new("t1"),
+            point: DfNavigationPoint::Snapshot("abc".to_string()),
+        }]);
+    }
+
+    {
+        let query = verified_query(
+            "SELECT * FROM db1.t1 AS a at (timestamp => '2022-01-01 00:00:00') JOIN t2 ON a.x = t2.x",
+        )?;
+        let expected = TableFactor::Table {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            alias: Some(TableAlias {
+                name: Ident::new("a"),
+                columns: vec![],
+            }),
+            args: vec![],
+            with_hints: vec![],
+        };
+        assert_eq!(query.from[0].relation, expected);
+        assert_eq!(query.navigations, vec![DfTableNavigation {
+            table: Ident::new("a"),
+            point: DfNavigationPoint::Timestamp("2022-01-01 00:00:00".to_string()),
+        }]);
+    }
+
+    // the time travel clauses are of the statement they are in
+    {
+        let (statements, _) =
+            DfParser::parse_sql("SELECT * FROM t1; SELECT * FROM t1 AT (SNAPSHOT => 'abc')")?;
+        let navigations = statements
+            .iter()
+            .map(|statement| match statement {
+                DfStatement::Query(query) => query.navigations.len(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(navigations, vec![0, 1]);
+    }
+
+    expect_parse_err(
+        "SELECT * FROM t1 AT (SNAPSHOT = 'abc')",
+        String::from("sql parser error: Expected =>, found: ="),
+    )?;
+
+    expect_parse_err(
+        "SELECT * FROM t1 AT (SNAPSHOT => 1)",
+        String::from("sql parser error: Expected literal string, found: 1"),
+    )?;
+
+    expect_parse_err(
+        "SELECT * FROM t1 AT (SNAPSHOT => 'a') JOIN t1 AT (SNAPSHOT => 'b')",
+        String::from("sql parser error: Duplicate time travel of table t1"),
+    )?;
+
+    expect_parse_err(
+        "SELECT * FROM t1 WHERE EXISTS (SELECT * FROM t2 AT (SNAPSHOT => 'abc'))",
+        String::from("sql parser error: Time travel is only supported for the tables in the FROM clause of the outermost query"),
+    )?;
+
+    expect_parse_err(
+        "DELETE FROM t1 AT (SNAPSHOT => 'abc')",
+        String::from("sql parser error: Time travel is only supported in the query statement"),
+    )?;
+
+    Ok(())
+}
+
 #[test]
 fn hint_test() -> Result<()> {
     {
@@ -557,6 +903,8 @@ fn copy_test() -> Result<()> {
             name,
             columns: vec![],
             location: "@my_ext_stage/tutorials/sample.csv".to_string(),
+            pattern: None,
+            on_error: CopyOnError::Abort,
             format: "csv".to_string(),
             options: maplit::hashmap! {
                 "csv_header".into() => "1".into(),
@@ -569,6 +917,110 @@ fn copy_test() -> Result<()> {
 
     )?;
 
+    expect_parse_ok(
+        "copy into test_csv from '@my_ext_stage/logs/' format csv PATTERN = '.*[.]csv[.]gz' compression = gzip ON_ERROR = skip_file;",
+        DfStatement::Copy(DfCopy {
+            name: ObjectName(vec![Ident::new("test_csv")]),
+            columns: vec![],
+            location: "@my_ext_stage/logs/".to_string(),
+            pattern: Some(".*[.]csv[.]gz".to_string()),
+            on_error: CopyOnError::SkipFile,
+            format: "csv".to_string(),
+            options: maplit::hashmap! {
+                "compression".into() => "gzip".into(),
+            },
+        }),
+    )?;
+
+    expect_parse_err(
+        "copy into test_csv from '@my_ext_stage/logs/' format csv ON_ERROR = retry;",
+        "sql parser error: Unknown ON_ERROR: RETRY, expect one of CONTINUE, SKIP_FILE, ABORT"
+            .to_string(),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn copy_into_stage_test() -> Result<()> {
+    expect_parse_ok(
+        "copy into '@my_ext_stage/unload/' from (select number from numbers(10)) file_format = (format = parquet) max_file_size = 1024;",
+        DfStatement::CopyIntoStage(DfCopyIntoStage {
+            location: "@my_ext_stage/unload/".to_string(),
+            query: verified_query("select number from numbers(10)")?,
+            file_format: FileFormat {
+                format: Format::Parquet,
+                ..Default::default()
+            },
+            options: maplit::hashmap! {
+                "max_file_size".into() => "1024".into(),
+            },
+        }),
+    )?;
+
+    expect_parse_ok(
+        "copy into '@my_ext_stage/unload/data' from (select a, b from t) file_format = (format = csv field_delimiter = '|' csv_header = 1);",
+        DfStatement::CopyIntoStage(DfCopyIntoStage {
+            location: "@my_ext_stage/unload/data".to_string(),
+            query: verified_query("select a, b from t")?,
+            file_format: FileFormat {
+                format: Format::Csv,
+                field_delimiter: "|".to_string(),
+                csv_header: true,
+                ..Default::default()
+            },
+            options: HashMap::new(),
+        }),
+    )?;
+
+    Ok(())
+}
+
+#[test]
+fn list_stage_test() -> Result<()> {
+    expect_parse_ok(
+        "list @my_ext_stage/tutorials/",
+        DfStatement::ListStage(DfListStage {
+            location: "@my_ext_stage/tutorials/".to_string(),
+            pattern: None,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "LIST @my_ext_stage;",
+        DfStatement::ListStage(DfListStage {
+            location: "@my_ext_stage/".to_string(),
+            pattern: None,
+        }),
+    )?;
+
+    expect_parse_ok(
+        "list '@my_ext_stage/logs/2021/' pattern = '.*[.]csv'",
+        DfStatement::ListStage(DfListStage {
+            location: "@my_ext_stage/logs/2021/".to_string(),
+            pattern: Some(".*[.]csv".to_string()),
+        }),
+    )?;
+
+    expect_parse_ok(
+        "list @my_ext_stage/logs/sample.csv PATTERN = 'sample.*'",
+        DfStatement::ListStage(DfListStage {
+            location: "@my_ext_stage/logs/sample.csv".to_string(),
+            pattern: Some("sample.*".to_string()),
+        }),
+    )?;
+
+    expect_parse_err(
+        "list my_ext_stage",
+        "sql parser error: Expected stage location like @stage/path, found: my_ext_stage"
+            .to_string(),
+    )?;
+
+    expect_parse_err(
+        "list @my_ext_stage/logs/ recursive = 1",
+        "sql parser error: Unknown option of LIST: recursive".to_string(),
+    )?;
+
     Ok(())
 }
 
@@ -1257,6 +1709,8 @@ fn create_table_select() -> Result<()> {
             name: ObjectName(vec![Ident::new("foo")]),
             columns: vec![],
             engine: "FUSE".to_string(),
+            cluster_keys: vec![],
+            ttl: None,
             options: maplit::hashmap! {},
             like: None,
             query: Some(verified_query("SELECT a, b FROM bar")?),
@@ -1270,6 +1724,8 @@ fn create_table_select() -> Result<()> {
             name: ObjectName(vec![Ident::new("foo")]),
             columns: vec![make_column_def("a", DataType::Int(None))],
             engine: "FUSE".to_string(),
+            cluster_keys: vec![],
+            ttl: None,
             options: maplit::hashmap! {},
             like: None,
             query: Some(verified_query("SELECT a, b FROM bar")?),
@@ -1347,6 +1803,64 @@ fn optimize_table() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn alter_table() -> Result<()> {
+    {
+        let sql = "ALTER TABLE t1 ADD COLUMN c2 int";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: DfAlterTableOperation::AddColumn {
+                column_def: make_column_def("c2", DataType::Int(None)),
+            },
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE db1.t1 ADD c2 int";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("db1"), Ident::new("t1")]),
+            operation: DfAlterTableOperation::AddColumn {
+                column_def: make_column_def("c2", DataType::Int(None)),
+            },
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 DROP COLUMN c2";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: DfAlterTableOperation::DropColumn {
+                column_name: Ident::new("c2"),
+            },
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 RENAME COLUMN c1 TO c2";
+        let expected = DfStatement::AlterTable(DfAlterTable {
+            name: ObjectName(vec![Ident::new("t1")]),
+            operation: DfAlterTableOperation::RenameColumn {
+                old_column_name: Ident::new("c1"),
+                new_column_name: Ident::new("c2"),
+            },
+        });
+        expect_parse_ok(sql, expected)?;
+    }
+
+    {
+        let sql = "ALTER TABLE t1 MODIFY c1 int";
+        expect_parse_err(
+            sql,
+            "sql parser error: Expected one of ADD, DROP, RENAME, found: MODIFY".to_string(),
+        )?;
+    }
+
+    Ok(())
+}
+
 #[test]
 fn drop_stage_test() -> Result<()> {
     expect_parse_ok(
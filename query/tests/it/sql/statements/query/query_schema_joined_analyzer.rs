@@ -49,6 +49,17 @@ async fn test_joined_schema_analyzer() -> Result<()> {
             query: "SELECT * FROM (SELECT * FROM system.databases)",
             expect: "QuerySchema { short_names: [\"name\"] }",
         },
+        TestCase {
+            name: "Join query",
+            query:
+                "SELECT * FROM system.databases AS a JOIN system.databases AS b ON a.name = b.name",
+            expect: "QuerySchema { ambiguity_names: [[\"a\", \"name\"], [\"b\", \"name\"]] }",
+        },
+        TestCase {
+            name: "Join query with different columns",
+            query: "SELECT * FROM system.databases AS a CROSS JOIN (SELECT 1 AS c) AS b",
+            expect: "QuerySchema { short_names: [\"name\", \"c\"] }",
+        },
     ];
 
     for test_case in &tests {
@@ -73,3 +84,47 @@ async fn test_joined_schema_analyzer() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_joined_schema_analyzer_with_error() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+    }
+    let tests = vec![
+        TestCase {
+            name: "Duplicated table in join",
+            query: "SELECT * FROM system.databases JOIN system.databases ON 1 = 1",
+            expect: "Code: 1005, displayText = Table system.databases is duplicated in JOIN, please use alias.",
+        },
+        TestCase {
+            name: "Non-equi condition in outer join",
+            query: "SELECT * FROM numbers(3) AS a LEFT JOIN numbers(3) AS b ON a.number > b.number",
+            expect: "Code: 1002, displayText = Unsupported non-equi condition in LEFT JOIN yet..",
+        },
+    ];
+
+    for test_case in &tests {
+        let ctx = create_query_context()?;
+        let (mut statements, _) = DfParser::parse_sql(test_case.query)?;
+
+        match statements.remove(0) {
+            DfStatement::Query(query) => {
+                let analyzer = JoinedSchemaAnalyzer::create(ctx);
+                let res = analyzer.analyze(&query).await;
+                assert_eq!(
+                    test_case.expect,
+                    res.err().unwrap().to_string(),
+                    "{:#?}",
+                    test_case.name
+                )
+            }
+            _ => {
+                return Err(ErrorCode::LogicalError("Cannot get analyze query state."));
+            }
+        }
+    }
+
+    Ok(())
+}
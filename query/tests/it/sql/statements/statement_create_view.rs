@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sql::statements::ViewQualifier;
+use databend_query::sql::DfParser;
+use databend_query::sql::DfStatement;
+
+#[test]
+fn test_statement_create_view_qualify() -> Result<()> {
+    struct TestCase {
+        name: &'static str,
+        query: &'static str,
+        expect: &'static str,
+    }
+
+    let tests = vec![
+        TestCase {
+            name: "Simple query",
+            query: "CREATE VIEW v AS SELECT a FROM t, db2.t2, numbers(3)",
+            expect: "SELECT a FROM `db1`.t, db2.t2, numbers(3)",
+        },
+        TestCase {
+            name: "Subquery",
+            query: "CREATE VIEW v AS SELECT a FROM (SELECT a FROM t) WHERE a IN (SELECT a FROM t2)",
+            expect: "SELECT a FROM (SELECT a FROM `db1`.t) WHERE a IN (SELECT a FROM `db1`.t2)",
+        },
+        TestCase {
+            name: "Union query",
+            query: "CREATE VIEW v AS SELECT a FROM t UNION ALL (SELECT a FROM t2 UNION SELECT a FROM t3)",
+            expect: "SELECT a FROM `db1`.t UNION ALL (SELECT a FROM `db1`.t2 UNION SELECT a FROM `db1`.t3)",
+        },
+        TestCase {
+            name: "CTE query",
+            query: "CREATE VIEW v AS WITH c1 AS (SELECT a FROM t), c2 AS (SELECT a FROM c1) SELECT a FROM c2 UNION SELECT a FROM t2",
+            expect: "WITH c1 AS (SELECT a FROM `db1`.t), c2 AS (SELECT a FROM c1) SELECT a FROM c2 UNION SELECT a FROM `db1`.t2",
+        },
+        TestCase {
+            name: "CTE out of scope",
+            query: "CREATE VIEW v AS SELECT a FROM (WITH c AS (SELECT a FROM t) SELECT a FROM c), c",
+            expect: "SELECT a FROM (WITH c AS (SELECT a FROM `db1`.t) SELECT a FROM c), `db1`.c",
+        },
+    ];
+
+    for test in &tests {
+        let (mut statements, _) = DfParser::parse_sql(test.query)?;
+        match statements.remove(0) {
+            DfStatement::CreateView(create_view) => {
+                let mut query = create_view.query.as_ref().clone();
+                ViewQualifier::qualify_query(&mut query, "db1");
+                assert_eq!(test.expect, query.to_string(), "{:#?}", test.name);
+            }
+            _ => return Err(ErrorCode::LogicalError("Expect a create view statement")),
+        }
+    }
+
+    Ok(())
+}
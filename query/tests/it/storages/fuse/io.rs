@@ -13,6 +13,7 @@
 //  limitations under the License.
 //
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_base::tokio;
@@ -22,10 +23,17 @@ use common_datavalues::prelude::SeriesFrom;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
+use databend_query::storages::fuse::io::gen_block_location;
+use databend_query::storages::fuse::io::snapshot_location;
+use databend_query::storages::fuse::io::table_prefix_of;
 use databend_query::storages::fuse::io::BlockStreamWriter;
+use databend_query::storages::fuse::io::BlockWriteOptions;
+use databend_query::storages::fuse::meta::BlockCompression;
+use databend_query::storages::fuse::meta::ColumnEncoding;
 use databend_query::storages::fuse::DEFAULT_CHUNK_BLOCK_NUM;
 use futures::StreamExt;
 use tempfile::TempDir;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn test_fuse_table_block_appender() {
@@ -40,8 +48,13 @@ async fn test_fuse_table_block_appender() {
 
     let segments = BlockStreamWriter::write_block_stream(
         local_fs.clone(),
+        "".to_string(),
         Box::pin(block_stream),
         schema.clone(),
+        vec![0],
+        vec![],
+        vec![],
+        BlockWriteOptions::default(),
         DEFAULT_CHUNK_BLOCK_NUM,
         0,
     )
@@ -65,8 +78,13 @@ async fn test_fuse_table_block_appender() {
 
     let segments = BlockStreamWriter::write_block_stream(
         local_fs.clone(),
+        "".to_string(),
         Box::pin(block_stream),
         schema.clone(),
+        vec![0],
+        vec![],
+        vec![],
+        BlockWriteOptions::default(),
         chunk_size,
         0,
     )
@@ -84,8 +102,13 @@ async fn test_fuse_table_block_appender() {
     let block_stream = futures::stream::iter(vec![]);
     let segments = BlockStreamWriter::write_block_stream(
         local_fs,
+        "".to_string(),
         Box::pin(block_stream),
         schema,
+        vec![0],
+        vec![],
+        vec![],
+        BlockWriteOptions::default(),
         DEFAULT_CHUNK_BLOCK_NUM,
         0,
     )
@@ -157,6 +180,73 @@ fn test_fuse_table_block_appender_reshape() -> common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_fuse_block_write_options() -> common_exception::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::String, false),
+        DataField::new("c", DataType::Boolean, false),
+    ]);
+    let column_ids = vec![0, 2, 3];
+
+    // defaults
+    let options = BlockWriteOptions::try_create(&HashMap::new(), &schema, &column_ids)?;
+    assert_eq!(options, BlockWriteOptions::default());
+    assert_eq!(options.column_encoding(0), ColumnEncoding {
+        compression: BlockCompression::Lz4,
+        dictionary: false,
+    });
+
+    let table_options = maplit::hashmap! {
+        "compression".to_owned() => "ZSTD".to_owned(),
+        "column_compression".to_owned() => "b:none, c:snappy".to_owned(),
+        "dictionary_columns".to_owned() => "b".to_owned(),
+        "row_group_size".to_owned() => "1024".to_owned(),
+    };
+    let options = BlockWriteOptions::try_create(&table_options, &schema, &column_ids)?;
+    assert_eq!(options.row_group_size, 1024);
+    assert_eq!(options.column_encoding(0), ColumnEncoding {
+        compression: BlockCompression::Zstd,
+        dictionary: false,
+    });
+    assert_eq!(options.column_encoding(2), ColumnEncoding {
+        compression: BlockCompression::None,
+        dictionary: true,
+    });
+    assert_eq!(options.column_encoding(3), ColumnEncoding {
+        compression: BlockCompression::Snappy,
+        dictionary: false,
+    });
+    assert_eq!(options.column_options(&schema, &column_ids), vec![
+        (
+            "column_compression".to_owned(),
+            "b:none,c:snappy".to_owned()
+        ),
+        ("dictionary_columns".to_owned(), "b".to_owned()),
+    ]);
+
+    // invalid options
+    for (key, value, err) in [
+        ("compression", "gzip", "unknown compression gzip"),
+        ("column_compression", "x:zstd", "unknown column x"),
+        ("column_compression", "a", "expect column:compression"),
+        ("dictionary_columns", "c", "can not be dictionary encoded"),
+        ("row_group_size", "0", "expect a positive integer"),
+    ] {
+        let table_options = maplit::hashmap! { key.to_owned() => value.to_owned() };
+        let r = BlockWriteOptions::try_create(&table_options, &schema, &column_ids);
+        assert!(
+            matches!(&r, Err(e) if e.message().contains(err)),
+            "unexpected result of {} = {}: {:?}",
+            key,
+            value,
+            r
+        );
+    }
+
+    Ok(())
+}
+
 fn gen_blocks(sample_block: &DataBlock, num: usize) -> (impl Iterator<Item = DataBlock>, usize) {
     let block_size = sample_block.memory_size();
     let block = sample_block.clone();
@@ -164,3 +254,20 @@ fn gen_blocks(sample_block: &DataBlock, num: usize) -> (impl Iterator<Item = Dat
     let ideal_threshold = block_size * num;
     (blocks, ideal_threshold)
 }
+
+#[test]
+fn test_fuse_table_locations() {
+    // the objects of a table are kept under the prefix of it
+    let block_location = gen_block_location("1");
+    assert!(block_location.starts_with("1/_b/"));
+
+    let id = Uuid::new_v4();
+    let location = snapshot_location("1", &id);
+    assert_eq!(location, format!("1/_ss/{}", id.simple()));
+    assert_eq!("1", table_prefix_of(&location));
+
+    // the tables created by older versions keep theirs under the root
+    let location = snapshot_location("", &id);
+    assert_eq!(location, format!("_ss/{}", id.simple()));
+    assert_eq!("", table_prefix_of(&location));
+}
@@ -0,0 +1,295 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use std::collections::HashMap;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::serialize_into_buf;
+use common_io::prelude::BytesMut;
+use databend_query::storages::fuse::meta::BlockCompression;
+use databend_query::storages::fuse::meta::BlockLocation;
+use databend_query::storages::fuse::meta::BloomFilterIndexLocation;
+use databend_query::storages::fuse::meta::ClusterStatistics;
+use databend_query::storages::fuse::meta::ColumnEncoding;
+use databend_query::storages::fuse::meta::ColumnId;
+use databend_query::storages::fuse::meta::MetaFormat;
+use databend_query::storages::fuse::meta::SegmentInfo;
+use databend_query::storages::fuse::meta::Statistics;
+use databend_query::storages::fuse::meta::TableSnapshot;
+use databend_query::storages::fuse::statistics::StatisticsAccumulator;
+use databend_query::storages::index::ColumnStatistics;
+use uuid::Uuid;
+
+fn sample_segment() -> Result<SegmentInfo> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+    let block = DataBlock::create_by_array(schema.clone(), vec![Series::new(vec![1, 2, 3])]);
+    let acc = StatisticsAccumulator::new()
+        .begin(&block, &[0], None)?
+        .with_bloom_filter_index(Some(BloomFilterIndexLocation {
+            path: "_b/sample.bloom".to_owned(),
+            size: 64,
+        }))
+        .with_col_encodings(HashMap::from([(0, ColumnEncoding {
+            compression: BlockCompression::Zstd,
+            dictionary: true,
+        })]))
+        .end(1024, "_b/sample.parquet".to_owned());
+    let col_stats = acc.summary(schema.as_ref(), &[0])?;
+    Ok(SegmentInfo {
+        format_version: SegmentInfo::FORMAT_VERSION,
+        blocks: acc.blocks_metas,
+        summary: Statistics {
+            row_count: acc.summary_row_count,
+            block_count: acc.summary_block_count,
+            uncompressed_byte_size: acc.in_memory_size,
+            compressed_byte_size: acc.file_size,
+            col_stats,
+        },
+    })
+}
+
+fn sample_snapshot() -> TableSnapshot {
+    TableSnapshot {
+        format_version: TableSnapshot::FORMAT_VERSION,
+        snapshot_id: Uuid::new_v4(),
+        prev_snapshot_id: None,
+        timestamp: None,
+        schema: DataSchema::empty(),
+        column_ids: vec![],
+        summary: Default::default(),
+        segments: vec!["_sg/sample".to_owned()],
+    }
+}
+
+#[test]
+fn test_fuse_meta_snapshot_format() -> Result<()> {
+    let snapshot = sample_snapshot();
+    let decoded = TableSnapshot::decode(&snapshot.encode()?)?;
+    assert_eq!(TableSnapshot::FORMAT_VERSION, decoded.format_version);
+    assert_eq!(snapshot.snapshot_id, decoded.snapshot_id);
+    assert_eq!(snapshot.segments, decoded.segments);
+
+    // snapshots of version 0, which have no `format_version` field
+    let mut legacy = serde_json::to_value(&snapshot)?;
+    legacy.as_object_mut().unwrap().remove("format_version");
+    let decoded = TableSnapshot::decode(&serde_json::to_vec(&legacy)?)?;
+    assert_eq!(0, decoded.format_version);
+    assert_eq!(snapshot.snapshot_id, decoded.snapshot_id);
+    Ok(())
+}
+
+#[test]
+fn test_fuse_meta_segment_format() -> Result<()> {
+    let segment = sample_segment()?;
+    let bytes = segment.encode()?;
+    assert_eq!(&SegmentInfo::FORMAT_VERSION.to_le_bytes(), &bytes[..8]);
+
+    let decoded = SegmentInfo::decode(&bytes)?;
+    assert_eq!(SegmentInfo::FORMAT_VERSION, decoded.format_version);
+    assert_eq!(1, decoded.blocks.len());
+    assert_eq!(3, decoded.summary.row_count);
+    assert_eq!("_b/sample.parquet", decoded.blocks[0].location.path);
+    assert_eq!(
+        Some("_b/sample.bloom"),
+        decoded.blocks[0]
+            .bloom_filter_index
+            .as_ref()
+            .map(|l| l.path.as_str())
+    );
+    assert_eq!(
+        Some(BlockCompression::Zstd),
+        decoded.blocks[0]
+            .col_encodings
+            .get(&0)
+            .map(|e| e.compression)
+    );
+    let col_stats = decoded.summary.col_stats.get(&0).unwrap();
+    assert_eq!(DataValue::Int32(Some(1)), col_stats.min);
+    assert_eq!(DataValue::Int32(Some(3)), col_stats.max);
+
+    // segments of version 0, which are json without the `format_version` field
+    let mut legacy = serde_json::to_value(&segment)?;
+    legacy.as_object_mut().unwrap().remove("format_version");
+    let decoded = SegmentInfo::decode(&serde_json::to_vec(&legacy)?)?;
+    assert_eq!(0, decoded.format_version);
+    assert_eq!(1, decoded.blocks.len());
+    assert_eq!(3, decoded.summary.row_count);
+
+    // segments of version 1, of which the blocks have no `cluster_stats`
+    #[derive(serde::Serialize)]
+    struct BlockMetaV1<'a> {
+        row_count: u64,
+        block_size: u64,
+        file_size: u64,
+        col_stats: &'a HashMap<ColumnId, ColumnStatistics>,
+        location: &'a BlockLocation,
+    }
+
+    #[derive(serde::Serialize)]
+    struct SegmentInfoV1<'a> {
+        format_version: u64,
+        blocks: Vec<BlockMetaV1<'a>>,
+        summary: &'a Statistics,
+    }
+
+    let legacy = SegmentInfoV1 {
+        format_version: 1,
+        blocks: segment
+            .blocks
+            .iter()
+            .map(|b| BlockMetaV1 {
+                row_count: b.row_count,
+                block_size: b.block_size,
+                file_size: b.file_size,
+                col_stats: &b.col_stats,
+                location: &b.location,
+            })
+            .collect(),
+        summary: &segment.summary,
+    };
+    let mut payload = BytesMut::new();
+    serialize_into_buf(&mut payload, &legacy)?;
+    let mut bytes = 1u64.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&lz4::block::compress(&payload, None, true)?);
+    let decoded = SegmentInfo::decode(&bytes)?;
+    assert_eq!(1, decoded.format_version);
+    assert_eq!(1, decoded.blocks.len());
+    assert_eq!("_b/sample.parquet", decoded.blocks[0].location.path);
+    assert_eq!(None, decoded.blocks[0].cluster_stats);
+
+    // segments of version 2, of which the blocks have no `bloom_filter_index`
+    #[derive(serde::Serialize)]
+    struct BlockMetaV2<'a> {
+        row_count: u64,
+        block_size: u64,
+        file_size: u64,
+        col_stats: &'a HashMap<ColumnId, ColumnStatistics>,
+        location: &'a BlockLocation,
+        cluster_stats: &'a Option<ClusterStatistics>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct SegmentInfoV2<'a> {
+        format_version: u64,
+        blocks: Vec<BlockMetaV2<'a>>,
+        summary: &'a Statistics,
+    }
+
+    let legacy = SegmentInfoV2 {
+        format_version: 2,
+        blocks: segment
+            .blocks
+            .iter()
+            .map(|b| BlockMetaV2 {
+                row_count: b.row_count,
+                block_size: b.block_size,
+                file_size: b.file_size,
+                col_stats: &b.col_stats,
+                location: &b.location,
+                cluster_stats: &b.cluster_stats,
+            })
+            .collect(),
+        summary: &segment.summary,
+    };
+    let mut payload = BytesMut::new();
+    serialize_into_buf(&mut payload, &legacy)?;
+    let mut bytes = 2u64.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&lz4::block::compress(&payload, None, true)?);
+    let decoded = SegmentInfo::decode(&bytes)?;
+    assert_eq!(2, decoded.format_version);
+    assert_eq!(1, decoded.blocks.len());
+    assert_eq!("_b/sample.parquet", decoded.blocks[0].location.path);
+    assert_eq!(None, decoded.blocks[0].bloom_filter_index);
+
+    // segments of version 3, of which the blocks have no `col_encodings`
+    #[derive(serde::Serialize)]
+    struct BlockMetaV3<'a> {
+        row_count: u64,
+        block_size: u64,
+        file_size: u64,
+        col_stats: &'a HashMap<ColumnId, ColumnStatistics>,
+        location: &'a BlockLocation,
+        cluster_stats: &'a Option<ClusterStatistics>,
+        bloom_filter_index: &'a Option<BloomFilterIndexLocation>,
+    }
+
+    #[derive(serde::Serialize)]
+    struct SegmentInfoV3<'a> {
+        format_version: u64,
+        blocks: Vec<BlockMetaV3<'a>>,
+        summary: &'a Statistics,
+    }
+
+    let legacy = SegmentInfoV3 {
+        format_version: 3,
+        blocks: segment
+            .blocks
+            .iter()
+            .map(|b| BlockMetaV3 {
+                row_count: b.row_count,
+                block_size: b.block_size,
+                file_size: b.file_size,
+                col_stats: &b.col_stats,
+                location: &b.location,
+                cluster_stats: &b.cluster_stats,
+                bloom_filter_index: &b.bloom_filter_index,
+            })
+            .collect(),
+        summary: &segment.summary,
+    };
+    let mut payload = BytesMut::new();
+    serialize_into_buf(&mut payload, &legacy)?;
+    let mut bytes = 3u64.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&lz4::block::compress(&payload, None, true)?);
+    let decoded = SegmentInfo::decode(&bytes)?;
+    assert_eq!(3, decoded.format_version);
+    assert_eq!(1, decoded.blocks.len());
+    assert_eq!("_b/sample.parquet", decoded.blocks[0].location.path);
+    assert_eq!(
+        Some("_b/sample.bloom"),
+        decoded.blocks[0]
+            .bloom_filter_index
+            .as_ref()
+            .map(|l| l.path.as_str())
+    );
+    assert!(decoded.blocks[0].col_encodings.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_fuse_meta_unsupported_format_version() -> Result<()> {
+    let mut bytes = sample_segment()?.encode()?;
+    bytes[..8].copy_from_slice(&100u64.to_le_bytes());
+    let err = SegmentInfo::decode(&bytes).unwrap_err();
+    assert_eq!(ErrorCode::UnsupportedFormatVersion("").code(), err.code());
+
+    let mut snapshot = sample_snapshot();
+    snapshot.format_version = 100;
+    let err = TableSnapshot::decode(&snapshot.encode()?).unwrap_err();
+    assert_eq!(ErrorCode::UnsupportedFormatVersion("").code(), err.code());
+
+    let err = SegmentInfo::decode(&[1, 0]).unwrap_err();
+    assert_eq!(ErrorCode::InvalidMetaBinaryFormat("").code(), err.code());
+    Ok(())
+}
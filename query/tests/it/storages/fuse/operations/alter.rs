@@ -0,0 +1,175 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_alter_table_stmt() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!("insert into {}.{} values(1),(2)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "alter_before", 1, 1, 1).await;
+
+    // existing blocks are not rewritten, the added column is filled with its default value
+    let qry = format!("alter table {}.{} add column c int default 10", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "alter_add_column", 2, 1, 1).await;
+
+    let qry = format!("insert into {}.{} values(3, 30)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    let qry = format!("select id, c from {}.{} order by id", db, tbl);
+    expects_ok(
+        "select_after_add_column",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec![
+            "+----+----+",
+            "| id | c  |",
+            "+----+----+",
+            "| 1  | 10 |",
+            "| 2  | 10 |",
+            "| 3  | 30 |",
+            "+----+----+",
+        ],
+    )
+    .await?;
+
+    // the data of a renamed column is kept
+    let qry = format!("alter table {}.{} rename column c to d", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    let qry = format!("select sum(d) as s from {}.{}", db, tbl);
+    expects_ok(
+        "select_after_rename_column",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+----+", "| s  |", "+----+", "| 50 |", "+----+"],
+    )
+    .await?;
+
+    // a dropped column does not come back, even if a column of the same name is added later
+    let qry = format!("alter table {}.{} drop column d", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    let qry = format!("alter table {}.{} add column d int", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    let qry = format!("select id, d from {}.{} order by id", db, tbl);
+    expects_ok(
+        "select_after_drop_column",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec![
+            "+----+------+",
+            "| id | d    |",
+            "+----+------+",
+            "| 1  | NULL |",
+            "| 2  | NULL |",
+            "| 3  | NULL |",
+            "+----+------+",
+        ],
+    )
+    .await?;
+
+    // blocks are still shared by all the snapshots
+    check_data_dir(&fixture, "alter_after", 6, 2, 2).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_alter_table_invalid_column() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!("alter table {}.{} add column id int", db, tbl);
+    expects_err(
+        "add_existing_column",
+        ErrorCode::bad_arguments_code(),
+        execute_command(qry.as_str(), ctx.clone()).await,
+    );
+
+    let qry = format!("alter table {}.{} drop column not_exist", db, tbl);
+    expects_err(
+        "drop_unknown_column",
+        ErrorCode::unknown_column_code(),
+        execute_command(qry.as_str(), ctx.clone()).await,
+    );
+
+    let qry = format!("alter table {}.{} drop column id", db, tbl);
+    expects_err(
+        "drop_the_only_column",
+        ErrorCode::bad_arguments_code(),
+        execute_command(qry.as_str(), ctx.clone()).await,
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_alter_table_ttl_column() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+
+    let create = format!(
+        "create table {}.{}(d Date, a UInt64) TTL d + INTERVAL '1' DAY",
+        db, tbl
+    );
+    execute_command(create.as_str(), ctx.clone()).await?;
+
+    let qry = format!("alter table {}.{} drop column d", db, tbl);
+    expects_err(
+        "drop_ttl_column",
+        ErrorCode::bad_arguments_code(),
+        execute_command(qry.as_str(), ctx.clone()).await,
+    );
+
+    let qry = format!("alter table {}.{} rename column d to e", db, tbl);
+    expects_err(
+        "rename_ttl_column",
+        ErrorCode::bad_arguments_code(),
+        execute_command(qry.as_str(), ctx.clone()).await,
+    );
+
+    // the other columns can still be altered
+    let qry = format!("alter table {}.{} rename column a to b", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    let qry = format!("insert into {}.{} values(today(), 1)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    let qry = format!("select b from {}.{}", db, tbl);
+    expects_ok(
+        "select_after_alter_ttl_table",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+---+", "| b |", "+---+", "| 1 |", "+---+"],
+    )
+    .await
+}
@@ -0,0 +1,117 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::DeletePlan;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_commit_concurrent_appends() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // both of the tables are loaded at the same version
+    let table_a = fixture.latest_default_table().await?;
+    let table_b = fixture.latest_default_table().await?;
+
+    let stream = TestFixture::gen_sample_blocks_stream(2, 1);
+    let r = table_a.append_data(ctx.clone(), stream).await?;
+    table_a
+        .commit_insertion(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    // the version of table_b is stale, the commit is retried on top of the latest snapshot
+    let stream = TestFixture::gen_sample_blocks_stream(3, 1);
+    let r = table_b.append_data(ctx.clone(), stream).await?;
+    table_b
+        .commit_insertion(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    // none of the appended blocks are lost
+    let table = fixture.latest_default_table().await?;
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 5);
+    assert_eq!(stats.read_rows, 5 * 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_commit_unresolvable_conflict() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    let stale_table = fixture.latest_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    // deletion is based on the stale snapshot, it can not be merged with the latest one
+    let delete_plan = DeletePlan {
+        database_name: fixture.default_db_name(),
+        table_name: fixture.default_table_name(),
+        table_id: stale_table.get_id(),
+        selection: None,
+    };
+    let r = stale_table.delete(ctx.clone(), delete_plan).await;
+    expects_err(
+        "delete_on_stale_table",
+        ErrorCode::TableCommitConflict("").code(),
+        r,
+    );
+
+    // the concurrent insertion is intact
+    let table = fixture.latest_default_table().await?;
+    let (stats, _) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(stats.read_rows, 2 * 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_commit_overwrite_conflict() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let stale_table = fixture.latest_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    // overwriting is not retried, it would discard the concurrent insertion otherwise
+    let stream = TestFixture::gen_sample_blocks_stream(2, 1);
+    let r = stale_table.append_data(ctx.clone(), stream).await?;
+    let r = stale_table
+        .commit_insertion(ctx.clone(), r.try_collect().await?, true)
+        .await;
+    expects_err(
+        "overwrite_on_stale_table",
+        ErrorCode::TableCommitConflict("").code(),
+        r,
+    );
+
+    // the concurrent insertion is intact
+    let table = fixture.latest_default_table().await?;
+    let (stats, _) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(stats.read_rows, 3);
+
+    Ok(())
+}
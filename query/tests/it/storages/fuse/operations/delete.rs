@@ -0,0 +1,90 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_delete_stmt() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // two segments, one block for each of them
+    let qry = format!("insert into {}.{} values(1),(2),(3)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    let qry = format!("insert into {}.{} values(4),(5),(6)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "delete_before", 2, 2, 2).await;
+
+    // the second block matches completely, it is removed without being rewritten
+    let qry = format!("delete from {}.{} where id > 3", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "delete_whole_block", 3, 2, 2).await;
+
+    // the first block matches partially, it is rewritten
+    let qry = format!("delete from {}.{} where id = 2", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "delete_partial_block", 4, 3, 3).await;
+
+    let qry = format!("select id from {}.{} order by id", db, tbl);
+    expects_ok(
+        "select_after_delete",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+----+", "| id |", "+----+", "| 1  |", "| 3  |", "+----+"],
+    )
+    .await?;
+
+    // the block of a single value matches the IN list completely, it is removed without being rewritten
+    let qry = format!("insert into {}.{} values(7),(7)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    let qry = format!("delete from {}.{} where id in (7, 8)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "delete_whole_block_in_list", 6, 4, 4).await;
+
+    // nothing matches, no new segments or blocks
+    let qry = format!("delete from {}.{} where id > 100", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "delete_nothing", 7, 4, 4).await;
+
+    // delete all
+    let qry = format!("delete from {}.{}", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "delete_all", 8, 4, 4).await;
+
+    let qry = format!("select count(*) as count from {}.{}", db, tbl);
+    expects_ok(
+        "select_after_delete_all",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec![
+            "+-------+",
+            "| count |",
+            "+-------+",
+            "| 0     |",
+            "+-------+",
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::storages::fuse::FuseTable;
+
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+async fn expire_default_table(fixture: &TestFixture) -> Result<u64> {
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    fuse_table.do_expire(&fixture.ctx()).await
+}
+
+#[tokio::test]
+async fn test_fuse_expire_ttl() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+
+    let create = format!(
+        "create table {}.{}(d Date, a UInt64) TTL d + INTERVAL '1' DAY",
+        db, tbl
+    );
+    execute_command(create.as_str(), ctx.clone()).await?;
+
+    // 3 blocks: expired, partially expired and live
+    for values in [
+        "('2000-01-01', 1), ('2000-01-02', 2)",
+        "('2000-01-03', 3), (today(), 4)",
+        "(today(), 5), (today(), 6)",
+    ] {
+        let insert = format!("insert into {}.{} values {}", db, tbl, values);
+        execute_command(insert.as_str(), ctx.clone()).await?;
+    }
+
+    // the expired rows are hidden, before they are removed
+    let qry = format!("select a from {}.{} order by a", db, tbl);
+    let expected = vec![
+        "+---+", //
+        "| a |", "+---+", "| 4 |", "| 5 |", "| 6 |", "+---+",
+    ];
+    expects_ok(
+        "expired_rows_should_be_hidden",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        expected.clone(),
+    )
+    .await?;
+
+    // the first block is removed, the second one is rewritten
+    assert_eq!(2, expire_default_table(&fixture).await?);
+    check_data_dir(&fixture, "expire_ttl", 4, 4, 4).await;
+
+    // nothing left to be expired
+    assert_eq!(0, expire_default_table(&fixture).await?);
+
+    expects_ok(
+        "live_rows_should_be_kept",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        expected,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_fuse_expire_no_ttl() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    fixture.create_default_table().await?;
+
+    assert_eq!(0, expire_default_table(&fixture).await?);
+    Ok(())
+}
@@ -13,8 +13,15 @@
 //  limitations under the License.
 //
 
+mod alter;
+mod commit;
+mod delete;
+mod expire;
+mod navigate;
 mod optimize;
 mod part_info;
 mod purge_drop;
 mod purge_truncate;
 mod read_plan;
+mod update;
+mod vacuum;
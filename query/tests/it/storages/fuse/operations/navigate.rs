@@ -0,0 +1,121 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use uuid::Uuid;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_err;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_navigate() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    let qry = format!("insert into {}.{} values(1),(2),(3)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    // the id of the first snapshot, which is the file name of the snapshot location
+    let table = fixture.latest_default_table().await?;
+    let first_snapshot_loc = table.options().get(TBL_OPT_KEY_SNAPSHOT_LOC).unwrap();
+    let first_snapshot_id = first_snapshot_loc.rsplit('/').next().unwrap();
+
+    let qry = format!("insert into {}.{} values(4),(5),(6)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    // the latest snapshot
+    let qry = format!("select count(*) as c from {}.{}", db, tbl);
+    expects_ok(
+        "count_of_latest_snapshot",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+---+", "| c |", "+---+", "| 6 |", "+---+"],
+    )
+    .await?;
+
+    // navigate to the first snapshot by id
+    let qry = format!(
+        "select id from {}.{} at (snapshot => '{}') order by id",
+        db, tbl, first_snapshot_id
+    );
+    expects_ok(
+        "navigate_by_snapshot_id",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec![
+            "+----+", "| id |", "+----+", "| 1  |", "| 2  |", "| 3  |", "+----+",
+        ],
+    )
+    .await?;
+
+    // navigate with alias, and join the historical data with the latest one
+    let qry = format!(
+        "select count(*) as c from {0}.{1} as a at (snapshot => '{2}') join {0}.{1} as b on a.id = b.id",
+        db, tbl, first_snapshot_id
+    );
+    expects_ok(
+        "navigate_with_alias",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+---+", "| c |", "+---+", "| 3 |", "+---+"],
+    )
+    .await?;
+
+    // navigate by time point, the time point later than the latest snapshot
+    let qry = format!(
+        "select count(*) as c from {}.{} at (timestamp => '2999-01-01 00:00:00')",
+        db, tbl
+    );
+    expects_ok(
+        "navigate_by_time_point",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+---+", "| c |", "+---+", "| 6 |", "+---+"],
+    )
+    .await?;
+
+    // no snapshot is earlier than the time point
+    let qry = format!(
+        "select * from {}.{} at (timestamp => '2000-01-01 00:00:00')",
+        db, tbl
+    );
+    let res = execute_query(qry.as_str(), ctx.clone()).await;
+    expects_err(
+        "navigate_to_time_point_before_creation",
+        ErrorCode::TableHistoricalDataNotFound("").code(),
+        res,
+    );
+
+    // unknown snapshot id
+    let qry = format!(
+        "select * from {}.{} at (snapshot => '{}')",
+        db,
+        tbl,
+        Uuid::new_v4().simple()
+    );
+    let res = execute_query(qry.as_str(), ctx.clone()).await;
+    expects_err(
+        "navigate_to_unknown_snapshot",
+        ErrorCode::TableHistoricalDataNotFound("").code(),
+        res,
+    );
+
+    Ok(())
+}
@@ -13,8 +13,14 @@
 //  limitations under the License.
 //
 
+use std::time::Duration;
+
 use common_base::tokio;
 use common_exception::Result;
+use common_planners::DropTablePlan;
+use databend_query::catalogs::Catalog;
+use databend_query::configs::Config;
+use databend_query::storages::fuse::TBL_OPT_KEY_ROW_PER_BLOCK;
 use futures::TryStreamExt;
 
 use crate::storages::fuse::table_test_fixture::append_sample_data;
@@ -106,3 +112,142 @@ async fn test_fuse_history_optimize_compact() -> Result<()> {
     )
     .await
 }
+
+#[tokio::test]
+async fn test_fuse_optimize_compact_blocks() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+
+    let mut create_table_plan = fixture.default_crate_table_plan();
+    // under-sized blocks are merged until there are 6 rows
+    create_table_plan
+        .table_meta
+        .options
+        .insert(TBL_OPT_KEY_ROW_PER_BLOCK.to_owned(), 6.to_string());
+    ctx.get_catalog()
+        .create_table(create_table_plan.into())
+        .await?;
+
+    // 5 blocks of 3 rows, 1 segment for each of them
+    for _ in 0..5 {
+        append_sample_data(1, &fixture).await?;
+    }
+    check_data_dir(&fixture, "compact_before", 5, 5, 5).await;
+
+    // blocks of rows [3, 3, 3, 3, 3] are compacted into blocks of rows [6, 6, 3]
+    let qry = format!("optimize table {}.{} compact", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "compact", 6, 8, 7).await;
+
+    let table = fixture.latest_default_table().await?;
+    let (stats, parts) = table.read_partitions(ctx.clone(), None).await?;
+    assert_eq!(parts.len(), 3);
+    assert_eq!(stats.read_rows, 5 * 3);
+
+    // already compacted, nothing happens
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "compact_again", 6, 8, 7).await;
+
+    let qry = format!("select count(*) as c, sum(id) as s from {}.{}", db, tbl);
+    expects_ok(
+        "select_after_compact",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec![
+            "+----+----+",
+            "| c  | s  |",
+            "+----+----+",
+            "| 15 | 30 |",
+            "+----+----+",
+        ],
+    )
+    .await
+}
+
+// Creates a fixture of which the orphan files expire quickly, below the minimum of the server.
+async fn fixture_with_short_orphan_retention() -> TestFixture {
+    let mut config = Config::default();
+    config.query.table_orphan_retention_secs = 1;
+    TestFixture::new_with_config(config).await
+}
+
+async fn wait_orphan_retention() {
+    tokio::time::sleep(Duration::from_secs(2)).await;
+}
+
+#[tokio::test]
+async fn test_fuse_optimize_purge_orphan_files() -> Result<()> {
+    let fixture = fixture_with_short_orphan_retention().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    append_sample_data(1, &fixture).await?;
+
+    // a failed insertion, the block and segment written are never committed
+    let table = fixture.latest_default_table().await?;
+    let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+    let r = table.append_data(ctx.clone(), stream).await?;
+    r.try_collect::<Vec<_>>().await?;
+    check_data_dir(&fixture, "orphan_files", 1, 2, 2).await;
+
+    // orphan files are kept during the retention period
+    let qry = format!("optimize table {}.{} purge", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "orphan_files_retained", 1, 2, 2).await;
+
+    wait_orphan_retention().await;
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "orphan_files_removed", 1, 1, 1).await;
+
+    let qry = format!("select count(*) as c from {}.{}", db, tbl);
+    expects_ok(
+        "select_after_orphan_files_removed",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+---+", "| c |", "+---+", "| 3 |", "+---+"],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_fuse_optimize_purge_keeps_other_tables() -> Result<()> {
+    let fixture = fixture_with_short_orphan_retention().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
+
+    // another table, 1 snapshot, 1 segment and 1 block
+    let qry = format!("create table {}.t2(a int)", db);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    let qry = format!("insert into {}.t2 values(1), (2)", db);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "before_drop", 3, 3, 3).await;
+
+    // the data of a dropped table is not reachable from the other tables, but it is never
+    // removed by the garbage collection of them
+    let plan = DropTablePlan {
+        if_exists: false,
+        tenant: fixture.default_tenant(),
+        db: db.clone(),
+        table: tbl.clone(),
+    };
+    ctx.get_catalog().drop_table(plan.into()).await?;
+
+    wait_orphan_retention().await;
+    let qry = format!("optimize table {}.t2 purge", db);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "drop_kept_after_purge", 3, 3, 3).await;
+
+    let qry = format!("select sum(a) as s from {}.t2", db);
+    expects_ok(
+        "select_after_purge",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+---+", "| s |", "+---+", "| 3 |", "+---+"],
+    )
+    .await
+}
@@ -13,16 +13,27 @@
 //  limitations under the License.
 //
 
+use std::time::Duration;
+
 use common_base::tokio;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::PurgeDroppedTableReq;
+use databend_query::catalogs::Catalog;
+use databend_query::configs::Config;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::storages::fuse::FUSE_TBL_BLOCK_PREFIX;
+use walkdir::WalkDir;
 
 use crate::storages::fuse::table_test_fixture::append_sample_data;
 use crate::storages::fuse::table_test_fixture::check_data_dir;
 use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
 use crate::storages::fuse::table_test_fixture::TestFixture;
 
 #[tokio::test]
-async fn test_fuse_history_truncate_in_drop_stmt() -> Result<()> {
+async fn test_fuse_history_retained_in_drop_stmt() -> Result<()> {
     let fixture = TestFixture::new().await;
     let db = fixture.default_db_name();
     let tbl = fixture.default_table_name();
@@ -30,18 +41,109 @@ async fn test_fuse_history_truncate_in_drop_stmt() -> Result<()> {
     fixture.create_default_table().await?;
 
     // ingests some test data
-    append_sample_data(10, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
     // let's Drop
     let qry = format!("drop table '{}'.'{}'", db, tbl);
     execute_command(qry.as_str(), ctx.clone()).await?;
-    // there should be no files left inside test root (dirs are kept, though)
-    check_data_dir(
-        &fixture,
-        "drop table: there should be no file left",
-        0,
-        0,
-        0,
+    // the files are kept, so that the table can be restored by UNDROP
+    check_data_dir(&fixture, "drop table: the files are kept", 1, 1, 1).await;
+
+    let qry = format!("undrop table {}.{}", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    let qry = format!("select count(*) as c from {}.{}", db, tbl);
+    expects_ok(
+        "select_after_undrop",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec!["+---+", "| c |", "+---+", "| 3 |", "+---+"],
     )
-    .await;
+    .await
+}
+
+#[tokio::test]
+async fn test_fuse_undrop_out_of_retention() -> Result<()> {
+    let mut config = Config::default();
+    config.query.table_dropped_retention_secs = 1;
+    let fixture = TestFixture::new_with_config(config).await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    append_sample_data(1, &fixture).await?;
+    let qry = format!("drop table {}.{}", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    // the table may have been removed by the vacuum
+    let qry = format!("undrop table {}.{}", db, tbl);
+    let res = execute_command(qry.as_str(), ctx.clone()).await;
+    assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_purge_dropped_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    append_sample_data(2, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let qry = format!("drop table {}.{}", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    // the way the vacuum purges a dropped table, the meta first and then the data
+    let req = PurgeDroppedTableReq {
+        tenant: fixture.default_tenant(),
+        db: db.clone(),
+        table: tbl.clone(),
+        table_id: table.get_table_info().ident.table_id,
+    };
+    ctx.get_catalog().purge_dropped_table(req).await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    fuse_table.do_purge(ctx.as_ref()).await?;
+    check_data_dir(&fixture, "purge dropped table", 0, 0, 0).await;
+
+    let qry = format!("undrop table {}.{}", db, tbl);
+    let res = execute_command(qry.as_str(), ctx.clone()).await;
+    assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_purge_failed_create_table_as_select() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // the insertion of the statement fails while reading the removed block
+    append_sample_data(1, &fixture).await?;
+    let data_path = ctx.get_config().storage.disk.data_path;
+    for entry in WalkDir::new(data_path) {
+        let entry = entry.unwrap();
+        if entry.file_type().is_file()
+            && entry
+                .path()
+                .to_str()
+                .unwrap()
+                .contains(FUSE_TBL_BLOCK_PREFIX)
+        {
+            std::fs::remove_file(entry.path()).unwrap();
+        }
+    }
+    let qry = format!("create table {}.ctas as select * from {}.{}", db, db, tbl);
+    let res = execute_command(qry.as_str(), ctx.clone()).await;
+    assert!(res.is_err());
+
+    // nothing is left by the statement, neither the table nor its data
+    check_data_dir(&fixture, "failed create table as select", 1, 1, 0).await;
+    let qry = format!("undrop table {}.ctas", db);
+    let res = execute_command(qry.as_str(), ctx.clone()).await;
+    assert_eq!(ErrorCode::UnknownTable("").code(), res.unwrap_err().code());
     Ok(())
 }
@@ -53,15 +53,20 @@ fn test_to_partitions() -> Result<()> {
             path: "".to_string(),
             meta_size: 0,
         },
+        cluster_stats: None,
+        bloom_filter_index: None,
+        col_encodings: HashMap::new(),
     };
 
+    let column_ids = (0..num_of_col as u32).into_iter().collect::<Vec<_>>();
+
     let blocks_metas = (0..num_of_block)
         .into_iter()
         .map(|_| block_meta.clone())
         .collect::<Vec<_>>();
 
     // CASE I:  no projection
-    let (s, _) = FuseTable::to_partitions(&blocks_metas, None);
+    let (s, _) = FuseTable::to_partitions(&blocks_metas, &column_ids, None);
     let expected_block_size: u64 = cols_stats
         .iter()
         .map(|(_, col_stats)| col_stats.in_memory_size)
@@ -89,7 +94,7 @@ fn test_to_partitions() -> Result<()> {
         limit: None,
         order_by: vec![],
     });
-    let (stats, _) = FuseTable::to_partitions(&blocks_metas, push_down);
+    let (stats, _) = FuseTable::to_partitions(&blocks_metas, &column_ids, push_down);
     assert_eq!(expected_block_size * num_of_block, stats.read_bytes as u64);
     Ok(())
 }
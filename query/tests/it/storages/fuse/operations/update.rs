@@ -0,0 +1,81 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_update_stmt() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    // two segments, one block for each of them
+    let qry = format!("insert into {}.{} values(1),(2),(3)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    let qry = format!("insert into {}.{} values(4),(5),(6)", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "update_before", 2, 2, 2).await;
+
+    // only the second block is rewritten
+    let qry = format!("update {}.{} set id = id * 10 where id > 4", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "update_partial", 3, 3, 3).await;
+
+    let qry = format!("select id from {}.{} order by id", db, tbl);
+    expects_ok(
+        "select_after_update",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec![
+            "+----+", "| id |", "+----+", "| 1  |", "| 2  |", "| 3  |", "| 4  |", "| 50 |",
+            "| 60 |", "+----+",
+        ],
+    )
+    .await?;
+
+    // nothing matches, no new segments or blocks
+    let qry = format!("update {}.{} set id = 0 where id > 100", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "update_nothing", 4, 3, 3).await;
+
+    // update all
+    let qry = format!("update {}.{} set id = 0", db, tbl);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+    check_data_dir(&fixture, "update_all", 5, 5, 5).await;
+
+    let qry = format!("select sum(id) as s, count(*) as c from {}.{}", db, tbl);
+    expects_ok(
+        "select_after_update_all",
+        execute_query(qry.as_str(), ctx.clone()).await,
+        vec![
+            "+---+---+",
+            "| s | c |",
+            "+---+---+",
+            "| 0 | 6 |",
+            "+---+---+",
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+use std::time::Duration;
+
+use common_base::tokio;
+use common_exception::Result;
+use databend_query::catalogs::Catalog;
+use databend_query::configs::Config;
+use databend_query::storages::fuse::FuseTable;
+use databend_query::storages::fuse::TableVacuum;
+use databend_query::storages::fuse::TBL_OPT_KEY_MAX_SNAPSHOTS;
+use databend_query::storages::fuse::TBL_OPT_KEY_SNAPSHOT_RETENTION_PERIOD;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::append_sample_data_overwrite;
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_query;
+use crate::storages::fuse::table_test_fixture::expects_ok;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+async fn create_table_with_option(fixture: &TestFixture, key: &str, value: &str) -> Result<()> {
+    let mut create_table_plan = fixture.default_crate_table_plan();
+    create_table_plan
+        .table_meta
+        .options
+        .insert(key.to_owned(), value.to_owned());
+    fixture
+        .ctx()
+        .get_catalog()
+        .create_table(create_table_plan.into())
+        .await?;
+    Ok(())
+}
+
+async fn vacuum_default_table(fixture: &TestFixture) -> Result<u64> {
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    fuse_table.do_vacuum(fixture.ctx().as_ref()).await
+}
+
+#[tokio::test]
+async fn test_fuse_vacuum_max_snapshots() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    create_table_with_option(&fixture, TBL_OPT_KEY_MAX_SNAPSHOTS, "2").await?;
+
+    // 3 snapshots, the latest one of them references all the 3 segments
+    for _ in 0..3 {
+        append_sample_data(1, &fixture).await?;
+    }
+    check_data_dir(&fixture, "max_snapshots_before", 3, 3, 3).await;
+
+    assert_eq!(1, vacuum_default_table(&fixture).await?);
+    check_data_dir(&fixture, "max_snapshots", 2, 3, 3).await;
+
+    // nothing left to be removed
+    assert_eq!(0, vacuum_default_table(&fixture).await?);
+
+    let qry = format!(
+        "select count(*) from fuse_history('{}', '{}')",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    let expected = vec![
+        "+----------+",
+        "| count(0) |",
+        "+----------+",
+        "| 2        |",
+        "+----------+",
+    ];
+    expects_ok(
+        "history_should_have_2_items",
+        execute_query(qry.as_str(), fixture.ctx()).await,
+        expected,
+    )
+    .await?;
+
+    let qry = format!(
+        "select count(*) from {}.{}",
+        fixture.default_db_name(),
+        fixture.default_table_name()
+    );
+    let expected = vec![
+        "+----------+",
+        "| count(0) |",
+        "+----------+",
+        "| 9        |",
+        "+----------+",
+    ];
+    expects_ok(
+        "data_should_be_kept",
+        execute_query(qry.as_str(), fixture.ctx()).await,
+        expected,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_fuse_vacuum_overwritten_history() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    create_table_with_option(&fixture, TBL_OPT_KEY_MAX_SNAPSHOTS, "1").await?;
+
+    append_sample_data(1, &fixture).await?;
+    append_sample_data_overwrite(1, true, &fixture).await?;
+    check_data_dir(&fixture, "overwrite_before", 2, 2, 2).await;
+
+    // the overwritten segment and block are no longer referenced
+    assert_eq!(1, vacuum_default_table(&fixture).await?);
+    check_data_dir(&fixture, "overwrite", 1, 1, 1).await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_vacuum_retention_period() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    create_table_with_option(&fixture, TBL_OPT_KEY_SNAPSHOT_RETENTION_PERIOD, "3600").await?;
+
+    for _ in 0..2 {
+        append_sample_data(1, &fixture).await?;
+    }
+
+    // snapshots are kept within the retention period
+    assert_eq!(0, vacuum_default_table(&fixture).await?);
+    check_data_dir(&fixture, "retention_period", 2, 2, 2).await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_vacuum_zero_retention_period() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    create_table_with_option(&fixture, TBL_OPT_KEY_SNAPSHOT_RETENTION_PERIOD, "0").await?;
+
+    for _ in 0..3 {
+        append_sample_data(1, &fixture).await?;
+    }
+
+    // all the snapshots but the current one are expired
+    assert_eq!(2, vacuum_default_table(&fixture).await?);
+    check_data_dir(&fixture, "zero_retention_period", 1, 3, 3).await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_vacuum_no_retention_policy() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    fixture.create_default_table().await?;
+
+    for _ in 0..2 {
+        append_sample_data(1, &fixture).await?;
+    }
+
+    assert_eq!(0, vacuum_default_table(&fixture).await?);
+    check_data_dir(&fixture, "no_retention_policy", 2, 2, 2).await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_vacuum_orphan_files() -> Result<()> {
+    let mut config = Config::default();
+    config.query.table_orphan_retention_secs = 1;
+    let fixture = TestFixture::new_with_config(config).await;
+    let ctx = fixture.ctx();
+    create_table_with_option(&fixture, TBL_OPT_KEY_MAX_SNAPSHOTS, "1").await?;
+
+    append_sample_data(1, &fixture).await?;
+
+    // a failed insertion, the block and segment written are never committed
+    let table = fixture.latest_default_table().await?;
+    let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+    let r = table.append_data(ctx.clone(), stream).await?;
+    r.try_collect::<Vec<_>>().await?;
+    check_data_dir(&fixture, "orphan_files", 1, 2, 2).await;
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    let fuse_table = table.as_any().downcast_ref::<FuseTable>().unwrap();
+    let db = fixture.default_db_name();
+    assert_eq!(
+        (0, 0),
+        TableVacuum::vacuum_table(&ctx, &db, fuse_table).await?
+    );
+    check_data_dir(&fixture, "orphan_files_removed", 1, 1, 1).await;
+    Ok(())
+}
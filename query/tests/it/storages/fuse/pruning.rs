@@ -28,15 +28,20 @@ use common_meta_types::CreateTableReq;
 use common_meta_types::TableMeta;
 use common_planners::col;
 use common_planners::lit;
+use common_planners::Expression;
 use common_planners::Extras;
 use databend_query::catalogs::Catalog;
 use databend_query::sessions::QueryContext;
+use databend_query::storages::fuse::io::BlockReader;
 use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::meta::BlockMeta;
 use databend_query::storages::fuse::meta::TableSnapshot;
 use databend_query::storages::fuse::pruning::BlockPruner;
+use databend_query::storages::fuse::DEFAULT_ROW_PER_PAGE;
+use databend_query::storages::fuse::TBL_OPT_KEY_BLOOM_FILTER_COLUMNS;
 use databend_query::storages::fuse::TBL_OPT_KEY_CHUNK_BLOCK_NUM;
 use databend_query::storages::fuse::TBL_OPT_KEY_SNAPSHOT_LOC;
+use databend_query::storages::index::RangeFilter;
 use futures::TryStreamExt;
 
 use crate::storages::fuse::table_test_fixture::TestFixture;
@@ -167,5 +172,251 @@ async fn test_block_pruner() -> Result<()> {
     .await?;
     assert_eq!(num - 1, blocks.len() as u64);
 
+    // all the conjuncts are taken into account
+    let mut extra = Extras::default();
+    extra.filters = vec![col("a").gt(lit(3)), col("b").lt(lit(50))];
+
+    let blocks = apply_block_pruning(
+        &snapshot,
+        table.get_table_info().schema(),
+        &Some(extra),
+        ctx.clone(),
+    )
+    .await?;
+    assert_eq!(4, blocks.len());
+
+    // in-list
+    let mut extra = Extras::default();
+    let pred = Expression::create_scalar_function("IN", vec![col("a"), lit(1), lit(12)]);
+    extra.filters = vec![pred];
+
+    let blocks = apply_block_pruning(
+        &snapshot,
+        table.get_table_info().schema(),
+        &Some(extra),
+        ctx.clone(),
+    )
+    .await?;
+    assert_eq!(2, blocks.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_block_pruner_bloom_filter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let test_tbl_name = "test_bloom_filter";
+    let test_schema = DataSchemaRefExt::create(vec![
+        DataField::new("id", DataType::String, false),
+        DataField::new("v", DataType::UInt64, false),
+    ]);
+
+    let crate_table_plan = CreateTableReq {
+        if_not_exists: false,
+        tenant: fixture.default_tenant(),
+        db: fixture.default_db_name(),
+        table: test_tbl_name.to_string(),
+        table_meta: TableMeta {
+            schema: test_schema.clone(),
+            engine: "FUSE".to_string(),
+            options: [
+                (TBL_OPT_KEY_CHUNK_BLOCK_NUM.to_owned(), "1".to_owned()),
+                (TBL_OPT_KEY_BLOOM_FILTER_COLUMNS.to_owned(), "id".to_owned()),
+            ]
+            .into(),
+            ..Default::default()
+        },
+    };
+
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+    let table = catalog
+        .get_table(
+            fixture.default_tenant().as_str(),
+            fixture.default_db_name().as_str(),
+            test_tbl_name,
+        )
+        .await?;
+
+    // the min/max of the ids of all the blocks are the same, which can not prune any block
+    let num = 10;
+    let blocks = (0..num)
+        .into_iter()
+        .map(|idx| {
+            Ok(DataBlock::create_by_array(test_schema.clone(), vec![
+                Series::new(vec![
+                    b"a".to_vec(),
+                    format!("m{}", idx).into_bytes(),
+                    b"z".to_vec(),
+                ]),
+                Series::new(vec![idx, idx, idx]),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let stream = Box::pin(futures::stream::iter(blocks));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit_insertion(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    let table = catalog
+        .get_table(
+            fixture.default_tenant().as_str(),
+            fixture.default_db_name().as_str(),
+            test_tbl_name,
+        )
+        .await?;
+    let snapshot_loc = table
+        .get_table_info()
+        .options()
+        .get(TBL_OPT_KEY_SNAPSHOT_LOC)
+        .unwrap();
+    let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+    let snapshot = reader.read(snapshot_loc.as_str()).await?;
+
+    // (case, filter, number of the blocks left)
+    let cases = vec![
+        ("id = 'm3'", col("id").eq(lit("m3".as_bytes())), 1),
+        ("id = 'a'", col("id").eq(lit("a".as_bytes())), num),
+        ("id = 'x'", col("id").eq(lit("x".as_bytes())), 0),
+        (
+            "id in ('m1', 'm2', 'x')",
+            Expression::create_scalar_function("IN", vec![
+                col("id"),
+                lit("m1".as_bytes()),
+                lit("m2".as_bytes()),
+                lit("x".as_bytes()),
+            ]),
+            2,
+        ),
+        (
+            "id = 'm3' or v = 5",
+            col("id")
+                .eq(lit("m3".as_bytes()))
+                .or(col("v").eq(lit(5u64))),
+            2,
+        ),
+        (
+            "id = 'm3' or v > 5",
+            col("id")
+                .eq(lit("m3".as_bytes()))
+                .or(col("v").gt(lit(5u64))),
+            5,
+        ),
+    ];
+
+    for (name, pred, expected) in cases {
+        let extra = Extras {
+            filters: vec![pred],
+            ..Extras::default()
+        };
+        let blocks = apply_block_pruning(
+            &snapshot,
+            table.get_table_info().schema(),
+            &Some(extra),
+            ctx.clone(),
+        )
+        .await?;
+        assert_eq!(expected, blocks.len() as u64, "case {}", name);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_block_reader_page_filter() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let test_tbl_name = "test_page_filter";
+    let test_schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::UInt64, false)]);
+
+    let crate_table_plan = CreateTableReq {
+        if_not_exists: false,
+        tenant: fixture.default_tenant(),
+        db: fixture.default_db_name(),
+        table: test_tbl_name.to_string(),
+        table_meta: TableMeta {
+            schema: test_schema.clone(),
+            engine: "FUSE".to_string(),
+            ..Default::default()
+        },
+    };
+
+    let catalog = ctx.get_catalog();
+    catalog.create_table(crate_table_plan).await?;
+    let table = catalog
+        .get_table(
+            fixture.default_tenant().as_str(),
+            fixture.default_db_name().as_str(),
+            test_tbl_name,
+        )
+        .await?;
+
+    // one block of 4 pages, the last one is not full
+    let page_size = DEFAULT_ROW_PER_PAGE as u64;
+    let num_rows = page_size * 3 + 100;
+    let block = DataBlock::create_by_array(test_schema.clone(), vec![Series::new(
+        (0..num_rows).collect::<Vec<_>>(),
+    )]);
+    let stream = Box::pin(futures::stream::iter(vec![Ok(block)]));
+    let r = table.append_data(ctx.clone(), stream).await?;
+    table
+        .commit_insertion(ctx.clone(), r.try_collect().await?, false)
+        .await?;
+
+    let table = catalog
+        .get_table(
+            fixture.default_tenant().as_str(),
+            fixture.default_db_name().as_str(),
+            test_tbl_name,
+        )
+        .await?;
+    let snapshot_loc = table
+        .get_table_info()
+        .options()
+        .get(TBL_OPT_KEY_SNAPSHOT_LOC)
+        .unwrap();
+    let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+    let snapshot = reader.read(snapshot_loc.as_str()).await?;
+    let schema = table.get_table_info().schema();
+    let blocks = apply_block_pruning(&snapshot, schema.clone(), &None, ctx.clone()).await?;
+    assert_eq!(1, blocks.len());
+
+    let cases = vec![
+        ("no filter", None, num_rows),
+        ("first page", Some(col("a").lt(lit(10u64))), page_size),
+        ("last page", Some(col("a").gt(lit(page_size * 3))), 100),
+        (
+            "first and last pages",
+            Some(col("a").lt(lit(10u64)).or(col("a").gt(lit(page_size * 3)))),
+            page_size + 100,
+        ),
+        ("no page", Some(col("a").gt(lit(num_rows))), 0),
+    ];
+
+    for (name, filter, expected) in cases {
+        let page_filter = filter
+            .map(|expr| RangeFilter::try_create(&expr, schema.clone()))
+            .transpose()?
+            .map(|filter| Arc::new(filter.with_column_ids(&snapshot.column_ids())));
+        let mut block_reader = BlockReader::new(
+            ctx.get_storage_accessor()?,
+            blocks[0].location.path.clone(),
+            schema.clone(),
+            snapshot.column_ids(),
+            vec![0],
+            blocks[0].file_size,
+            ctx.get_settings().get_storage_read_buffer_size()?,
+            MetaReaders::block_meta_reader(ctx.clone()),
+        )
+        .with_page_filter(page_filter);
+        let block = block_reader.read().await?;
+        assert_eq!(expected, block.num_rows() as u64, "case {}", name);
+    }
+
     Ok(())
 }
@@ -20,8 +20,15 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::DataType;
 use common_datavalues::DataValue;
+use common_planners::col;
+use common_planners::neg;
+use databend_query::storages::fuse::meta::BlockMeta;
+use databend_query::storages::fuse::meta::ClusterStatistics;
 use databend_query::storages::fuse::statistics::accumulator;
+use databend_query::storages::fuse::statistics::cluster;
 use databend_query::storages::fuse::statistics::reducers;
+use databend_query::storages::fuse::statistics::BlockClusterer;
+use databend_query::storages::fuse::statistics::ClusteringInformation;
 use databend_query::storages::fuse::statistics::StatisticsAccumulator;
 
 use crate::storages::fuse::table_test_fixture::TestFixture;
@@ -30,7 +37,7 @@ use crate::storages::fuse::table_test_fixture::TestFixture;
 fn test_ft_stats_block_stats() -> common_exception::Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
     let block = DataBlock::create_by_array(schema, vec![Series::new(vec![1, 2, 3])]);
-    let r = StatisticsAccumulator::acc_columns(&block)?;
+    let r = StatisticsAccumulator::acc_columns(&block, &[0])?;
     assert_eq!(1, r.len());
     let col_stats = r.get(&0).unwrap();
     assert_eq!(col_stats.min, DataValue::Int32(Some(1)));
@@ -44,9 +51,9 @@ fn test_ft_stats_col_stats_reduce() -> common_exception::Result<()> {
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
     let col_stats = blocks
         .iter()
-        .map(|b| StatisticsAccumulator::acc_columns(&b.clone().unwrap()))
+        .map(|b| StatisticsAccumulator::acc_columns(&b.clone().unwrap(), &[0]))
         .collect::<common_exception::Result<Vec<_>>>()?;
-    let r = reducers::reduce_block_stats(&col_stats, &schema);
+    let r = reducers::reduce_block_stats(&col_stats, &schema, &[0]);
     assert!(r.is_ok());
     let r = r.unwrap();
     assert_eq!(1, r.len());
@@ -61,10 +68,140 @@ fn test_ft_stats_accumulator() -> common_exception::Result<()> {
     let blocks = TestFixture::gen_sample_blocks(10, 1);
     let mut stats_acc = accumulator::StatisticsAccumulator::new();
     for item in blocks {
-        let block_acc = stats_acc.begin(&item?)?;
+        let block_acc = stats_acc.begin(&item?, &[0], None)?;
         stats_acc = block_acc.end(1, "".to_owned());
     }
     assert_eq!(10, stats_acc.blocks_statistics.len());
     // TODO more cases here pls
     Ok(())
 }
+
+#[test]
+fn test_ft_stats_block_clusterer() -> common_exception::Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("a", DataType::Int32, false),
+        DataField::new("b", DataType::Int32, false),
+    ]);
+    let block = DataBlock::create_by_array(schema, vec![
+        Series::new(vec![3, 1, 2, 1]),
+        Series::new(vec![1, 2, 3, 4]),
+    ]);
+
+    // cluster by (a, -b)
+    let clusterer = BlockClusterer::new(vec![col("a"), neg(col("b"))]);
+    let (sorted, stats) = clusterer.cluster(block.clone())?;
+    assert_eq!(sorted.schema(), block.schema());
+    let values = (0..sorted.num_rows())
+        .map(|i| Ok((sorted.column(0).try_get(i)?, sorted.column(1).try_get(i)?)))
+        .collect::<common_exception::Result<Vec<_>>>()?;
+    assert_eq!(values, vec![
+        (DataValue::Int32(Some(1)), DataValue::Int32(Some(4))),
+        (DataValue::Int32(Some(1)), DataValue::Int32(Some(2))),
+        (DataValue::Int32(Some(2)), DataValue::Int32(Some(3))),
+        (DataValue::Int32(Some(3)), DataValue::Int32(Some(1))),
+    ]);
+    assert_eq!(stats.min[0], DataValue::Int32(Some(1)));
+    assert_eq!(stats.max[0], DataValue::Int32(Some(3)));
+
+    // rows of several blocks are sorted as a whole
+    let clustered = BlockClusterer::new(vec![col("a")]).cluster_blocks(&[block.clone(), block])?;
+    assert_eq!(clustered.len(), 2);
+    assert_eq!(clustered[0].1.min, vec![DataValue::Int32(Some(1))]);
+    assert_eq!(clustered[0].1.max, vec![DataValue::Int32(Some(1))]);
+    assert_eq!(clustered[1].1.min, vec![DataValue::Int32(Some(2))]);
+    assert_eq!(clustered[1].1.max, vec![DataValue::Int32(Some(3))]);
+    Ok(())
+}
+
+#[test]
+fn test_ft_stats_group_overlapped_blocks() -> common_exception::Result<()> {
+    let block_meta = |min: i32, max: i32| -> common_exception::Result<BlockMeta> {
+        let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+        let block = DataBlock::create_by_array(schema, vec![Series::new(vec![min, max])]);
+        let cluster_stats = ClusterStatistics {
+            min: vec![DataValue::Int32(Some(min))],
+            max: vec![DataValue::Int32(Some(max))],
+        };
+        let acc = StatisticsAccumulator::new().begin(&block, &[0], Some(cluster_stats))?;
+        Ok(acc.end(1, "".to_owned()).blocks_metas.remove(0))
+    };
+    let ranges = |groups: &[Vec<BlockMeta>]| {
+        groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|b| b.cluster_stats.as_ref().unwrap().min[0].clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // [1, 3], [2, 5], [4, 7], [6, 9], [8, 10] overlap as a chain, and [20, 21] overlaps with none
+    let metas = vec![
+        block_meta(6, 9)?,
+        block_meta(1, 3)?,
+        block_meta(20, 21)?,
+        block_meta(4, 7)?,
+        block_meta(8, 10)?,
+        block_meta(2, 5)?,
+    ];
+    let block_size = metas[0].block_size as usize;
+
+    let (groups, unclustered) = cluster::group_overlapped_blocks(metas.clone(), usize::MAX);
+    assert!(unclustered.is_empty());
+    assert_eq!(ranges(&groups), vec![
+        vec![1, 2, 4, 6, 8]
+            .into_iter()
+            .map(|v| DataValue::Int32(Some(v)))
+            .collect::<Vec<_>>(),
+        vec![DataValue::Int32(Some(20))],
+    ]);
+
+    // the chain is larger than the cap, thus it is split into groups of at most two blocks
+    let (groups, _) = cluster::group_overlapped_blocks(metas, block_size * 2);
+    let group_lens = groups.iter().map(|group| group.len()).collect::<Vec<_>>();
+    assert_eq!(group_lens, vec![2, 2, 1, 1]);
+    assert!(groups
+        .iter()
+        .all(|group| group.iter().map(|b| b.block_size as usize).sum::<usize>() <= block_size * 2));
+    Ok(())
+}
+
+#[test]
+fn test_ft_stats_clustering_information() -> common_exception::Result<()> {
+    let block_meta = |min: i32, max: i32| -> common_exception::Result<BlockMeta> {
+        let schema = DataSchemaRefExt::create(vec![DataField::new("a", DataType::Int32, false)]);
+        let block = DataBlock::create_by_array(schema, vec![Series::new(vec![min, max])]);
+        let cluster_stats = ClusterStatistics {
+            min: vec![DataValue::Int32(Some(min))],
+            max: vec![DataValue::Int32(Some(max))],
+        };
+        let acc = StatisticsAccumulator::new().begin(&block, &[0], Some(cluster_stats))?;
+        Ok(acc.end(1, "".to_owned()).blocks_metas.remove(0))
+    };
+
+    // no blocks
+    let info = ClusteringInformation::from_block_metas(&[]);
+    assert_eq!(info.total_block_count, 0);
+    assert_eq!(info.average_overlaps, 0.0);
+    assert_eq!(info.average_depth, 0.0);
+
+    // disjoint blocks
+    let metas = vec![block_meta(1, 2)?, block_meta(3, 4)?, block_meta(5, 5)?];
+    let info = ClusteringInformation::from_block_metas(&metas);
+    assert_eq!(info.total_block_count, 3);
+    assert_eq!(info.total_constant_block_count, 1);
+    assert_eq!(info.average_overlaps, 0.0);
+    assert_eq!(info.average_depth, 1.0);
+
+    // [1, 4] overlaps with [2, 3] and [3, 6], [2, 3] overlaps with [3, 6]
+    let metas = vec![block_meta(1, 4)?, block_meta(2, 3)?, block_meta(3, 6)?];
+    let info = ClusteringInformation::from_block_metas(&metas);
+    assert_eq!(info.total_block_count, 3);
+    assert_eq!(info.total_constant_block_count, 0);
+    assert_eq!(info.average_overlaps, 2.0);
+    // depth at 1, 2, 3, 4, 6 is 1, 2, 3, 2, 1
+    assert_eq!(info.average_depth, 9.0 / 5.0);
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+//
+
+use common_base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::*;
+
+#[tokio::test]
+async fn test_clustering_information_table_read() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+
+    let qry = format!("create table {}.t(a int, b int) cluster by (a)", db);
+    execute_command(qry.as_str(), ctx.clone()).await?;
+
+    {
+        let expected = vec![
+            "+-----------------+-------------------+----------------------------+------------------+---------------+",
+            "| cluster_by_keys | total_block_count | total_constant_block_count | average_overlaps | average_depth |",
+            "+-----------------+-------------------+----------------------------+------------------+---------------+",
+            "| (a)             | 0                 | 0                          | 0                | 0             |",
+            "+-----------------+-------------------+----------------------------+------------------+---------------+",
+        ];
+        let qry = format!("select * from clustering_information('{}', 't')", db);
+        expects_ok(
+            "empty_table",
+            execute_query(qry.as_str(), ctx.clone()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        // two blocks, of which the ranges of the cluster key are [1, 3] and [2, 5]
+        let qry = format!("insert into {}.t values(3, 1), (1, 2), (2, 3)", db);
+        execute_command(qry.as_str(), ctx.clone()).await?;
+        let qry = format!("insert into {}.t values(5, 1), (2, 1)", db);
+        execute_command(qry.as_str(), ctx.clone()).await?;
+
+        let expected = vec![
+            "+-------------------+------------------+---------------+",
+            "| total_block_count | average_overlaps | average_depth |",
+            "+-------------------+------------------+---------------+",
+            "| 2                 | 1                | 1.5           |",
+            "+-------------------+------------------+---------------+",
+        ];
+        let qry = format!(
+            "select total_block_count, average_overlaps, average_depth from clustering_information('{}', 't')",
+            db
+        );
+        expects_ok(
+            "overlapped_blocks",
+            execute_query(qry.as_str(), ctx.clone()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        // the overlapped blocks are re-clustered
+        let qry = format!("optimize table {}.t compact", db);
+        execute_command(qry.as_str(), ctx.clone()).await?;
+
+        let expected = vec![
+            "+-------------------+------------------+---------------+",
+            "| total_block_count | average_overlaps | average_depth |",
+            "+-------------------+------------------+---------------+",
+            "| 1                 | 0                | 1             |",
+            "+-------------------+------------------+---------------+",
+        ];
+        let qry = format!(
+            "select total_block_count, average_overlaps, average_depth from clustering_information('{}', 't')",
+            db
+        );
+        expects_ok(
+            "reclustered_blocks",
+            execute_query(qry.as_str(), ctx.clone()).await,
+            expected,
+        )
+        .await?;
+
+        let expected = vec![
+            "+---+---+",
+            "| a | b |",
+            "+---+---+",
+            "| 1 | 2 |",
+            "| 2 | 1 |",
+            "| 2 | 3 |",
+            "| 3 | 1 |",
+            "| 5 | 1 |",
+            "+---+---+",
+        ];
+        let qry = format!("select * from {}.t", db);
+        expects_ok(
+            "rows_kept_after_reclustering",
+            execute_query(qry.as_str(), ctx.clone()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        // table without cluster keys
+        fixture.create_default_table().await?;
+        let qry = format!(
+            "select * from clustering_information('{}', '{}')",
+            db,
+            fixture.default_table_name()
+        );
+        expects_err(
+            "table_not_clustered",
+            ErrorCode::bad_arguments_code(),
+            execute_query(qry.as_str(), ctx.clone()).await,
+        );
+    }
+
+    Ok(())
+}
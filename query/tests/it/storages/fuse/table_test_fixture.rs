@@ -58,8 +58,11 @@ pub struct TestFixture {
 
 impl TestFixture {
     pub async fn new() -> TestFixture {
+        Self::new_with_config(Config::default()).await
+    }
+
+    pub async fn new_with_config(mut config: Config) -> TestFixture {
         let tmp_dir = TempDir::new().unwrap();
-        let mut config = Config::default();
         // make sure we are suing `Disk` storage
         config.storage.storage_type = "Disk".to_string();
         // use `TempDir` as root path (auto clean)
@@ -132,7 +135,8 @@ impl TestFixture {
     pub async fn create_default_table(&self) -> Result<()> {
         let create_table_plan = self.default_crate_table_plan();
         let catalog = self.ctx.get_catalog();
-        catalog.create_table(create_table_plan.into()).await
+        catalog.create_table(create_table_plan.into()).await?;
+        Ok(())
     }
 
     pub fn gen_sample_blocks(num: u32, start: i32) -> Vec<Result<DataBlock>> {
@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Expression;
+use databend_query::storages::index::BloomFilter;
+use databend_query::storages::index::BloomFilterIndex;
+use databend_query::storages::index::BloomFilterPredicate;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_bloom_filter() -> Result<()> {
+    let mut filter = BloomFilter::with_capacity(1000, 0.01);
+    for i in 0..1000u32 {
+        filter.add(&i.to_le_bytes());
+    }
+
+    // no false negatives
+    assert!((0..1000u32).all(|i| filter.contains(&i.to_le_bytes())));
+
+    // a few false positives
+    let false_positives = (1000..11000u32)
+        .filter(|i| filter.contains(&i.to_le_bytes()))
+        .count();
+    assert!(false_positives < 300, "false positives {}", false_positives);
+    Ok(())
+}
+
+#[test]
+fn test_bloom_filter_index() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![
+        DataField::new("name", DataType::String, true),
+        DataField::new("age", DataType::Int32, false),
+        DataField::new("score", DataType::Float64, false),
+    ]);
+
+    let block = DataBlock::create_by_array(schema.clone(), vec![
+        Series::new(vec!["jack", "ace", "bohu"]),
+        Series::new(vec![11, 6, 24]),
+        Series::new(vec![1.0f64, 2.0, 3.0]),
+    ]);
+
+    // columns are keyed by ids, the float column is not supported
+    let index = BloomFilterIndex::create_index(&block, &[(0, 10), (1, 11), (2, 12)], 0.01)?;
+    assert_eq!(index.filters.len(), 2);
+    let index = BloomFilterIndex::decode(&index.encode()?)?;
+
+    let string = |v: &str| DataValue::String(Some(v.as_bytes().to_vec()));
+    assert!(index.may_contain(10, &string("ace"), &DataType::String));
+    assert!(!index.may_contain(10, &string("xace"), &DataType::String));
+    // integers of different types
+    assert!(index.may_contain(11, &DataValue::UInt8(Some(24)), &DataType::Int32));
+    assert!(index.may_contain(11, &DataValue::Int64(Some(6)), &DataType::Int32));
+    assert!(!index.may_contain(11, &DataValue::Int64(Some(7)), &DataType::Int32));
+    // unknown values, or columns without filters
+    assert!(index.may_contain(11, &DataValue::Int32(None), &DataType::Int32));
+    assert!(index.may_contain(11, &string("7"), &DataType::Int32));
+    assert!(index.may_contain(12, &DataValue::Float64(Some(7.0)), &DataType::Float64));
+
+    struct Test {
+        name: &'static str,
+        expr: Expression,
+        expect: Option<bool>,
+    }
+
+    let tests: Vec<Test> = vec![
+        Test {
+            name: "name = 'ace'",
+            expr: col("name").eq(lit("ace".as_bytes())),
+            expect: Some(true),
+        },
+        Test {
+            name: "'xace' = name",
+            expr: lit("xace".as_bytes()).eq(col("name")),
+            expect: Some(false),
+        },
+        Test {
+            name: "age in (7, 8)",
+            expr: Expression::create_scalar_function("IN", vec![col("age"), lit(7), lit(8)]),
+            expect: Some(false),
+        },
+        Test {
+            name: "age in (7, 24)",
+            expr: Expression::create_scalar_function("IN", vec![col("age"), lit(7), lit(24)]),
+            expect: Some(true),
+        },
+        Test {
+            name: "name = 'ace' and age = 7",
+            expr: col("name")
+                .eq(lit("ace".as_bytes()))
+                .and(col("age").eq(lit(7))),
+            expect: Some(false),
+        },
+        Test {
+            name: "name = 'xace' or age = 6",
+            expr: col("name")
+                .eq(lit("xace".as_bytes()))
+                .or(col("age").eq(lit(6))),
+            expect: Some(true),
+        },
+        Test {
+            name: "name = 'xace' and age > 7",
+            expr: col("name")
+                .eq(lit("xace".as_bytes()))
+                .and(col("age").gt(lit(7))),
+            expect: Some(false),
+        },
+        Test {
+            name: "name = 'xace' or age > 7",
+            expr: col("name")
+                .eq(lit("xace".as_bytes()))
+                .or(col("age").gt(lit(7))),
+            expect: None,
+        },
+        Test {
+            name: "score = 7",
+            expr: col("score").eq(lit(7.0f64)),
+            expect: None,
+        },
+    ];
+
+    for test in tests {
+        let pred = BloomFilterPredicate::try_create(&test.expr, &schema)?
+            .map(|pred| pred.with_column_ids(&[10, 11, 12]));
+        let actual = pred.map(|pred| pred.eval(&index));
+        assert_eq!(test.expect, actual, "{:#?}", test.name);
+    }
+
+    Ok(())
+}
@@ -20,6 +20,7 @@ use common_exception::Result;
 use common_planners::col;
 use common_planners::lit;
 use databend_query::storages::index::IndexSchemaVersion;
+use databend_query::storages::index::RangeFilter;
 use databend_query::storages::index::SparseIndex;
 use databend_query::storages::index::SparseIndexValue;
 use pretty_assertions::assert_eq;
@@ -36,39 +37,43 @@ fn test_sparse_index() -> Result<()> {
         Series::new(vec![11, 6, 24]),
     ]);
 
-    let block2 = DataBlock::create_by_array(schema, vec![
+    let block2 = DataBlock::create_by_array(schema.clone(), vec![
         Series::new(vec!["xjack", "xace", "xbohu"]),
-        Series::new(vec![11, 6, 24]),
+        Series::new(vec![31, 26, 44]),
     ]);
 
     let idx_slice = vec![
         SparseIndex {
-            col: "name".to_string(),
+            column_id: 3,
             values: vec![
                 SparseIndexValue {
-                    min: DataValue::String(Some("jack".as_bytes().to_vec())),
-                    max: DataValue::String(Some("bohu".as_bytes().to_vec())),
+                    min: DataValue::String(Some("ace".as_bytes().to_vec())),
+                    max: DataValue::String(Some("jack".as_bytes().to_vec())),
+                    null_count: 0,
                     page_no: 0,
                 },
                 SparseIndexValue {
-                    min: DataValue::String(Some("xjack".as_bytes().to_vec())),
-                    max: DataValue::String(Some("xbohu".as_bytes().to_vec())),
+                    min: DataValue::String(Some("xace".as_bytes().to_vec())),
+                    max: DataValue::String(Some("xjack".as_bytes().to_vec())),
+                    null_count: 0,
                     page_no: 1,
                 },
             ],
             version: IndexSchemaVersion::V1,
         },
         SparseIndex {
-            col: "age".to_string(),
+            column_id: 5,
             values: vec![
                 SparseIndexValue {
-                    min: DataValue::Int32(Some(11)),
+                    min: DataValue::Int32(Some(6)),
                     max: DataValue::Int32(Some(24)),
+                    null_count: 0,
                     page_no: 0,
                 },
                 SparseIndexValue {
-                    min: DataValue::Int32(Some(11)),
-                    max: DataValue::Int32(Some(24)),
+                    min: DataValue::Int32(Some(26)),
+                    max: DataValue::Int32(Some(44)),
+                    null_count: 0,
                     page_no: 1,
                 },
             ],
@@ -78,8 +83,7 @@ fn test_sparse_index() -> Result<()> {
 
     // Create index.
     {
-        let actual =
-            SparseIndex::create_index(&["name".to_string(), "age".to_string()], &[block1, block2])?;
+        let actual = SparseIndex::create_index(&[3, 5], &[block1, block2])?;
         let expected = idx_slice.clone();
         assert_eq!(actual, expected);
     }
@@ -87,12 +91,20 @@ fn test_sparse_index() -> Result<()> {
     // Apply index.
     {
         let mut idx_map = HashMap::new();
-        idx_map.insert("name".to_string(), idx_slice[0].clone());
-        idx_map.insert("age".to_string(), idx_slice[1].clone());
-        let expr = col("name").eq(lit(24));
-        let (actual, _) = SparseIndex::apply_index(idx_map, &expr)?;
-        let expected = true;
-        assert_eq!(actual, expected);
+        idx_map.insert(3, idx_slice[0].clone());
+        idx_map.insert(5, idx_slice[1].clone());
+
+        let tests = vec![
+            (col("age").gt(lit(30)), (false, vec![1])),
+            (col("name").lt(lit("b".as_bytes())), (false, vec![0])),
+            (col("age").lt(lit(0)), (false, vec![])),
+            (col("age").gt(lit(10)), (true, vec![])),
+        ];
+        for (expr, expected) in tests {
+            let filter = RangeFilter::try_create(&expr, schema.clone())?.with_column_ids(&[3, 5]);
+            let actual = SparseIndex::apply_index(&idx_map, &filter)?;
+            assert_eq!(actual, expected, "{:?}", expr);
+        }
     }
 
     Ok(())
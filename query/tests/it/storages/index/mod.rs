@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod index_bloom;
 mod index_min_max;
 mod index_sparse;
 mod range_filter;
@@ -151,8 +151,7 @@ fn test_range_filter() -> Result<()> {
             name: "a - b <= -10",
             expr: sub(col("a"), col("b")).lt_eq(lit(-10)),
             expect: true,
-            error:
-                "Code: 1067, displayText = Function '-' is not monotonic in the variables range.",
+            error: "",
         },
         Test {
             name: "a < b",
@@ -166,6 +165,53 @@ fn test_range_filter() -> Result<()> {
             expect: false,
             error: "",
         },
+        Test {
+            name: "a in (0, 30)",
+            expr: Expression::create_scalar_function("IN", vec![col("a"), lit(0), lit(30)]),
+            expect: false,
+            error: "",
+        },
+        Test {
+            name: "a in (0, 5)",
+            expr: Expression::create_scalar_function("IN", vec![col("a"), lit(0), lit(5)]),
+            expect: true,
+            error: "",
+        },
+        Test {
+            name: "not (a > 0)",
+            expr: not(col("a").gt(lit(0))),
+            expect: false,
+            error: "",
+        },
+        Test {
+            name: "not (b >= 3 or a = 5)",
+            expr: not(col("b").gt_eq(lit(3)).or(col("a").eq(lit(5)))),
+            expect: false,
+            error: "",
+        },
+        Test {
+            name: "b between 11 and 20",
+            expr: col("b").gt_eq(lit(11)).and(col("b").lt_eq(lit(20))),
+            expect: false,
+            error: "",
+        },
+        Test {
+            name: "cast(b as bigint) > 20",
+            expr: Expression::Cast {
+                expr: Box::new(col("b")),
+                data_type: DataType::Int64,
+            }
+            .gt(lit(20)),
+            expect: false,
+            error: "",
+        },
+        Test {
+            name: "abs(a - 10) < 0",
+            expr: Expression::create_scalar_function("abs", vec![sub(col("a"), lit(10))])
+                .lt(lit(0)),
+            expect: true,
+            error: "",
+        },
     ];
 
     for test in tests {
@@ -310,6 +356,61 @@ fn test_build_verifiable_function() -> Result<()> {
             expr: add(col("a"), col("b")).lt_eq(sub(lit(10), col("a"))),
             expect: "true",
         },
+        Test {
+            name: "a in (1, 3)",
+            expr: Expression::create_scalar_function("IN", vec![col("a"), lit(1), lit(3)]),
+            expect: "(((min_a <= 1) and (max_a >= 1)) or ((min_a <= 3) and (max_a >= 3)))",
+        },
+        Test {
+            name: "a not in (1, 3)",
+            expr: Expression::create_scalar_function("NOT_IN", vec![col("a"), lit(1), lit(3)]),
+            expect: "(((min_a != 1) or (max_a != 1)) and ((min_a != 3) or (max_a != 3)))",
+        },
+        Test {
+            name: "a not in (1, null)",
+            expr: Expression::create_scalar_function("NOT_IN", vec![
+                col("a"),
+                lit(1),
+                Expression::create_literal(DataValue::Null),
+            ]),
+            expect: "true",
+        },
+        Test {
+            name: "a in (b, 1)",
+            expr: Expression::create_scalar_function("IN", vec![col("a"), col("b"), lit(1)]),
+            expect: "true",
+        },
+        Test {
+            name: "not (a < 1 or c like 'sys%')",
+            expr: not(Expression::create_binary_expression("or", vec![
+                col("a").lt(lit(1)),
+                Expression::create_binary_expression("like", vec![
+                    col("c"),
+                    lit("sys%".as_bytes()),
+                ]),
+            ])),
+            expect: "((max_a >= 1) and ((min_c < sys) or (max_c >= syt)))",
+        },
+        Test {
+            name: "not (a is null)",
+            expr: not(Expression::create_scalar_function("isNull", vec![col("a")])),
+            expect: "isNotNull(min_a)",
+        },
+        Test {
+            name: "not (not (b <> 3))",
+            expr: not(not(Expression::create_binary_expression("<>", vec![
+                col("b"),
+                lit(3),
+            ]))),
+            expect: "((min_b != 3) or (max_b != 3))",
+        },
+        Test {
+            name: "not (a < 1 and rand() > 1)",
+            expr: not(col("a")
+                .lt(lit(1))
+                .and(Expression::create_scalar_function("rand", vec![]).gt(lit(1)))),
+            expect: "((max_a >= 1) or true)",
+        },
         Test {
             name: "a <= b + rand()",
             expr: add(
@@ -38,7 +38,7 @@ async fn test_configs_table() -> Result<()> {
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
     assert_eq!(block.num_columns(), 4);
-    assert_eq!(block.num_rows(), 58);
+    assert_eq!(block.num_rows(), 59);
 
     let expected = vec![
         "+--------------------------------------+------------------+---------+-------------+",
@@ -59,6 +59,7 @@ async fn test_configs_table() -> Result<()> {
         "| flight_api_address                   | 127.0.0.1:9090   | query   |             |",
         "| http_api_address                     | 127.0.0.1:8080   | query   |             |",
         "| http_handler_host                    | 127.0.0.1        | query   |             |",
+        "| http_handler_jwt_secret_file         |                  | query   |             |",
         "| http_handler_port                    | 8000             | query   |             |",
         "| http_handler_tls_server_cert         |                  | query   |             |",
         "| http_handler_tls_server_key          |                  | query   |             |",
@@ -96,10 +97,13 @@ async fn test_configs_table() -> Result<()> {
         "| table_cache_snapshot_count           | 256              | query   |             |",
         "| table_disk_cache_mb_size             | 1024             | query   |             |",
         "| table_disk_cache_root                | _cache           | query   |             |",
+        "| table_dropped_retention_secs         | 86400            | query   |             |",
         "| table_engine_csv_enabled             | false            | query   |             |",
         "| table_engine_memory_enabled          | true             | query   |             |",
         "| table_engine_parquet_enabled         | false            | query   |             |",
         "| table_memory_cache_mb_size           | 256              | query   |             |",
+        "| table_orphan_retention_secs          | 86400            | query   |             |",
+        "| table_vacuum_interval_secs           | 3600             | query   |             |",
         "| tenant_id                            |                  | query   |             |",
         "| wait_timeout_mills                   | 5000             | query   |             |",
         "+--------------------------------------+------------------+---------+-------------+",
@@ -87,6 +87,12 @@ impl SessionManagerBuilder {
         SessionManagerBuilder::inner_create(new_config)
     }
 
+    pub fn http_handler_jwt_secret_file(self, value: impl Into<String>) -> SessionManagerBuilder {
+        let mut new_config = self.config;
+        new_config.query.http_handler_jwt_secret_file = value.into();
+        SessionManagerBuilder::inner_create(new_config)
+    }
+
     pub fn api_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.api_tls_server_key = value.into();
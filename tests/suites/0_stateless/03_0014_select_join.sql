@@ -0,0 +1,10 @@
+SELECT a.number, b.number FROM numbers(3) AS a JOIN numbers(3) AS b ON a.number = b.number ORDER BY a.number;
+SELECT count() FROM numbers(10) AS a, numbers(10) AS b;
+SELECT count() FROM numbers(10) AS a CROSS JOIN numbers(5) AS b WHERE a.number = b.number;
+SELECT count() FROM numbers(5) AS a JOIN numbers(10) AS b USING(number);
+SELECT count() FROM numbers(5) AS a JOIN numbers(5) AS b ON a.number = b.number AND a.number > 2;
+SELECT a.number, b.number FROM numbers(4) AS a LEFT JOIN numbers(2) AS b ON a.number = b.number ORDER BY a.number;
+SELECT a.number, b.number FROM numbers(2) AS a RIGHT JOIN numbers(4) AS b ON a.number = b.number ORDER BY b.number;
+SELECT count(a.number), count(b.number) FROM numbers(3) AS a FULL JOIN (SELECT number + 1 AS number FROM numbers(3)) AS b ON a.number = b.number;
+SELECT a.number FROM numbers(3) AS a LEFT JOIN numbers(3) AS b ON a.number > b.number; -- {ErrorCode 1002}
+SELECT number FROM numbers(3) AS a JOIN numbers(3) AS b ON a.number = b.number; -- {ErrorCode 1058}
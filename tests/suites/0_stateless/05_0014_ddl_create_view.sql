@@ -0,0 +1,40 @@
+DROP DATABASE IF EXISTS db_view;
+CREATE DATABASE db_view;
+USE db_view;
+
+CREATE TABLE t(a INT, b INT);
+INSERT INTO t VALUES (1, 2), (3, 4), (5, 6);
+
+CREATE VIEW v AS SELECT a, b FROM t WHERE a > 1;
+SELECT * FROM v ORDER BY a;
+SELECT v.a FROM v WHERE b > 4;
+SELECT count(*) FROM v JOIN t ON v.a = t.a;
+
+CREATE VIEW v AS SELECT a FROM t; -- {ErrorCode 2302}
+CREATE VIEW IF NOT EXISTS v AS SELECT a FROM t;
+CREATE TABLE v1(a INT) ENGINE = VIEW; -- {ErrorCode 1006}
+
+SHOW CREATE TABLE v;
+SELECT name, engine FROM system.tables WHERE database = 'db_view' ORDER BY name;
+
+CREATE VIEW v2 AS SELECT a FROM (SELECT a FROM t WHERE a > 3);
+
+USE default;
+SELECT a FROM db_view.v ORDER BY a;
+SELECT a FROM db_view.v2;
+
+DROP VIEW db_view.t; -- {ErrorCode 1006}
+DROP VIEW db_view.v;
+DROP VIEW IF EXISTS db_view.v;
+DROP VIEW db_view.v; -- {ErrorCode 1025}
+DROP VIEW db_view.v2;
+
+-- The views referring to each other are never expanded.
+CREATE TABLE db_view.t0(a INT);
+CREATE VIEW db_view.v3 AS SELECT a FROM db_view.t0;
+CREATE VIEW db_view.v4 AS SELECT a FROM db_view.v3;
+DROP TABLE db_view.t0;
+RENAME TABLE db_view.v4 TO db_view.t0;
+SELECT a FROM db_view.v3; -- {ErrorCode 1065}
+
+DROP DATABASE db_view;
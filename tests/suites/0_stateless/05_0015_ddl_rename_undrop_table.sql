@@ -0,0 +1,38 @@
+DROP DATABASE IF EXISTS db_rename;
+DROP DATABASE IF EXISTS db_rename2;
+CREATE DATABASE db_rename;
+CREATE DATABASE db_rename2;
+USE db_rename;
+
+CREATE TABLE t(a INT);
+INSERT INTO t VALUES (1), (2);
+
+RENAME TABLE t TO t1;
+SELECT * FROM t; -- {ErrorCode 1025}
+SELECT sum(a) FROM t1;
+RENAME TABLE t1 TO db_rename2.t2;
+SELECT sum(a) FROM db_rename2.t2;
+
+RENAME TABLE t1 TO t3; -- {ErrorCode 1025}
+RENAME TABLE IF EXISTS t1 TO t3;
+CREATE TABLE t3(a INT);
+RENAME TABLE db_rename2.t2 TO t3; -- {ErrorCode 2302}
+RENAME TABLE system.tables TO t4; -- {ErrorCode 1002}
+
+DROP TABLE t3;
+SELECT name FROM system.tables WHERE database = 'db_rename';
+SELECT name FROM system.tables_with_history WHERE database = 'db_rename' AND dropped_on IS NOT NULL;
+
+UNDROP TABLE t3;
+UNDROP TABLE t3; -- {ErrorCode 1025}
+SELECT name FROM system.tables WHERE database = 'db_rename';
+
+DROP TABLE db_rename2.t2;
+UNDROP TABLE db_rename2.t2;
+SELECT sum(a) FROM db_rename2.t2;
+DROP TABLE db_rename2.t2;
+CREATE TABLE db_rename2.t2(b INT);
+UNDROP TABLE db_rename2.t2; -- {ErrorCode 2302}
+
+DROP DATABASE db_rename;
+DROP DATABASE db_rename2;
@@ -0,0 +1,41 @@
+DROP DATABASE IF EXISTS db_09_0009;
+CREATE DATABASE db_09_0009;
+USE db_09_0009;
+
+create table t(a uint64, b varchar);
+
+insert into t values (1, 'a'), (2, 'b'), (3, 'c');
+insert into t values (4, 'd'), (5, 'e'), (6, 'f');
+
+---------------------------
+
+-- the whole second block is deleted
+delete from t where a > 3;
+select * from t order by a;
+
+-- part of the first block is deleted
+delete from t where b = 'b';
+select * from t order by a;
+
+-- nothing is deleted
+delete from t where a > 100;
+select count(*) from t;
+
+-- expects 5 history items, 2 of previous insertion, 3 for deletion
+select count(*)=5 from fuse_history('db_09_0009', 't');
+
+-- delete all
+delete from t;
+select count(*) from t;
+
+---------------------
+
+-- delete from memory table is not supported
+create table m(a uint64) engine=Memory;
+delete from m where a = 1; -- {ErrorCode 1002}
+
+---------------------
+
+DROP TABLE t;
+DROP TABLE m;
+DROP DATABASE db_09_0009;
@@ -0,0 +1,44 @@
+DROP DATABASE IF EXISTS db_09_0010;
+CREATE DATABASE db_09_0010;
+USE db_09_0010;
+
+create table t(a uint64, b varchar);
+
+insert into t values (1, 'a'), (2, 'b'), (3, 'c');
+insert into t values (4, 'd'), (5, 'e'), (6, 'f');
+
+---------------------------
+
+-- part of the second block is updated
+update t set b = 'x', a = a + 10 where a > 4;
+select * from t order by a;
+
+-- the assignments are evaluated against the original rows
+update t set a = a * 2, b = toString(a) where b = 'a';
+select * from t order by a;
+
+-- nothing is updated
+update t set a = 0 where a > 100;
+select count(*) from t;
+
+-- update all
+update t set b = 'z';
+select count(*) from t where b = 'z';
+
+---------------------
+
+-- unknown column
+update t set c = 1; -- {ErrorCode 1058}
+
+-- duplicated column
+update t set a = 1, a = 2; -- {ErrorCode 1005}
+
+-- update memory table is not supported
+create table m(a uint64) engine=Memory;
+update m set a = 1 where a = 1; -- {ErrorCode 1002}
+
+---------------------
+
+DROP TABLE t;
+DROP TABLE m;
+DROP DATABASE db_09_0010;
@@ -0,0 +1,50 @@
+DROP DATABASE IF EXISTS db_09_0011;
+CREATE DATABASE db_09_0011;
+USE db_09_0011;
+
+create table t(a uint64);
+
+insert into t values (1), (2), (3);
+insert into t values (4), (5), (6);
+
+---------------------------
+
+-- each insertion generates a snapshot, with its timestamp
+select count(*) from fuse_history('db_09_0011', 't') where timestamp is not null;
+
+-- the latest snapshot at the time point
+select count(*) from t at (timestamp => '2999-01-01 00:00:00');
+select sum(a) from t at (timestamp => '2999-01-01T00:00:00Z');
+
+-- the table is referenced by its alias
+select sum(x.a) from t as x at (timestamp => '2999-01-01 00:00:00');
+
+---------------------
+
+-- no snapshot at the time point
+select * from t at (timestamp => '2000-01-01 00:00:00'); -- {ErrorCode 3006}
+
+-- invalid time point
+select * from t at (timestamp => 'not a time point'); -- {ErrorCode 1006}
+
+-- invalid snapshot id
+select * from t at (snapshot => 'not a snapshot id'); -- {ErrorCode 1006}
+
+-- unknown snapshot
+select * from t at (snapshot => '00000000000000000000000000000000'); -- {ErrorCode 3006}
+
+-- table hints are not time travel
+select * from t with (snapshot = '00000000000000000000000000000000'); -- {ErrorCode 1005}
+
+-- time travel of a table not in the FROM clause
+select a at (snapshot => '00000000000000000000000000000000') from t; -- {ErrorCode 1005}
+
+-- time travel of memory table is not supported
+create table m(a uint64) engine=Memory;
+select * from m at (snapshot => '00000000000000000000000000000000'); -- {ErrorCode 1002}
+
+---------------------
+
+DROP TABLE t;
+DROP TABLE m;
+DROP DATABASE db_09_0011;
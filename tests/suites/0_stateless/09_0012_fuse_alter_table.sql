@@ -0,0 +1,41 @@
+DROP DATABASE IF EXISTS db_09_0012;
+CREATE DATABASE db_09_0012;
+USE db_09_0012;
+
+create table t(a uint64);
+insert into t values (1), (2);
+
+---------------------------
+
+-- the existing rows take the default value of the added column
+alter table t add column b uint64 default 10;
+insert into t values (3, 30);
+select * from t order by a;
+
+-- the data is kept by the renamed column
+alter table t rename column b to c;
+select sum(c) from t;
+
+-- a dropped column is gone, even if a column of the same name is added again
+alter table t drop column c;
+alter table t add column c uint64;
+select * from t order by a;
+
+---------------------
+
+-- existing column
+alter table t add column a uint64; -- {ErrorCode 1006}
+
+-- unknown column
+alter table t drop column x; -- {ErrorCode 1058}
+alter table t rename column x to y; -- {ErrorCode 1058}
+
+-- alter memory table is not supported
+create table m(a uint64) engine=Memory;
+alter table m add column b uint64; -- {ErrorCode 1002}
+
+---------------------
+
+DROP TABLE t;
+DROP TABLE m;
+DROP DATABASE db_09_0012;
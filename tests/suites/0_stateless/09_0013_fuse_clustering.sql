@@ -0,0 +1,36 @@
+DROP DATABASE IF EXISTS db_09_0013;
+CREATE DATABASE db_09_0013;
+USE db_09_0013;
+
+create table t(a uint64, b uint64) cluster by (a);
+
+-- two blocks, of which the ranges of the cluster key are [1, 3] and [2, 5]
+insert into t values (3, 1), (1, 2), (2, 3);
+insert into t values (5, 1), (2, 1);
+select total_block_count, average_overlaps, average_depth from clustering_information('db_09_0013', 't');
+
+-- the overlapped blocks are re-clustered
+optimize table t compact;
+select total_block_count, average_overlaps, average_depth from clustering_information('db_09_0013', 't');
+select * from t order by a, b;
+
+---------------------
+
+-- unknown column
+create table t1(a uint64) cluster by (b); -- {ErrorCode 1058}
+
+-- only fuse tables can be clustered
+create table m(a uint64) engine=Memory cluster by (a); -- {ErrorCode 1006}
+
+-- columns of the cluster key can not be dropped
+alter table t drop column a; -- {ErrorCode 1006}
+
+-- table without cluster keys
+create table t2(a uint64);
+select * from clustering_information('db_09_0013', 't2'); -- {ErrorCode 1006}
+
+---------------------
+
+DROP TABLE t;
+DROP TABLE t2;
+DROP DATABASE db_09_0013;
@@ -0,0 +1,31 @@
+DROP DATABASE IF EXISTS db_09_0014;
+CREATE DATABASE db_09_0014;
+USE db_09_0014;
+
+create table t(id varchar, v uint64) bloom_filter_columns='id';
+
+insert into t values ('a', 1), ('m1', 1), ('z', 1);
+insert into t values ('a', 2), ('m2', 2), ('z', 2);
+insert into t values ('a', 3), ('m3', 3), ('z', 3);
+
+select * from t where id = 'm2';
+select * from t where id in ('m1', 'm3', 'x') order by v;
+select count(*) from t where id = 'x';
+select count(*) from t where id = 'a';
+
+-- the index follows the renamed column
+alter table t rename column id to uid;
+insert into t values ('m4', 4);
+select * from t where uid = 'm4';
+
+---------------------
+
+-- unknown column
+create table t1(a uint64) bloom_filter_columns='b';
+insert into t1 values (1); -- {ErrorCode 1022}
+
+---------------------
+
+DROP TABLE t;
+DROP TABLE t1;
+DROP DATABASE db_09_0014;
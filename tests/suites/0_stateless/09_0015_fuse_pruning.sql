@@ -0,0 +1,21 @@
+DROP DATABASE IF EXISTS db_09_0015;
+CREATE DATABASE db_09_0015;
+USE db_09_0015;
+
+create table t(a int, b varchar);
+
+insert into t values (1, 'apple'), (2, 'banana');
+insert into t values (10, 'cherry'), (11, 'date');
+insert into t values (20, 'egg'), (21, 'fig');
+
+select a from t where a in (2, 20) order by a;
+select a from t where a not in (1, 2, 10) order by a;
+select a from t where not (a >= 10) order by a;
+select a from t where a between 10 and 20 order by a;
+select a from t where b = 'fig' and a between 1 and 30;
+select a from t where b like 'b%';
+select a from t where b not like 'c%' and a > 5 order by a;
+select a from t where cast(a as bigint) > 15 order by a;
+
+DROP TABLE t;
+DROP DATABASE db_09_0015;
@@ -0,0 +1,22 @@
+DROP DATABASE IF EXISTS db_09_0016;
+CREATE DATABASE db_09_0016;
+USE db_09_0016;
+
+create table t(d Date, a uint64) TTL d + interval '1' day;
+
+insert into t values ('2000-01-01', 1), ('2000-01-02', 2);
+insert into t values ('2000-01-03', 3), (today(), 4);
+insert into t values (today(), 5), (today(), 6);
+
+---------------------------
+
+-- the expired rows are hidden, before they are removed
+select a from t order by a;
+select count(*) from t where a < 3;
+
+-- TTL must reference a date column of a fuse table
+create table t1(a uint64) TTL a + 1; -- {ErrorCode 1006}
+create table t1(d Date, a uint64) ENGINE = Memory TTL d + interval '1' day; -- {ErrorCode 1006}
+
+DROP TABLE t;
+DROP DATABASE db_09_0016;
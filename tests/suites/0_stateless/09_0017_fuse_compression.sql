@@ -0,0 +1,29 @@
+DROP DATABASE IF EXISTS db_09_0017;
+CREATE DATABASE db_09_0017;
+USE db_09_0017;
+
+create table t(id uint64, region varchar, payload varchar) compression='zstd' column_compression='id:none,payload:snappy' dictionary_columns='region,id' row_group_size='2';
+
+insert into t values (1, 'eu', 'a'), (2, 'us', 'b'), (3, 'eu', 'c');
+insert into t values (4, 'us', 'd'), (5, 'eu', 'e');
+
+select * from t order by id;
+select region, count(*) from t group by region order by region;
+
+-- the options of the columns follow the renamed column
+alter table t rename column region to area;
+insert into t values (6, 'us', 'f');
+select count(*) from t where area = 'us';
+
+---------------------
+
+-- invalid options
+create table t1(a uint64) compression='gzip'; -- {ErrorCode 1022}
+create table t1(a uint64) column_compression='b:zstd'; -- {ErrorCode 1022}
+create table t1(a boolean) dictionary_columns='a'; -- {ErrorCode 1022}
+create table t1(a uint64) row_group_size='0'; -- {ErrorCode 1022}
+
+---------------------
+
+DROP TABLE t;
+DROP DATABASE db_09_0017;
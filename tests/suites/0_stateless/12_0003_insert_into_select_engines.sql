@@ -0,0 +1,31 @@
+DROP DATABASE IF EXISTS db_12_0003;
+CREATE DATABASE db_12_0003;
+USE db_12_0003;
+
+CREATE TABLE src(a Int32, b Int32);
+INSERT INTO src VALUES (1, 10), (2, 20), (3, 30);
+
+-- the selected columns are casted, and the others are filled with the default values
+CREATE TABLE dst_fuse(a Int64, b String, c Int32 DEFAULT 7);
+INSERT INTO dst_fuse (a, b) SELECT a + 10, b FROM src;
+SELECT * FROM dst_fuse ORDER BY a;
+
+CREATE TABLE dst_memory(a Int64, b String, c Int32 DEFAULT 7) ENGINE = Memory;
+INSERT INTO dst_memory (a, b) SELECT a + 10, b FROM src;
+SELECT * FROM dst_memory ORDER BY a;
+
+CREATE TABLE dst_null(a Int32) ENGINE = Null;
+INSERT INTO dst_null SELECT a FROM src;
+SELECT COUNT(*) FROM dst_null;
+
+-- create table as select
+CREATE TABLE ctas_fuse AS SELECT a, b * 2 AS b2 FROM src WHERE a > 1;
+SELECT * FROM ctas_fuse ORDER BY a;
+CREATE TABLE ctas_memory ENGINE = Memory AS SELECT a, b * 2 AS b2 FROM src;
+SELECT * FROM ctas_memory ORDER BY a;
+CREATE TABLE ctas_memory ENGINE = Memory AS SELECT a FROM src; -- {ErrorCode 2302}
+-- nothing is inserted if the table exists
+CREATE TABLE IF NOT EXISTS ctas_memory ENGINE = Memory AS SELECT a, b FROM src;
+SELECT COUNT(*) FROM ctas_memory;
+
+DROP DATABASE db_12_0003;
@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../shell_env.sh
+
+
+echo "drop table if exists streaming_load_formats;" | $MYSQL_CLIENT_CONNECT
+echo "create table streaming_load_formats(a int, b varchar, c double);" | $MYSQL_CLIENT_CONNECT
+
+## ndjson, by column name
+printf '{"b": "x", "a": 1, "c": 1.5}\n{"a": 2, "b": "y"}\n' > /tmp/streaming_load_formats.ndjson
+curl -s -u root: -H "insert_sql:insert into streaming_load_formats format NDJSON" -F "upload=@/tmp/streaming_load_formats.ndjson" -XPUT http://localhost:8001/v1/streaming_load > /dev/null 2>&1
+
+## tsv, with escapes
+printf 'a\tb\tc\n3\tz\\tz\t3.5\n4\t\\N\t4.5\n' > /tmp/streaming_load_formats.tsv
+curl -s -u root: -H "insert_sql:insert into streaming_load_formats format TSV" -H "csv_header:1" -F "upload=@/tmp/streaming_load_formats.tsv" -XPUT http://localhost:8001/v1/streaming_load > /dev/null 2>&1
+
+echo "select a, b, c from streaming_load_formats order by a;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table streaming_load_formats;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/streaming_load_formats.ndjson /tmp/streaming_load_formats.tsv
@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../shell_env.sh
+
+
+echo "drop table if exists streaming_load_compression;" | $MYSQL_CLIENT_CONNECT
+echo "create table streaming_load_compression(a int, b varchar);" | $MYSQL_CLIENT_CONNECT
+
+## detected by the file extension
+printf '1,x\n2,y\n' | gzip > /tmp/streaming_load_compression.csv.gz
+curl -s -u root: -H "insert_sql:insert into streaming_load_compression format CSV" -F "upload=@/tmp/streaming_load_compression.csv.gz" -XPUT http://localhost:8001/v1/streaming_load > /dev/null 2>&1
+
+## set by the compression option
+printf '{"a": 3, "b": "z"}\n' | gzip > /tmp/streaming_load_compression.ndjson
+curl -s -u root: -H "insert_sql:insert into streaming_load_compression format NDJSON" -H "compression:gzip" -F "upload=@/tmp/streaming_load_compression.ndjson" -XPUT http://localhost:8001/v1/streaming_load > /dev/null 2>&1
+
+echo "select a, b from streaming_load_compression order by a;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table streaming_load_compression;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/streaming_load_compression.csv.gz /tmp/streaming_load_compression.ndjson
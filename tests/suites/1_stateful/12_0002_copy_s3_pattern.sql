@@ -0,0 +1,20 @@
+drop table if exists default.test_copy_pattern;
+
+create table default.test_copy_pattern (id int,name varchar(255),rank int);
+copy into default.test_copy_pattern from '@s3_stage/tests/data/copy/' format CSV PATTERN = '.*[.]csv';
+select count(), sum(id) from default.test_copy_pattern;
+
+-- the loaded files are skipped
+copy into default.test_copy_pattern from '@s3_stage/tests/data/copy/' format CSV PATTERN = '.*[.]csv';
+select count(), sum(id) from default.test_copy_pattern;
+
+drop table default.test_copy_pattern;
+
+-- bad files
+create table default.test_copy_pattern (id int,name varchar(255),rank int);
+copy into default.test_copy_pattern from '@s3_stage/tests/data/copy/' format CSV ON_ERROR = SKIP_FILE;
+select count(), sum(id) from default.test_copy_pattern;
+copy into default.test_copy_pattern from '@s3_stage/tests/data/copy/part_3.csv.bak' format CSV ON_ERROR = ABORT; -- {ErrorCode 1046}
+select count(), sum(id) from default.test_copy_pattern;
+
+drop table default.test_copy_pattern;
@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../shell_env.sh
+
+# unload to a fresh directory, the file names depend on the query id
+UNLOAD_DIR="tests/unload/$(date +%s%N)/"
+
+echo "drop table if exists default.test_copy_into_stage;" | $MYSQL_CLIENT_CONNECT
+echo "create table default.test_copy_into_stage (number bigint unsigned);" | $MYSQL_CLIENT_CONNECT
+
+for format in csv tsv json parquet; do
+    echo "copy into '@s3_stage/${UNLOAD_DIR}${format}/' from (select number from numbers(100)) file_format = (format = ${format}) max_file_size = 100;" | $MYSQL_CLIENT_CONNECT > /dev/null
+    echo "copy into default.test_copy_into_stage from '@s3_stage/${UNLOAD_DIR}${format}/' format ${format};" | $MYSQL_CLIENT_CONNECT
+    echo "select count(), sum(number) from default.test_copy_into_stage;" | $MYSQL_CLIENT_CONNECT
+done
+
+echo "drop table default.test_copy_into_stage;" | $MYSQL_CLIENT_CONNECT
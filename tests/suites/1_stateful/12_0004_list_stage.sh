@@ -0,0 +1,9 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../shell_env.sh
+
+# the etags and modification times depend on the upload, only names and sizes are checked
+echo "list @s3_stage/tests/data/copy/" | $MYSQL_CLIENT_CONNECT | cut -f1,2 | sort
+echo "list '@s3_stage/tests/data/copy/' pattern = '.*[.]csv'" | $MYSQL_CLIENT_CONNECT | cut -f1,2 | sort
+echo "list @s3_stage/tests/data/copy/part_1.csv" | $MYSQL_CLIENT_CONNECT | cut -f1,2